@@ -62,16 +62,64 @@ const docTemplate = `{
                         "description": "Filter articles written after this timestamp (RFC3339 format)",
                         "name": "since",
                         "in": "query"
+                    },
+                    {
+                        "type": "boolean",
+                        "example": false,
+                        "description": "Filter to curated articles only (requires profile_id)",
+                        "name": "curated",
+                        "in": "query"
+                    },
+                    {
+                        "type": "string",
+                        "description": "Filter by a tag assigned to the article's source",
+                        "name": "tag",
+                        "in": "query"
+                    },
+                    {
+                        "enum": [
+                            "json",
+                            "ndjson",
+                            "csv",
+                            "tsv"
+                        ],
+                        "type": "string",
+                        "description": "Response format: json (default), ndjson (one article per line), csv or tsv; has_more is in the X-Has-More header for all but json",
+                        "name": "format",
+                        "in": "query"
+                    },
+                    {
+                        "type": "string",
+                        "description": "Comma-separated CSV/TSV columns (default: uid,source_type,title,author,url,written_at)",
+                        "name": "columns",
+                        "in": "query"
+                    },
+                    {
+                        "minimum": 0,
+                        "type": "integer",
+                        "description": "Cut longer titles to this many characters, ending in …; 0 or unset keeps them whole",
+                        "name": "max_title_chars",
+                        "in": "query"
+                    },
+                    {
+                        "minimum": 0,
+                        "type": "integer",
+                        "description": "Cut longer contents to this many characters, ending in …; 0 or unset keeps them whole",
+                        "name": "max_body_chars",
+                        "in": "query"
                     }
                 ],
                 "responses": {
                     "200": {
                         "description": "OK",
                         "schema": {
-                            "type": "array",
-                            "items": {
-                                "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Article"
-                            }
+                            "$ref": "#/definitions/internal_api.ArticleListResponse"
+                        }
+                    },
+                    "400": {
+                        "description": "Invalid format, column or max_*_chars",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
                     },
                     "500": {
@@ -103,6 +151,12 @@ const docTemplate = `{
                         "name": "id",
                         "in": "path",
                         "required": true
+                    },
+                    {
+                        "type": "string",
+                        "description": "Profile ID for like status",
+                        "name": "profile_id",
+                        "in": "query"
                     }
                 ],
                 "responses": {
@@ -133,33 +187,9 @@ const docTemplate = `{
                 }
             }
         },
-        "/config": {
-            "get": {
-                "description": "Returns the global crawl schedule and rate limits that apply to all sources",
-                "produces": [
-                    "application/json"
-                ],
-                "tags": [
-                    "config"
-                ],
-                "summary": "Get global configuration",
-                "responses": {
-                    "200": {
-                        "description": "OK",
-                        "schema": {
-                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.GlobalConfig"
-                        }
-                    },
-                    "500": {
-                        "description": "Database error",
-                        "schema": {
-                            "$ref": "#/definitions/internal_api.ErrorResponse"
-                        }
-                    }
-                }
-            },
-            "patch": {
-                "description": "Updates global crawl schedule and rate limits (partial update), then hot-reloads the scheduler",
+        "/articles/{id}/like": {
+            "post": {
+                "description": "Create a like for an article, triggers character update if milestone reached",
                 "consumes": [
                     "application/json"
                 ],
@@ -167,35 +197,54 @@ const docTemplate = `{
                     "application/json"
                 ],
                 "tags": [
-                    "config"
+                    "likes"
                 ],
-                "summary": "Update global configuration",
+                "summary": "Like an article",
                 "parameters": [
                     {
-                        "description": "Configuration updates (PATCH-style partial updates)",
-                        "name": "config",
+                        "type": "string",
+                        "description": "Article ID",
+                        "name": "id",
+                        "in": "path",
+                        "required": true
+                    },
+                    {
+                        "description": "Like data",
+                        "name": "like",
                         "in": "body",
                         "required": true,
                         "schema": {
-                            "$ref": "#/definitions/internal_api.UpdateGlobalConfigRequest"
+                            "$ref": "#/definitions/internal_api.CreateLikeRequest"
                         }
                     }
                 ],
                 "responses": {
-                    "200": {
-                        "description": "OK",
+                    "201": {
+                        "description": "Created",
                         "schema": {
-                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.GlobalConfig"
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Like"
                         }
                     },
                     "400": {
-                        "description": "Invalid request body or validation error",
+                        "description": "Invalid request body",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "404": {
+                        "description": "Article not found",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "409": {
+                        "description": "Already liked",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
                     },
                     "500": {
-                        "description": "Database or scheduler error",
+                        "description": "Database error",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
@@ -232,9 +281,9 @@ const docTemplate = `{
                 }
             }
         },
-        "/metrics": {
-            "get": {
-                "description": "Get service metrics and statistics (sources, articles, errors)",
+        "/likes/{id}": {
+            "delete": {
+                "description": "Delete a like",
                 "consumes": [
                     "application/json"
                 ],
@@ -242,14 +291,26 @@ const docTemplate = `{
                     "application/json"
                 ],
                 "tags": [
-                    "monitoring"
+                    "likes"
+                ],
+                "summary": "Unlike an article",
+                "parameters": [
+                    {
+                        "type": "string",
+                        "description": "Like ID",
+                        "name": "id",
+                        "in": "path",
+                        "required": true
+                    }
                 ],
-                "summary": "Service metrics",
                 "responses": {
-                    "200": {
-                        "description": "OK",
+                    "204": {
+                        "description": "No content"
+                    },
+                    "404": {
+                        "description": "Like not found",
                         "schema": {
-                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Metrics"
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
                     },
                     "500": {
@@ -261,9 +322,9 @@ const docTemplate = `{
                 }
             }
         },
-        "/schedule": {
+        "/metrics": {
             "get": {
-                "description": "Returns the global crawl schedule (applies to all sources)",
+                "description": "Get service metrics and statistics (sources, articles, errors)",
                 "consumes": [
                     "application/json"
                 ],
@@ -271,18 +332,18 @@ const docTemplate = `{
                     "application/json"
                 ],
                 "tags": [
-                    "schedule"
+                    "monitoring"
                 ],
-                "summary": "Get global schedule information",
+                "summary": "Service metrics",
                 "responses": {
                     "200": {
                         "description": "OK",
                         "schema": {
-                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.ScheduleEntry"
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Metrics"
                         }
                     },
                     "500": {
-                        "description": "Scheduler error",
+                        "description": "Database error",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
@@ -290,9 +351,9 @@ const docTemplate = `{
                 }
             }
         },
-        "/sources": {
+        "/profiles": {
             "get": {
-                "description": "Get all configured crawling sources, optionally filtered by type",
+                "description": "Get all profiles",
                 "consumes": [
                     "application/json"
                 ],
@@ -300,28 +361,16 @@ const docTemplate = `{
                     "application/json"
                 ],
                 "tags": [
-                    "sources"
-                ],
-                "summary": "List all sources",
-                "parameters": [
-                    {
-                        "enum": [
-                            "reddit",
-                            "semantic_scholar"
-                        ],
-                        "type": "string",
-                        "description": "Filter by source type",
-                        "name": "type",
-                        "in": "query"
-                    }
+                    "profiles"
                 ],
+                "summary": "List all profiles",
                 "responses": {
                     "200": {
                         "description": "OK",
                         "schema": {
                             "type": "array",
                             "items": {
-                                "$ref": "#/definitions/internal_api.SourceResponse"
+                                "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Profile"
                             }
                         }
                     },
@@ -334,7 +383,7 @@ const docTemplate = `{
                 }
             },
             "post": {
-                "description": "Add a new source for Reddit or Semantic Scholar (schedule is global)",
+                "description": "Create a new profile with AI-generated character (async)",
                 "consumes": [
                     "application/json"
                 ],
@@ -342,17 +391,17 @@ const docTemplate = `{
                     "application/json"
                 ],
                 "tags": [
-                    "sources"
+                    "profiles"
                 ],
-                "summary": "Create a new crawling source",
+                "summary": "Create a new profile",
                 "parameters": [
                     {
-                        "description": "Source configuration",
-                        "name": "source",
+                        "description": "Profile data",
+                        "name": "profile",
                         "in": "body",
                         "required": true,
                         "schema": {
-                            "$ref": "#/definitions/internal_api.CreateSourceRequest"
+                            "$ref": "#/definitions/internal_api.CreateProfileRequest"
                         }
                     }
                 ],
@@ -360,17 +409,11 @@ const docTemplate = `{
                     "201": {
                         "description": "Created",
                         "schema": {
-                            "$ref": "#/definitions/internal_api.SourceResponse"
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Profile"
                         }
                     },
                     "400": {
-                        "description": "Invalid request body, type, or config",
-                        "schema": {
-                            "$ref": "#/definitions/internal_api.ErrorResponse"
-                        }
-                    },
-                    "409": {
-                        "description": "Source with this configuration already exists",
+                        "description": "Invalid request body",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
@@ -384,9 +427,9 @@ const docTemplate = `{
                 }
             }
         },
-        "/sources/{id}": {
+        "/profiles/{id}": {
             "get": {
-                "description": "Retrieve a specific source by its UUID",
+                "description": "Get a specific profile",
                 "consumes": [
                     "application/json"
                 ],
@@ -394,13 +437,13 @@ const docTemplate = `{
                     "application/json"
                 ],
                 "tags": [
-                    "sources"
+                    "profiles"
                 ],
-                "summary": "Get a source by ID",
+                "summary": "Get a profile by ID",
                 "parameters": [
                     {
                         "type": "string",
-                        "description": "Source ID (UUID)",
+                        "description": "Profile ID",
                         "name": "id",
                         "in": "path",
                         "required": true
@@ -410,11 +453,11 @@ const docTemplate = `{
                     "200": {
                         "description": "OK",
                         "schema": {
-                            "$ref": "#/definitions/internal_api.SourceResponse"
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Profile"
                         }
                     },
                     "404": {
-                        "description": "Source not found",
+                        "description": "Profile not found",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
@@ -427,8 +470,8 @@ const docTemplate = `{
                     }
                 }
             },
-            "put": {
-                "description": "Update source configuration. Schedule is global and managed separately.",
+            "delete": {
+                "description": "Delete a profile and all associated data",
                 "consumes": [
                     "application/json"
                 ],
@@ -436,24 +479,63 @@ const docTemplate = `{
                     "application/json"
                 ],
                 "tags": [
-                    "sources"
+                    "profiles"
                 ],
-                "summary": "Update a source",
+                "summary": "Delete a profile",
                 "parameters": [
                     {
                         "type": "string",
-                        "description": "Source ID (UUID)",
+                        "description": "Profile ID",
+                        "name": "id",
+                        "in": "path",
+                        "required": true
+                    }
+                ],
+                "responses": {
+                    "204": {
+                        "description": "No content"
+                    },
+                    "404": {
+                        "description": "Profile not found",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "500": {
+                        "description": "Database error",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            },
+            "patch": {
+                "description": "Update profile nickname and/or user description",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "profiles"
+                ],
+                "summary": "Update a profile",
+                "parameters": [
+                    {
+                        "type": "string",
+                        "description": "Profile ID",
                         "name": "id",
                         "in": "path",
                         "required": true
                     },
                     {
-                        "description": "Updated source configuration",
-                        "name": "source",
+                        "description": "Profile updates",
+                        "name": "profile",
                         "in": "body",
                         "required": true,
                         "schema": {
-                            "$ref": "#/definitions/internal_api.UpdateSourceRequest"
+                            "$ref": "#/definitions/internal_api.UpdateProfileRequest"
                         }
                     }
                 ],
@@ -461,17 +543,17 @@ const docTemplate = `{
                     "200": {
                         "description": "OK",
                         "schema": {
-                            "$ref": "#/definitions/internal_api.SourceResponse"
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Profile"
                         }
                     },
                     "400": {
-                        "description": "Invalid request body or config",
+                        "description": "Invalid request body",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
                     },
                     "404": {
-                        "description": "Source not found",
+                        "description": "Profile not found",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
@@ -483,34 +565,36 @@ const docTemplate = `{
                         }
                     }
                 }
-            },
-            "delete": {
-                "description": "Remove source by UUID. Cascades to delete associated articles and comments.",
-                "consumes": [
-                    "application/json"
-                ],
+            }
+        },
+        "/profiles/{id}/status": {
+            "get": {
+                "description": "Get only the character generation status for efficient polling",
                 "produces": [
                     "application/json"
                 ],
                 "tags": [
-                    "sources"
+                    "profiles"
                 ],
-                "summary": "Delete a source by ID",
+                "summary": "Get profile character generation status",
                 "parameters": [
                     {
                         "type": "string",
-                        "description": "Source ID (UUID)",
+                        "description": "Profile ID",
                         "name": "id",
                         "in": "path",
                         "required": true
                     }
                 ],
                 "responses": {
-                    "204": {
-                        "description": "Source deleted successfully"
+                    "200": {
+                        "description": "OK",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ProfileStatusResponse"
+                        }
                     },
                     "404": {
-                        "description": "Source not found",
+                        "description": "Profile not found",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
@@ -524,9 +608,9 @@ const docTemplate = `{
                 }
             }
         },
-        "/sources/{type}/{external_id}": {
-            "delete": {
-                "description": "Alternative deletion method using type and external identifier (subreddit name, query, or paper ID)",
+        "/schedule": {
+            "get": {
+                "description": "Returns the crawl schedule of a job (default: the default job, which applies to all sources unless source patterns narrow it)",
                 "consumes": [
                     "application/json"
                 ],
@@ -534,253 +618,1324 @@ const docTemplate = `{
                     "application/json"
                 ],
                 "tags": [
-                    "sources"
+                    "schedule"
                 ],
-                "summary": "Delete a source by type and external ID",
+                "summary": "Get global schedule information",
                 "parameters": [
-                    {
-                        "enum": [
-                            "reddit",
-                            "semantic_scholar"
-                        ],
-                        "type": "string",
-                        "description": "Source type",
-                        "name": "type",
-                        "in": "path",
-                        "required": true
-                    },
                     {
                         "type": "string",
-                        "description": "External identifier (URL-encode if contains special characters)",
-                        "name": "external_id",
-                        "in": "path",
-                        "required": true
+                        "description": "Job name from COLLECTOR_JOBS (default: default)",
+                        "name": "job",
+                        "in": "query"
                     }
                 ],
                 "responses": {
-                    "204": {
-                        "description": "Source deleted successfully"
-                    },
-                    "400": {
-                        "description": "Invalid type or external_id contains slashes",
+                    "200": {
+                        "description": "OK",
                         "schema": {
-                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.ScheduleEntry"
                         }
                     },
                     "404": {
-                        "description": "Source not found",
+                        "description": "Unknown job",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
                     },
                     "500": {
-                        "description": "Database error",
+                        "description": "Scheduler error",
                         "schema": {
                             "$ref": "#/definitions/internal_api.ErrorResponse"
                         }
                     }
                 }
             }
-        }
-    },
-    "definitions": {
-        "github_com_cheolwanpark_meows_collector_internal_db.Article": {
-            "description": "Crawled article from Reddit or Semantic Scholar",
-            "type": "object",
-            "properties": {
-                "author": {
+        },
+        "/schedule/last-run": {
+            "get": {
+                "description": "Returns per-source outcomes (status, counts, timing, HTTP requests, retries and 429s) of a job's current or most recent global run, and its most active and highest-scoring authors when COLLECTOR_REPORT_TOP_AUTHORS is set",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "schedule"
+                ],
+                "summary": "Get the latest crawl run report",
+                "parameters": [
+                    {
+                        "type": "string",
+                        "description": "Job name from COLLECTOR_JOBS (default: default)",
+                        "name": "job",
+                        "in": "query"
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "schema": {
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_scheduler.RunReport"
+                        }
+                    },
+                    "404": {
+                        "description": "Unknown job",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            }
+        },
+        "/sources": {
+            "get": {
+                "description": "Get all configured crawling sources, optionally filtered by type or by a \"type:external_id\" pattern",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "sources"
+                ],
+                "summary": "List all sources",
+                "parameters": [
+                    {
+                        "enum": [
+                            "reddit",
+                            "semantic_scholar",
+                            "hackernews",
+                            "github",
+                            "stackexchange",
+                            "bluesky",
+                            "openalex",
+                            "pubmed",
+                            "arxiv",
+                            "discourse",
+                            "nitter",
+                            "google_scholar",
+                            "web",
+                            "json_api",
+                            "news",
+                            "wikipedia",
+                            "kaggle",
+                            "podcast",
+                            "huggingface",
+                            "package_registry",
+                            "imap",
+                            "file",
+                            "feed",
+                            "zenodo"
+                        ],
+                        "type": "string",
+                        "description": "Filter by source type",
+                        "name": "type",
+                        "in": "query"
+                    },
+                    {
+                        "type": "string",
+                        "description": "Filter by source pattern, e.g. reddit:* or semantic_scholar:bulk:*",
+                        "name": "match",
+                        "in": "query"
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "schema": {
+                            "type": "array",
+                            "items": {
+                                "$ref": "#/definitions/internal_api.SourceResponse"
+                            }
+                        }
+                    },
+                    "400": {
+                        "description": "Invalid source pattern",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "500": {
+                        "description": "Database error",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            },
+            "post": {
+                "description": "Add a new source for Reddit, Semantic Scholar, or Hacker News (schedule is global)",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "sources"
+                ],
+                "summary": "Create a new crawling source",
+                "parameters": [
+                    {
+                        "description": "Source configuration",
+                        "name": "source",
+                        "in": "body",
+                        "required": true,
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.CreateSourceRequest"
+                        }
+                    }
+                ],
+                "responses": {
+                    "201": {
+                        "description": "Created",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.SourceResponse"
+                        }
+                    },
+                    "400": {
+                        "description": "Invalid request body, type, or config",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "409": {
+                        "description": "Source with this configuration already exists",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "500": {
+                        "description": "Database error",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            }
+        },
+        "/sources/{id}": {
+            "get": {
+                "description": "Retrieve a specific source by its UUID",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "sources"
+                ],
+                "summary": "Get a source by ID",
+                "parameters": [
+                    {
+                        "type": "string",
+                        "description": "Source ID (UUID)",
+                        "name": "id",
+                        "in": "path",
+                        "required": true
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.SourceResponse"
+                        }
+                    },
+                    "404": {
+                        "description": "Source not found",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "500": {
+                        "description": "Database error",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            },
+            "put": {
+                "description": "Update source configuration. Schedule is global and managed separately.",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "sources"
+                ],
+                "summary": "Update a source",
+                "parameters": [
+                    {
+                        "type": "string",
+                        "description": "Source ID (UUID)",
+                        "name": "id",
+                        "in": "path",
+                        "required": true
+                    },
+                    {
+                        "description": "Updated source configuration",
+                        "name": "source",
+                        "in": "body",
+                        "required": true,
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.UpdateSourceRequest"
+                        }
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.SourceResponse"
+                        }
+                    },
+                    "400": {
+                        "description": "Invalid request body or config",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "404": {
+                        "description": "Source not found",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "500": {
+                        "description": "Database error",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            },
+            "delete": {
+                "description": "Remove source by UUID. Cascades to delete associated articles and comments.",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "sources"
+                ],
+                "summary": "Delete a source by ID",
+                "parameters": [
+                    {
+                        "type": "string",
+                        "description": "Source ID (UUID)",
+                        "name": "id",
+                        "in": "path",
+                        "required": true
+                    }
+                ],
+                "responses": {
+                    "204": {
+                        "description": "Source deleted successfully"
+                    },
+                    "404": {
+                        "description": "Source not found",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "500": {
+                        "description": "Database error",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            }
+        },
+        "/sources/{id}/trigger": {
+            "post": {
+                "description": "Manually triggers an immediate fetch for the specified source (fire-and-forget)",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "sources"
+                ],
+                "summary": "Trigger immediate crawl for a single source",
+                "parameters": [
+                    {
+                        "type": "string",
+                        "description": "Source ID (UUID)",
+                        "name": "id",
+                        "in": "path",
+                        "required": true
+                    }
+                ],
+                "responses": {
+                    "202": {
+                        "description": "Crawl triggered successfully",
+                        "schema": {
+                            "type": "object",
+                            "additionalProperties": {
+                                "type": "string"
+                            }
+                        }
+                    },
+                    "404": {
+                        "description": "Source not found",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "409": {
+                        "description": "Source is already running",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "500": {
+                        "description": "Failed to trigger crawl",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            }
+        },
+        "/sources/{type}/{external_id}": {
+            "delete": {
+                "description": "Alternative deletion method using type and external identifier (subreddit name, query, or paper ID)",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "sources"
+                ],
+                "summary": "Delete a source by type and external ID",
+                "parameters": [
+                    {
+                        "enum": [
+                            "reddit",
+                            "semantic_scholar",
+                            "hackernews",
+                            "github",
+                            "stackexchange",
+                            "bluesky",
+                            "openalex",
+                            "pubmed",
+                            "arxiv",
+                            "discourse",
+                            "nitter",
+                            "google_scholar",
+                            "web",
+                            "json_api",
+                            "news",
+                            "wikipedia",
+                            "kaggle",
+                            "podcast",
+                            "huggingface",
+                            "package_registry",
+                            "imap",
+                            "file",
+                            "feed",
+                            "zenodo"
+                        ],
+                        "type": "string",
+                        "description": "Source type",
+                        "name": "type",
+                        "in": "path",
+                        "required": true
+                    },
+                    {
+                        "type": "string",
+                        "description": "External identifier (URL-encode if contains special characters)",
+                        "name": "external_id",
+                        "in": "path",
+                        "required": true
+                    }
+                ],
+                "responses": {
+                    "204": {
+                        "description": "Source deleted successfully"
+                    },
+                    "400": {
+                        "description": "Invalid type or external_id contains slashes",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "404": {
+                        "description": "Source not found",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "500": {
+                        "description": "Database error",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            }
+        },
+        "/store/export": {
+            "get": {
+                "description": "Streams every source's crawl cursor (last run and last success) followed by every stored article as NDJSON, for POST /store/import on another instance. Source configs are not included",
+                "produces": [
+                    "application/x-ndjson"
+                ],
+                "tags": [
+                    "monitoring"
+                ],
+                "summary": "Export crawl state",
+                "parameters": [
+                    {
+                        "type": "string",
+                        "description": "Limit the export to one profile (UUID)",
+                        "name": "profile_id",
+                        "in": "query"
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "One record per line",
+                        "schema": {
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.StateRecord"
+                        }
+                    },
+                    "400": {
+                        "description": "Invalid profile_id",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            }
+        },
+        "/store/import": {
+            "post": {
+                "description": "Applies an NDJSON export from GET /store/export, or articles from GET /articles?format=ndjson, in one transaction. Records are matched to existing sources by type, external ID and profile; records of missing sources are skipped. Source cursors only move forward and existing articles are left untouched",
+                "consumes": [
+                    "application/x-ndjson"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "monitoring"
+                ],
+                "summary": "Import crawl state",
+                "parameters": [
+                    {
+                        "type": "string",
+                        "description": "Match records to this profile's sources instead of their own (UUID)",
+                        "name": "profile_id",
+                        "in": "query"
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "schema": {
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.ImportResult"
+                        }
+                    },
+                    "400": {
+                        "description": "Invalid profile_id or malformed record",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            }
+        },
+        "/store/prune": {
+            "post": {
+                "description": "Removes articles stored more than older_than_days ago (default: COLLECTOR_RETENTION_DAYS), with their comments and curation entries, then compacts the database file if enough of it is unused. Liked articles are kept",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "monitoring"
+                ],
+                "summary": "Prune and compact the database",
+                "parameters": [
+                    {
+                        "minimum": 1,
+                        "type": "integer",
+                        "description": "Retention period in days (default: COLLECTOR_RETENTION_DAYS; 0 there means compaction only)",
+                        "name": "older_than_days",
+                        "in": "query"
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.PruneStoreResponse"
+                        }
+                    },
+                    "400": {
+                        "description": "Invalid older_than_days",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "409": {
+                        "description": "A crawl is running",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    },
+                    "500": {
+                        "description": "Database error",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            }
+        },
+        "/store/stats": {
+            "get": {
+                "description": "Row counts, the oldest stored article and the size of the database file, including unused space compaction would reclaim",
+                "consumes": [
+                    "application/json"
+                ],
+                "produces": [
+                    "application/json"
+                ],
+                "tags": [
+                    "monitoring"
+                ],
+                "summary": "Database statistics",
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "schema": {
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.StoreStats"
+                        }
+                    },
+                    "500": {
+                        "description": "Database error",
+                        "schema": {
+                            "$ref": "#/definitions/internal_api.ErrorResponse"
+                        }
+                    }
+                }
+            }
+        }
+    },
+    "definitions": {
+        "github_com_cheolwanpark_meows_collector_internal_db.Comment": {
+            "type": "object",
+            "properties": {
+                "article_id": {
+                    "type": "string"
+                },
+                "author": {
+                    "type": "string"
+                },
+                "content": {
+                    "type": "string"
+                },
+                "depth": {
+                    "description": "Reddit comment depth",
+                    "type": "integer"
+                },
+                "external_id": {
+                    "type": "string"
+                },
+                "id": {
+                    "type": "string"
+                },
+                "parent_id": {
+                    "description": "NULL for top-level",
+                    "type": "string"
+                },
+                "written_at": {
+                    "type": "string"
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_db.HealthStatus": {
+            "description": "Service health status",
+            "type": "object",
+            "properties": {
+                "database": {
                     "type": "string",
-                    "example": "user123"
+                    "example": "ok"
+                },
+                "scheduler": {
+                    "type": "string",
+                    "example": "ok"
+                },
+                "status": {
+                    "type": "string",
+                    "enum": [
+                        "healthy",
+                        "unhealthy"
+                    ],
+                    "example": "healthy"
+                },
+                "timestamp": {
+                    "type": "string",
+                    "example": "2024-11-15T12:00:00Z"
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_db.ImportResult": {
+            "description": "Outcome of a state import",
+            "type": "object",
+            "properties": {
+                "articles": {
+                    "description": "Articles inserted",
+                    "type": "integer",
+                    "example": 15230
+                },
+                "existing": {
+                    "description": "Articles already present, left untouched",
+                    "type": "integer",
+                    "example": 40
+                },
+                "skipped": {
+                    "description": "Records whose source doesn't exist here",
+                    "type": "integer",
+                    "example": 3
+                },
+                "sources": {
+                    "description": "Source cursors moved forward",
+                    "type": "integer",
+                    "example": 12
+                },
+                "unknown_sources": {
+                    "description": "type:external_id of those sources",
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_db.Like": {
+            "description": "Article like by a profile",
+            "type": "object",
+            "properties": {
+                "article_id": {
+                    "type": "string",
+                    "example": "770e8400-e29b-41d4-a716-446655440002"
+                },
+                "created_at": {
+                    "type": "string",
+                    "example": "2024-11-15T12:00:00Z"
+                },
+                "id": {
+                    "type": "string",
+                    "example": "550e8400-e29b-41d4-a716-446655440000"
+                },
+                "profile_id": {
+                    "type": "string",
+                    "example": "660e8400-e29b-41d4-a716-446655440001"
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_db.Metrics": {
+            "description": "Service metrics and statistics",
+            "type": "object",
+            "properties": {
+                "articles_today": {
+                    "type": "integer",
+                    "example": 45
+                },
+                "last_crawl": {
+                    "type": "string",
+                    "example": "2024-11-15T12:00:00Z"
+                },
+                "sources_with_errors": {
+                    "type": "integer",
+                    "example": 1
+                },
+                "timestamp": {
+                    "type": "string",
+                    "example": "2024-11-15T12:05:00Z"
+                },
+                "total_articles": {
+                    "type": "integer",
+                    "example": 1523
+                },
+                "total_sources": {
+                    "type": "integer",
+                    "example": 10
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_db.Profile": {
+            "description": "User profile with AI-generated character description",
+            "type": "object",
+            "properties": {
+                "character": {
+                    "type": "string",
+                    "example": "A curious developer who enjoys diving deep into systems programming"
+                },
+                "character_error": {
+                    "type": "string"
+                },
+                "character_status": {
+                    "type": "string",
+                    "enum": [
+                        "pending",
+                        "ready",
+                        "updating",
+                        "error"
+                    ],
+                    "example": "ready"
+                },
+                "created_at": {
+                    "type": "string",
+                    "example": "2024-11-15T12:00:00Z"
+                },
+                "id": {
+                    "type": "string",
+                    "example": "550e8400-e29b-41d4-a716-446655440000"
+                },
+                "milestone": {
+                    "type": "string",
+                    "enum": [
+                        "init",
+                        "3",
+                        "10",
+                        "20",
+                        "weekly"
+                    ],
+                    "example": "init"
+                },
+                "nickname": {
+                    "type": "string",
+                    "example": "Tech Enthusiast"
+                },
+                "updated_at": {
+                    "type": "string",
+                    "example": "2024-11-15T12:00:00Z"
+                },
+                "user_description": {
+                    "type": "string",
+                    "example": "I love reading about Go and distributed systems"
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_db.PruneResult": {
+            "description": "Rows removed by a prune",
+            "type": "object",
+            "properties": {
+                "articles": {
+                    "type": "integer",
+                    "format": "int64",
+                    "example": 1200
+                },
+                "comments": {
+                    "type": "integer",
+                    "format": "int64",
+                    "example": 8300
+                },
+                "curated": {
+                    "type": "integer",
+                    "format": "int64",
+                    "example": 45
+                },
+                "cutoff": {
+                    "description": "Articles stored before this were removed",
+                    "type": "string",
+                    "example": "2024-08-15T00:00:00Z"
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_db.ScheduleEntry": {
+            "description": "Scheduled crawl job information",
+            "type": "object",
+            "properties": {
+                "last_run_at": {
+                    "type": "string",
+                    "example": "2024-11-15T12:00:00Z"
+                },
+                "next_run": {
+                    "type": "string",
+                    "example": "2024-11-15T18:00:00Z"
+                },
+                "source_id": {
+                    "type": "string",
+                    "example": "550e8400-e29b-41d4-a716-446655440000"
+                },
+                "source_type": {
+                    "type": "string",
+                    "enum": [
+                        "reddit",
+                        "semantic_scholar",
+                        "hackernews",
+                        "github",
+                        "stackexchange",
+                        "bluesky",
+                        "openalex",
+                        "pubmed",
+                        "arxiv",
+                        "discourse",
+                        "nitter",
+                        "google_scholar",
+                        "web",
+                        "json_api",
+                        "news",
+                        "wikipedia",
+                        "kaggle",
+                        "podcast",
+                        "huggingface",
+                        "package_registry",
+                        "imap",
+                        "file",
+                        "feed",
+                        "zenodo"
+                    ],
+                    "example": "reddit"
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_db.StateRecord": {
+            "type": "object",
+            "properties": {
+                "author": {
+                    "type": "string"
+                },
+                "content": {
+                    "type": "string"
+                },
+                "created_at": {
+                    "type": "string"
+                },
+                "external_id": {
+                    "type": "string"
+                },
+                "last_run_at": {
+                    "type": "string"
+                },
+                "last_success_at": {
+                    "description": "Where the next incremental fetch of the source starts",
+                    "type": "string"
+                },
+                "metadata": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer"
+                    }
+                },
+                "profile_id": {
+                    "type": "string"
+                },
+                "record": {
+                    "description": "\"source\" or \"article\"; lines without it, as from GET /articles?format=ndjson, are articles",
+                    "type": "string"
+                },
+                "source_external_id": {
+                    "type": "string"
+                },
+                "source_id": {
+                    "description": "Fallback for lines without source_external_id",
+                    "type": "string"
+                },
+                "source_type": {
+                    "type": "string"
+                },
+                "title": {
+                    "type": "string"
+                },
+                "url": {
+                    "type": "string"
+                },
+                "written_at": {
+                    "type": "string"
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_db.StoreStats": {
+            "description": "Database size and row counts",
+            "type": "object",
+            "properties": {
+                "articles": {
+                    "type": "integer",
+                    "example": 15230
+                },
+                "comments": {
+                    "type": "integer",
+                    "example": 80412
+                },
+                "curated": {
+                    "type": "integer",
+                    "example": 1204
+                },
+                "free_bytes": {
+                    "description": "Unused pages that compaction would reclaim",
+                    "type": "integer",
+                    "format": "int64",
+                    "example": 4194304
+                },
+                "likes": {
+                    "type": "integer",
+                    "example": 37
+                },
+                "oldest_article": {
+                    "description": "When the oldest stored article was stored",
+                    "type": "string",
+                    "example": "2024-01-02T12:00:00Z"
+                },
+                "size_bytes": {
+                    "description": "Database file size, without the WAL",
+                    "type": "integer",
+                    "format": "int64",
+                    "example": 104857600
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_scheduler.AuthorReport": {
+            "type": "object",
+            "properties": {
+                "most_active": {
+                    "description": "By articles, then score",
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_scheduler.AuthorStat"
+                    }
+                },
+                "top_scoring": {
+                    "description": "By score, then articles; authors without a score are left out",
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_scheduler.AuthorStat"
+                    }
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_scheduler.AuthorStat": {
+            "type": "object",
+            "properties": {
+                "articles": {
+                    "type": "integer"
                 },
-                "content": {
-                    "type": "string",
-                    "example": "This is the article content..."
+                "author": {
+                    "type": "string"
                 },
-                "created_at": {
-                    "type": "string",
-                    "example": "2024-11-15T12:00:00Z"
+                "score": {
+                    "description": "Sum of the articles' metadata score (upvotes, stars, citations, ...), where the source sets one",
+                    "type": "integer"
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_scheduler.FilterDiagnostic": {
+            "type": "object",
+            "properties": {
+                "exclude_keywords": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
                 },
-                "external_id": {
-                    "description": "Reddit post ID / S2 paper ID",
-                    "type": "string",
-                    "example": "abc123"
+                "fetched": {
+                    "type": "integer"
                 },
-                "id": {
-                    "type": "string",
-                    "example": "550e8400-e29b-41d4-a716-446655440000"
+                "include_keywords": {
+                    "description": "Source, watchlist and query keywords combined",
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
                 },
-                "metadata": {
+                "near_misses": {
+                    "description": "Closest dropped articles, best first",
                     "type": "array",
                     "items": {
-                        "type": "integer"
+                        "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_source.KeywordNearMiss"
                     }
+                }
+            }
+        },
+        "github_com_cheolwanpark_meows_collector_internal_scheduler.RunReport": {
+            "type": "object",
+            "properties": {
+                "authors": {
+                    "description": "Across every source of the run, set when COLLECTOR_REPORT_TOP_AUTHORS is",
+                    "allOf": [
+                        {
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_scheduler.AuthorReport"
+                        }
+                    ]
                 },
-                "source_id": {
-                    "type": "string",
-                    "example": "660e8400-e29b-41d4-a716-446655440001"
+                "finished_at": {
+                    "description": "Nil while the run is in progress",
+                    "type": "string"
                 },
-                "title": {
-                    "type": "string",
-                    "example": "Understanding Go Concurrency"
+                "job": {
+                    "type": "string"
                 },
-                "url": {
-                    "type": "string",
-                    "example": "https://reddit.com/r/golang/comments/abc123"
+                "outcomes": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_scheduler.SourceOutcome"
+                    }
                 },
-                "written_at": {
-                    "type": "string",
-                    "example": "2024-11-15T08:00:00Z"
+                "run_id": {
+                    "type": "string"
+                },
+                "started_at": {
+                    "type": "string"
                 }
             }
         },
-        "github_com_cheolwanpark_meows_collector_internal_db.Comment": {
+        "github_com_cheolwanpark_meows_collector_internal_scheduler.SourceOutcome": {
             "type": "object",
             "properties": {
-                "article_id": {
-                    "type": "string"
+                "articles": {
+                    "type": "integer"
                 },
-                "author": {
+                "authors": {
+                    "description": "Set when COLLECTOR_REPORT_TOP_AUTHORS is",
+                    "allOf": [
+                        {
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_scheduler.AuthorReport"
+                        }
+                    ]
+                },
+                "comments": {
+                    "type": "integer"
+                },
+                "duration_ms": {
+                    "type": "integer",
+                    "format": "int64"
+                },
+                "error": {
+                    "description": "Failure or skip reason",
                     "type": "string"
                 },
-                "content": {
+                "filter_diagnostic": {
+                    "description": "Set when keyword filters dropped every fetched article",
+                    "allOf": [
+                        {
+                            "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_scheduler.FilterDiagnostic"
+                        }
+                    ]
+                },
+                "http": {
+                    "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_source.RequestStats"
+                },
+                "run_id": {
+                    "description": "The run that crawled the source; manual triggers have their own",
                     "type": "string"
                 },
-                "depth": {
-                    "description": "Reddit comment depth",
-                    "type": "integer"
+                "source_id": {
+                    "type": "string"
                 },
-                "external_id": {
+                "source_key": {
+                    "description": "\"type:external_id\", as matched by source patterns",
                     "type": "string"
                 },
-                "id": {
+                "source_type": {
                     "type": "string"
                 },
-                "parent_id": {
-                    "description": "NULL for top-level",
+                "started_at": {
                     "type": "string"
                 },
-                "written_at": {
+                "status": {
+                    "description": "\"succeeded\", \"failed\" or \"skipped\"",
                     "type": "string"
                 }
             }
         },
-        "github_com_cheolwanpark_meows_collector_internal_db.GlobalConfig": {
+        "github_com_cheolwanpark_meows_collector_internal_source.KeywordNearMiss": {
             "type": "object",
             "properties": {
-                "cron_expr": {
+                "excluded_by": {
+                    "description": "Exclude keyword that dropped the article",
                     "type": "string"
                 },
-                "id": {
-                    "type": "integer"
+                "matched": {
+                    "description": "Include keyword words found in the title or content",
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    }
                 },
-                "reddit_rate_limit_delay_ms": {
-                    "type": "integer"
+                "overlap": {
+                    "description": "Share of include keyword words found, 1 when an include keyword matched",
+                    "type": "number"
                 },
-                "semantic_scholar_rate_limit_delay_ms": {
-                    "type": "integer"
+                "title": {
+                    "type": "string"
                 },
-                "updated_at": {
+                "url": {
                     "type": "string"
                 }
             }
         },
-        "github_com_cheolwanpark_meows_collector_internal_db.HealthStatus": {
-            "description": "Service health status",
+        "github_com_cheolwanpark_meows_collector_internal_source.RequestStats": {
             "type": "object",
             "properties": {
-                "database": {
-                    "type": "string",
-                    "example": "ok"
+                "mirror_switches": {
+                    "description": "Times a fallback mirror or host replaced the active one",
+                    "type": "integer"
                 },
-                "scheduler": {
-                    "type": "string",
-                    "example": "ok"
+                "rate_limited": {
+                    "description": "429 responses, including ones that were retried",
+                    "type": "integer"
                 },
-                "status": {
-                    "type": "string",
-                    "enum": [
-                        "healthy",
-                        "unhealthy"
-                    ],
-                    "example": "healthy"
+                "requests": {
+                    "description": "Each request counts once, however often it was retried",
+                    "type": "integer"
                 },
-                "timestamp": {
-                    "type": "string",
-                    "example": "2024-11-15T12:00:00Z"
+                "retries": {
+                    "description": "Extra attempts made by the retry transport",
+                    "type": "integer"
                 }
             }
         },
-        "github_com_cheolwanpark_meows_collector_internal_db.Metrics": {
-            "description": "Service metrics and statistics",
+        "internal_api.ArticleDetailResponse": {
             "type": "object",
             "properties": {
-                "articles_today": {
-                    "type": "integer",
-                    "example": 45
+                "article": {
+                    "$ref": "#/definitions/internal_api.ArticleWithLikeStatus"
                 },
-                "last_crawl": {
-                    "type": "string",
-                    "example": "2024-11-15T12:00:00Z"
+                "comments": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Comment"
+                    }
                 },
-                "sources_with_errors": {
-                    "type": "integer",
-                    "example": 1
+                "source_type": {
+                    "description": "\"reddit\" or \"semantic_scholar\"",
+                    "type": "string"
+                }
+            }
+        },
+        "internal_api.ArticleListResponse": {
+            "description": "Paginated list of articles with has-more indicator",
+            "type": "object",
+            "properties": {
+                "articles": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#/definitions/internal_api.ArticleWithLikeStatus"
+                    }
                 },
-                "timestamp": {
-                    "type": "string",
-                    "example": "2024-11-15T12:05:00Z"
+                "has_more": {
+                    "type": "boolean"
                 },
-                "total_articles": {
-                    "type": "integer",
-                    "example": 1523
+                "limit": {
+                    "type": "integer"
                 },
-                "total_sources": {
-                    "type": "integer",
-                    "example": 10
+                "offset": {
+                    "type": "integer"
                 }
             }
         },
-        "github_com_cheolwanpark_meows_collector_internal_db.ScheduleEntry": {
-            "description": "Scheduled crawl job information",
+        "internal_api.ArticleWithLikeStatus": {
+            "description": "Article with like status for a specific profile",
             "type": "object",
             "properties": {
-                "last_run_at": {
+                "author": {
+                    "type": "string",
+                    "example": "user123"
+                },
+                "content": {
+                    "type": "string",
+                    "example": "This is the article content..."
+                },
+                "created_at": {
                     "type": "string",
                     "example": "2024-11-15T12:00:00Z"
                 },
-                "next_run": {
+                "external_id": {
+                    "description": "Reddit post ID / S2 paper ID",
                     "type": "string",
-                    "example": "2024-11-15T18:00:00Z"
+                    "example": "abc123"
                 },
-                "source_id": {
+                "id": {
+                    "type": "string",
+                    "example": "550e8400-e29b-41d4-a716-446655440000"
+                },
+                "like_id": {
                     "type": "string",
                     "example": "550e8400-e29b-41d4-a716-446655440000"
                 },
+                "liked": {
+                    "type": "boolean",
+                    "example": true
+                },
+                "metadata": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer"
+                    }
+                },
+                "profile_id": {
+                    "type": "string",
+                    "example": "770e8400-e29b-41d4-a716-446655440002"
+                },
+                "source_id": {
+                    "type": "string",
+                    "example": "660e8400-e29b-41d4-a716-446655440001"
+                },
                 "source_type": {
                     "type": "string",
-                    "enum": [
-                        "reddit",
-                        "semantic_scholar"
-                    ],
                     "example": "reddit"
+                },
+                "title": {
+                    "type": "string",
+                    "example": "Understanding Go Concurrency"
+                },
+                "uid": {
+                    "description": "ArticleUID of the source type and external ID; derived, not a column",
+                    "type": "string",
+                    "example": "9f86d081884c7d659a2feaa0c55ad015"
+                },
+                "url": {
+                    "type": "string",
+                    "example": "https://reddit.com/r/golang/comments/abc123"
+                },
+                "written_at": {
+                    "type": "string",
+                    "example": "2024-11-15T08:00:00Z"
                 }
             }
         },
-        "internal_api.ArticleDetailResponse": {
+        "internal_api.CreateLikeRequest": {
+            "description": "Request body for liking an article",
             "type": "object",
             "properties": {
-                "article": {
-                    "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Article"
-                },
-                "comments": {
-                    "type": "array",
-                    "items": {
-                        "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.Comment"
-                    }
+                "profile_id": {
+                    "type": "string",
+                    "example": "550e8400-e29b-41d4-a716-446655440000"
+                }
+            }
+        },
+        "internal_api.CreateProfileRequest": {
+            "description": "Request body for creating a new profile",
+            "type": "object",
+            "properties": {
+                "nickname": {
+                    "type": "string",
+                    "example": "Tech Enthusiast"
                 },
-                "source_type": {
-                    "description": "\"reddit\" or \"semantic_scholar\"",
-                    "type": "string"
+                "user_description": {
+                    "type": "string",
+                    "example": "I love reading about Go and distributed systems"
                 }
             }
         },
@@ -794,11 +1949,37 @@ const docTemplate = `{
                         "type": "integer"
                     }
                 },
+                "profile_id": {
+                    "type": "string",
+                    "example": "550e8400-e29b-41d4-a716-446655440000"
+                },
                 "type": {
                     "type": "string",
                     "enum": [
                         "reddit",
-                        "semantic_scholar"
+                        "semantic_scholar",
+                        "hackernews",
+                        "github",
+                        "stackexchange",
+                        "bluesky",
+                        "openalex",
+                        "pubmed",
+                        "arxiv",
+                        "discourse",
+                        "nitter",
+                        "google_scholar",
+                        "web",
+                        "json_api",
+                        "news",
+                        "wikipedia",
+                        "kaggle",
+                        "podcast",
+                        "huggingface",
+                        "package_registry",
+                        "imap",
+                        "file",
+                        "feed",
+                        "zenodo"
                     ],
                     "example": "reddit"
                 }
@@ -814,6 +1995,31 @@ const docTemplate = `{
                 }
             }
         },
+        "internal_api.ProfileStatusResponse": {
+            "type": "object",
+            "properties": {
+                "character_error": {
+                    "type": "string"
+                },
+                "character_status": {
+                    "type": "string"
+                }
+            }
+        },
+        "internal_api.PruneStoreResponse": {
+            "description": "Rows pruned and bytes reclaimed by POST /store/prune",
+            "type": "object",
+            "properties": {
+                "pruned": {
+                    "$ref": "#/definitions/github_com_cheolwanpark_meows_collector_internal_db.PruneResult"
+                },
+                "reclaimed_bytes": {
+                    "type": "integer",
+                    "format": "int64",
+                    "example": 52428800
+                }
+            }
+        },
         "internal_api.SourceResponse": {
             "description": "Source response with sanitized configuration (credentials omitted)",
             "type": "object",
@@ -858,27 +2064,45 @@ const docTemplate = `{
                     "type": "string",
                     "enum": [
                         "reddit",
-                        "semantic_scholar"
+                        "semantic_scholar",
+                        "hackernews",
+                        "github",
+                        "stackexchange",
+                        "bluesky",
+                        "openalex",
+                        "pubmed",
+                        "arxiv",
+                        "discourse",
+                        "nitter",
+                        "google_scholar",
+                        "web",
+                        "json_api",
+                        "news",
+                        "wikipedia",
+                        "kaggle",
+                        "podcast",
+                        "huggingface",
+                        "package_registry",
+                        "imap",
+                        "file",
+                        "feed",
+                        "zenodo"
                     ],
                     "example": "reddit"
                 }
             }
         },
-        "internal_api.UpdateGlobalConfigRequest": {
-            "description": "Request body for updating global configuration (PATCH-style partial updates)",
+        "internal_api.UpdateProfileRequest": {
+            "description": "Request body for updating a profile",
             "type": "object",
             "properties": {
-                "cron_expr": {
+                "nickname": {
                     "type": "string",
-                    "example": "0 */6 * * *"
+                    "example": "Tech Enthusiast"
                 },
-                "reddit_rate_limit_delay_ms": {
-                    "type": "integer",
-                    "example": 2000
-                },
-                "semantic_scholar_rate_limit_delay_ms": {
-                    "type": "integer",
-                    "example": 1000
+                "user_description": {
+                    "type": "string",
+                    "example": "I love reading about Go and distributed systems"
                 }
             }
         },
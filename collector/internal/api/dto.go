@@ -3,6 +3,7 @@ package api
 import (
 	"encoding/json"
 	"fmt"
+	"strings"
 	"time"
 
 	"github.com/cheolwanpark/meows/collector/internal/db"
@@ -14,7 +15,7 @@ import (
 // @Description Source response with sanitized configuration (credentials omitted)
 type SourceResponse struct {
 	ID            string     `json:"id" example:"550e8400-e29b-41d4-a716-446655440000"`
-	Type          string     `json:"type" example:"reddit" enums:"reddit,semantic_scholar,hackernews"`
+	Type          string     `json:"type" example:"reddit" enums:"reddit,semantic_scholar,hackernews,github,stackexchange,bluesky,openalex,pubmed,arxiv,discourse,nitter,google_scholar,web,json_api,news,wikipedia,kaggle,podcast,huggingface,package_registry,imap,file,feed,zenodo"`
 	ConfigSummary string     `json:"config_summary" example:"subreddit: golang, sort: hot, limit: 100"`
 	ExternalID    string     `json:"external_id" example:"golang"`
 	LastRunAt     *time.Time `json:"last_run_at,omitempty" example:"2024-11-15T12:00:00Z"`
@@ -34,7 +35,7 @@ type ErrorResponse struct {
 // Schedule is now global (configured separately)
 // @Description Request body for creating a new crawling source
 type CreateSourceRequest struct {
-	Type      string          `json:"type" example:"reddit" enums:"reddit,semantic_scholar,hackernews"`
+	Type      string          `json:"type" example:"reddit" enums:"reddit,semantic_scholar,hackernews,github,stackexchange,bluesky,openalex,pubmed,arxiv,discourse,nitter,google_scholar,web,json_api,news,wikipedia,kaggle,podcast,huggingface,package_registry,imap,file,feed,zenodo"`
 	Config    json.RawMessage `json:"config"`
 	ProfileID string          `json:"profile_id" example:"550e8400-e29b-41d4-a716-446655440000"`
 }
@@ -70,20 +71,36 @@ func extractConfigSummary(sourceType string, config json.RawMessage) string {
 		if err := json.Unmarshal(config, &redditConfig); err != nil {
 			return "invalid config"
 		}
-		return fmt.Sprintf("subreddit: %s, sort: %s, limit: %d",
-			redditConfig.Subreddit, redditConfig.Sort, redditConfig.Limit)
+		if redditConfig.FetchMode == "comments" && redditConfig.PostID != "" {
+			return fmt.Sprintf("comments of post: %s, limit: %d", redditConfig.PostID, redditConfig.Limit)
+		}
+		listing := "subreddit: " + redditConfig.Subreddit
+		if redditConfig.User != "" {
+			listing = "user: " + redditConfig.User
+		}
+		if redditConfig.FetchMode == "comments" {
+			return fmt.Sprintf("%s, mode: comments, limit: %d", listing, redditConfig.Limit)
+		}
+		return fmt.Sprintf("%s, sort: %s, limit: %d",
+			listing, redditConfig.Sort, redditConfig.Limit)
 
 	case "semantic_scholar":
 		var s2Config db.SemanticScholarConfig
 		if err := json.Unmarshal(config, &s2Config); err != nil {
 			return "invalid config"
 		}
-		if s2Config.Mode == "search" && s2Config.Query != nil {
+		if (s2Config.Mode == "search" || s2Config.Mode == "bulk") && len(s2Config.Query) > 0 {
 			return fmt.Sprintf("query: %s, mode: %s, max_results: %d",
-				*s2Config.Query, s2Config.Mode, s2Config.MaxResults)
+				s2Config.Query, s2Config.Mode, s2Config.MaxResults)
 		} else if s2Config.Mode == "recommendations" && s2Config.PaperID != nil {
 			return fmt.Sprintf("paper_id: %s, mode: %s, max_results: %d",
 				*s2Config.PaperID, s2Config.Mode, s2Config.MaxResults)
+		} else if s2Config.Mode == "author" && s2Config.AuthorID != nil {
+			return fmt.Sprintf("author_id: %s, mode: %s, max_results: %d",
+				*s2Config.AuthorID, s2Config.Mode, s2Config.MaxResults)
+		} else if s2Config.Mode == "dataset" && s2Config.DatasetPath != nil {
+			return fmt.Sprintf("dataset: %s, mode: %s, max_results: %d",
+				*s2Config.DatasetPath, s2Config.Mode, s2Config.MaxResults)
 		}
 		return fmt.Sprintf("mode: %s, max_results: %d",
 			s2Config.Mode, s2Config.MaxResults)
@@ -100,6 +117,216 @@ func extractConfigSummary(sourceType string, config json.RawMessage) string {
 		return fmt.Sprintf("%s stories (limit: %d, comments: %s)",
 			hnConfig.ItemType, hnConfig.Limit, comments)
 
+	case "github":
+		var ghConfig db.GitHubConfig
+		if err := json.Unmarshal(config, &ghConfig); err != nil {
+			return "invalid config"
+		}
+		if ghConfig.Mode == "search" {
+			return fmt.Sprintf("query: %s, mode: %s, limit: %d",
+				ghConfig.Query, ghConfig.Mode, ghConfig.Limit)
+		}
+		return fmt.Sprintf("repo: %s, mode: %s, limit: %d",
+			ghConfig.Repo, ghConfig.Mode, ghConfig.Limit)
+
+	case "stackexchange":
+		var seConfig db.StackExchangeConfig
+		if err := json.Unmarshal(config, &seConfig); err != nil {
+			return "invalid config"
+		}
+		return fmt.Sprintf("site: %s, tags: %s, sort: %s, limit: %d",
+			seConfig.Site, strings.Join(seConfig.Tags, ";"), seConfig.Sort, seConfig.Limit)
+
+	case "bluesky":
+		var bskyConfig db.BlueskyConfig
+		if err := json.Unmarshal(config, &bskyConfig); err != nil {
+			return "invalid config"
+		}
+		target := bskyConfig.Query
+		if bskyConfig.Mode == "author" {
+			target = bskyConfig.Actor
+		} else if bskyConfig.Mode == "feed" {
+			target = bskyConfig.FeedURI
+		}
+		return fmt.Sprintf("%s: %s, limit: %d", bskyConfig.Mode, target, bskyConfig.Limit)
+
+	case "openalex":
+		var oaConfig db.OpenAlexConfig
+		if err := json.Unmarshal(config, &oaConfig); err != nil {
+			return "invalid config"
+		}
+		return fmt.Sprintf("query: %s, sort: %s, max_results: %d",
+			oaConfig.Query, oaConfig.Sort, oaConfig.MaxResults)
+
+	case "pubmed":
+		var pmConfig db.PubMedConfig
+		if err := json.Unmarshal(config, &pmConfig); err != nil {
+			return "invalid config"
+		}
+		return fmt.Sprintf("query: %s, sort: %s, max_results: %d",
+			pmConfig.Query, pmConfig.Sort, pmConfig.MaxResults)
+
+	case "arxiv":
+		var arxivConfig db.ArxivConfig
+		if err := json.Unmarshal(config, &arxivConfig); err != nil {
+			return "invalid config"
+		}
+		names := make([]string, len(arxivConfig.Categories))
+		for i, c := range arxivConfig.Categories {
+			names[i] = c.Name
+		}
+		return fmt.Sprintf("categories: %s, cross-lists: %v",
+			strings.Join(names, ", "), arxivConfig.IncludeCrossLists)
+
+	case "discourse":
+		var discourseConfig db.DiscourseConfig
+		if err := json.Unmarshal(config, &discourseConfig); err != nil {
+			return "invalid config"
+		}
+		category := discourseConfig.Category
+		if category == "" {
+			category = "latest"
+		}
+		return fmt.Sprintf("forum: %s, category: %s, limit: %d",
+			discourseConfig.BaseURL, category, discourseConfig.Limit)
+
+	case "nitter":
+		var nitterConfig db.NitterConfig
+		if err := json.Unmarshal(config, &nitterConfig); err != nil {
+			return "invalid config"
+		}
+		return fmt.Sprintf("query: %s, min_likes: %d, limit: %d",
+			nitterConfig.Query, nitterConfig.MinLikes, nitterConfig.Limit)
+
+	case "google_scholar":
+		var gsConfig db.GoogleScholarConfig
+		if err := json.Unmarshal(config, &gsConfig); err != nil {
+			return "invalid config"
+		}
+		return fmt.Sprintf("query: %s, years: %d-%d, max_results: %d",
+			gsConfig.Query, gsConfig.YearLow, gsConfig.YearHigh, gsConfig.MaxResults)
+
+	case "web":
+		var webConfig db.WebConfig
+		if err := json.Unmarshal(config, &webConfig); err != nil {
+			return "invalid config"
+		}
+		if webConfig.SitemapURL != "" {
+			return fmt.Sprintf("sitemap: %s, limit: %d", webConfig.SitemapURL, webConfig.Limit)
+		}
+		return fmt.Sprintf("pages: %d, limit: %d", len(webConfig.URLs), webConfig.Limit)
+
+	case "json_api":
+		var jsonAPIConfig db.JSONAPIConfig
+		if err := json.Unmarshal(config, &jsonAPIConfig); err != nil {
+			return "invalid config"
+		}
+		return fmt.Sprintf("url: %s, items: %s, limit: %d",
+			jsonAPIConfig.URL, jsonAPIConfig.ItemsPath, jsonAPIConfig.Limit)
+
+	case "news":
+		var newsConfig db.NewsConfig
+		if err := json.Unmarshal(config, &newsConfig); err != nil {
+			return "invalid config"
+		}
+		provider := newsConfig.Provider
+		if provider == "" {
+			provider = "gdelt"
+		}
+		return fmt.Sprintf("%s: %s, language: %s, limit: %d",
+			provider, newsConfig.Query, newsConfig.Language, newsConfig.Limit)
+
+	case "wikipedia":
+		var wikipediaConfig db.WikipediaConfig
+		if err := json.Unmarshal(config, &wikipediaConfig); err != nil {
+			return "invalid config"
+		}
+		if wikipediaConfig.Mode == "recent_changes" {
+			return fmt.Sprintf("recent changes: %d pages, language: %s, limit: %d",
+				len(wikipediaConfig.Pages), wikipediaConfig.Language, wikipediaConfig.Limit)
+		}
+		return fmt.Sprintf("search: %s, language: %s, limit: %d",
+			wikipediaConfig.Query, wikipediaConfig.Language, wikipediaConfig.Limit)
+
+	case "kaggle":
+		var kaggleConfig db.KaggleConfig
+		if err := json.Unmarshal(config, &kaggleConfig); err != nil {
+			return "invalid config"
+		}
+		kind := kaggleConfig.Kind
+		if kind == "" {
+			kind = "datasets"
+		}
+		return fmt.Sprintf("%s: %s, sort: %s, limit: %d",
+			kind, kaggleConfig.Search, kaggleConfig.Sort, kaggleConfig.Limit)
+
+	case "podcast":
+		var podcastConfig db.PodcastConfig
+		if err := json.Unmarshal(config, &podcastConfig); err != nil {
+			return "invalid config"
+		}
+		if podcastConfig.Query == "" {
+			return fmt.Sprintf("feeds: %d, limit: %d", len(podcastConfig.FeedIDs), podcastConfig.Limit)
+		}
+		return fmt.Sprintf("search: %s, limit: %d", podcastConfig.Query, podcastConfig.Limit)
+
+	case "huggingface":
+		var huggingFaceConfig db.HuggingFaceConfig
+		if err := json.Unmarshal(config, &huggingFaceConfig); err != nil {
+			return "invalid config"
+		}
+		kind := huggingFaceConfig.Kind
+		if kind == "" {
+			kind = "models"
+		}
+		return fmt.Sprintf("%s: %s, tag: %s, author: %s, sort: %s, limit: %d",
+			kind, huggingFaceConfig.Search, huggingFaceConfig.Tag, huggingFaceConfig.Author, huggingFaceConfig.Sort, huggingFaceConfig.Limit)
+
+	case "package_registry":
+		var packageRegistryConfig db.PackageRegistryConfig
+		if err := json.Unmarshal(config, &packageRegistryConfig); err != nil {
+			return "invalid config"
+		}
+		return fmt.Sprintf("%s: %s, min downloads: %d",
+			packageRegistryConfig.Registry, strings.Join(packageRegistryConfig.Keywords, ", "), packageRegistryConfig.MinDownloads)
+
+	case "imap":
+		var imapConfig db.ImapConfig
+		if err := json.Unmarshal(config, &imapConfig); err != nil {
+			return "invalid config"
+		}
+		folder := imapConfig.Folder
+		if folder == "" {
+			folder = "INBOX"
+		}
+		return fmt.Sprintf("folder: %s, from: %s, limit: %d",
+			folder, strings.Join(imapConfig.From, ", "), imapConfig.Limit)
+
+	case "file":
+		var fileConfig db.FileConfig
+		if err := json.Unmarshal(config, &fileConfig); err != nil {
+			return "invalid config"
+		}
+		return fmt.Sprintf("path: %s", fileConfig.Path)
+
+	case "feed":
+		var feedConfig db.FeedConfig
+		if err := json.Unmarshal(config, &feedConfig); err != nil {
+			return "invalid config"
+		}
+		if feedConfig.OPMLPath != "" {
+			return fmt.Sprintf("opml: %s, limit: %d", feedConfig.OPMLPath, feedConfig.Limit)
+		}
+		return fmt.Sprintf("feeds: %d, limit: %d", len(feedConfig.URLs), feedConfig.Limit)
+
+	case "zenodo":
+		var zenodoConfig db.ZenodoConfig
+		if err := json.Unmarshal(config, &zenodoConfig); err != nil {
+			return "invalid config"
+		}
+		return fmt.Sprintf("query: %s, communities: %s, limit: %d",
+			zenodoConfig.Query, strings.Join(zenodoConfig.Communities, ","), zenodoConfig.Limit)
+
 	default:
 		return "unknown type"
 	}
@@ -142,3 +369,10 @@ type ArticleListResponse struct {
 	Limit    int                     `json:"limit"`
 	Offset   int                     `json:"offset"`
 }
+
+// PruneStoreResponse reports a manual prune and compaction
+// @Description Rows pruned and bytes reclaimed by POST /store/prune
+type PruneStoreResponse struct {
+	Pruned         *db.PruneResult `json:"pruned,omitempty"`
+	ReclaimedBytes int64           `json:"reclaimed_bytes" example:"52428800"`
+}
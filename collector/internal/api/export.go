@@ -0,0 +1,180 @@
+package api
+
+import (
+	"encoding/csv"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"strconv"
+	"strings"
+	"time"
+	"unicode/utf8"
+)
+
+// truncationMarker ends a title or body cut short by max_title_chars or max_body_chars
+const truncationMarker = "…"
+
+// articleFormats are the values GET /articles accepts for format
+var articleFormats = []string{"json", "ndjson", "csv", "tsv"}
+
+// articleColumns maps the column names of CSV/TSV exports to article fields
+var articleColumns = map[string]func(a ArticleWithLikeStatus) string{
+	"id":          func(a ArticleWithLikeStatus) string { return a.ID },
+	"uid":         func(a ArticleWithLikeStatus) string { return a.UID },
+	"source_id":   func(a ArticleWithLikeStatus) string { return a.SourceID },
+	"source_type": func(a ArticleWithLikeStatus) string { return a.SourceType },
+	"external_id": func(a ArticleWithLikeStatus) string { return a.ExternalID },
+	"title":       func(a ArticleWithLikeStatus) string { return a.Title },
+	"author":      func(a ArticleWithLikeStatus) string { return a.Author },
+	"content":     func(a ArticleWithLikeStatus) string { return a.Content },
+	"url":         func(a ArticleWithLikeStatus) string { return a.URL },
+	"written_at":  func(a ArticleWithLikeStatus) string { return a.WrittenAt.UTC().Format(time.RFC3339) },
+	"created_at":  func(a ArticleWithLikeStatus) string { return a.CreatedAt.UTC().Format(time.RFC3339) },
+	"liked":       func(a ArticleWithLikeStatus) string { return strconv.FormatBool(a.Liked) },
+	"metadata":    func(a ArticleWithLikeStatus) string { return string(a.Metadata) },
+}
+
+// defaultArticleColumns are exported when columns is not set; content is left out to keep rows spreadsheet-sized
+var defaultArticleColumns = []string{"uid", "source_type", "title", "author", "url", "written_at"}
+
+// parseArticleColumns splits a comma-separated columns parameter and checks every name
+func parseArticleColumns(param string) ([]string, error) {
+	if strings.TrimSpace(param) == "" {
+		return defaultArticleColumns, nil
+	}
+	var columns []string
+	for _, name := range strings.Split(param, ",") {
+		name = strings.TrimSpace(name)
+		if _, ok := articleColumns[name]; !ok {
+			return nil, fmt.Errorf("unknown column %q", name)
+		}
+		columns = append(columns, name)
+	}
+	return columns, nil
+}
+
+// validArticleFormat reports whether format is one of articleFormats ("" means json)
+func validArticleFormat(format string) bool {
+	if format == "" {
+		return true
+	}
+	for _, f := range articleFormats {
+		if f == format {
+			return true
+		}
+	}
+	return false
+}
+
+// writeArticlesNDJSON writes one article per line, for piping into jq, log shippers or message queues
+// has_more moves to the X-Has-More header since the body has no envelope
+func writeArticlesNDJSON(w http.ResponseWriter, articles []ArticleWithLikeStatus, hasMore bool) {
+	w.Header().Set("Content-Type", "application/x-ndjson")
+	w.Header().Set("X-Has-More", strconv.FormatBool(hasMore))
+
+	encoder := json.NewEncoder(w) // Encode terminates every value with a newline
+	for _, article := range articles {
+		if err := encoder.Encode(article); err != nil {
+			slog.Error("Failed to encode NDJSON article", "article_id", article.ID, "error", err)
+			return
+		}
+	}
+}
+
+// writeArticlesTable writes articles as CSV (or TSV with comma '\t'), with a header row of the column names
+// Quoting follows RFC 4180, so fields with separators, quotes or newlines survive a spreadsheet import
+func writeArticlesTable(w http.ResponseWriter, articles []ArticleWithLikeStatus, hasMore bool, columns []string, comma rune) {
+	contentType, extension := "text/csv; charset=utf-8", "csv"
+	if comma == '\t' {
+		contentType, extension = "text/tab-separated-values; charset=utf-8", "tsv"
+	}
+	w.Header().Set("Content-Type", contentType)
+	w.Header().Set("Content-Disposition", fmt.Sprintf(`attachment; filename="articles.%s"`, extension))
+	w.Header().Set("X-Has-More", strconv.FormatBool(hasMore))
+
+	writer := csv.NewWriter(w)
+	writer.Comma = comma
+	if err := writer.Write(columns); err != nil {
+		slog.Error("Failed to write article table header", "error", err)
+		return
+	}
+	row := make([]string, len(columns))
+	for _, article := range articles {
+		for i, column := range columns {
+			row[i] = articleColumns[column](article)
+		}
+		if err := writer.Write(row); err != nil {
+			slog.Error("Failed to write article table row", "article_id", article.ID, "error", err)
+			return
+		}
+	}
+	writer.Flush()
+	if err := writer.Error(); err != nil {
+		slog.Error("Failed to flush article table", "error", err)
+	}
+}
+
+// parseMaxChars reads a max_*_chars parameter; unset or 0 means no limit
+func parseMaxChars(name, value string) (int, error) {
+	if value == "" {
+		return 0, nil
+	}
+	n, err := strconv.Atoi(value)
+	if err != nil || n < 0 {
+		return 0, fmt.Errorf("%s must be a non-negative integer", name)
+	}
+	return n, nil
+}
+
+// truncateArticles shortens titles and contents longer than the limits (0 = no limit), counted in characters,
+// and records their original lengths in a "truncated" metadata object
+// Only the response is affected; stored articles and state exports stay whole
+func truncateArticles(articles []ArticleWithLikeStatus, maxTitle, maxBody int) {
+	for i := range articles {
+		originals := make(map[string]int)
+		var length int
+		if articles[i].Title, length = truncateText(articles[i].Title, maxTitle); length > 0 {
+			originals["title"] = length
+		}
+		if articles[i].Content, length = truncateText(articles[i].Content, maxBody); length > 0 {
+			originals["content"] = length
+		}
+		if len(originals) > 0 {
+			articles[i].Metadata = withTruncation(articles[i].Metadata, originals)
+		}
+	}
+}
+
+// truncateText cuts text to limit characters, the last being truncationMarker
+// Returns the original length in characters when text was cut, 0 otherwise
+func truncateText(text string, limit int) (string, int) {
+	length := utf8.RuneCountInString(text)
+	if limit <= 0 || length <= limit {
+		return text, 0
+	}
+	kept := 0
+	for offset := range text {
+		if kept == limit-1 {
+			return text[:offset] + truncationMarker, length
+		}
+		kept++
+	}
+	return text, 0
+}
+
+// withTruncation adds the "truncated" object to article metadata; metadata that isn't a JSON object is left as is
+func withTruncation(metadata json.RawMessage, originals map[string]int) json.RawMessage {
+	fields := make(map[string]interface{})
+	if len(metadata) > 0 {
+		if err := json.Unmarshal(metadata, &fields); err != nil {
+			return metadata
+		}
+	}
+	fields["truncated"] = originals
+	updated, err := json.Marshal(fields)
+	if err != nil {
+		return metadata
+	}
+	return updated
+}
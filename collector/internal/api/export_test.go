@@ -0,0 +1,102 @@
+package api
+
+import (
+	"bufio"
+	"encoding/csv"
+	"encoding/json"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestWriteArticlesNDJSON(t *testing.T) {
+	articles := []ArticleWithLikeStatus{
+		{Article: db.Article{ID: "a1", Title: "First\nline"}, SourceType: "reddit"},
+		{Article: db.Article{ID: "a2", Title: "Second"}, SourceType: "hackernews"},
+	}
+
+	w := httptest.NewRecorder()
+	writeArticlesNDJSON(w, articles, true)
+
+	if ct := w.Header().Get("Content-Type"); ct != "application/x-ndjson" {
+		t.Errorf("Expected application/x-ndjson, got %q", ct)
+	}
+	if w.Header().Get("X-Has-More") != "true" {
+		t.Errorf("Expected X-Has-More: true, got %q", w.Header().Get("X-Has-More"))
+	}
+
+	var ids []string
+	scanner := bufio.NewScanner(w.Body)
+	for scanner.Scan() {
+		var article ArticleWithLikeStatus
+		if err := json.Unmarshal(scanner.Bytes(), &article); err != nil {
+			t.Fatalf("Line is not a JSON article: %v", err)
+		}
+		ids = append(ids, article.ID)
+	}
+	if len(ids) != 2 || ids[0] != "a1" || ids[1] != "a2" {
+		t.Errorf("Expected one line per article, got %v", ids)
+	}
+}
+
+func TestWriteArticlesTable(t *testing.T) {
+	articles := []ArticleWithLikeStatus{
+		{Article: db.Article{ID: "a1", Title: "Quotes \"and\", commas", URL: "https://example.com"}, SourceType: "reddit"},
+	}
+
+	columns, err := parseArticleColumns("id, title,url")
+	if err != nil {
+		t.Fatalf("parseArticleColumns failed: %v", err)
+	}
+	if _, err := parseArticleColumns("id,score"); err == nil {
+		t.Error("Expected an unknown column to be rejected")
+	}
+
+	w := httptest.NewRecorder()
+	writeArticlesTable(w, articles, false, columns, ',')
+
+	records, err := csv.NewReader(w.Body).ReadAll()
+	if err != nil {
+		t.Fatalf("Output is not valid CSV: %v", err)
+	}
+	if len(records) != 2 || records[0][1] != "title" || records[1][1] != `Quotes "and", commas` || records[1][2] != "https://example.com" {
+		t.Errorf("Unexpected records: %q", records)
+	}
+
+	w = httptest.NewRecorder()
+	writeArticlesTable(w, articles, false, columns, '\t')
+	if ct := w.Header().Get("Content-Type"); ct != "text/tab-separated-values; charset=utf-8" {
+		t.Errorf("Expected a TSV content type, got %q", ct)
+	}
+}
+
+func TestTruncateArticles(t *testing.T) {
+	articles := []ArticleWithLikeStatus{
+		{Article: db.Article{ID: "a1", Title: "Short", Content: "héllo wörld", Metadata: json.RawMessage(`{"score":3}`)}},
+		{Article: db.Article{ID: "a2", Title: "Kept", Content: "tiny"}},
+	}
+
+	truncateArticles(articles, 10, 5)
+
+	if articles[0].Title != "Short" || articles[0].Content != "héll…" {
+		t.Errorf("Unexpected truncation: %q %q", articles[0].Title, articles[0].Content)
+	}
+	var metadata struct {
+		Score     int            `json:"score"`
+		Truncated map[string]int `json:"truncated"`
+	}
+	if err := json.Unmarshal(articles[0].Metadata, &metadata); err != nil {
+		t.Fatal(err)
+	}
+	if metadata.Score != 3 || len(metadata.Truncated) != 1 || metadata.Truncated["content"] != 11 {
+		t.Errorf("Expected the original content length in metadata, got %s", articles[0].Metadata)
+	}
+	if articles[1].Content != "tiny" || articles[1].Metadata != nil {
+		t.Errorf("Expected a short article to be left alone, got %+v", articles[1])
+	}
+
+	if _, err := parseMaxChars("max_body_chars", "-1"); err == nil {
+		t.Error("Expected a negative limit to be rejected")
+	}
+}
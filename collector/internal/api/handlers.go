@@ -1,11 +1,17 @@
 package api
 
 import (
+	"crypto/sha256"
 	"database/sql"
+	"encoding/hex"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"log/slog"
 	"net/http"
+	"net/url"
+	"path"
+	"sort"
 	"strconv"
 	"strings"
 	"time"
@@ -13,6 +19,7 @@ import (
 	"github.com/cheolwanpark/meows/collector/internal/db"
 	"github.com/cheolwanpark/meows/collector/internal/personalization"
 	"github.com/cheolwanpark/meows/collector/internal/scheduler"
+	"github.com/cheolwanpark/meows/collector/internal/source"
 	"github.com/go-chi/chi/v5"
 	"github.com/google/uuid"
 )
@@ -54,8 +61,8 @@ func (h *Handler) CreateSource(w http.ResponseWriter, r *http.Request) {
 	}
 
 	// Validate type
-	if req.Type != "reddit" && req.Type != "semantic_scholar" && req.Type != "hackernews" {
-		respondError(w, http.StatusBadRequest, "type must be 'reddit', 'semantic_scholar', or 'hackernews'")
+	if !source.IsValidType(req.Type) {
+		respondError(w, http.StatusBadRequest, "type must be one of: "+strings.Join(source.Types, ", "))
 		return
 	}
 
@@ -72,8 +79,14 @@ func (h *Handler) CreateSource(w http.ResponseWriter, r *http.Request) {
 		return
 	}
 
+	// Validate options shared by all source types (e.g. tags)
+	if _, err := source.ParseOptions(req.Config); err != nil {
+		respondError(w, http.StatusBadRequest, fmt.Sprintf("invalid config: %v", err))
+		return
+	}
+
 	// Create source
-	source := &db.Source{
+	src := &db.Source{
 		ID:         uuid.New().String(),
 		Type:       req.Type,
 		Config:     req.Config,
@@ -87,7 +100,7 @@ func (h *Handler) CreateSource(w http.ResponseWriter, r *http.Request) {
 	_, err = h.db.Exec(`
 		INSERT INTO sources (id, type, config, external_id, profile_id, status, created_at)
 		VALUES (?, ?, ?, ?, ?, ?, ?)
-	`, source.ID, source.Type, source.Config, source.ExternalID, source.ProfileID, source.Status, source.CreatedAt)
+	`, src.ID, src.Type, src.Config, src.ExternalID, src.ProfileID, src.Status, src.CreatedAt)
 
 	if err != nil {
 		// Check for unique constraint violation
@@ -102,24 +115,27 @@ func (h *Handler) CreateSource(w http.ResponseWriter, r *http.Request) {
 	// No need to register job - scheduler runs all sources on global schedule
 
 	w.WriteHeader(http.StatusCreated)
-	if err := json.NewEncoder(w).Encode(toSourceResponse(source)); err != nil {
+	if err := json.NewEncoder(w).Encode(toSourceResponse(src)); err != nil {
 		slog.Error("Failed to encode response", "error", err)
 	}
 }
 
 // ListSources godoc
 // @Summary List all sources
-// @Description Get all configured crawling sources, optionally filtered by type
+// @Description Get all configured crawling sources, optionally filtered by type or by a "type:external_id" pattern
 // @Tags sources
 // @Accept json
 // @Produce json
-// @Param type query string false "Filter by source type" Enums(reddit, semantic_scholar, hackernews)
+// @Param type query string false "Filter by source type" Enums(reddit, semantic_scholar, hackernews, github, stackexchange, bluesky, openalex, pubmed, arxiv, discourse, nitter, google_scholar, web, json_api, news, wikipedia, kaggle, podcast, huggingface, package_registry, imap, file, feed, zenodo)
+// @Param match query string false "Filter by source pattern, e.g. reddit:* or semantic_scholar:bulk:*"
 // @Success 200 {array} SourceResponse
+// @Failure 400 {object} ErrorResponse "Invalid source pattern"
 // @Failure 500 {object} ErrorResponse "Database error"
 // @Router /sources [get]
 func (h *Handler) ListSources(w http.ResponseWriter, r *http.Request) {
 	sourceType := r.URL.Query().Get("type")
 	profileID := r.URL.Query().Get("profile_id")
+	pattern := r.URL.Query().Get("match")
 
 	// Require profile_id for authorization
 	if profileID == "" {
@@ -127,6 +143,13 @@ func (h *Handler) ListSources(w http.ResponseWriter, r *http.Request) {
 		return
 	}
 
+	if pattern != "" {
+		if err := source.ValidatePattern(pattern); err != nil {
+			respondError(w, http.StatusBadRequest, err.Error())
+			return
+		}
+	}
+
 	query := "SELECT id, type, config, external_id, last_run_at, last_success_at, last_error, status, created_at FROM sources WHERE profile_id = ?"
 	args := []interface{}{profileID}
 
@@ -169,6 +192,10 @@ func (h *Handler) ListSources(w http.ResponseWriter, r *http.Request) {
 		if externalID.Valid {
 			src.ExternalID = externalID.String
 		}
+		// Patterns can't be expressed in SQL, so they are matched here
+		if pattern != "" && !source.MatchPattern(pattern, source.SourceKey(src.Type, src.ExternalID)) {
+			continue
+		}
 		if lastRunAt.Valid {
 			src.LastRunAt = &lastRunAt.Time
 		}
@@ -328,6 +355,10 @@ func (h *Handler) UpdateSource(w http.ResponseWriter, r *http.Request) {
 			respondError(w, http.StatusBadRequest, fmt.Sprintf("invalid config: %v", err))
 			return
 		}
+		if _, err := source.ParseOptions(*req.Config); err != nil {
+			respondError(w, http.StatusBadRequest, fmt.Sprintf("invalid config: %v", err))
+			return
+		}
 		src.Config = *req.Config
 		src.ExternalID = newExternalID
 	}
@@ -403,7 +434,7 @@ func (h *Handler) DeleteSource(w http.ResponseWriter, r *http.Request) {
 // @Tags sources
 // @Accept json
 // @Produce json
-// @Param type path string true "Source type" Enums(reddit, semantic_scholar, hackernews)
+// @Param type path string true "Source type" Enums(reddit, semantic_scholar, hackernews, github, stackexchange, bluesky, openalex, pubmed, arxiv, discourse, nitter, google_scholar, web, json_api, news, wikipedia, kaggle, podcast, huggingface, package_registry, imap, file, feed, zenodo)
 // @Param external_id path string true "External identifier (URL-encode if contains special characters)"
 // @Success 204 "Source deleted successfully"
 // @Failure 400 {object} ErrorResponse "Invalid type or external_id contains slashes"
@@ -422,8 +453,8 @@ func (h *Handler) DeleteSourceByTypeAndExternalID(w http.ResponseWriter, r *http
 	}
 
 	// Validate type (whitelist)
-	if sourceType != "reddit" && sourceType != "semantic_scholar" && sourceType != "hackernews" {
-		respondError(w, http.StatusBadRequest, "type must be 'reddit', 'semantic_scholar', or 'hackernews'")
+	if !source.IsValidType(sourceType) {
+		respondError(w, http.StatusBadRequest, "type must be one of: "+strings.Join(source.Types, ", "))
 		return
 	}
 
@@ -479,6 +510,22 @@ func (h *Handler) TriggerSource(w http.ResponseWriter, r *http.Request) {
 		return
 	}
 
+	// Claim the source in the scheduler first, so a source a job's run is crawling is refused before its status is touched
+	if err := h.scheduler.ClaimSource(id); err != nil {
+		status := http.StatusInternalServerError
+		if errors.Is(err, scheduler.ErrSourceRunning) {
+			status = http.StatusConflict
+		}
+		respondError(w, status, err.Error())
+		return
+	}
+	claimed := true
+	defer func() {
+		if claimed {
+			h.scheduler.ReleaseSource(id)
+		}
+	}()
+
 	// Atomically claim the source by updating status only if not already running
 	// Include profile ownership check
 	result, err := h.db.Exec(`
@@ -552,8 +599,9 @@ func (h *Handler) TriggerSource(w http.ResponseWriter, r *http.Request) {
 		src.LastError = lastError.String
 	}
 
-	// Trigger async crawl (fire-and-forget)
-	go h.scheduler.RunSingleSource(&src)
+	// Trigger async crawl (fire-and-forget); the crawl releases the claim when it finishes
+	h.scheduler.RunSingleSource(&src)
+	claimed = false
 
 	w.WriteHeader(http.StatusAccepted)
 	if err := json.NewEncoder(w).Encode(map[string]string{
@@ -565,15 +613,21 @@ func (h *Handler) TriggerSource(w http.ResponseWriter, r *http.Request) {
 
 // GetSchedule godoc
 // @Summary Get global schedule information
-// @Description Returns the global crawl schedule (applies to all sources)
+// @Description Returns the crawl schedule of a job (default: the default job, which applies to all sources unless source patterns narrow it)
 // @Tags schedule
 // @Accept json
 // @Produce json
+// @Param job query string false "Job name from COLLECTOR_JOBS (default: default)"
 // @Success 200 {object} db.ScheduleEntry
+// @Failure 404 {object} ErrorResponse "Unknown job"
 // @Failure 500 {object} ErrorResponse "Scheduler error"
 // @Router /schedule [get]
 func (h *Handler) GetSchedule(w http.ResponseWriter, r *http.Request) {
-	schedule, err := h.scheduler.GetSchedule()
+	schedule, err := h.scheduler.GetSchedule(r.URL.Query().Get("job"))
+	if errors.Is(err, scheduler.ErrUnknownJob) {
+		respondError(w, http.StatusNotFound, err.Error())
+		return
+	}
 	if err != nil {
 		respondError(w, http.StatusInternalServerError, fmt.Sprintf("failed to get schedule: %v", err))
 		return
@@ -584,6 +638,27 @@ func (h *Handler) GetSchedule(w http.ResponseWriter, r *http.Request) {
 	}
 }
 
+// GetLastRun godoc
+// @Summary Get the latest crawl run report
+// @Description Returns per-source outcomes (status, counts, timing, HTTP requests, retries and 429s) of a job's current or most recent global run, and its most active and highest-scoring authors when COLLECTOR_REPORT_TOP_AUTHORS is set
+// @Tags schedule
+// @Accept json
+// @Produce json
+// @Param job query string false "Job name from COLLECTOR_JOBS (default: default)"
+// @Success 200 {object} scheduler.RunReport
+// @Failure 404 {object} ErrorResponse "Unknown job"
+// @Router /schedule/last-run [get]
+func (h *Handler) GetLastRun(w http.ResponseWriter, r *http.Request) {
+	report, err := h.scheduler.RunReport(r.URL.Query().Get("job"))
+	if err != nil {
+		respondError(w, http.StatusNotFound, err.Error())
+		return
+	}
+	if err := json.NewEncoder(w).Encode(report); err != nil {
+		slog.Error("Failed to encode run report response", "error", err)
+	}
+}
+
 // ListArticles godoc
 // @Summary List articles
 // @Description Get crawled articles with pagination and filtering
@@ -595,7 +670,13 @@ func (h *Handler) GetSchedule(w http.ResponseWriter, r *http.Request) {
 // @Param offset query int false "Pagination offset (default: 0)" minimum(0)
 // @Param since query string false "Filter articles written after this timestamp (RFC3339 format)" example(2024-11-15T00:00:00Z)
 // @Param curated query bool false "Filter to curated articles only (requires profile_id)" example(false)
+// @Param tag query string false "Filter by a tag assigned to the article's source"
+// @Param format query string false "Response format: json (default), ndjson (one article per line), csv or tsv; has_more is in the X-Has-More header for all but json" Enums(json, ndjson, csv, tsv)
+// @Param columns query string false "Comma-separated CSV/TSV columns (default: uid,source_type,title,author,url,written_at)"
+// @Param max_title_chars query int false "Cut longer titles to this many characters, ending in …; 0 or unset keeps them whole" minimum(0)
+// @Param max_body_chars query int false "Cut longer contents to this many characters, ending in …; 0 or unset keeps them whole" minimum(0)
 // @Success 200 {object} ArticleListResponse
+// @Failure 400 {object} ErrorResponse "Invalid format, column or max_*_chars"
 // @Failure 500 {object} ErrorResponse "Database error"
 // @Router /articles [get]
 func (h *Handler) ListArticles(w http.ResponseWriter, r *http.Request) {
@@ -605,6 +686,28 @@ func (h *Handler) ListArticles(w http.ResponseWriter, r *http.Request) {
 	offsetStr := r.URL.Query().Get("offset")
 	sinceStr := r.URL.Query().Get("since")
 	curatedStr := r.URL.Query().Get("curated")
+	tag := r.URL.Query().Get("tag")
+	format := r.URL.Query().Get("format")
+
+	if !validArticleFormat(format) {
+		respondError(w, http.StatusBadRequest, "format must be one of: "+strings.Join(articleFormats, ", "))
+		return
+	}
+	columns, err := parseArticleColumns(r.URL.Query().Get("columns"))
+	if err != nil {
+		respondError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+	maxTitle, err := parseMaxChars("max_title_chars", r.URL.Query().Get("max_title_chars"))
+	if err != nil {
+		respondError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+	maxBody, err := parseMaxChars("max_body_chars", r.URL.Query().Get("max_body_chars"))
+	if err != nil {
+		respondError(w, http.StatusBadRequest, err.Error())
+		return
+	}
 
 	// Parse parameters
 	limit := 50
@@ -693,6 +796,10 @@ func (h *Handler) ListArticles(w http.ResponseWriter, r *http.Request) {
 		filterConditions = append(filterConditions, tablePrefix+"written_at >= ?")
 		filterArgs = append(filterArgs, *since)
 	}
+	if tag != "" {
+		filterConditions = append(filterConditions, "EXISTS (SELECT 1 FROM json_each("+tablePrefix+"metadata, '$.tags') WHERE value = ?)")
+		filterArgs = append(filterArgs, tag)
+	}
 
 	// Apply filters to query
 	for _, cond := range filterConditions {
@@ -736,6 +843,7 @@ func (h *Handler) ListArticles(w http.ResponseWriter, r *http.Request) {
 			article.Metadata = json.RawMessage(metadata.String)
 		}
 		article.Liked = (liked == 1)
+		article.UID = db.ArticleUID(article.SourceType, article.ExternalID)
 
 		articles = append(articles, article)
 	}
@@ -750,6 +858,19 @@ func (h *Handler) ListArticles(w http.ResponseWriter, r *http.Request) {
 	if hasMore {
 		articles = articles[:limit] // Trim to requested limit
 	}
+	truncateArticles(articles, maxTitle, maxBody)
+
+	switch format {
+	case "ndjson":
+		writeArticlesNDJSON(w, articles, hasMore)
+		return
+	case "csv":
+		writeArticlesTable(w, articles, hasMore, columns, ',')
+		return
+	case "tsv":
+		writeArticlesTable(w, articles, hasMore, columns, '\t')
+		return
+	}
 
 	response := ArticleListResponse{
 		Articles: articles,
@@ -880,6 +1001,7 @@ func (h *Handler) GetArticle(w http.ResponseWriter, r *http.Request) {
 	if metadata.Valid {
 		article.Metadata = json.RawMessage(metadata.String)
 	}
+	article.UID = db.ArticleUID(article.SourceType, article.ExternalID)
 
 	// Query comments for this article
 	rows, err := h.db.Query(`
@@ -1032,6 +1154,120 @@ func (h *Handler) Metrics(w http.ResponseWriter, r *http.Request) {
 	}
 }
 
+// GetStoreStats godoc
+// @Summary Database statistics
+// @Description Row counts, the oldest stored article and the size of the database file, including unused space compaction would reclaim
+// @Tags monitoring
+// @Accept json
+// @Produce json
+// @Success 200 {object} db.StoreStats
+// @Failure 500 {object} ErrorResponse "Database error"
+// @Router /store/stats [get]
+func (h *Handler) GetStoreStats(w http.ResponseWriter, r *http.Request) {
+	stats, err := h.db.Stats()
+	if err != nil {
+		respondError(w, http.StatusInternalServerError, err.Error())
+		return
+	}
+	if err := json.NewEncoder(w).Encode(stats); err != nil {
+		slog.Error("Failed to encode store stats response", "error", err)
+	}
+}
+
+// PruneStore godoc
+// @Summary Prune and compact the database
+// @Description Removes articles stored more than older_than_days ago (default: COLLECTOR_RETENTION_DAYS), with their comments and curation entries, then compacts the database file if enough of it is unused. Liked articles are kept
+// @Tags monitoring
+// @Accept json
+// @Produce json
+// @Param older_than_days query int false "Retention period in days (default: COLLECTOR_RETENTION_DAYS; 0 there means compaction only)" minimum(1)
+// @Success 200 {object} PruneStoreResponse
+// @Failure 400 {object} ErrorResponse "Invalid older_than_days"
+// @Failure 409 {object} ErrorResponse "A crawl is running"
+// @Failure 500 {object} ErrorResponse "Database error"
+// @Router /store/prune [post]
+func (h *Handler) PruneStore(w http.ResponseWriter, r *http.Request) {
+	days := 0
+	if value := r.URL.Query().Get("older_than_days"); value != "" {
+		parsed, err := strconv.Atoi(value)
+		if err != nil || parsed < 1 {
+			respondError(w, http.StatusBadRequest, "older_than_days must be a positive integer")
+			return
+		}
+		days = parsed
+	}
+
+	pruned, reclaimed, err := h.scheduler.MaintainStore(days)
+	if err != nil {
+		status := http.StatusInternalServerError
+		if errors.Is(err, scheduler.ErrCrawlRunning) {
+			status = http.StatusConflict
+		}
+		respondError(w, status, err.Error())
+		return
+	}
+
+	if err := json.NewEncoder(w).Encode(PruneStoreResponse{Pruned: pruned, ReclaimedBytes: reclaimed}); err != nil {
+		slog.Error("Failed to encode prune response", "error", err)
+	}
+}
+
+// ExportStore godoc
+// @Summary Export crawl state
+// @Description Streams every source's crawl cursor (last run and last success) followed by every stored article as NDJSON, for POST /store/import on another instance. Source configs are not included
+// @Tags monitoring
+// @Produce application/x-ndjson
+// @Param profile_id query string false "Limit the export to one profile (UUID)"
+// @Success 200 {object} db.StateRecord "One record per line"
+// @Failure 400 {object} ErrorResponse "Invalid profile_id"
+// @Router /store/export [get]
+func (h *Handler) ExportStore(w http.ResponseWriter, r *http.Request) {
+	profileID := r.URL.Query().Get("profile_id")
+	if profileID != "" {
+		if _, err := uuid.Parse(profileID); err != nil {
+			respondError(w, http.StatusBadRequest, "invalid profile_id format")
+			return
+		}
+	}
+
+	w.Header().Set("Content-Type", "application/x-ndjson")
+	w.Header().Set("Content-Disposition", `attachment; filename="meows-state.ndjson"`)
+	if err := h.db.ExportState(w, profileID); err != nil {
+		// Headers are already out, so the truncated body is all the client sees
+		slog.Error("Failed to export state", "error", err)
+	}
+}
+
+// ImportStore godoc
+// @Summary Import crawl state
+// @Description Applies an NDJSON export from GET /store/export, or articles from GET /articles?format=ndjson, in one transaction. Records are matched to existing sources by type, external ID and profile; records of missing sources are skipped. Source cursors only move forward and existing articles are left untouched
+// @Tags monitoring
+// @Accept application/x-ndjson
+// @Produce json
+// @Param profile_id query string false "Match records to this profile's sources instead of their own (UUID)"
+// @Success 200 {object} db.ImportResult
+// @Failure 400 {object} ErrorResponse "Invalid profile_id or malformed record"
+// @Router /store/import [post]
+func (h *Handler) ImportStore(w http.ResponseWriter, r *http.Request) {
+	profileID := r.URL.Query().Get("profile_id")
+	if profileID != "" {
+		if _, err := uuid.Parse(profileID); err != nil {
+			respondError(w, http.StatusBadRequest, "invalid profile_id format")
+			return
+		}
+	}
+
+	result, err := h.db.ImportState(r.Body, profileID)
+	if err != nil {
+		respondError(w, http.StatusBadRequest, fmt.Sprintf("import failed: %v", err))
+		return
+	}
+
+	if err := json.NewEncoder(w).Encode(result); err != nil {
+		slog.Error("Failed to encode import response", "error", err)
+	}
+}
+
 // Note: Global config endpoints (GET/PATCH /config) removed
 // Configuration is now file-based (.config.yaml) and requires service restart to apply changes
 
@@ -1513,19 +1749,40 @@ func extractExternalID(sourceType string, config json.RawMessage) (string, error
 		if err := json.Unmarshal(config, &redditConfig); err != nil {
 			return "", err
 		}
-		return redditConfig.Subreddit, nil
+		// User crawls are keyed "u:{name}" so they never collide with a subreddit of the same name
+		listing := redditConfig.Subreddit
+		if redditConfig.User != "" {
+			listing = "u:" + redditConfig.User
+		}
+		// Comment crawls get their own IDs so they can coexist with a posts source for the same listing
+		if redditConfig.FetchMode == "comments" {
+			if redditConfig.PostID != "" {
+				return "comments:" + redditConfig.PostID, nil
+			}
+			return listing + ":comments", nil
+		}
+		return listing, nil
 
 	case "semantic_scholar":
 		var s2Config db.SemanticScholarConfig
 		if err := json.Unmarshal(config, &s2Config); err != nil {
 			return "", err
 		}
-		if s2Config.Mode == "search" && s2Config.Query != nil {
-			return *s2Config.Query, nil
+		if s2Config.Mode == "search" && len(s2Config.Query) > 0 {
+			return s2Config.Query.String(), nil
+		}
+		if s2Config.Mode == "bulk" && len(s2Config.Query) > 0 {
+			return "bulk:" + s2Config.Query.String(), nil
 		}
 		if s2Config.Mode == "recommendations" && s2Config.PaperID != nil {
 			return *s2Config.PaperID, nil
 		}
+		if s2Config.Mode == "author" && s2Config.AuthorID != nil {
+			return "author:" + *s2Config.AuthorID, nil
+		}
+		if s2Config.Mode == "dataset" && s2Config.DatasetPath != nil {
+			return "dataset:" + *s2Config.DatasetPath, nil
+		}
 		return "", fmt.Errorf("invalid semantic scholar config")
 
 	case "hackernews":
@@ -1537,6 +1794,315 @@ func extractExternalID(sourceType string, config json.RawMessage) (string, error
 		// This allows one source per story type
 		return hnConfig.ItemType, nil
 
+	case "github":
+		var ghConfig db.GitHubConfig
+		if err := json.Unmarshal(config, &ghConfig); err != nil {
+			return "", fmt.Errorf("invalid github config: %w", err)
+		}
+		// Prefix with mode so issues and discussions of one repo can coexist
+		if ghConfig.Mode == "search" && ghConfig.Query != "" {
+			return "search:" + ghConfig.Query, nil
+		}
+		if ghConfig.Repo != "" {
+			return ghConfig.Mode + ":" + ghConfig.Repo, nil
+		}
+		return "", fmt.Errorf("invalid github config")
+
+	case "stackexchange":
+		var seConfig db.StackExchangeConfig
+		if err := json.Unmarshal(config, &seConfig); err != nil {
+			return "", fmt.Errorf("invalid stackexchange config: %w", err)
+		}
+		// One source per site and tag set (e.g. "stackoverflow:go;generics")
+		site := seConfig.Site
+		if site == "" {
+			site = "stackoverflow"
+		}
+		return site + ":" + strings.Join(seConfig.Tags, ";"), nil
+
+	case "bluesky":
+		var bskyConfig db.BlueskyConfig
+		if err := json.Unmarshal(config, &bskyConfig); err != nil {
+			return "", fmt.Errorf("invalid bluesky config: %w", err)
+		}
+		switch bskyConfig.Mode {
+		case "search":
+			return "search:" + bskyConfig.Query, nil
+		case "author":
+			return "author:" + bskyConfig.Actor, nil
+		case "feed":
+			return "feed:" + bskyConfig.FeedURI, nil
+		}
+		return "", fmt.Errorf("invalid bluesky config")
+
+	case "openalex":
+		var oaConfig db.OpenAlexConfig
+		if err := json.Unmarshal(config, &oaConfig); err != nil {
+			return "", fmt.Errorf("invalid openalex config: %w", err)
+		}
+		if oaConfig.Query != "" {
+			return oaConfig.Query, nil
+		}
+		if len(oaConfig.Concepts) > 0 {
+			return "concepts:" + strings.Join(oaConfig.Concepts, "|"), nil
+		}
+		return "", fmt.Errorf("invalid openalex config")
+
+	case "pubmed":
+		var pmConfig db.PubMedConfig
+		if err := json.Unmarshal(config, &pmConfig); err != nil {
+			return "", fmt.Errorf("invalid pubmed config: %w", err)
+		}
+		if pmConfig.Query == "" {
+			return "", fmt.Errorf("invalid pubmed config")
+		}
+		return pmConfig.Query, nil
+
+	case "arxiv":
+		var arxivConfig db.ArxivConfig
+		if err := json.Unmarshal(config, &arxivConfig); err != nil {
+			return "", fmt.Errorf("invalid arxiv config: %w", err)
+		}
+		if len(arxivConfig.Categories) == 0 {
+			return "", fmt.Errorf("invalid arxiv config")
+		}
+		// Watchlist as "cs.LG+cs.AI", matching arXiv's combined feed URL syntax
+		names := make([]string, len(arxivConfig.Categories))
+		for i, c := range arxivConfig.Categories {
+			names[i] = c.Name
+		}
+		return strings.Join(names, "+"), nil
+
+	case "discourse":
+		var discourseConfig db.DiscourseConfig
+		if err := json.Unmarshal(config, &discourseConfig); err != nil {
+			return "", fmt.Errorf("invalid discourse config: %w", err)
+		}
+		if discourseConfig.BaseURL == "" {
+			return "", fmt.Errorf("invalid discourse config")
+		}
+		u, err := url.Parse(discourseConfig.BaseURL)
+		if err != nil || u.Host == "" {
+			return "", fmt.Errorf("invalid discourse config: bad base_url")
+		}
+		// Host (plus category) keeps the ID free of slashes for DELETE /sources/{type}/{external_id}
+		if discourseConfig.Category != "" {
+			return u.Host + ":" + strings.ReplaceAll(strings.Trim(discourseConfig.Category, "/"), "/", ":"), nil
+		}
+		return u.Host, nil
+
+	case "nitter":
+		var nitterConfig db.NitterConfig
+		if err := json.Unmarshal(config, &nitterConfig); err != nil {
+			return "", fmt.Errorf("invalid nitter config: %w", err)
+		}
+		if nitterConfig.Query == "" {
+			return "", fmt.Errorf("invalid nitter config")
+		}
+		return nitterConfig.Query, nil
+
+	case "google_scholar":
+		var gsConfig db.GoogleScholarConfig
+		if err := json.Unmarshal(config, &gsConfig); err != nil {
+			return "", fmt.Errorf("invalid google_scholar config: %w", err)
+		}
+		if gsConfig.Query == "" {
+			return "", fmt.Errorf("invalid google_scholar config")
+		}
+		return gsConfig.Query, nil
+
+	case "web":
+		var webConfig db.WebConfig
+		if err := json.Unmarshal(config, &webConfig); err != nil {
+			return "", fmt.Errorf("invalid web config: %w", err)
+		}
+		if webConfig.SitemapURL != "" {
+			u, err := url.Parse(webConfig.SitemapURL)
+			if err != nil || u.Host == "" {
+				return "", fmt.Errorf("invalid web config: bad sitemap_url")
+			}
+			// Colons instead of slashes keep the ID usable in DELETE /sources/{type}/{external_id}
+			return "sitemap:" + u.Host + strings.ReplaceAll(strings.TrimRight(u.Path, "/"), "/", ":"), nil
+		}
+		if len(webConfig.URLs) == 0 {
+			return "", fmt.Errorf("invalid web config")
+		}
+		// A page list has no natural name, so use a short hash of the sorted URLs
+		urls := append([]string(nil), webConfig.URLs...)
+		sort.Strings(urls)
+		sum := sha256.Sum256([]byte(strings.Join(urls, "\n")))
+		return "pages:" + hex.EncodeToString(sum[:6]), nil
+
+	case "json_api":
+		var jsonAPIConfig db.JSONAPIConfig
+		if err := json.Unmarshal(config, &jsonAPIConfig); err != nil {
+			return "", fmt.Errorf("invalid json_api config: %w", err)
+		}
+		u, err := url.Parse(jsonAPIConfig.URL)
+		if err != nil || u.Host == "" {
+			return "", fmt.Errorf("invalid json_api config: bad url")
+		}
+		// Colons instead of slashes keep the ID usable in DELETE /sources/{type}/{external_id}
+		return u.Host + strings.ReplaceAll(strings.TrimRight(u.Path, "/"), "/", ":"), nil
+
+	case "news":
+		var newsConfig db.NewsConfig
+		if err := json.Unmarshal(config, &newsConfig); err != nil {
+			return "", fmt.Errorf("invalid news config: %w", err)
+		}
+		if newsConfig.Query == "" {
+			return "", fmt.Errorf("invalid news config")
+		}
+		provider := newsConfig.Provider
+		if provider == "" {
+			provider = "gdelt"
+		}
+		return provider + ":" + newsConfig.Query, nil
+
+	case "wikipedia":
+		var wikipediaConfig db.WikipediaConfig
+		if err := json.Unmarshal(config, &wikipediaConfig); err != nil {
+			return "", fmt.Errorf("invalid wikipedia config: %w", err)
+		}
+		language := wikipediaConfig.Language
+		if language == "" {
+			language = "en"
+		}
+		if wikipediaConfig.Mode == "recent_changes" {
+			if len(wikipediaConfig.Pages) == 0 {
+				return "", fmt.Errorf("invalid wikipedia config")
+			}
+			pages := append([]string(nil), wikipediaConfig.Pages...)
+			sort.Strings(pages)
+			sum := sha256.Sum256([]byte(strings.Join(pages, "\n")))
+			return language + ":changes:" + hex.EncodeToString(sum[:6]), nil
+		}
+		if wikipediaConfig.Query == "" {
+			return "", fmt.Errorf("invalid wikipedia config")
+		}
+		return language + ":search:" + wikipediaConfig.Query, nil
+
+	case "kaggle":
+		var kaggleConfig db.KaggleConfig
+		if err := json.Unmarshal(config, &kaggleConfig); err != nil {
+			return "", fmt.Errorf("invalid kaggle config: %w", err)
+		}
+		kind := kaggleConfig.Kind
+		if kind == "" {
+			kind = "datasets"
+		}
+		return kind + ":" + kaggleConfig.Search, nil
+
+	case "podcast":
+		var podcastConfig db.PodcastConfig
+		if err := json.Unmarshal(config, &podcastConfig); err != nil {
+			return "", fmt.Errorf("invalid podcast config: %w", err)
+		}
+		if podcastConfig.Query != "" {
+			return "search:" + podcastConfig.Query, nil
+		}
+		if len(podcastConfig.FeedIDs) == 0 {
+			return "", fmt.Errorf("invalid podcast config")
+		}
+		ids := make([]string, len(podcastConfig.FeedIDs))
+		for i, id := range podcastConfig.FeedIDs {
+			ids[i] = strconv.FormatInt(id, 10)
+		}
+		sort.Strings(ids)
+		return "feeds:" + strings.Join(ids, ","), nil
+
+	case "huggingface":
+		var huggingFaceConfig db.HuggingFaceConfig
+		if err := json.Unmarshal(config, &huggingFaceConfig); err != nil {
+			return "", fmt.Errorf("invalid huggingface config: %w", err)
+		}
+		kind := huggingFaceConfig.Kind
+		if kind == "" {
+			kind = "models"
+		}
+		return strings.Join([]string{kind, huggingFaceConfig.Author, huggingFaceConfig.Tag, huggingFaceConfig.Search}, ":"), nil
+
+	case "package_registry":
+		var packageRegistryConfig db.PackageRegistryConfig
+		if err := json.Unmarshal(config, &packageRegistryConfig); err != nil {
+			return "", fmt.Errorf("invalid package_registry config: %w", err)
+		}
+		if packageRegistryConfig.Registry == "" || len(packageRegistryConfig.Keywords) == 0 {
+			return "", fmt.Errorf("invalid package_registry config")
+		}
+		keywords := append([]string(nil), packageRegistryConfig.Keywords...)
+		sort.Strings(keywords)
+		return packageRegistryConfig.Registry + ":" + strings.Join(keywords, ","), nil
+
+	case "imap":
+		var imapConfig db.ImapConfig
+		if err := json.Unmarshal(config, &imapConfig); err != nil {
+			return "", fmt.Errorf("invalid imap config: %w", err)
+		}
+		folder := imapConfig.Folder
+		if folder == "" {
+			folder = "INBOX"
+		}
+		senders := append([]string(nil), imapConfig.From...)
+		sort.Strings(senders)
+		return folder + ":" + strings.Join(senders, ","), nil
+
+	case "file":
+		var fileConfig db.FileConfig
+		if err := json.Unmarshal(config, &fileConfig); err != nil {
+			return "", fmt.Errorf("invalid file config: %w", err)
+		}
+		if fileConfig.Path == "" {
+			return "", fmt.Errorf("invalid file config")
+		}
+		// Colons instead of slashes keep the ID usable in DELETE /sources/{type}/{external_id}
+		name := strings.ReplaceAll(strings.TrimPrefix(path.Clean("/"+fileConfig.Path), "/"), "/", ":")
+		if name == "" {
+			name = "." // The root itself
+		}
+		return name, nil
+
+	case "feed":
+		var feedConfig db.FeedConfig
+		if err := json.Unmarshal(config, &feedConfig); err != nil {
+			return "", fmt.Errorf("invalid feed config: %w", err)
+		}
+		if feedConfig.OPMLPath != "" {
+			// Colons instead of slashes keep the ID usable in DELETE /sources/{type}/{external_id}
+			return "opml:" + strings.ReplaceAll(strings.TrimPrefix(path.Clean("/"+feedConfig.OPMLPath), "/"), "/", ":"), nil
+		}
+		if len(feedConfig.URLs) == 0 {
+			return "", fmt.Errorf("invalid feed config")
+		}
+		// A feed list has no natural name, so use a short hash of the sorted URLs
+		urls := append([]string(nil), feedConfig.URLs...)
+		sort.Strings(urls)
+		sum := sha256.Sum256([]byte(strings.Join(urls, "\n")))
+		return "feeds:" + hex.EncodeToString(sum[:6]), nil
+
+	case "zenodo":
+		var zenodoConfig db.ZenodoConfig
+		if err := json.Unmarshal(config, &zenodoConfig); err != nil {
+			return "", fmt.Errorf("invalid zenodo config: %w", err)
+		}
+		if zenodoConfig.Query == "" && len(zenodoConfig.Communities) == 0 {
+			return "", fmt.Errorf("invalid zenodo config")
+		}
+		// Type and communities prefix the query so narrower searches can coexist
+		var parts []string
+		if zenodoConfig.ResourceType != "" {
+			parts = append(parts, zenodoConfig.ResourceType)
+		}
+		if len(zenodoConfig.Communities) > 0 {
+			communities := append([]string(nil), zenodoConfig.Communities...)
+			sort.Strings(communities)
+			parts = append(parts, "communities:"+strings.Join(communities, ","))
+		}
+		if zenodoConfig.Query != "" {
+			parts = append(parts, zenodoConfig.Query)
+		}
+		return strings.Join(parts, ":"), nil
+
 	default:
 		return "", fmt.Errorf("unknown source type: %s", sourceType)
 	}
@@ -2,6 +2,7 @@ package api
 
 import (
 	"context"
+	"encoding/json"
 	"net/http"
 	"net/http/httptest"
 	"testing"
@@ -44,6 +45,33 @@ func setupTestConfig() *config.Config {
 				RedditDelayMs:          1000,
 				SemanticScholarDelayMs: 1000,
 				HackerNewsDelayMs:      500,
+				GitHubDelayMs:          2000,
+				StackExchangeDelayMs:   1000,
+				BlueskyDelayMs:         500,
+				OpenAlexDelayMs:        200,
+				PubMedDelayMs:          400,
+				ArxivDelayMs:           3000,
+				DiscourseDelayMs:       1000,
+				NitterDelayMs:          2000,
+				GoogleScholarDelayMs:   1000,
+				WebDelayMs:             1000,
+				JSONAPIDelayMs:         1000,
+				NewsDelayMs:            5000,
+				WikipediaDelayMs:       1000,
+				KaggleDelayMs:          1000,
+				PodcastDelayMs:         1000,
+				HuggingFaceDelayMs:     1000,
+				PackageRegistryDelayMs: 1000,
+				ImapDelayMs:            1000,
+				FeedDelayMs:            500,
+				ZenodoDelayMs:          2000,
+			},
+			HTTP: config.HTTPConfig{
+				TimeoutSeconds:        30,
+				ConnectTimeoutSeconds: 10,
+				KeepAliveSeconds:      30,
+				MaxIdleConnsPerHost:   10,
+				EnableHTTP2:           true,
 			},
 			Profile: config.ProfileConfig{
 				MilestoneThreshold1: 3,
@@ -141,6 +169,49 @@ func TestDeleteSourceByTypeAndExternalID_ExternalIDWithSlash(t *testing.T) {
 	}
 }
 
+func TestDeleteSourceByTypeAndExternalID_FileSource(t *testing.T) {
+	database := setupTestDB(t)
+	defer database.Close()
+
+	cfg := setupTestConfig()
+	profService := setupTestProfileService(t, database)
+	sched := setupTestScheduler(t, database, profService)
+
+	externalID, err := extractExternalID("file", json.RawMessage(`{"path":"exports/2024/articles.jsonl"}`))
+	if err != nil {
+		t.Fatalf("Failed to derive external_id: %v", err)
+	}
+	if externalID != "exports:2024:articles.jsonl" {
+		t.Errorf("Expected slashes to become colons, got %q", externalID)
+	}
+
+	_, err = database.Exec(`
+		INSERT INTO profiles (id, nickname, user_description, created_at)
+		VALUES ('test-profile-id', 'testuser', 'Test user', datetime('now'))
+	`)
+	if err != nil {
+		t.Fatalf("Failed to insert test profile: %v", err)
+	}
+	_, err = database.Exec(`
+		INSERT INTO sources (id, type, external_id, profile_id, config, status, created_at)
+		VALUES ('file-id', 'file', ?, 'test-profile-id', '{"path":"exports/2024/articles.jsonl"}', 'idle', datetime('now'))
+	`, externalID)
+	if err != nil {
+		t.Fatalf("Failed to insert test source: %v", err)
+	}
+
+	router := SetupRouter(cfg, database, sched, profService)
+
+	req := httptest.NewRequest("DELETE", "/sources/file/"+externalID+"?profile_id=test-profile-id", nil)
+	w := httptest.NewRecorder()
+
+	router.ServeHTTP(w, req)
+
+	if w.Code != http.StatusNoContent {
+		t.Errorf("Expected status 204, got %d. Body: %s", w.Code, w.Body.String())
+	}
+}
+
 func TestDeleteSourceByTypeAndExternalID_NotFound(t *testing.T) {
 	database := setupTestDB(t)
 	defer database.Close()
@@ -54,8 +54,13 @@ func SetupRouter(cfg *config.Config, database *db.DB, sched *scheduler.Scheduler
 	r.Delete("/likes/{id}", h.UnlikeArticle)
 
 	r.Get("/schedule", h.GetSchedule)
+	r.Get("/schedule/last-run", h.GetLastRun)
 	r.Get("/health", h.Health)
 	r.Get("/metrics", h.Metrics)
+	r.Get("/store/stats", h.GetStoreStats)
+	r.Post("/store/prune", h.PruneStore)
+	r.Get("/store/export", h.ExportStore)
+	r.Post("/store/import", h.ImportStore)
 	// Note: Global config endpoints removed - config is now file-based (.config.yaml)
 
 	// Swagger UI (environment-gated for development only)
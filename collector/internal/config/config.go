@@ -3,7 +3,10 @@ package config
 import (
 	"fmt"
 	"log"
+	"net/url"
 	"os"
+	"regexp"
+	"slices"
 	"strconv"
 	"strings"
 
@@ -20,23 +23,46 @@ type CollectorConfig struct {
 	Server      ServerConfig
 	Schedule    ScheduleConfig
 	RateLimits  RateLimitsConfig
+	HTTP        HTTPConfig
 	Credentials CredentialsConfig
+	Webhooks    WebhookConfig
+	ObjectStore ObjectStoreConfig
+	Search      SearchIndexConfig
 	Gemini      GeminiConfig
 	Profile     ProfileConfig
 }
 
 // ServerConfig represents collector server settings
 type ServerConfig struct {
-	Port            int
-	DBPath          string
-	LogLevel        string
-	EnableSwagger   bool
-	MaxCommentDepth int
+	Port                int
+	DBPath              string
+	LogLevel            string
+	EnableSwagger       bool
+	MaxCommentDepth     int
+	RetentionDays       int    // Prune articles stored longer ago than this (0 = keep forever)
+	MaintenanceCronExpr string // When to prune and compact the database
 }
 
 // ScheduleConfig represents scheduling configuration
 type ScheduleConfig struct {
-	CronExpr string
+	CronExpr             string
+	FetchCacheTTLSeconds int         // Reuse fetch results of identical source configs for this long (0 disables)
+	RunDeadlineMinutes   int         // Start no further sources once a run has lasted this long (0 = unlimited)
+	RunMaxRequests       int         // Start no further sources once a run has made this many HTTP requests (0 = unlimited)
+	ReportTopAuthors     int         // List this many most active and highest-scoring authors per source and run in the run report (0 disables)
+	OnlySources          []string    // Source patterns ("type:external_id" globs); if set, scheduled runs crawl only matching sources
+	SkipSources          []string    // Source patterns excluded from scheduled runs
+	Jobs                 []JobConfig // Named jobs run alongside the default one above (COLLECTOR_JOBS)
+}
+
+// JobConfig represents one named crawl job with its own schedule, source patterns and webhooks
+// Configured by COLLECTOR_JOB_<NAME>_* variables for each name listed in COLLECTOR_JOBS
+type JobConfig struct {
+	Name        string
+	CronExpr    string
+	OnlySources []string
+	SkipSources []string
+	Webhooks    WebhookConfig // Secret and events fall back to COLLECTOR_WEBHOOK_SECRET and COLLECTOR_WEBHOOK_EVENTS
 }
 
 // RateLimitsConfig represents rate limiting configuration per source type
@@ -44,6 +70,42 @@ type RateLimitsConfig struct {
 	RedditDelayMs          int
 	SemanticScholarDelayMs int
 	HackerNewsDelayMs      int
+	GitHubDelayMs          int
+	StackExchangeDelayMs   int
+	BlueskyDelayMs         int
+	OpenAlexDelayMs        int
+	PubMedDelayMs          int
+	ArxivDelayMs           int
+	DiscourseDelayMs       int
+	NitterDelayMs          int
+	GoogleScholarDelayMs   int
+	WebDelayMs             int
+	JSONAPIDelayMs         int
+	NewsDelayMs            int
+	WikipediaDelayMs       int
+	KaggleDelayMs          int
+	PodcastDelayMs         int
+	HuggingFaceDelayMs     int
+	PackageRegistryDelayMs int
+	ImapDelayMs            int
+	FeedDelayMs            int
+	ZenodoDelayMs          int
+}
+
+// HTTPConfig represents outbound HTTP client tuning shared by all sources
+type HTTPConfig struct {
+	TimeoutSeconds        int    // Overall per-request timeout (default: 30)
+	ConnectTimeoutSeconds int    // TCP connect timeout (default: 10)
+	KeepAliveSeconds      int    // TCP keepalive interval, 0 uses the Go default (default: 30)
+	MaxIdleConnsPerHost   int    // Idle connections kept open per host (default: 10)
+	EnableHTTP2           bool   // Negotiate HTTP/2 with servers that support it (default: true)
+	CABundlePath          string // PEM file with extra root CAs to trust (optional)
+	ClientCertPath        string // PEM client certificate for mutual TLS (optional)
+	ClientKeyPath         string // PEM private key for ClientCertPath (optional)
+	InsecureSkipVerify    bool   // Accept invalid TLS certificates, testing only (default: false)
+	ArchiveRawDir         string // Directory to save gzip-compressed raw response bodies (optional)
+	MaxRetries            int    // Retries for network errors, 429 and 502/503/504, 0 disables (default: 2)
+	RetryBaseDelayMs      int    // First retry delay, doubled on each attempt (default: 1000)
 }
 
 // CredentialsConfig represents global credentials shared by all sources
@@ -53,6 +115,56 @@ type CredentialsConfig struct {
 	RedditUsername        string
 	RedditPassword        string
 	SemanticScholarAPIKey string
+	GitHubToken           string // Optional, raises GitHub API rate limits and enables discussions
+	StackExchangeAPIKey   string // Optional, raises the Stack Exchange daily quota
+	BlueskyIdentifier     string // Optional handle or email for app-password auth
+	BlueskyAppPassword    string // Optional app password (never the account password)
+	OpenAlexMailto        string // Optional contact email for the OpenAlex polite pool
+	NCBIAPIKey            string // Optional, raises the E-utilities limit to 10 requests/second
+	SerpAPIKey            string // Required for google_scholar sources (queried through SerpAPI)
+	NewsAPIKey            string // Required for news sources using the newsapi provider
+	KaggleUsername        string // Required for kaggle sources
+	KaggleKey             string // Required for kaggle sources (API token key)
+	PodcastIndexAPIKey    string // Required for podcast sources
+	PodcastIndexAPISecret string // Required for podcast sources
+	HuggingFaceToken      string // Optional, raises Hub API rate limits
+	IMAPHost              string // Required for imap sources, "host" (port 993) or "host:port"
+	IMAPUsername          string // Required for imap sources
+	IMAPPassword          string // Required for imap sources (an app password where the provider offers them)
+	FileSourceRoot        string // Directory file sources may read from; file sources fail when unset
+	ZenodoToken           string // Optional personal access token for higher Zenodo rate limits
+}
+
+// WebhookEvents lists the lifecycle events webhooks can subscribe to
+var WebhookEvents = []string{"run_started", "run_completed", "source_failed", "source_empty"}
+
+// WebhookConfig represents lifecycle webhook configuration
+type WebhookConfig struct {
+	URLs   []string // Endpoints that receive a JSON POST per event (empty disables webhooks)
+	Events []string // Subset of WebhookEvents to send (default: all)
+	Secret string   // Signs payloads with HMAC-SHA256 in X-Meows-Signature (optional)
+}
+
+// ObjectKeyPlaceholders lists the placeholders object key templates may use
+var ObjectKeyPlaceholders = []string{"date", "time", "job", "run_id", "source", "type", "source_id"}
+
+// ObjectStoreConfig represents the S3-compatible bucket each source's stored articles are uploaded to
+type ObjectStoreConfig struct {
+	URL             string // s3://bucket/key-template, e.g. s3://my-bucket/meows/{date}/{source}.json (empty disables uploads)
+	Region          string // Signing region (default: AWS_REGION, then us-east-1)
+	Endpoint        string // Base URL of an S3-compatible service such as MinIO or R2, addressed path-style (optional)
+	AccessKeyID     string
+	SecretAccessKey string
+	SessionToken    string // Only for temporary credentials (optional)
+}
+
+// SearchIndexConfig represents the Elasticsearch or OpenSearch index stored articles are bulk-indexed into
+type SearchIndexConfig struct {
+	URL      string // Cluster base URL, e.g. https://localhost:9200 (empty disables indexing)
+	Index    string // Index name (default: meows-articles)
+	Username string // Basic auth (optional)
+	Password string
+	APIKey   string // Elasticsearch API key, sent instead of basic auth (optional)
 }
 
 // GeminiConfig represents Gemini API configuration
@@ -62,12 +174,15 @@ type GeminiConfig struct {
 
 // ProfileConfig represents profile-related configuration
 type ProfileConfig struct {
-	DailyCronExpr        string
-	MilestoneThreshold1  int  // First milestone threshold (default: 3 likes)
-	MilestoneThreshold2  int  // Second milestone threshold (default: 10 likes)
-	MilestoneThreshold3  int  // Third milestone threshold (default: 20 likes)
-	CurationWorkers      int  // Number of concurrent curation workers (default: 5)
-	CurationEnabled      bool // Enable article curation (default: true)
+	DailyCronExpr              string
+	MilestoneThreshold1        int     // First milestone threshold (default: 3 likes)
+	MilestoneThreshold2        int     // Second milestone threshold (default: 10 likes)
+	MilestoneThreshold3        int     // Third milestone threshold (default: 20 likes)
+	CurationWorkers            int     // Number of concurrent curation workers (default: 5)
+	CurationEnabled            bool    // Enable article curation (default: true)
+	CurationMaxRequestsPerRun  int     // Gemini curation calls allowed per crawl run (default: 0 = unlimited)
+	CurationMaxTokensPerRun    int     // Prompt tokens allowed per crawl run (default: 0 = unlimited)
+	CurationUSDPerMillionToken float64 // Prompt token price used for cost estimates (default: 0.30)
 }
 
 // LoadConfig loads and validates the configuration from environment variables
@@ -75,19 +190,62 @@ func LoadConfig() (*Config, error) {
 	cfg := &Config{
 		Collector: CollectorConfig{
 			Server: ServerConfig{
-				Port:            getEnvAsInt("COLLECTOR_PORT", 8080),
-				DBPath:          getEnv("COLLECTOR_DB_PATH", "/data/meows.db"),
-				LogLevel:        getEnv("COLLECTOR_LOG_LEVEL", "info"),
-				EnableSwagger:   getEnvAsBool("COLLECTOR_ENABLE_SWAGGER", true),
-				MaxCommentDepth: getEnvAsInt("COLLECTOR_MAX_COMMENT_DEPTH", 5),
+				Port:                getEnvAsInt("COLLECTOR_PORT", 8080),
+				DBPath:              getEnv("COLLECTOR_DB_PATH", "/data/meows.db"),
+				LogLevel:            getEnv("COLLECTOR_LOG_LEVEL", "info"),
+				EnableSwagger:       getEnvAsBool("COLLECTOR_ENABLE_SWAGGER", true),
+				MaxCommentDepth:     getEnvAsInt("COLLECTOR_MAX_COMMENT_DEPTH", 5),
+				RetentionDays:       getEnvAsInt("COLLECTOR_RETENTION_DAYS", 0),
+				MaintenanceCronExpr: getEnv("COLLECTOR_MAINTENANCE_CRON_EXPR", "30 4 * * *"),
 			},
 			Schedule: ScheduleConfig{
-				CronExpr: getEnv("COLLECTOR_CRON_EXPR", "0 */6 * * *"),
+				CronExpr:             getEnv("COLLECTOR_CRON_EXPR", "0 */6 * * *"),
+				FetchCacheTTLSeconds: getEnvAsInt("COLLECTOR_FETCH_CACHE_TTL_SECONDS", 0),
+				RunDeadlineMinutes:   getEnvAsInt("COLLECTOR_RUN_DEADLINE_MINUTES", 0),
+				RunMaxRequests:       getEnvAsInt("COLLECTOR_RUN_MAX_REQUESTS", 0),
+				ReportTopAuthors:     getEnvAsInt("COLLECTOR_REPORT_TOP_AUTHORS", 0),
+				OnlySources:          getEnvAsList("COLLECTOR_ONLY_SOURCES"),
+				SkipSources:          getEnvAsList("COLLECTOR_SKIP_SOURCES"),
+				Jobs:                 loadJobs(),
 			},
 			RateLimits: RateLimitsConfig{
 				RedditDelayMs:          getEnvAsInt("COLLECTOR_REDDIT_DELAY_MS", 2000),
 				SemanticScholarDelayMs: getEnvAsInt("COLLECTOR_SEMANTIC_SCHOLAR_DELAY_MS", 1000),
 				HackerNewsDelayMs:      getEnvAsInt("COLLECTOR_HACKERNEWS_DELAY_MS", 500),
+				GitHubDelayMs:          getEnvAsInt("COLLECTOR_GITHUB_DELAY_MS", 2000), // Unset: preset (see presets.go)
+				StackExchangeDelayMs:   getEnvAsInt("COLLECTOR_STACKEXCHANGE_DELAY_MS", 1000),
+				BlueskyDelayMs:         getEnvAsInt("COLLECTOR_BLUESKY_DELAY_MS", 500),
+				OpenAlexDelayMs:        getEnvAsInt("COLLECTOR_OPENALEX_DELAY_MS", 200),
+				PubMedDelayMs:          getEnvAsInt("COLLECTOR_PUBMED_DELAY_MS", 400), // Unset: preset (see presets.go)
+				ArxivDelayMs:           getEnvAsInt("COLLECTOR_ARXIV_DELAY_MS", 3000),
+				DiscourseDelayMs:       getEnvAsInt("COLLECTOR_DISCOURSE_DELAY_MS", 1000),
+				NitterDelayMs:          getEnvAsInt("COLLECTOR_NITTER_DELAY_MS", 2000),
+				GoogleScholarDelayMs:   getEnvAsInt("COLLECTOR_GOOGLE_SCHOLAR_DELAY_MS", 1000),
+				WebDelayMs:             getEnvAsInt("COLLECTOR_WEB_DELAY_MS", 1000),
+				JSONAPIDelayMs:         getEnvAsInt("COLLECTOR_JSON_API_DELAY_MS", 1000),
+				NewsDelayMs:            getEnvAsInt("COLLECTOR_NEWS_DELAY_MS", 5000),
+				WikipediaDelayMs:       getEnvAsInt("COLLECTOR_WIKIPEDIA_DELAY_MS", 1000),
+				KaggleDelayMs:          getEnvAsInt("COLLECTOR_KAGGLE_DELAY_MS", 1000),
+				PodcastDelayMs:         getEnvAsInt("COLLECTOR_PODCAST_DELAY_MS", 1000),
+				HuggingFaceDelayMs:     getEnvAsInt("COLLECTOR_HUGGINGFACE_DELAY_MS", 1000),
+				PackageRegistryDelayMs: getEnvAsInt("COLLECTOR_PACKAGE_REGISTRY_DELAY_MS", 1000),
+				ImapDelayMs:            getEnvAsInt("COLLECTOR_IMAP_DELAY_MS", 1000),
+				FeedDelayMs:            getEnvAsInt("COLLECTOR_FEED_DELAY_MS", 500),
+				ZenodoDelayMs:          getEnvAsInt("COLLECTOR_ZENODO_DELAY_MS", 2000),
+			},
+			HTTP: HTTPConfig{
+				TimeoutSeconds:        getEnvAsInt("COLLECTOR_HTTP_TIMEOUT_SECONDS", 30),
+				ConnectTimeoutSeconds: getEnvAsInt("COLLECTOR_HTTP_CONNECT_TIMEOUT_SECONDS", 10),
+				KeepAliveSeconds:      getEnvAsInt("COLLECTOR_HTTP_KEEPALIVE_SECONDS", 30),
+				MaxIdleConnsPerHost:   getEnvAsInt("COLLECTOR_HTTP_MAX_IDLE_CONNS_PER_HOST", 10),
+				EnableHTTP2:           getEnvAsBool("COLLECTOR_HTTP_ENABLE_HTTP2", true),
+				CABundlePath:          getEnv("COLLECTOR_HTTP_CA_BUNDLE", ""),
+				ClientCertPath:        getEnv("COLLECTOR_HTTP_CLIENT_CERT", ""),
+				ClientKeyPath:         getEnv("COLLECTOR_HTTP_CLIENT_KEY", ""),
+				InsecureSkipVerify:    getEnvAsBool("COLLECTOR_HTTP_INSECURE_SKIP_VERIFY", false),
+				ArchiveRawDir:         getEnv("COLLECTOR_ARCHIVE_RAW_DIR", ""),
+				MaxRetries:            getEnvAsInt("COLLECTOR_HTTP_MAX_RETRIES", 2),
+				RetryBaseDelayMs:      getEnvAsInt("COLLECTOR_HTTP_RETRY_BASE_DELAY_MS", 1000),
 			},
 			Credentials: CredentialsConfig{
 				RedditClientID:        getEnv("COLLECTOR_REDDIT_CLIENT_ID", ""),
@@ -95,21 +253,65 @@ func LoadConfig() (*Config, error) {
 				RedditUsername:        getEnv("COLLECTOR_REDDIT_USERNAME", ""),
 				RedditPassword:        getEnv("COLLECTOR_REDDIT_PASSWORD", ""),
 				SemanticScholarAPIKey: getEnv("COLLECTOR_SEMANTIC_SCHOLAR_API_KEY", ""),
+				GitHubToken:           getEnv("COLLECTOR_GITHUB_TOKEN", ""),
+				StackExchangeAPIKey:   getEnv("COLLECTOR_STACKEXCHANGE_API_KEY", ""),
+				BlueskyIdentifier:     getEnv("COLLECTOR_BLUESKY_IDENTIFIER", ""),
+				BlueskyAppPassword:    getEnv("COLLECTOR_BLUESKY_APP_PASSWORD", ""),
+				OpenAlexMailto:        getEnv("COLLECTOR_OPENALEX_MAILTO", ""),
+				NCBIAPIKey:            getEnv("COLLECTOR_NCBI_API_KEY", ""),
+				SerpAPIKey:            getEnv("COLLECTOR_SERPAPI_API_KEY", ""),
+				NewsAPIKey:            getEnv("COLLECTOR_NEWSAPI_API_KEY", ""),
+				KaggleUsername:        getEnv("COLLECTOR_KAGGLE_USERNAME", ""),
+				KaggleKey:             getEnv("COLLECTOR_KAGGLE_KEY", ""),
+				PodcastIndexAPIKey:    getEnv("COLLECTOR_PODCASTINDEX_API_KEY", ""),
+				PodcastIndexAPISecret: getEnv("COLLECTOR_PODCASTINDEX_API_SECRET", ""),
+				HuggingFaceToken:      getEnv("COLLECTOR_HUGGINGFACE_TOKEN", ""),
+				IMAPHost:              getEnv("COLLECTOR_IMAP_HOST", ""),
+				IMAPUsername:          getEnv("COLLECTOR_IMAP_USERNAME", ""),
+				IMAPPassword:          getEnv("COLLECTOR_IMAP_PASSWORD", ""),
+				FileSourceRoot:        getEnv("COLLECTOR_FILE_SOURCE_ROOT", ""),
+				ZenodoToken:           getEnv("COLLECTOR_ZENODO_TOKEN", ""),
+			},
+			Webhooks: WebhookConfig{
+				URLs:   getEnvAsList("COLLECTOR_WEBHOOK_URLS"),
+				Events: getEnvAsList("COLLECTOR_WEBHOOK_EVENTS"),
+				Secret: getEnv("COLLECTOR_WEBHOOK_SECRET", ""),
+			},
+			ObjectStore: ObjectStoreConfig{
+				URL:             getEnv("COLLECTOR_S3_URL", ""),
+				Region:          getEnv("COLLECTOR_S3_REGION", getEnv("AWS_REGION", "us-east-1")),
+				Endpoint:        getEnv("COLLECTOR_S3_ENDPOINT", ""),
+				AccessKeyID:     getEnv("AWS_ACCESS_KEY_ID", ""),
+				SecretAccessKey: getEnv("AWS_SECRET_ACCESS_KEY", ""),
+				SessionToken:    getEnv("AWS_SESSION_TOKEN", ""),
+			},
+			Search: SearchIndexConfig{
+				URL:      getEnv("COLLECTOR_ELASTICSEARCH_URL", ""),
+				Index:    getEnv("COLLECTOR_ELASTICSEARCH_INDEX", "meows-articles"),
+				Username: getEnv("COLLECTOR_ELASTICSEARCH_USERNAME", ""),
+				Password: getEnv("COLLECTOR_ELASTICSEARCH_PASSWORD", ""),
+				APIKey:   getEnv("COLLECTOR_ELASTICSEARCH_API_KEY", ""),
 			},
 			Gemini: GeminiConfig{
 				APIKey: getEnv("GEMINI_API_KEY", ""),
 			},
 			Profile: ProfileConfig{
-				DailyCronExpr:       getEnv("PROFILE_DAILY_CRON", "0 1 * * *"),
-				MilestoneThreshold1: getEnvAsInt("PROFILE_MILESTONE_1", 3),
-				MilestoneThreshold2: getEnvAsInt("PROFILE_MILESTONE_2", 10),
-				MilestoneThreshold3: getEnvAsInt("PROFILE_MILESTONE_3", 20),
-				CurationWorkers:     getEnvAsInt("PROFILE_CURATION_WORKERS", 5),
-				CurationEnabled:     getEnvAsBool("PROFILE_CURATION_ENABLED", true),
+				DailyCronExpr:              getEnv("PROFILE_DAILY_CRON", "0 1 * * *"),
+				MilestoneThreshold1:        getEnvAsInt("PROFILE_MILESTONE_1", 3),
+				MilestoneThreshold2:        getEnvAsInt("PROFILE_MILESTONE_2", 10),
+				MilestoneThreshold3:        getEnvAsInt("PROFILE_MILESTONE_3", 20),
+				CurationWorkers:            getEnvAsInt("PROFILE_CURATION_WORKERS", 5),
+				CurationEnabled:            getEnvAsBool("PROFILE_CURATION_ENABLED", true),
+				CurationMaxRequestsPerRun:  getEnvAsInt("PROFILE_CURATION_MAX_REQUESTS_PER_RUN", 0),
+				CurationMaxTokensPerRun:    getEnvAsInt("PROFILE_CURATION_MAX_TOKENS_PER_RUN", 0),
+				CurationUSDPerMillionToken: getEnvAsFloat("PROFILE_CURATION_USD_PER_MILLION_TOKENS", 0.30),
 			},
 		},
 	}
 
+	// Credential-dependent delays the user didn't set explicitly
+	cfg.Collector.applyRateLimitPresets()
+
 	// Validate
 	if err := cfg.Validate(); err != nil {
 		return nil, fmt.Errorf("config validation failed: %w", err)
@@ -118,6 +320,30 @@ func LoadConfig() (*Config, error) {
 	return cfg, nil
 }
 
+// loadJobs reads the named jobs listed in COLLECTOR_JOBS from their COLLECTOR_JOB_<NAME>_* variables
+func loadJobs() []JobConfig {
+	var jobs []JobConfig
+	for _, name := range getEnvAsList("COLLECTOR_JOBS") {
+		prefix := "COLLECTOR_JOB_" + strings.ToUpper(strings.ReplaceAll(name, "-", "_")) + "_"
+		events := getEnvAsList(prefix + "WEBHOOK_EVENTS")
+		if len(events) == 0 {
+			events = getEnvAsList("COLLECTOR_WEBHOOK_EVENTS")
+		}
+		jobs = append(jobs, JobConfig{
+			Name:        name,
+			CronExpr:    getEnv(prefix+"CRON_EXPR", ""),
+			OnlySources: getEnvAsList(prefix + "ONLY_SOURCES"),
+			SkipSources: getEnvAsList(prefix + "SKIP_SOURCES"),
+			Webhooks: WebhookConfig{
+				URLs:   getEnvAsList(prefix + "WEBHOOK_URLS"),
+				Events: events,
+				Secret: getEnv(prefix+"WEBHOOK_SECRET", getEnv("COLLECTOR_WEBHOOK_SECRET", "")),
+			},
+		})
+	}
+	return jobs
+}
+
 // Helper functions for environment variable parsing
 
 // getEnv returns the environment variable value or the default value if not set
@@ -145,6 +371,34 @@ func getEnvAsInt(key string, defaultValue int) int {
 	return value
 }
 
+// getEnvAsFloat returns the environment variable as a float or the default value
+// Logs a warning and returns default if the value cannot be parsed
+func getEnvAsFloat(key string, defaultValue float64) float64 {
+	valueStr := os.Getenv(key)
+	if valueStr == "" {
+		return defaultValue
+	}
+
+	value, err := strconv.ParseFloat(valueStr, 64)
+	if err != nil {
+		log.Printf("Warning: Invalid number for %s=%s, using default %v", key, valueStr, defaultValue)
+		return defaultValue
+	}
+
+	return value
+}
+
+// getEnvAsList returns the comma-separated environment variable as a list, skipping empty entries
+func getEnvAsList(key string) []string {
+	var values []string
+	for _, value := range strings.Split(os.Getenv(key), ",") {
+		if value = strings.TrimSpace(value); value != "" {
+			values = append(values, value)
+		}
+	}
+	return values
+}
+
 // getEnvAsBool returns the environment variable as a boolean or the default value
 // Accepts: true/false, 1/0, yes/no, on/off (case-insensitive)
 // Logs a warning and returns default if the value cannot be parsed
@@ -192,6 +446,12 @@ func (c *CollectorConfig) Validate() error {
 	if c.Server.MaxCommentDepth < 0 {
 		return fmt.Errorf("COLLECTOR_MAX_COMMENT_DEPTH must be non-negative, got %d", c.Server.MaxCommentDepth)
 	}
+	if c.Server.RetentionDays < 0 {
+		return fmt.Errorf("COLLECTOR_RETENTION_DAYS must be non-negative, got %d", c.Server.RetentionDays)
+	}
+	if _, err := cron.ParseStandard(c.Server.MaintenanceCronExpr); err != nil {
+		return fmt.Errorf("COLLECTOR_MAINTENANCE_CRON_EXPR is invalid: %w", err)
+	}
 
 	// Schedule validation
 	if c.Schedule.CronExpr == "" {
@@ -201,6 +461,21 @@ func (c *CollectorConfig) Validate() error {
 	if _, err := cron.ParseStandard(c.Schedule.CronExpr); err != nil {
 		return fmt.Errorf("COLLECTOR_CRON_EXPR is invalid: %w", err)
 	}
+	if err := c.Schedule.validateJobs(); err != nil {
+		return err
+	}
+	if c.Schedule.FetchCacheTTLSeconds < 0 {
+		return fmt.Errorf("COLLECTOR_FETCH_CACHE_TTL_SECONDS must be non-negative, got %d", c.Schedule.FetchCacheTTLSeconds)
+	}
+	if c.Schedule.RunDeadlineMinutes < 0 {
+		return fmt.Errorf("COLLECTOR_RUN_DEADLINE_MINUTES must be non-negative, got %d", c.Schedule.RunDeadlineMinutes)
+	}
+	if c.Schedule.RunMaxRequests < 0 {
+		return fmt.Errorf("COLLECTOR_RUN_MAX_REQUESTS must be non-negative, got %d", c.Schedule.RunMaxRequests)
+	}
+	if c.Schedule.ReportTopAuthors < 0 {
+		return fmt.Errorf("COLLECTOR_REPORT_TOP_AUTHORS must be non-negative, got %d", c.Schedule.ReportTopAuthors)
+	}
 
 	// Rate limits validation
 	if c.RateLimits.RedditDelayMs < 0 {
@@ -212,6 +487,115 @@ func (c *CollectorConfig) Validate() error {
 	if c.RateLimits.HackerNewsDelayMs < 0 {
 		return fmt.Errorf("COLLECTOR_HACKERNEWS_DELAY_MS must be non-negative, got %d", c.RateLimits.HackerNewsDelayMs)
 	}
+	if c.RateLimits.GitHubDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_GITHUB_DELAY_MS must be non-negative, got %d", c.RateLimits.GitHubDelayMs)
+	}
+	if c.RateLimits.StackExchangeDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_STACKEXCHANGE_DELAY_MS must be non-negative, got %d", c.RateLimits.StackExchangeDelayMs)
+	}
+	if c.RateLimits.BlueskyDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_BLUESKY_DELAY_MS must be non-negative, got %d", c.RateLimits.BlueskyDelayMs)
+	}
+	if c.RateLimits.OpenAlexDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_OPENALEX_DELAY_MS must be non-negative, got %d", c.RateLimits.OpenAlexDelayMs)
+	}
+	if c.RateLimits.PubMedDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_PUBMED_DELAY_MS must be non-negative, got %d", c.RateLimits.PubMedDelayMs)
+	}
+	if c.RateLimits.ArxivDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_ARXIV_DELAY_MS must be non-negative, got %d", c.RateLimits.ArxivDelayMs)
+	}
+	if c.RateLimits.DiscourseDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_DISCOURSE_DELAY_MS must be non-negative, got %d", c.RateLimits.DiscourseDelayMs)
+	}
+	if c.RateLimits.NitterDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_NITTER_DELAY_MS must be non-negative, got %d", c.RateLimits.NitterDelayMs)
+	}
+	if c.RateLimits.GoogleScholarDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_GOOGLE_SCHOLAR_DELAY_MS must be non-negative, got %d", c.RateLimits.GoogleScholarDelayMs)
+	}
+	if c.RateLimits.WebDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_WEB_DELAY_MS must be non-negative, got %d", c.RateLimits.WebDelayMs)
+	}
+	if c.RateLimits.JSONAPIDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_JSON_API_DELAY_MS must be non-negative, got %d", c.RateLimits.JSONAPIDelayMs)
+	}
+	if c.RateLimits.NewsDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_NEWS_DELAY_MS must be non-negative, got %d", c.RateLimits.NewsDelayMs)
+	}
+	if c.RateLimits.WikipediaDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_WIKIPEDIA_DELAY_MS must be non-negative, got %d", c.RateLimits.WikipediaDelayMs)
+	}
+	if c.RateLimits.KaggleDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_KAGGLE_DELAY_MS must be non-negative, got %d", c.RateLimits.KaggleDelayMs)
+	}
+	if c.RateLimits.PodcastDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_PODCAST_DELAY_MS must be non-negative, got %d", c.RateLimits.PodcastDelayMs)
+	}
+	if c.RateLimits.HuggingFaceDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_HUGGINGFACE_DELAY_MS must be non-negative, got %d", c.RateLimits.HuggingFaceDelayMs)
+	}
+	if c.RateLimits.PackageRegistryDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_PACKAGE_REGISTRY_DELAY_MS must be non-negative, got %d", c.RateLimits.PackageRegistryDelayMs)
+	}
+	if c.RateLimits.ImapDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_IMAP_DELAY_MS must be non-negative, got %d", c.RateLimits.ImapDelayMs)
+	}
+	if c.RateLimits.FeedDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_FEED_DELAY_MS must be non-negative, got %d", c.RateLimits.FeedDelayMs)
+	}
+	if c.RateLimits.ZenodoDelayMs < 0 {
+		return fmt.Errorf("COLLECTOR_ZENODO_DELAY_MS must be non-negative, got %d", c.RateLimits.ZenodoDelayMs)
+	}
+
+	// Webhook validation
+	if err := c.Webhooks.validate("COLLECTOR_WEBHOOK"); err != nil {
+		return err
+	}
+
+	// Object storage validation
+	if err := c.ObjectStore.validate(); err != nil {
+		return err
+	}
+
+	// Search index validation
+	if err := c.Search.validate(); err != nil {
+		return err
+	}
+
+	// HTTP client validation
+	if c.HTTP.TimeoutSeconds <= 0 {
+		return fmt.Errorf("COLLECTOR_HTTP_TIMEOUT_SECONDS must be positive, got %d", c.HTTP.TimeoutSeconds)
+	}
+	if c.HTTP.ConnectTimeoutSeconds <= 0 {
+		return fmt.Errorf("COLLECTOR_HTTP_CONNECT_TIMEOUT_SECONDS must be positive, got %d", c.HTTP.ConnectTimeoutSeconds)
+	}
+	if c.HTTP.KeepAliveSeconds < 0 {
+		return fmt.Errorf("COLLECTOR_HTTP_KEEPALIVE_SECONDS must be non-negative, got %d", c.HTTP.KeepAliveSeconds)
+	}
+	if c.HTTP.MaxIdleConnsPerHost <= 0 {
+		return fmt.Errorf("COLLECTOR_HTTP_MAX_IDLE_CONNS_PER_HOST must be positive, got %d", c.HTTP.MaxIdleConnsPerHost)
+	}
+	if c.HTTP.MaxRetries < 0 {
+		return fmt.Errorf("COLLECTOR_HTTP_MAX_RETRIES must be non-negative, got %d", c.HTTP.MaxRetries)
+	}
+	if c.HTTP.MaxRetries > 0 && c.HTTP.RetryBaseDelayMs <= 0 {
+		return fmt.Errorf("COLLECTOR_HTTP_RETRY_BASE_DELAY_MS must be positive, got %d", c.HTTP.RetryBaseDelayMs)
+	}
+	if (c.HTTP.ClientCertPath == "") != (c.HTTP.ClientKeyPath == "") {
+		return fmt.Errorf("COLLECTOR_HTTP_CLIENT_CERT and COLLECTOR_HTTP_CLIENT_KEY must be set together")
+	}
+
+	// Curation budget validation
+	if c.Profile.CurationMaxRequestsPerRun < 0 {
+		return fmt.Errorf("PROFILE_CURATION_MAX_REQUESTS_PER_RUN must be non-negative, got %d", c.Profile.CurationMaxRequestsPerRun)
+	}
+	if c.Profile.CurationMaxTokensPerRun < 0 {
+		return fmt.Errorf("PROFILE_CURATION_MAX_TOKENS_PER_RUN must be non-negative, got %d", c.Profile.CurationMaxTokensPerRun)
+	}
+	if c.Profile.CurationUSDPerMillionToken < 0 {
+		return fmt.Errorf("PROFILE_CURATION_USD_PER_MILLION_TOKENS must be non-negative, got %v", c.Profile.CurationUSDPerMillionToken)
+	}
 
 	// Credentials validation (check non-empty for required fields)
 	if c.Credentials.RedditClientID == "" {
@@ -232,3 +616,100 @@ func (c *CollectorConfig) Validate() error {
 
 	return nil
 }
+
+// jobNamePattern restricts job names to what maps cleanly onto environment variable names
+var jobNamePattern = regexp.MustCompile(`^[a-z0-9][a-z0-9_-]*$`)
+
+// validateJobs checks the named jobs; their source patterns are checked by the scheduler
+func (c *ScheduleConfig) validateJobs() error {
+	seen := map[string]bool{"default": true} // The job configured by COLLECTOR_CRON_EXPR
+	for _, job := range c.Jobs {
+		prefix := "COLLECTOR_JOB_" + strings.ToUpper(strings.ReplaceAll(job.Name, "-", "_"))
+		if !jobNamePattern.MatchString(job.Name) {
+			return fmt.Errorf("COLLECTOR_JOBS names must be lowercase letters, digits, '-' and '_', got %q", job.Name)
+		}
+		if seen[job.Name] {
+			return fmt.Errorf("COLLECTOR_JOBS must not repeat a job name or use \"default\", got %q", job.Name)
+		}
+		seen[job.Name] = true
+		if job.CronExpr == "" {
+			return fmt.Errorf("%s_CRON_EXPR is required", prefix)
+		}
+		if _, err := cron.ParseStandard(job.CronExpr); err != nil {
+			return fmt.Errorf("%s_CRON_EXPR is invalid: %w", prefix, err)
+		}
+		if err := job.Webhooks.validate(prefix + "_WEBHOOK"); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// validate checks webhook URLs and events, naming the variables by prefix (e.g. "COLLECTOR_WEBHOOK")
+func (c *WebhookConfig) validate(prefix string) error {
+	for _, endpoint := range c.URLs {
+		if u, err := url.Parse(endpoint); err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+			return fmt.Errorf("%s_URLS must contain absolute http(s) URLs, got %q", prefix, endpoint)
+		}
+	}
+	for _, event := range c.Events {
+		if !slices.Contains(WebhookEvents, event) {
+			return fmt.Errorf("%s_EVENTS must contain only %s, got %q", prefix, strings.Join(WebhookEvents, ", "), event)
+		}
+	}
+	return nil
+}
+
+// objectKeyPlaceholder matches a {name} placeholder in an object key template
+var objectKeyPlaceholder = regexp.MustCompile(`\{([^{}]*)\}`)
+
+// validate checks the bucket URL, its key template, the endpoint and the credentials
+func (c *ObjectStoreConfig) validate() error {
+	if c.URL == "" {
+		return nil
+	}
+	u, err := url.Parse(c.URL)
+	if err != nil || u.Scheme != "s3" || u.Host == "" {
+		return fmt.Errorf("COLLECTOR_S3_URL must look like s3://bucket/key-template, got %q", c.URL)
+	}
+	for _, match := range objectKeyPlaceholder.FindAllStringSubmatch(u.Path, -1) {
+		if !slices.Contains(ObjectKeyPlaceholders, match[1]) {
+			return fmt.Errorf("COLLECTOR_S3_URL key placeholders must be one of {%s}, got {%s}", strings.Join(ObjectKeyPlaceholders, "}, {"), match[1])
+		}
+	}
+	if c.Endpoint != "" {
+		if e, err := url.Parse(c.Endpoint); err != nil || (e.Scheme != "http" && e.Scheme != "https") || e.Host == "" {
+			return fmt.Errorf("COLLECTOR_S3_ENDPOINT must be an absolute http(s) URL, got %q", c.Endpoint)
+		}
+	}
+	if c.Region == "" {
+		return fmt.Errorf("COLLECTOR_S3_REGION is required when COLLECTOR_S3_URL is set")
+	}
+	if c.AccessKeyID == "" || c.SecretAccessKey == "" {
+		return fmt.Errorf("AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY are required when COLLECTOR_S3_URL is set")
+	}
+	return nil
+}
+
+// indexNamePattern follows Elasticsearch's index naming rules: lowercase, no separators or wildcards, not starting with -, _ or +
+var indexNamePattern = regexp.MustCompile(`^[a-z0-9][^A-Z\\/*?"<>| ,#:]*$`)
+
+// validate checks the cluster URL, the index name and the credentials
+func (c *SearchIndexConfig) validate() error {
+	if c.URL == "" {
+		return nil
+	}
+	if u, err := url.Parse(c.URL); err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+		return fmt.Errorf("COLLECTOR_ELASTICSEARCH_URL must be an absolute http(s) URL, got %q", c.URL)
+	}
+	if !indexNamePattern.MatchString(c.Index) || len(c.Index) > 255 {
+		return fmt.Errorf("COLLECTOR_ELASTICSEARCH_INDEX must be a valid lowercase index name, got %q", c.Index)
+	}
+	if (c.Username == "") != (c.Password == "") {
+		return fmt.Errorf("COLLECTOR_ELASTICSEARCH_USERNAME and COLLECTOR_ELASTICSEARCH_PASSWORD must be set together")
+	}
+	if c.APIKey != "" && c.Username != "" {
+		return fmt.Errorf("COLLECTOR_ELASTICSEARCH_API_KEY and COLLECTOR_ELASTICSEARCH_USERNAME are mutually exclusive")
+	}
+	return nil
+}
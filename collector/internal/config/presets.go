@@ -0,0 +1,35 @@
+package config
+
+import "os"
+
+// rateLimitPreset is a built-in delay for an API whose limits depend on the credentials in use
+type rateLimitPreset struct {
+	env           string // Delay env var; an explicit value always wins over the preset
+	delayMs       *int
+	authenticated bool
+	withCredsMs   int // Safe delay with credentials
+	withoutMs     int // Safe delay for anonymous access
+}
+
+// rateLimitPresets lists the APIs with credential-dependent limits
+func (c *CollectorConfig) rateLimitPresets() []rateLimitPreset {
+	return []rateLimitPreset{
+		// Search allows 30 requests/minute with a token and 10 without (REST core: 5,000 vs 60 per hour)
+		{"COLLECTOR_GITHUB_DELAY_MS", &c.RateLimits.GitHubDelayMs, c.Credentials.GitHubToken != "", 2000, 6000},
+		// E-utilities allow 10 requests/second with an API key and 3 without
+		{"COLLECTOR_PUBMED_DELAY_MS", &c.RateLimits.PubMedDelayMs, c.Credentials.NCBIAPIKey != "", 100, 400},
+	}
+}
+
+// applyRateLimitPresets sets credential-dependent delays that weren't set explicitly
+func (c *CollectorConfig) applyRateLimitPresets() {
+	for _, preset := range c.rateLimitPresets() {
+		if os.Getenv(preset.env) != "" {
+			continue
+		}
+		*preset.delayMs = preset.withoutMs
+		if preset.authenticated {
+			*preset.delayMs = preset.withCredsMs
+		}
+	}
+}
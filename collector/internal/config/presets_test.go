@@ -0,0 +1,25 @@
+package config
+
+import "testing"
+
+func TestApplyRateLimitPresets_OnlyWhenUnset(t *testing.T) {
+	t.Setenv("COLLECTOR_GITHUB_DELAY_MS", "")
+	t.Setenv("COLLECTOR_PUBMED_DELAY_MS", "250")
+
+	c := &CollectorConfig{}
+	c.RateLimits.PubMedDelayMs = 250
+	c.applyRateLimitPresets()
+	if c.RateLimits.GitHubDelayMs != 6000 {
+		t.Errorf("Expected the anonymous GitHub preset, got %d", c.RateLimits.GitHubDelayMs)
+	}
+	if c.RateLimits.PubMedDelayMs != 250 {
+		t.Errorf("Expected the explicit PubMed delay to win over the preset, got %d", c.RateLimits.PubMedDelayMs)
+	}
+
+	c.Credentials.GitHubToken = "token"
+	c.Credentials.NCBIAPIKey = "key"
+	c.applyRateLimitPresets()
+	if c.RateLimits.GitHubDelayMs != 2000 || c.RateLimits.PubMedDelayMs != 250 {
+		t.Errorf("Expected the GitHub token preset and the explicit PubMed delay, got %d and %d", c.RateLimits.GitHubDelayMs, c.RateLimits.PubMedDelayMs)
+	}
+}
@@ -0,0 +1,277 @@
+package db
+
+import (
+	"bufio"
+	"database/sql"
+	"encoding/json"
+	"fmt"
+	"io"
+	"time"
+
+	"github.com/google/uuid"
+)
+
+// maxArchiveLine bounds one NDJSON line read by ImportState
+const maxArchiveLine = 16 << 20
+
+// StateRecord is one line of a state export: a source's crawl cursor or one of its articles
+// Sources are addressed by type, external ID and profile rather than row ID, so records carry over
+// to a database whose sources were created anew
+type StateRecord struct {
+	Record           string     `json:"record"` // "source" or "article"; lines without it, as from GET /articles?format=ndjson, are articles
+	SourceType       string     `json:"source_type"`
+	SourceExternalID string     `json:"source_external_id,omitempty"`
+	SourceID         string     `json:"source_id,omitempty"` // Fallback for lines without source_external_id
+	ProfileID        string     `json:"profile_id"`
+	LastRunAt        *time.Time `json:"last_run_at,omitempty"`
+	LastSuccessAt    *time.Time `json:"last_success_at,omitempty"` // Where the next incremental fetch of the source starts
+
+	ExternalID string          `json:"external_id,omitempty"`
+	Title      string          `json:"title,omitempty"`
+	Author     string          `json:"author,omitempty"`
+	Content    string          `json:"content,omitempty"`
+	URL        string          `json:"url,omitempty"`
+	WrittenAt  *time.Time      `json:"written_at,omitempty"`
+	Metadata   json.RawMessage `json:"metadata,omitempty"`
+	CreatedAt  *time.Time      `json:"created_at,omitempty"`
+}
+
+// ImportResult counts what ImportState applied and skipped
+// @Description Outcome of a state import
+type ImportResult struct {
+	Sources        int      `json:"sources" example:"12"`      // Source cursors moved forward
+	Articles       int      `json:"articles" example:"15230"`  // Articles inserted
+	Existing       int      `json:"existing" example:"40"`     // Articles already present, left untouched
+	Skipped        int      `json:"skipped" example:"3"`       // Records whose source doesn't exist here
+	UnknownSources []string `json:"unknown_sources,omitempty"` // type:external_id of those sources
+}
+
+// ExportState writes every source cursor followed by every article as NDJSON, limited to one profile if profileID is set
+// Configs are left out, since they may hold credentials; create the sources on the target before importing
+func (db *DB) ExportState(w io.Writer, profileID string) error {
+	encoder := json.NewEncoder(w)
+
+	rows, err := db.Query(`
+		SELECT type, COALESCE(external_id, ''), profile_id, last_run_at, last_success_at
+		FROM sources
+		WHERE ? = '' OR profile_id = ?
+		ORDER BY type, external_id`, profileID, profileID)
+	if err != nil {
+		return fmt.Errorf("failed to query sources: %w", err)
+	}
+	for rows.Next() {
+		record := StateRecord{Record: "source"}
+		var lastRunAt, lastSuccessAt sql.NullTime
+		if err := rows.Scan(&record.SourceType, &record.SourceExternalID, &record.ProfileID, &lastRunAt, &lastSuccessAt); err != nil {
+			rows.Close()
+			return fmt.Errorf("failed to scan source: %w", err)
+		}
+		record.LastRunAt = nullTimePtr(lastRunAt)
+		record.LastSuccessAt = nullTimePtr(lastSuccessAt)
+		if err := encoder.Encode(record); err != nil {
+			rows.Close()
+			return err
+		}
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return err
+	}
+
+	rows, err = db.Query(`
+		SELECT s.type, COALESCE(s.external_id, ''), a.profile_id, a.external_id, a.title, a.author, a.content, a.url, a.written_at, a.metadata, a.created_at
+		FROM articles a
+		JOIN sources s ON a.source_id = s.id
+		WHERE ? = '' OR a.profile_id = ?
+		ORDER BY a.source_id, a.created_at`, profileID, profileID)
+	if err != nil {
+		return fmt.Errorf("failed to query articles: %w", err)
+	}
+	defer rows.Close()
+	for rows.Next() {
+		record := StateRecord{Record: "article"}
+		var title, author, content, url, metadata sql.NullString
+		var writtenAt, createdAt sql.NullTime
+		if err := rows.Scan(&record.SourceType, &record.SourceExternalID, &record.ProfileID, &record.ExternalID,
+			&title, &author, &content, &url, &writtenAt, &metadata, &createdAt); err != nil {
+			return fmt.Errorf("failed to scan article: %w", err)
+		}
+		record.Title, record.Author, record.Content, record.URL = title.String, author.String, content.String, url.String
+		record.WrittenAt = nullTimePtr(writtenAt)
+		record.CreatedAt = nullTimePtr(createdAt)
+		if metadata.Valid && metadata.String != "" {
+			record.Metadata = json.RawMessage(metadata.String)
+		}
+		if err := encoder.Encode(record); err != nil {
+			return err
+		}
+	}
+	return rows.Err()
+}
+
+// ImportState applies an NDJSON state export in one transaction
+// Source cursors only move forward and existing articles are never overwritten, so importing an older export is harmless
+// profileID, if set, replaces the profile of every record, for moving state between profiles
+func (db *DB) ImportState(r io.Reader, profileID string) (*ImportResult, error) {
+	tx, err := db.Begin()
+	if err != nil {
+		return nil, err
+	}
+	defer tx.Rollback()
+
+	insert, err := tx.Prepare(`
+		INSERT INTO articles (id, source_id, external_id, profile_id, title, author, content, url, written_at, metadata, created_at)
+		VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+		ON CONFLICT(source_id, external_id) DO NOTHING`)
+	if err != nil {
+		return nil, fmt.Errorf("failed to prepare statement: %w", err)
+	}
+	defer insert.Close()
+
+	result := &ImportResult{}
+	sources := make(map[string]importSource) // Lookup key -> source (zero when missing)
+	unknown := make(map[string]bool)
+
+	scanner := bufio.NewScanner(r)
+	scanner.Buffer(make([]byte, 64*1024), maxArchiveLine)
+	for line := 1; scanner.Scan(); line++ {
+		if len(scanner.Bytes()) == 0 {
+			continue
+		}
+		var record StateRecord
+		if err := json.Unmarshal(scanner.Bytes(), &record); err != nil {
+			return nil, fmt.Errorf("line %d: %w", line, err)
+		}
+		if profileID != "" {
+			record.ProfileID = profileID
+		}
+
+		src, err := resolveSource(tx, sources, record)
+		if err != nil {
+			return nil, fmt.Errorf("line %d: %w", line, err)
+		}
+		if src.id == "" {
+			result.Skipped++
+			if key := record.SourceType + ":" + record.SourceExternalID; record.SourceExternalID != "" && !unknown[key] {
+				unknown[key] = true
+				result.UnknownSources = append(result.UnknownSources, key)
+			}
+			continue
+		}
+
+		switch record.Record {
+		case "source":
+			advanced, err := advanceCursor(tx, src.id, record)
+			if err != nil {
+				return nil, fmt.Errorf("line %d: %w", line, err)
+			}
+			if advanced {
+				result.Sources++
+			}
+		case "", "article":
+			if record.ExternalID == "" {
+				return nil, fmt.Errorf("line %d: external_id is required", line)
+			}
+			now := time.Now()
+			writtenAt, createdAt := now, now
+			if record.WrittenAt != nil {
+				writtenAt = *record.WrittenAt
+			}
+			if record.CreatedAt != nil {
+				createdAt = *record.CreatedAt
+			}
+			metadata := record.Metadata
+			if len(metadata) == 0 {
+				metadata = json.RawMessage("{}")
+			}
+			res, err := insert.Exec(uuid.New().String(), src.id, record.ExternalID, src.profileID,
+				record.Title, record.Author, record.Content, record.URL, writtenAt, metadata, createdAt)
+			if err != nil {
+				return nil, fmt.Errorf("line %d: failed to insert article: %w", line, err)
+			}
+			if n, _ := res.RowsAffected(); n > 0 {
+				result.Articles++
+			} else {
+				result.Existing++
+			}
+		default:
+			return nil, fmt.Errorf("line %d: unknown record %q", line, record.Record)
+		}
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, err
+	}
+
+	if err := tx.Commit(); err != nil {
+		return nil, err
+	}
+	return result, nil
+}
+
+// importSource is a source records were matched to
+type importSource struct {
+	id        string
+	profileID string
+}
+
+// resolveSource finds a record's source, by type, external ID and profile or else by source_id
+// Returns the zero importSource for a source that doesn't exist in this database
+func resolveSource(tx *sql.Tx, cache map[string]importSource, record StateRecord) (importSource, error) {
+	query, args := "SELECT id, profile_id FROM sources WHERE id = ?", []interface{}{record.SourceID}
+	key := "id:" + record.SourceID
+	if record.SourceExternalID != "" {
+		query = "SELECT id, profile_id FROM sources WHERE type = ? AND external_id = ? AND profile_id = ?"
+		args = []interface{}{record.SourceType, record.SourceExternalID, record.ProfileID}
+		key = record.SourceType + "\x00" + record.SourceExternalID + "\x00" + record.ProfileID
+	} else if record.SourceID == "" {
+		return importSource{}, fmt.Errorf("source_external_id or source_id is required")
+	}
+
+	if src, ok := cache[key]; ok {
+		return src, nil
+	}
+	var src importSource
+	if err := tx.QueryRow(query, args...).Scan(&src.id, &src.profileID); err != nil && err != sql.ErrNoRows {
+		return importSource{}, fmt.Errorf("failed to look up source: %w", err)
+	}
+	cache[key] = src
+	return src, nil
+}
+
+// advanceCursor moves a source's last run and last success times forward to the record's, never back
+func advanceCursor(tx *sql.Tx, sourceID string, record StateRecord) (bool, error) {
+	var lastRunAt, lastSuccessAt sql.NullTime
+	if err := tx.QueryRow("SELECT last_run_at, last_success_at FROM sources WHERE id = ?", sourceID).Scan(&lastRunAt, &lastSuccessAt); err != nil {
+		return false, fmt.Errorf("failed to read source: %w", err)
+	}
+
+	advanced := false
+	for _, cursor := range []struct {
+		current  *sql.NullTime
+		imported *time.Time
+	}{
+		{&lastRunAt, record.LastRunAt},
+		{&lastSuccessAt, record.LastSuccessAt},
+	} {
+		if cursor.imported != nil && (!cursor.current.Valid || cursor.current.Time.Before(*cursor.imported)) {
+			*cursor.current = sql.NullTime{Time: *cursor.imported, Valid: true}
+			advanced = true
+		}
+	}
+	if !advanced {
+		return false, nil
+	}
+
+	if _, err := tx.Exec("UPDATE sources SET last_run_at = ?, last_success_at = ? WHERE id = ?", lastRunAt, lastSuccessAt, sourceID); err != nil {
+		return false, fmt.Errorf("failed to update source: %w", err)
+	}
+	return true, nil
+}
+
+// nullTimePtr converts a nullable column to an optional time
+func nullTimePtr(t sql.NullTime) *time.Time {
+	if !t.Valid {
+		return nil
+	}
+	return &t.Time
+}
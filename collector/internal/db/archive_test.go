@@ -0,0 +1,68 @@
+package db
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestExportImportState(t *testing.T) {
+	from, err := Init(t.TempDir() + "/from.db")
+	if err != nil {
+		t.Fatalf("Failed to create test database: %v", err)
+	}
+	defer from.Close()
+	to, err := Init(t.TempDir() + "/to.db")
+	if err != nil {
+		t.Fatalf("Failed to create test database: %v", err)
+	}
+	defer to.Close()
+
+	lastSuccess := time.Date(2024, 11, 15, 12, 0, 0, 0, time.UTC)
+	if _, err := from.Exec(`INSERT INTO sources (id, type, config, external_id, profile_id, last_success_at) VALUES ('a', 'reddit', '{}', 'golang', 'p', ?)`, lastSuccess); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := from.Exec(`INSERT INTO articles (id, source_id, external_id, profile_id, title, written_at, metadata, created_at) VALUES ('x', 'a', 'abc', 'p', 'Hello', ?, '{"score":3}', ?)`, lastSuccess, lastSuccess); err != nil {
+		t.Fatal(err)
+	}
+	// The same source on the target has a different row ID and has never run
+	if _, err := to.Exec(`INSERT INTO sources (id, type, config, external_id, profile_id) VALUES ('b', 'reddit', '{}', 'golang', 'p')`); err != nil {
+		t.Fatal(err)
+	}
+
+	var archive bytes.Buffer
+	if err := from.ExportState(&archive, ""); err != nil {
+		t.Fatalf("ExportState failed: %v", err)
+	}
+	archive.WriteString(`{"record":"article","source_type":"reddit","source_external_id":"rust","profile_id":"p","external_id":"zzz"}` + "\n")
+
+	result, err := to.ImportState(strings.NewReader(archive.String()), "")
+	if err != nil {
+		t.Fatalf("ImportState failed: %v", err)
+	}
+	if result.Sources != 1 || result.Articles != 1 || result.Skipped != 1 || len(result.UnknownSources) != 1 || result.UnknownSources[0] != "reddit:rust" {
+		t.Errorf("Unexpected import result: %+v", result)
+	}
+
+	var title, metadata string
+	if err := to.QueryRow(`SELECT title, metadata FROM articles WHERE source_id = 'b' AND external_id = 'abc'`).Scan(&title, &metadata); err != nil {
+		t.Fatalf("Imported article not found: %v", err)
+	}
+	if title != "Hello" || metadata != `{"score":3}` {
+		t.Errorf("Unexpected imported article: %q %s", title, metadata)
+	}
+	var imported time.Time
+	if err := to.QueryRow(`SELECT last_success_at FROM sources WHERE id = 'b'`).Scan(&imported); err != nil || !imported.Equal(lastSuccess) {
+		t.Errorf("Expected last_success_at %v, got %v (%v)", lastSuccess, imported, err)
+	}
+
+	// Importing again changes nothing
+	result, err = to.ImportState(strings.NewReader(archive.String()), "")
+	if err != nil {
+		t.Fatalf("ImportState failed: %v", err)
+	}
+	if result.Sources != 0 || result.Articles != 0 || result.Existing != 1 {
+		t.Errorf("Expected a repeated import to be a no-op, got %+v", result)
+	}
+}
@@ -0,0 +1,140 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+	"time"
+)
+
+// compactFreeRatio is the share of free pages above which Compact rewrites the database file
+const compactFreeRatio = 0.2
+
+// StoreStats describes what the database holds and how much of its file is unused
+// @Description Database size and row counts
+type StoreStats struct {
+	Articles      int        `json:"articles" example:"15230"`
+	Comments      int        `json:"comments" example:"80412"`
+	Likes         int        `json:"likes" example:"37"`
+	Curated       int        `json:"curated" example:"1204"`
+	OldestArticle *time.Time `json:"oldest_article,omitempty" example:"2024-01-02T12:00:00Z"` // When the oldest stored article was stored
+	SizeBytes     int64      `json:"size_bytes" example:"104857600"`                          // Database file size, without the WAL
+	FreeBytes     int64      `json:"free_bytes" example:"4194304"`                            // Unused pages that compaction would reclaim
+}
+
+// PruneResult counts the rows removed by Prune
+// @Description Rows removed by a prune
+type PruneResult struct {
+	Articles int64     `json:"articles" example:"1200"`
+	Comments int64     `json:"comments" example:"8300"`
+	Curated  int64     `json:"curated" example:"45"`
+	Cutoff   time.Time `json:"cutoff" example:"2024-08-15T00:00:00Z"` // Articles stored before this were removed
+}
+
+// Stats returns row counts and the size of the database file
+func (db *DB) Stats() (*StoreStats, error) {
+	var stats StoreStats
+	for _, count := range []struct {
+		table string
+		dest  *int
+	}{
+		{"articles", &stats.Articles},
+		{"comments", &stats.Comments},
+		{"likes", &stats.Likes},
+		{"curated", &stats.Curated},
+	} {
+		if err := db.QueryRow("SELECT COUNT(*) FROM " + count.table).Scan(count.dest); err != nil {
+			return nil, fmt.Errorf("failed to count %s: %w", count.table, err)
+		}
+	}
+
+	var oldest sql.NullTime
+	if err := db.QueryRow("SELECT MIN(created_at) FROM articles").Scan(&oldest); err != nil {
+		return nil, fmt.Errorf("failed to query oldest article: %w", err)
+	}
+	if oldest.Valid {
+		stats.OldestArticle = &oldest.Time
+	}
+
+	size, free, err := db.pageUsage()
+	if err != nil {
+		return nil, err
+	}
+	stats.SizeBytes, stats.FreeBytes = size, free
+	return &stats, nil
+}
+
+// Prune removes articles stored before cutoff, with their comments and curation entries
+// Liked articles are kept, since likes drive profile updates
+func (db *DB) Prune(cutoff time.Time) (*PruneResult, error) {
+	tx, err := db.Begin()
+	if err != nil {
+		return nil, err
+	}
+	defer tx.Rollback()
+
+	// Foreign keys aren't enforced, so dependent rows are removed explicitly
+	const expired = `SELECT id FROM articles WHERE created_at < ? AND id NOT IN (SELECT article_id FROM likes)`
+	result := &PruneResult{Cutoff: cutoff}
+	for _, step := range []struct {
+		query string
+		count *int64
+	}{
+		{"DELETE FROM comments WHERE article_id IN (" + expired + ")", &result.Comments},
+		{"DELETE FROM curated WHERE article_id IN (" + expired + ")", &result.Curated},
+		{"DELETE FROM articles WHERE id IN (" + expired + ")", &result.Articles},
+	} {
+		res, err := tx.Exec(step.query, cutoff)
+		if err != nil {
+			return nil, fmt.Errorf("failed to prune: %w", err)
+		}
+		if *step.count, err = res.RowsAffected(); err != nil {
+			return nil, err
+		}
+	}
+
+	if err := tx.Commit(); err != nil {
+		return nil, err
+	}
+	return result, nil
+}
+
+// Compact rewrites the database file once free pages exceed compactFreeRatio of it
+// Returns the bytes reclaimed, 0 if the file didn't need compacting
+func (db *DB) Compact() (int64, error) {
+	size, free, err := db.pageUsage()
+	if err != nil {
+		return 0, err
+	}
+	if size == 0 || float64(free) < float64(size)*compactFreeRatio {
+		return 0, nil
+	}
+
+	// VACUUM blocks writers for its duration; the checkpoint first keeps the WAL from growing alongside
+	if _, err := db.Exec("PRAGMA wal_checkpoint(TRUNCATE)"); err != nil {
+		return 0, fmt.Errorf("failed to checkpoint WAL: %w", err)
+	}
+	if _, err := db.Exec("VACUUM"); err != nil {
+		return 0, fmt.Errorf("failed to vacuum: %w", err)
+	}
+
+	after, _, err := db.pageUsage()
+	if err != nil {
+		return 0, err
+	}
+	return size - after, nil
+}
+
+// pageUsage returns the size of the database file and of its unused pages
+func (db *DB) pageUsage() (size, free int64, err error) {
+	var pageSize, pageCount, freeCount int64
+	if err := db.QueryRow("PRAGMA page_size").Scan(&pageSize); err != nil {
+		return 0, 0, fmt.Errorf("failed to query page size: %w", err)
+	}
+	if err := db.QueryRow("PRAGMA page_count").Scan(&pageCount); err != nil {
+		return 0, 0, fmt.Errorf("failed to query page count: %w", err)
+	}
+	if err := db.QueryRow("PRAGMA freelist_count").Scan(&freeCount); err != nil {
+		return 0, 0, fmt.Errorf("failed to query free pages: %w", err)
+	}
+	return pageSize * pageCount, pageSize * freeCount, nil
+}
@@ -0,0 +1,57 @@
+package db
+
+import (
+	"testing"
+	"time"
+)
+
+func TestPrune(t *testing.T) {
+	database, err := Init(t.TempDir() + "/test.db")
+	if err != nil {
+		t.Fatalf("Failed to create test database: %v", err)
+	}
+	defer database.Close()
+
+	old := time.Now().AddDate(0, 0, -60)
+	for _, article := range []struct {
+		id        string
+		createdAt time.Time
+	}{
+		{"old", old},
+		{"old-liked", old},
+		{"new", time.Now()},
+	} {
+		if _, err := database.Exec(`INSERT INTO articles (id, source_id, external_id, profile_id, created_at) VALUES (?, 'src', ?, 'p', ?)`,
+			article.id, article.id, article.createdAt); err != nil {
+			t.Fatal(err)
+		}
+		if _, err := database.Exec(`INSERT INTO comments (id, article_id, external_id) VALUES (?, ?, 'c')`, "c-"+article.id, article.id); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if _, err := database.Exec(`INSERT INTO likes (id, profile_id, article_id) VALUES ('l', 'p', 'old-liked')`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := database.Exec(`INSERT INTO curated (id, profile_id, article_id, reason) VALUES ('k', 'p', 'old', 'r')`); err != nil {
+		t.Fatal(err)
+	}
+
+	result, err := database.Prune(time.Now().AddDate(0, 0, -30))
+	if err != nil {
+		t.Fatalf("Prune failed: %v", err)
+	}
+	if result.Articles != 1 || result.Comments != 1 || result.Curated != 1 {
+		t.Errorf("Unexpected prune result: %+v", result)
+	}
+
+	stats, err := database.Stats()
+	if err != nil {
+		t.Fatalf("Stats failed: %v", err)
+	}
+	if stats.Articles != 2 || stats.Comments != 2 || stats.Likes != 1 || stats.Curated != 0 {
+		t.Errorf("Unexpected stats after prune: %+v", stats)
+	}
+	if stats.SizeBytes <= 0 {
+		t.Errorf("Expected a database size, got %d", stats.SizeBytes)
+	}
+}
@@ -2,6 +2,8 @@ package db
 
 import (
 	"encoding/json"
+	"fmt"
+	"strings"
 	"time"
 )
 
@@ -10,7 +12,7 @@ import (
 // Global credentials and schedule are stored in .config.yaml file
 type Source struct {
 	ID            string          `json:"id"`
-	Type          string          `json:"type"`        // "reddit", "semantic_scholar", or "hackernews"
+	Type          string          `json:"type"`        // One of source.Types (e.g. "reddit", "hackernews", "github")
 	Config        json.RawMessage `json:"config"`      // Per-source settings (subreddit, query, filters, etc.)
 	ExternalID    string          `json:"external_id"` // For dedup (e.g., subreddit name)
 	ProfileID     string          `json:"profile_id"`  // Profile that owns this source
@@ -26,7 +28,8 @@ type Source struct {
 type Article struct {
 	ID         string          `json:"id" example:"550e8400-e29b-41d4-a716-446655440000"`
 	SourceID   string          `json:"source_id" example:"660e8400-e29b-41d4-a716-446655440001"`
-	ExternalID string          `json:"external_id" example:"abc123"` // Reddit post ID / S2 paper ID
+	ExternalID string          `json:"external_id" example:"abc123"`                             // Reddit post ID / S2 paper ID
+	UID        string          `json:"uid,omitempty" example:"9f86d081884c7d659a2feaa0c55ad015"` // ArticleUID of the source type and external ID; derived, not a column
 	ProfileID  string          `json:"profile_id" example:"770e8400-e29b-41d4-a716-446655440002"`
 	Title      string          `json:"title" example:"Understanding Go Concurrency"`
 	Author     string          `json:"author" example:"user123"`
@@ -76,39 +79,104 @@ type Like struct {
 // @Description Scheduled crawl job information
 type ScheduleEntry struct {
 	SourceID   string     `json:"source_id" example:"550e8400-e29b-41d4-a716-446655440000"`
-	SourceType string     `json:"source_type" example:"reddit" enums:"reddit,semantic_scholar,hackernews"`
+	SourceType string     `json:"source_type" example:"reddit" enums:"reddit,semantic_scholar,hackernews,github,stackexchange,bluesky,openalex,pubmed,arxiv,discourse,nitter,google_scholar,web,json_api,news,wikipedia,kaggle,podcast,huggingface,package_registry,imap,file,feed,zenodo"`
 	NextRun    time.Time  `json:"next_run" example:"2024-11-15T18:00:00Z"`
 	LastRunAt  *time.Time `json:"last_run_at,omitempty" example:"2024-11-15T12:00:00Z"`
 }
 
+// SourceOptions holds per-source settings shared by every source type
+// They are read from the same config JSON as the type-specific fields
+type SourceOptions struct {
+	Tags            []string       `json:"tags,omitempty"`               // User-defined labels copied into every article's metadata
+	UserAgents      []string       `json:"user_agents,omitempty"`        // Opt-in pool rotated per request for HTML scraping (ignored by API calls)
+	CookieFile      string         `json:"cookie_file,omitempty"`        // Opt-in JSON cookie jar for HTML scraping, loaded before and saved after each run
+	IncludeKeywords []string       `json:"include_keywords,omitempty"`   // Keep only articles whose title or content contains at least one of these (case-insensitive)
+	ExcludeKeywords []string       `json:"exclude_keywords,omitempty"`   // Drop articles whose title or content contains any of these (case-insensitive)
+	Queries         []KeywordQuery `json:"queries,omitempty"`            // Named keyword sets checked in the same pass; an article matching one is kept and lists it in matched_queries
+	KeywordsURL     string         `json:"keywords_url,omitempty"`       // Shared watchlist (one keyword per line, "-" to exclude) merged into the lists above each run
+	MaxItemAgeHours int            `json:"max_item_age_hours,omitempty"` // Never look further back than this; time-ordered listings stop paginating at older items
+	ActiveDays      []string       `json:"active_days,omitempty"`        // Scheduled runs only on these weekdays ("mon".."sun")
+	ActiveHours     string         `json:"active_hours,omitempty"`       // Scheduled runs only within "HH:MM-HH:MM" (may wrap past midnight)
+	Timezone        string         `json:"timezone,omitempty"`           // IANA zone for active_days/active_hours (default: server local time)
+	DedupKey        string         `json:"dedup_key,omitempty"`          // Article identity: "native" (default), "url", "title" or "content"
+	Priority        int            `json:"priority,omitempty"`           // Higher runs first within its type and is the last to be skipped by run limits (default: 0)
+	TargetMatches   int            `json:"target_matches,omitempty"`     // Paginated sources keep paging until this many articles pass the keyword filters, instead of stopping at limit
+	TargetRequests  int            `json:"target_requests,omitempty"`    // HTTP request budget per run for target_matches (default: 50)
+}
+
+// KeywordQuery is one named keyword set of a source's queries option
+type KeywordQuery struct {
+	Name     string   `json:"name"`
+	Keywords []string `json:"keywords"`        // Case-insensitive substrings of the title or content
+	Match    string   `json:"match,omitempty"` // "any" (default) or "all" of the keywords
+}
+
 // RedditConfig holds Reddit-specific per-source configuration
 // Credentials and rate limits are now global (see GlobalConfig and env vars)
 type RedditConfig struct {
-	Subreddit   string `json:"subreddit"`
+	Subreddit   string `json:"subreddit"`             // One subreddit, or several joined with "+" (e.g. "rust+golang")
+	User        string `json:"user,omitempty"`        // Crawl this user's submissions (or comments) instead of a subreddit
 	Sort        string `json:"sort"`                  // "hot", "new", "top", "rising"
 	TimeFilter  string `json:"time_filter,omitempty"` // For "top": "hour", "day", "week", "month", "year", "all"
 	Limit       int    `json:"limit"`
 	MinScore    int    `json:"min_score"`
 	MinComments int    `json:"min_comments"`
 	UserAgent   string `json:"user_agent"`
+	FetchMode   string `json:"fetch_mode,omitempty"` // "posts" (default) or "comments" (each comment becomes an article)
+	PostID      string `json:"post_id,omitempty"`    // For "comments": crawl this post's comment tree instead of the subreddit's recent comments
 }
 
 // SemanticScholarConfig holds Semantic Scholar per-source configuration
 // API key and rate limits are now global (see GlobalConfig and env vars)
 type SemanticScholarConfig struct {
-	Mode         string  `json:"mode"` // "search" or "recommendations"
-	Query        *string `json:"query,omitempty"`
-	PaperID      *string `json:"paper_id,omitempty"`
-	Year         *string `json:"year,omitempty"`
-	MaxResults   int     `json:"max_results"`
-	MinCitations int     `json:"min_citations"`
+	Mode          string   `json:"mode"`            // "search", "bulk", "recommendations", "author", or "dataset"
+	Query         S2Query  `json:"query,omitempty"` // Search/bulk: one query or a list of queries run concurrently
+	PaperID       *string  `json:"paper_id,omitempty"`
+	AuthorID      *string  `json:"author_id,omitempty"`    // Author mode: S2 author ID
+	DatasetPath   *string  `json:"dataset_path,omitempty"` // Dataset mode: local path or URL of a papers JSONL snapshot (.gz ok)
+	Year          *string  `json:"year,omitempty"`
+	FieldsOfStudy []string `json:"fields_of_study,omitempty"` // Search/bulk filter, e.g. ["Computer Science", "Medicine"]
+	Sort          string   `json:"sort,omitempty"`            // Search order: "relevance", "publicationDate", "citationCount" (default: "relevance")
+	MaxResults    int      `json:"max_results"`
+	MinCitations  int      `json:"min_citations"`
+}
+
+// S2Query is a Semantic Scholar search query, given in JSON as a string or a list of strings
+type S2Query []string
+
+// UnmarshalJSON accepts "query" as well as ["query", ...]
+func (q *S2Query) UnmarshalJSON(data []byte) error {
+	var single string
+	if err := json.Unmarshal(data, &single); err == nil {
+		*q = S2Query{single}
+		return nil
+	}
+	var list []string
+	if err := json.Unmarshal(data, &list); err != nil {
+		return fmt.Errorf("query must be a string or a list of strings")
+	}
+	*q = list
+	return nil
+}
+
+// MarshalJSON writes a single query back as a plain string
+func (q S2Query) MarshalJSON() ([]byte, error) {
+	if len(q) == 1 {
+		return json.Marshal(q[0])
+	}
+	return json.Marshal([]string(q))
+}
+
+// String joins the queries for external IDs and summaries; a single query is returned as is
+func (q S2Query) String() string {
+	return strings.Join(q, " | ")
 }
 
 // HackerNewsConfig holds Hacker News per-source configuration
 // No API key required (public API). Rate limits are global (see GlobalConfig and env vars)
 type HackerNewsConfig struct {
-	ItemType              string `json:"item_type"`                // "top", "new", "best", "ask", "show", "job"
-	Limit                 int    `json:"limit"`                    // Max story IDs to fetch (1-100)
+	ItemType              string `json:"item_type"`                // "top", "new", "best", "ask", "show", "job", "hiring"
+	Limit                 int    `json:"limit"`                    // Max story IDs to fetch (1-100), or job posts for "hiring" (1-1000, default: 500)
 	MinScore              int    `json:"min_score"`                // Filter by minimum points
 	MinComments           int    `json:"min_comments"`             // Filter by minimum descendants
 	IncludeComments       bool   `json:"include_comments"`         // Whether to fetch comments
@@ -117,6 +185,238 @@ type HackerNewsConfig struct {
 	ForceAPIMode          bool   `json:"force_api_mode"`           // Force API-only mode (emergency rollback, default: false)
 }
 
+// GitHubConfig holds GitHub per-source configuration
+// Token is optional and global (COLLECTOR_GITHUB_TOKEN). Rate limits are global (see GlobalConfig and env vars)
+type GitHubConfig struct {
+	Mode     string `json:"mode"`            // "search", "issues", or "discussions"
+	Query    string `json:"query,omitempty"` // Repository search query (search mode), e.g. "language:go topic:cli"
+	Repo     string `json:"repo,omitempty"`  // "owner/name" (issues and discussions modes)
+	Sort     string `json:"sort,omitempty"`  // Search sort: "stars", "forks", "updated" (default: best match)
+	Limit    int    `json:"limit"`           // Max items per fetch (1-100, default: 30)
+	MinStars int    `json:"min_stars"`       // Filter repositories by minimum stars (search mode)
+}
+
+// StackExchangeConfig holds Stack Exchange per-source configuration
+// API key is optional and global (COLLECTOR_STACKEXCHANGE_API_KEY). Rate limits are global (see GlobalConfig and env vars)
+type StackExchangeConfig struct {
+	Site       string   `json:"site"`           // API site parameter, e.g. "stackoverflow", "serverfault" (default: "stackoverflow")
+	Tags       []string `json:"tags,omitempty"` // Questions must have all of these tags
+	Sort       string   `json:"sort"`           // "activity", "votes", "creation" (default: "activity")
+	MaxAgeDays int      `json:"max_age_days"`   // Only questions created in the last N days (0 = no limit)
+	Limit      int      `json:"limit"`          // Max questions per fetch (1-100, default: 30)
+	MinScore   int      `json:"min_score"`      // Filter by minimum question score
+}
+
+// BlueskyConfig holds Bluesky per-source configuration
+// App-password auth is optional and global (see env vars). Rate limits are global (see GlobalConfig and env vars)
+type BlueskyConfig struct {
+	Mode     string `json:"mode"`               // "search", "author", or "feed"
+	Query    string `json:"query,omitempty"`    // Search query (search mode)
+	Sort     string `json:"sort,omitempty"`     // Search sort: "latest" or "top" (default: "latest")
+	Lang     string `json:"lang,omitempty"`     // Search language filter, e.g. "en"
+	Actor    string `json:"actor,omitempty"`    // Handle or DID (author mode)
+	FeedURI  string `json:"feed_uri,omitempty"` // at:// URI of a feed generator (feed mode)
+	Limit    int    `json:"limit"`              // Max posts per fetch (1-100, default: 50)
+	MinLikes int    `json:"min_likes"`          // Filter by minimum like count
+}
+
+// OpenAlexConfig holds OpenAlex per-source configuration
+// No API key required. Polite-pool email and rate limits are global (see GlobalConfig and env vars)
+type OpenAlexConfig struct {
+	Query               string   `json:"query,omitempty"`                 // Full-text search over title, abstract and fulltext
+	Concepts            []string `json:"concepts,omitempty"`              // OpenAlex concept IDs (e.g. "C41008148"), matched with OR
+	FromPublicationDate string   `json:"from_publication_date,omitempty"` // YYYY-MM-DD
+	MinCitations        int      `json:"min_citations"`                   // Filter by minimum cited_by_count
+	Sort                string   `json:"sort,omitempty"`                  // "relevance", "publication_date", "cited_by_count" (default: "relevance")
+	MaxResults          int      `json:"max_results"`                     // Total works per fetch, cursor-paginated (default: 100)
+}
+
+// PubMedConfig holds PubMed per-source configuration
+// NCBI API key is optional and global (COLLECTOR_NCBI_API_KEY). Rate limits are global (see GlobalConfig and env vars)
+type PubMedConfig struct {
+	Query      string `json:"query"`              // Entrez query, e.g. "crispr[Title/Abstract] AND review[pt]"
+	Sort       string `json:"sort,omitempty"`     // "pub_date" or "relevance" (default: "pub_date")
+	MinDate    string `json:"min_date,omitempty"` // Publication date range start, YYYY/MM/DD
+	MaxDate    string `json:"max_date,omitempty"` // Publication date range end, YYYY/MM/DD
+	MaxAgeDays int    `json:"max_age_days"`       // Only papers published in the last N days (overrides min/max date)
+	MaxResults int    `json:"max_results"`        // Max records per fetch (1-200, default: 50)
+}
+
+// ArxivCategory is one watched arXiv category with its own per-run limit
+type ArxivCategory struct {
+	Name  string `json:"name"`  // e.g. "cs.LG", "stat.ML"
+	Limit int    `json:"limit"` // Max papers taken from this category's listing (1-500, default: 50)
+}
+
+// ArxivConfig holds arXiv per-source configuration
+// No API key required (public RSS listings). Rate limits are global (see GlobalConfig and env vars)
+type ArxivConfig struct {
+	Categories          []ArxivCategory `json:"categories"`           // Watchlist, fetched in order; cross-listed papers are emitted once
+	IncludeCrossLists   bool            `json:"include_cross_lists"`  // Also emit papers cross-listed into a watched category
+	IncludeReplacements bool            `json:"include_replacements"` // Also emit revised versions of older papers
+}
+
+// DiscourseConfig holds Discourse per-source configuration
+// Works with any public Discourse forum. Rate limits are global (see GlobalConfig and env vars)
+type DiscourseConfig struct {
+	BaseURL  string `json:"base_url"`           // Forum root, e.g. "https://users.rust-lang.org"
+	Category string `json:"category,omitempty"` // Category slug or "slug/id" (default: site-wide latest)
+	Limit    int    `json:"limit"`              // Max topics per fetch (1-100, default: 30)
+}
+
+// NitterConfig holds X (Twitter) search configuration, scraped through a Nitter instance
+// No API key required. Rate limits are global (see GlobalConfig and env vars)
+type NitterConfig struct {
+	Query             string   `json:"query"`                        // X search query, e.g. "rustlang -filter:replies"
+	Instance          string   `json:"instance,omitempty"`           // Nitter instance root (default: "https://nitter.net")
+	FallbackInstances []string `json:"fallback_instances,omitempty"` // Tried in order when the active instance fails
+	Limit             int      `json:"limit"`                        // Max tweets per fetch (1-200, default: 40)
+	MinLikes          int      `json:"min_likes"`                    // Filter by minimum like count
+}
+
+// GoogleScholarConfig holds Google Scholar per-source configuration (queried through SerpAPI)
+// SerpAPI key is required and global (COLLECTOR_SERPAPI_API_KEY). Rate limits are global (see GlobalConfig and env vars)
+type GoogleScholarConfig struct {
+	Query      string `json:"query"`               // Google Scholar query, e.g. "retrieval augmented generation"
+	YearLow    int    `json:"year_low,omitempty"`  // Only results published in or after this year
+	YearHigh   int    `json:"year_high,omitempty"` // Only results published in or before this year
+	SortByDate bool   `json:"sort_by_date"`        // Newest first (recent additions only) instead of relevance
+	MaxResults int    `json:"max_results"`         // Max results per fetch (1-100, default: 20); each page of 20 costs one SerpAPI credit
+}
+
+// WebConfig holds generic web page per-source configuration
+// Pages are listed directly or discovered through a sitemap. Rate limits are global (see GlobalConfig and env vars)
+type WebConfig struct {
+	URLs       []string `json:"urls,omitempty"`        // Pages to fetch on every run
+	SitemapURL string   `json:"sitemap_url,omitempty"` // sitemap.xml or sitemap index, newest entries first
+	Limit      int      `json:"limit"`                 // Max pages per fetch (1-200, default: 30)
+	MinWords   int      `json:"min_words,omitempty"`   // Skip pages with less extracted text
+}
+
+// JSONAPIConfig holds generic JSON API per-source configuration
+// Items are mapped to articles with dotted paths, so new APIs need no code. Rate limits are global (see GlobalConfig and env vars)
+type JSONAPIConfig struct {
+	URL        string             `json:"url"`                  // Endpoint, may already carry query parameters
+	ItemsPath  string             `json:"items_path,omitempty"` // Path to the item array, e.g. "$.data.items" (default: response root)
+	Fields     JSONAPIFields      `json:"fields"`
+	Pagination *JSONAPIPagination `json:"pagination,omitempty"` // Omit to fetch a single page
+	Headers    map[string]string  `json:"headers,omitempty"`    // Extra request headers, e.g. an API key
+	Limit      int                `json:"limit"`                // Max items per fetch (1-500, default: 50)
+}
+
+// JSONAPIFields maps article fields to paths relative to each item
+// Title and one of ID or URL are required, the rest are optional
+type JSONAPIFields struct {
+	ID        string `json:"id,omitempty"`
+	Title     string `json:"title"`
+	Body      string `json:"body,omitempty"`
+	URL       string `json:"url,omitempty"`
+	Author    string `json:"author,omitempty"`
+	Score     string `json:"score,omitempty"`
+	Timestamp string `json:"timestamp,omitempty"` // Date string or Unix seconds/milliseconds
+}
+
+// JSONAPIPagination describes how to request the next page
+type JSONAPIPagination struct {
+	Type       string `json:"type"`                  // "page" (page number), "offset" (items seen so far) or "cursor"
+	Param      string `json:"param"`                 // Query parameter carrying the page number, offset or cursor
+	Start      int    `json:"start,omitempty"`       // First page number or offset (default: 1 for page, 0 for offset)
+	CursorPath string `json:"cursor_path,omitempty"` // Cursor mode: path to the next cursor in the response
+	MaxPages   int    `json:"max_pages,omitempty"`   // Pages per fetch (1-50, default: 5)
+}
+
+// NewsConfig holds news per-source configuration (NewsAPI.org or GDELT DOC 2.0)
+// NewsAPI key is global (COLLECTOR_NEWSAPI_API_KEY), GDELT needs none. Rate limits are global (see GlobalConfig and env vars)
+type NewsConfig struct {
+	Provider string `json:"provider,omitempty"` // "gdelt" (default, no key) or "newsapi"
+	Query    string `json:"query"`              // Search query in the provider's syntax
+	Language string `json:"language,omitempty"` // ISO 639-1 code, e.g. "en" (GDELT also accepts its own names, e.g. "english")
+	From     string `json:"from,omitempty"`     // Earliest publication date, YYYY-MM-DD
+	To       string `json:"to,omitempty"`       // Latest publication date, YYYY-MM-DD (inclusive)
+	Limit    int    `json:"limit"`              // Max articles per fetch (1-250, default: 50)
+}
+
+// WikipediaConfig holds Wikipedia per-source configuration (MediaWiki action API, no credentials)
+// Rate limits are global (see GlobalConfig and env vars)
+type WikipediaConfig struct {
+	Mode     string   `json:"mode,omitempty"`     // "search" (default) or "recent_changes"
+	Language string   `json:"language,omitempty"` // Wiki language code, e.g. "en" (default), "de"
+	Query    string   `json:"query,omitempty"`    // Full-text search query (search mode)
+	Pages    []string `json:"pages,omitempty"`    // Page titles to watch for edits (recent_changes mode, max 50)
+	Limit    int      `json:"limit"`              // Max articles per fetch (1-50, default: 20)
+}
+
+// KaggleConfig holds Kaggle per-source configuration
+// Kaggle credentials are global (COLLECTOR_KAGGLE_USERNAME/KEY). Rate limits are global (see GlobalConfig and env vars)
+type KaggleConfig struct {
+	Kind   string `json:"kind,omitempty"`   // "datasets" (default) or "competitions"
+	Search string `json:"search,omitempty"` // Search term (empty lists everything)
+	Sort   string `json:"sort,omitempty"`   // Datasets only: "hottest", "votes", "updated" (default), "active", "published"
+	Limit  int    `json:"limit"`            // Max items per fetch (1-100, default: 20)
+}
+
+// PodcastConfig holds podcast per-source configuration (Podcast Index API)
+// API key and secret are global (COLLECTOR_PODCASTINDEX_API_KEY/SECRET). Rate limits are global (see GlobalConfig and env vars)
+type PodcastConfig struct {
+	Query   string  `json:"query,omitempty"`    // Search episode titles, descriptions and person tags
+	FeedIDs []int64 `json:"feed_ids,omitempty"` // Or follow these Podcast Index feed IDs (max 50)
+	Limit   int     `json:"limit"`              // Max episodes per fetch (1-1000, default: 50)
+}
+
+// HuggingFaceConfig holds Hugging Face Hub per-source configuration
+// The optional token is global (COLLECTOR_HUGGINGFACE_TOKEN). Rate limits are global (see GlobalConfig and env vars)
+type HuggingFaceConfig struct {
+	Kind     string `json:"kind,omitempty"`   // "models" (default), "datasets" or "spaces"
+	Search   string `json:"search,omitempty"` // Substring match on the repo ID
+	Tag      string `json:"tag,omitempty"`    // Hub tag filter, e.g. "text-generation" or "license:mit"
+	Author   string `json:"author,omitempty"` // User or organization, e.g. "meta-llama"
+	Sort     string `json:"sort,omitempty"`   // "created" (default), "modified", "downloads" or "likes"
+	Limit    int    `json:"limit"`            // Max repos per fetch (1-1000, default: 50)
+	MinLikes int    `json:"min_likes"`        // Filter by minimum like count
+}
+
+// PackageRegistryConfig holds package registry per-source configuration
+// No credentials needed. Rate limits are global (see GlobalConfig and env vars)
+type PackageRegistryConfig struct {
+	Registry     string   `json:"registry"`      // "crates" (crates.io) or "pypi"
+	Keywords     []string `json:"keywords"`      // crates.io: one search per keyword; PyPI: matched against name and summary
+	Limit        int      `json:"limit"`         // Max crates per keyword search (1-100, default: 50), crates.io only
+	MinDownloads int      `json:"min_downloads"` // Filter by minimum recent downloads
+}
+
+// ImapConfig holds IMAP newsletter per-source configuration
+// The mailbox host and login are global (COLLECTOR_IMAP_*). Rate limits are global (see GlobalConfig and env vars)
+type ImapConfig struct {
+	Folder string   `json:"folder,omitempty"` // Mailbox folder (default: "INBOX")
+	From   []string `json:"from,omitempty"`   // Only messages from any of these senders (substring match)
+	Limit  int      `json:"limit"`            // Max messages per fetch, newest first (1-500, default: 50)
+}
+
+// FileConfig holds local file per-source configuration
+// Reads are confined to COLLECTOR_FILE_SOURCE_ROOT
+type FileConfig struct {
+	Path string `json:"path"` // File or directory relative to COLLECTOR_FILE_SOURCE_ROOT
+}
+
+// FeedConfig holds RSS/Atom per-source configuration
+// opml_path is read from COLLECTOR_FILE_SOURCE_ROOT. Rate limits are global (see GlobalConfig and env vars)
+type FeedConfig struct {
+	URLs     []string `json:"urls,omitempty"`      // RSS or Atom feed URLs
+	OPMLPath string   `json:"opml_path,omitempty"` // Feed reader export relative to COLLECTOR_FILE_SOURCE_ROOT, every feed in it is followed
+	Limit    int      `json:"limit"`               // Max entries per feed per fetch (1-200, default: 20)
+}
+
+// ZenodoConfig holds Zenodo per-source configuration
+// The optional token is global (COLLECTOR_ZENODO_TOKEN). Rate limits are global (see GlobalConfig and env vars)
+type ZenodoConfig struct {
+	Query        string   `json:"query,omitempty"`         // Elasticsearch query string, e.g. "title:(graph neural) AND keywords:chemistry"
+	Communities  []string `json:"communities,omitempty"`   // Community identifiers, e.g. "zenodo" (max 10)
+	ResourceType string   `json:"resource_type,omitempty"` // "publication", "dataset", "software", ... (default: all)
+	From         string   `json:"from,omitempty"`          // Earliest publication date, YYYY-MM-DD
+	To           string   `json:"to,omitempty"`            // Latest publication date, YYYY-MM-DD (inclusive)
+	Limit        int      `json:"limit"`                   // Max records per fetch (1-500, default: 50)
+}
+
 // HealthStatus represents the health of the service
 // @Description Service health status
 type HealthStatus struct {
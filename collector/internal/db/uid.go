@@ -0,0 +1,19 @@
+package db
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+)
+
+// ArticleUID returns the canonical key of an upstream item: a hash of its source type and external ID
+// Unlike the row ID it is the same for the item crawled by different sources or profiles, so the API, exports,
+// uploads, the search index and dedup all key articles by it
+func ArticleUID(sourceType, externalID string) string {
+	return ShortHash(sourceType + ":" + externalID)
+}
+
+// ShortHash returns the first 16 bytes of the SHA-256 of s as hex
+func ShortHash(s string) string {
+	sum := sha256.Sum256([]byte(s))
+	return hex.EncodeToString(sum[:16])
+}
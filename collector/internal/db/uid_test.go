@@ -0,0 +1,17 @@
+package db
+
+import "testing"
+
+func TestArticleUID(t *testing.T) {
+	uid := ArticleUID("reddit", "abc123")
+
+	if len(uid) != 32 {
+		t.Errorf("Expected 32-character UID, got %d (%s)", len(uid), uid)
+	}
+	if uid != ArticleUID("reddit", "abc123") {
+		t.Errorf("Expected UID to be stable for the same source type and external ID")
+	}
+	if uid == ArticleUID("hackernews", "abc123") {
+		t.Errorf("Expected different source types to produce different UIDs")
+	}
+}
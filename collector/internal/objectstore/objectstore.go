@@ -0,0 +1,208 @@
+package objectstore
+
+import (
+	"bytes"
+	"context"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+)
+
+// DefaultKeyTemplate is used when COLLECTOR_S3_URL names only a bucket
+const DefaultKeyTemplate = "{date}/{time}/{source}.json"
+
+// Client uploads objects to an S3-compatible bucket, signing requests with AWS Signature Version 4
+type Client struct {
+	bucket          string
+	keyTemplate     string
+	region          string
+	endpoint        string // Base URL for path-style requests; empty uses virtual-hosted AWS endpoints
+	accessKeyID     string
+	secretAccessKey string
+	sessionToken    string
+	http            *http.Client
+	now             func() time.Time
+}
+
+// NewClient creates a client for the bucket and key template in cfg.URL
+// cfg is expected to have passed config validation
+func NewClient(cfg config.ObjectStoreConfig, httpClient *http.Client) (*Client, error) {
+	u, err := url.Parse(cfg.URL)
+	if err != nil {
+		return nil, fmt.Errorf("invalid bucket URL: %w", err)
+	}
+	keyTemplate := strings.TrimPrefix(u.Path, "/")
+	if keyTemplate == "" {
+		keyTemplate = DefaultKeyTemplate
+	} else if strings.HasSuffix(keyTemplate, "/") {
+		keyTemplate += DefaultKeyTemplate
+	}
+
+	return &Client{
+		bucket:          u.Host,
+		keyTemplate:     keyTemplate,
+		region:          cfg.Region,
+		endpoint:        strings.TrimSuffix(cfg.Endpoint, "/"),
+		accessKeyID:     cfg.AccessKeyID,
+		secretAccessKey: cfg.SecretAccessKey,
+		sessionToken:    cfg.SessionToken,
+		http:            httpClient,
+		now:             time.Now,
+	}, nil
+}
+
+// Key expands the client's key template with the given placeholder values
+func (c *Client) Key(values map[string]string) string {
+	return ExpandKey(c.keyTemplate, values)
+}
+
+// Put uploads body under key, replacing any object already there
+func (c *Client) Put(ctx context.Context, key string, body []byte, contentType string) error {
+	req, err := http.NewRequestWithContext(ctx, "PUT", c.objectURL(key), bytes.NewReader(body))
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Content-Type", contentType)
+
+	payloadHash := sha256.Sum256(body)
+	c.sign(req, hex.EncodeToString(payloadHash[:]), c.now())
+
+	resp, err := c.http.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	message, _ := io.ReadAll(io.LimitReader(resp.Body, 4<<10))
+
+	if resp.StatusCode >= 300 {
+		return fmt.Errorf("PUT %s/%s returned %d: %s", c.bucket, key, resp.StatusCode, strings.TrimSpace(string(message)))
+	}
+	return nil
+}
+
+// objectURL addresses key path-style on a custom endpoint, virtual-hosted style on AWS
+func (c *Client) objectURL(key string) string {
+	if c.endpoint != "" {
+		return c.endpoint + "/" + c.bucket + "/" + escapePath(key)
+	}
+	return "https://" + c.bucket + ".s3." + c.region + ".amazonaws.com/" + escapePath(key)
+}
+
+// sign adds the SigV4 Authorization header, covering Host and every header already set on req
+func (c *Client) sign(req *http.Request, payloadHash string, now time.Time) {
+	amzDate := now.UTC().Format("20060102T150405Z")
+	date := amzDate[:8]
+
+	req.Header.Set("X-Amz-Date", amzDate)
+	req.Header.Set("X-Amz-Content-Sha256", payloadHash)
+	if c.sessionToken != "" {
+		req.Header.Set("X-Amz-Security-Token", c.sessionToken)
+	}
+
+	headers := map[string]string{"host": req.URL.Host}
+	for name, values := range req.Header {
+		headers[strings.ToLower(name)] = strings.Join(values, ",")
+	}
+	names := make([]string, 0, len(headers))
+	for name := range headers {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	var canonicalHeaders strings.Builder
+	for _, name := range names {
+		canonicalHeaders.WriteString(name + ":" + strings.TrimSpace(headers[name]) + "\n")
+	}
+	signedHeaders := strings.Join(names, ";")
+
+	canonicalRequest := strings.Join([]string{
+		req.Method,
+		req.URL.EscapedPath(),
+		req.URL.RawQuery,
+		canonicalHeaders.String(),
+		signedHeaders,
+		payloadHash,
+	}, "\n")
+	requestHash := sha256.Sum256([]byte(canonicalRequest))
+
+	scope := date + "/" + c.region + "/s3/aws4_request"
+	stringToSign := "AWS4-HMAC-SHA256\n" + amzDate + "\n" + scope + "\n" + hex.EncodeToString(requestHash[:])
+
+	key := []byte("AWS4" + c.secretAccessKey)
+	for _, part := range []string{date, c.region, "s3", "aws4_request"} {
+		key = hmacSHA256(key, part)
+	}
+	signature := hex.EncodeToString(hmacSHA256(key, stringToSign))
+
+	req.Header.Set("Authorization", fmt.Sprintf("AWS4-HMAC-SHA256 Credential=%s/%s, SignedHeaders=%s, Signature=%s",
+		c.accessKeyID, scope, signedHeaders, signature))
+}
+
+// ExpandKey replaces each {name} in template with its value, escaping characters S3 keys handle badly
+// Unknown placeholders are left as written
+func ExpandKey(template string, values map[string]string) string {
+	var key strings.Builder
+	for {
+		start := strings.IndexByte(template, '{')
+		if start < 0 {
+			break
+		}
+		end := strings.IndexByte(template[start:], '}')
+		if end < 0 {
+			break
+		}
+		end += start
+		key.WriteString(template[:start])
+		if value, ok := values[template[start+1:end]]; ok {
+			key.WriteString(keySafe(value))
+		} else {
+			key.WriteString(template[start : end+1])
+		}
+		template = template[end+1:]
+	}
+	key.WriteString(template)
+	return key.String()
+}
+
+// keySafe keeps a placeholder value from adding path segments or unusual characters to a key
+func keySafe(value string) string {
+	return strings.Map(func(r rune) rune {
+		switch {
+		case r >= 'a' && r <= 'z', r >= 'A' && r <= 'Z', r >= '0' && r <= '9':
+			return r
+		case r == '-', r == '_', r == '.', r == ':', r == '@':
+			return r
+		}
+		return '_'
+	}, value)
+}
+
+// escapePath URI-encodes every byte of a key except unreserved characters and '/', as SigV4 expects
+func escapePath(key string) string {
+	var escaped strings.Builder
+	for i := 0; i < len(key); i++ {
+		b := key[i]
+		if b >= 'a' && b <= 'z' || b >= 'A' && b <= 'Z' || b >= '0' && b <= '9' ||
+			b == '-' || b == '_' || b == '.' || b == '~' || b == '/' {
+			escaped.WriteByte(b)
+		} else {
+			fmt.Fprintf(&escaped, "%%%02X", b)
+		}
+	}
+	return escaped.String()
+}
+
+func hmacSHA256(key []byte, data string) []byte {
+	mac := hmac.New(sha256.New, key)
+	mac.Write([]byte(data))
+	return mac.Sum(nil)
+}
@@ -0,0 +1,91 @@
+package personalization
+
+import (
+	"errors"
+	"sync"
+)
+
+// curationOutputTokens approximates the size of a {"pass", "reason"} response for budgeting
+const curationOutputTokens = 64
+
+// errBudgetExhausted is returned when a curation call would exceed the per-run budget
+var errBudgetExhausted = errors.New("curation budget exhausted for this run")
+
+// CurationBudget caps Gemini curation usage per crawl run, zero limits mean unlimited
+type CurationBudget struct {
+	MaxRequests         int     // Curation calls allowed per run
+	MaxTokens           int     // Estimated tokens (prompt + response) allowed per run
+	USDPerMillionTokens float64 // Token price used for the cost estimate
+}
+
+// CurationUsage summarizes curation spend for a run
+type CurationUsage struct {
+	Requests         int
+	Tokens           int
+	Skipped          int // Articles left uncurated because the budget ran out
+	EstimatedCostUSD float64
+}
+
+// budgetTracker enforces a CurationBudget across all workers
+type budgetTracker struct {
+	limits CurationBudget
+
+	mu       sync.Mutex
+	requests int
+	tokens   int
+	skipped  int
+}
+
+// reserve accounts for one call of the given token size, refusing it if a cap would be exceeded
+func (b *budgetTracker) reserve(tokens int) error {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+
+	if b.limits.MaxRequests > 0 && b.requests+1 > b.limits.MaxRequests {
+		return errBudgetExhausted
+	}
+	if b.limits.MaxTokens > 0 && b.tokens+tokens > b.limits.MaxTokens {
+		return errBudgetExhausted
+	}
+
+	b.requests++
+	b.tokens += tokens
+	return nil
+}
+
+// skip records articles that were not curated because the budget ran out
+func (b *budgetTracker) skip(n int) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	b.skipped += n
+}
+
+// usage returns the spend so far
+func (b *budgetTracker) usage() CurationUsage {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	return b.usageLocked()
+}
+
+// reset starts a new run and returns the usage of the previous one
+func (b *budgetTracker) reset() CurationUsage {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+	prev := b.usageLocked()
+	b.requests, b.tokens, b.skipped = 0, 0, 0
+	return prev
+}
+
+func (b *budgetTracker) usageLocked() CurationUsage {
+	return CurationUsage{
+		Requests:         b.requests,
+		Tokens:           b.tokens,
+		Skipped:          b.skipped,
+		EstimatedCostUSD: estimateCost(b.tokens, b.limits.USDPerMillionTokens),
+	}
+}
+
+// estimateCost converts a token count into dollars at the configured price
+func estimateCost(tokens int, usdPerMillion float64) float64 {
+	return float64(tokens) * usdPerMillion / 1_000_000
+}
@@ -2,6 +2,7 @@ package personalization
 
 import (
 	"context"
+	"errors"
 	"fmt"
 	"log/slog"
 	"strings"
@@ -50,6 +51,7 @@ type CurationService struct {
 	gemini  *gemini.Client
 	workers int
 	enabled bool
+	budget  *budgetTracker
 
 	jobChan     chan CurationJob
 	workerWg    sync.WaitGroup
@@ -58,13 +60,15 @@ type CurationService struct {
 }
 
 // NewCurationService creates a new curation service with worker pool
-func NewCurationService(db *db.DB, gemini *gemini.Client, workers int, enabled bool) *CurationService {
+// budget caps Gemini usage per crawl run (see BeginRun)
+func NewCurationService(db *db.DB, gemini *gemini.Client, workers int, enabled bool, budget CurationBudget) *CurationService {
 	cs := &CurationService{
 		db:         db,
 		repo:       NewCurationRepository(db),
 		gemini:     gemini,
 		workers:    workers,
 		enabled:    enabled,
+		budget:     &budgetTracker{limits: budget},
 		jobChan:    make(chan CurationJob, curationJobBufferSize),
 		shutdownCh: make(chan struct{}),
 	}
@@ -107,18 +111,44 @@ func (s *CurationService) Stop() {
 	slog.Info("Curation service stopped")
 }
 
+// BeginRun resets the per-run budget at the start of a scheduler cycle and logs the previous cycle's spend
+// A cycle starts with a global run while no other job's run is in progress; overlapping runs share its budget
+// Jobs still queued from the previous cycle count against the new budget
+func (s *CurationService) BeginRun() {
+	if !s.enabled {
+		return
+	}
+
+	prev := s.budget.reset()
+	if prev.Requests > 0 || prev.Skipped > 0 {
+		slog.Info("Curation run summary",
+			"requests", prev.Requests,
+			"tokens", prev.Tokens,
+			"skipped_over_budget", prev.Skipped,
+			"estimated_cost_usd", fmt.Sprintf("%.4f", prev.EstimatedCostUSD))
+	}
+}
+
+// Usage returns the curation spend of the current run
+func (s *CurationService) Usage() CurationUsage {
+	return s.budget.usage()
+}
+
 // EnqueueArticles adds articles to the curation queue (non-blocking)
-func (s *CurationService) EnqueueArticles(profileID string, articles []db.Article) {
+// Returns false when the articles were dropped because the queue is full
+func (s *CurationService) EnqueueArticles(profileID string, articles []db.Article) bool {
 	if !s.enabled || len(articles) == 0 {
-		return
+		return true
 	}
 
 	// Non-blocking send - if queue is full, skip (curation is best-effort)
 	select {
 	case s.jobChan <- CurationJob{ProfileID: profileID, Articles: articles}:
 		slog.Debug("Enqueued articles for curation", "profile_id", profileID, "count", len(articles))
+		return true
 	default:
 		slog.Warn("Curation queue full, skipping articles", "profile_id", profileID, "count", len(articles))
+		return false
 	}
 }
 
@@ -166,8 +196,17 @@ func (s *CurationService) processJob(workerID int, job CurationJob) {
 	// Curate each article
 	curatedCount := 0
 	errorCount := 0
-	for _, article := range job.Articles {
+	skippedCount := 0
+	for i, article := range job.Articles {
 		result, err := s.curateArticleWithRetry(ctx, profile, article)
+		if errors.Is(err, errBudgetExhausted) {
+			// Refuse to overspend, remaining articles stay uncurated
+			skippedCount = len(job.Articles) - i
+			s.budget.skip(skippedCount)
+			slog.Warn("Curation budget exhausted, skipping remaining articles",
+				"profile_id", job.ProfileID, "skipped", skippedCount)
+			break
+		}
 		if err != nil {
 			errorCount++
 			slog.Error("Failed to curate article", "profile_id", job.ProfileID, "article_id", article.ID, "error", err)
@@ -185,7 +224,7 @@ func (s *CurationService) processJob(workerID int, job CurationJob) {
 	}
 
 	slog.Info("Curation job completed", "worker_id", workerID, "profile_id", job.ProfileID,
-		"total", len(job.Articles), "curated", curatedCount, "errors", errorCount)
+		"total", len(job.Articles), "curated", curatedCount, "errors", errorCount, "skipped", skippedCount)
 }
 
 // curateArticleWithRetry curates a single article with retry logic
@@ -195,8 +234,8 @@ func (s *CurationService) curateArticleWithRetry(ctx context.Context, profile *d
 
 	// First attempt
 	result, err := s.curateArticle(ctx, profile, article)
-	if err == nil {
-		return result, nil
+	if err == nil || errors.Is(err, errBudgetExhausted) {
+		return result, err
 	}
 
 	slog.Warn("Curation failed, retrying", "article_id", article.ID, "error", err)
@@ -222,6 +261,11 @@ func (s *CurationService) curateArticle(ctx context.Context, profile *db.Profile
 	// Build prompt
 	prompt := s.buildCurationPrompt(profile, article)
 
+	// Account for the call before making it so the cap is never exceeded mid-run
+	if err := s.budget.reserve(s.estimateTokens(prompt)); err != nil {
+		return nil, err
+	}
+
 	// Configure Gemini with structured output
 	temperature := float32(0.3) // Lower temperature for more consistent decisions
 	config := &genai.GenerateContentConfig{
@@ -257,6 +301,17 @@ func (s *CurationService) curateArticle(ctx context.Context, profile *db.Profile
 	return result, nil
 }
 
+// estimateTokens estimates the tokens of one curation call (system instruction, prompt and response)
+// Falls back to ~4 characters per token if the local tokenizer fails
+func (s *CurationService) estimateTokens(prompt string) int {
+	input := CURATION_SYSTEM_INSTRUCTION + "\n" + prompt
+	tokens, err := s.gemini.CountTokens(input)
+	if err != nil {
+		tokens = len(input) / 4
+	}
+	return tokens + curationOutputTokens
+}
+
 // buildCurationPrompt creates the prompt for article curation
 func (s *CurationService) buildCurationPrompt(profile *db.Profile, article db.Article) string {
 	// Truncate content to save tokens
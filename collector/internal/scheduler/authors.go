@@ -0,0 +1,127 @@
+package scheduler
+
+import (
+	"encoding/json"
+	"sort"
+	"strings"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+// AuthorStat summarizes the articles one author had stored in a run
+type AuthorStat struct {
+	Author   string `json:"author"`
+	Articles int    `json:"articles"`
+	Score    int    `json:"score"` // Sum of the articles' metadata score (upvotes, stars, citations, ...), where the source sets one
+}
+
+// AuthorReport lists the most active and the highest-scoring authors of a source or a whole run
+type AuthorReport struct {
+	MostActive []AuthorStat `json:"most_active"`           // By articles, then score
+	TopScoring []AuthorStat `json:"top_scoring,omitempty"` // By score, then articles; authors without a score are left out
+}
+
+// tallyAuthors counts the articles and score of each author, skipping articles without one
+func tallyAuthors(articles []db.Article) []AuthorStat {
+	index := make(map[string]int)
+	var stats []AuthorStat
+	for _, article := range articles {
+		author := strings.TrimSpace(article.Author)
+		if author == "" {
+			continue
+		}
+		i, ok := index[author]
+		if !ok {
+			i = len(stats)
+			index[author] = i
+			stats = append(stats, AuthorStat{Author: author})
+		}
+		stats[i].Articles++
+		stats[i].Score += articleScore(article)
+	}
+	return stats
+}
+
+// articleScore reads the numeric "score" metadata most sources set, 0 when there is none
+func articleScore(article db.Article) int {
+	var meta struct {
+		Score *float64 `json:"score"`
+	}
+	if len(article.Metadata) == 0 || json.Unmarshal(article.Metadata, &meta) != nil || meta.Score == nil {
+		return 0
+	}
+	return int(*meta.Score)
+}
+
+// mergeAuthors adds up tallies of several sources; the same name on different platforms counts as one author
+func mergeAuthors(tallies ...[]AuthorStat) []AuthorStat {
+	index := make(map[string]int)
+	var merged []AuthorStat
+	for _, tally := range tallies {
+		for _, stat := range tally {
+			i, ok := index[stat.Author]
+			if !ok {
+				i = len(merged)
+				index[stat.Author] = i
+				merged = append(merged, AuthorStat{Author: stat.Author})
+			}
+			merged[i].Articles += stat.Articles
+			merged[i].Score += stat.Score
+		}
+	}
+	return merged
+}
+
+// newAuthorReport ranks a tally into its top n authors by activity and by score
+// Returns nil for an empty tally
+func newAuthorReport(stats []AuthorStat, n int) *AuthorReport {
+	if len(stats) == 0 || n <= 0 {
+		return nil
+	}
+
+	report := &AuthorReport{
+		MostActive: rankAuthors(stats, n, func(a, b AuthorStat) bool {
+			if a.Articles != b.Articles {
+				return a.Articles > b.Articles
+			}
+			return a.Score > b.Score
+		}),
+	}
+
+	var scored []AuthorStat
+	for _, stat := range stats {
+		if stat.Score > 0 {
+			scored = append(scored, stat)
+		}
+	}
+	report.TopScoring = rankAuthors(scored, n, func(a, b AuthorStat) bool {
+		if a.Score != b.Score {
+			return a.Score > b.Score
+		}
+		return a.Articles > b.Articles
+	})
+
+	return report
+}
+
+// rankAuthors returns the first n of a sorted copy of stats, breaking ties by name
+func rankAuthors(stats []AuthorStat, n int, better func(a, b AuthorStat) bool) []AuthorStat {
+	if len(stats) == 0 {
+		return nil
+	}
+
+	ranked := append([]AuthorStat(nil), stats...)
+	sort.SliceStable(ranked, func(i, j int) bool {
+		if better(ranked[i], ranked[j]) {
+			return true
+		}
+		if better(ranked[j], ranked[i]) {
+			return false
+		}
+		return ranked[i].Author < ranked[j].Author
+	})
+	if len(ranked) > n {
+		ranked = ranked[:n]
+	}
+	return ranked
+}
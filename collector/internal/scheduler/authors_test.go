@@ -0,0 +1,39 @@
+package scheduler
+
+import (
+	"encoding/json"
+	"testing"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestAuthorReport_RanksByActivityAndScore(t *testing.T) {
+	reddit := tallyAuthors([]db.Article{
+		{Author: "alice", Metadata: json.RawMessage(`{"score":5}`)},
+		{Author: "alice", Metadata: json.RawMessage(`{"score":3}`)},
+		{Author: "bob", Metadata: json.RawMessage(`{"score":120}`)},
+		{Author: "carol"},
+		{Author: " "},
+	})
+	github := tallyAuthors([]db.Article{
+		{Author: "carol", Metadata: json.RawMessage(`{"score":"n/a"}`)},
+		{Author: "bob", Metadata: json.RawMessage(`{"score":30}`)},
+	})
+
+	report := newAuthorReport(mergeAuthors(reddit, github), 2)
+	if report == nil {
+		t.Fatal("Expected a report")
+	}
+
+	if len(report.MostActive) != 2 || report.MostActive[0] != (AuthorStat{Author: "bob", Articles: 2, Score: 150}) ||
+		report.MostActive[1] != (AuthorStat{Author: "alice", Articles: 2, Score: 8}) {
+		t.Errorf("Unexpected most active authors: %+v", report.MostActive)
+	}
+	if len(report.TopScoring) != 2 || report.TopScoring[0].Author != "bob" || report.TopScoring[1].Author != "alice" {
+		t.Errorf("Unexpected top scoring authors: %+v", report.TopScoring)
+	}
+
+	if newAuthorReport(nil, 2) != nil {
+		t.Error("Expected no report without authors")
+	}
+}
@@ -0,0 +1,143 @@
+package scheduler
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"sync"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+// fetchCache keeps recent fetch results keyed by a fingerprint of source type and config
+// Sources with identical configs (e.g. the same subreddit followed by several profiles)
+// then share one upstream fetch within the TTL instead of hitting the API again
+type fetchCache struct {
+	mu      sync.Mutex
+	ttl     time.Duration
+	entries map[string]fetchCacheEntry
+}
+
+type fetchCacheEntry struct {
+	since     time.Time // Window start the results were fetched with
+	fetchedAt time.Time
+	articles  []db.Article
+	comments  []db.Comment
+}
+
+// newFetchCache creates a cache; a non-positive TTL disables it
+func newFetchCache(ttl time.Duration) *fetchCache {
+	return &fetchCache{
+		ttl:     ttl,
+		entries: make(map[string]fetchCacheEntry),
+	}
+}
+
+// fetchFingerprint hashes the source type and its config with keys in canonical order
+func fetchFingerprint(sourceType string, config json.RawMessage) string {
+	canonical := []byte(config)
+	var v interface{}
+	if err := json.Unmarshal(config, &v); err == nil {
+		// Re-marshaling sorts object keys, so key order in the stored config doesn't matter
+		if b, err := json.Marshal(v); err == nil {
+			canonical = b
+		}
+	}
+
+	sum := sha256.Sum256(append([]byte(sourceType+"\x00"), canonical...))
+	return hex.EncodeToString(sum[:])
+}
+
+// get returns cached results covering the window starting at since, rebound to src
+// A cached fetch covers the window when it started at or before since; newer items are
+// all included, older ones are dropped here
+func (c *fetchCache) get(key string, since time.Time, src *db.Source) ([]db.Article, []db.Comment, bool) {
+	if c.ttl <= 0 {
+		return nil, nil, false
+	}
+
+	c.mu.Lock()
+	entry, ok := c.entries[key]
+	if ok && time.Since(entry.fetchedAt) > c.ttl {
+		delete(c.entries, key)
+		ok = false
+	}
+	c.mu.Unlock()
+
+	if !ok || entry.since.After(since) {
+		return nil, nil, false
+	}
+
+	articles, comments := rebindResults(entry.articles, entry.comments, since, src)
+	return articles, comments, true
+}
+
+// put stores the results of a fetch made with the given window start
+func (c *fetchCache) put(key string, since time.Time, articles []db.Article, comments []db.Comment) {
+	if c.ttl <= 0 {
+		return
+	}
+
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	// Drop expired entries so the map doesn't grow with configs that were removed
+	for k, entry := range c.entries {
+		if time.Since(entry.fetchedAt) > c.ttl {
+			delete(c.entries, k)
+		}
+	}
+
+	c.entries[key] = fetchCacheEntry{
+		since:     since,
+		fetchedAt: time.Now(),
+		// Copy so later per-source changes (profile ID, tags) don't leak into the cache
+		articles: append([]db.Article(nil), articles...),
+		comments: append([]db.Comment(nil), comments...),
+	}
+}
+
+// rebindResults copies cached results for another source, assigning fresh row IDs
+// Articles written before since are dropped along with their comments
+func rebindResults(articles []db.Article, comments []db.Comment, since time.Time, src *db.Source) ([]db.Article, []db.Comment) {
+	articleIDs := make(map[string]string, len(articles))
+	rebound := make([]db.Article, 0, len(articles))
+	for _, article := range articles {
+		if article.WrittenAt.Before(since) {
+			continue
+		}
+		newID := uuid.New().String()
+		articleIDs[article.ID] = newID
+
+		article.ID = newID
+		article.SourceID = src.ID
+		article.CreatedAt = time.Now()
+		rebound = append(rebound, article)
+	}
+
+	commentIDs := make(map[string]string, len(comments))
+	for _, comment := range comments {
+		commentIDs[comment.ID] = uuid.New().String()
+	}
+
+	reboundComments := make([]db.Comment, 0, len(comments))
+	for _, comment := range comments {
+		articleID, ok := articleIDs[comment.ArticleID]
+		if !ok {
+			continue
+		}
+		comment.ID = commentIDs[comment.ID]
+		comment.ArticleID = articleID
+		// Parents referencing row IDs follow the rebind; other references (e.g. upstream IDs) are kept
+		if comment.ParentID != nil {
+			if parentID, ok := commentIDs[*comment.ParentID]; ok {
+				comment.ParentID = &parentID
+			}
+		}
+		reboundComments = append(reboundComments, comment)
+	}
+
+	return rebound, reboundComments
+}
@@ -0,0 +1,66 @@
+package scheduler
+
+import (
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestFetchFingerprint_IgnoresKeyOrder(t *testing.T) {
+	a := fetchFingerprint("reddit", []byte(`{"subreddit":"golang","sort":"hot"}`))
+	b := fetchFingerprint("reddit", []byte(`{"sort": "hot", "subreddit": "golang"}`))
+	if a != b {
+		t.Errorf("Expected identical fingerprints for reordered config keys")
+	}
+	if a == fetchFingerprint("hackernews", []byte(`{"subreddit":"golang","sort":"hot"}`)) {
+		t.Errorf("Expected source type to be part of the fingerprint")
+	}
+}
+
+func TestFetchCache_RebindsForAnotherSource(t *testing.T) {
+	cache := newFetchCache(time.Minute)
+	now := time.Now()
+	parent := "c1"
+
+	articles := []db.Article{
+		{ID: "a1", SourceID: "s1", ExternalID: "new", WrittenAt: now},
+		{ID: "a2", SourceID: "s1", ExternalID: "old", WrittenAt: now.Add(-48 * time.Hour)},
+	}
+	comments := []db.Comment{
+		{ID: "c1", ArticleID: "a1"},
+		{ID: "c2", ArticleID: "a1", ParentID: &parent},
+		{ID: "c3", ArticleID: "a2"},
+	}
+	cache.put("key", now.Add(-72*time.Hour), articles, comments)
+
+	// A window starting before the cached one isn't covered
+	if _, _, ok := cache.get("key", now.Add(-96*time.Hour), &db.Source{ID: "s2"}); ok {
+		t.Fatalf("Expected cache miss for a wider window")
+	}
+
+	got, gotComments, ok := cache.get("key", now.Add(-24*time.Hour), &db.Source{ID: "s2"})
+	if !ok {
+		t.Fatalf("Expected cache hit")
+	}
+	if len(got) != 1 || got[0].ExternalID != "new" {
+		t.Fatalf("Expected only the article inside the window, got %+v", got)
+	}
+	if got[0].SourceID != "s2" || got[0].ID == "a1" {
+		t.Errorf("Expected article to be rebound with a fresh ID, got %+v", got[0])
+	}
+	if len(gotComments) != 2 {
+		t.Fatalf("Expected 2 comments, got %d", len(gotComments))
+	}
+	if gotComments[1].ArticleID != got[0].ID || *gotComments[1].ParentID != gotComments[0].ID {
+		t.Errorf("Expected comment links to follow the new IDs, got %+v", gotComments)
+	}
+}
+
+func TestFetchCache_DisabledWithZeroTTL(t *testing.T) {
+	cache := newFetchCache(0)
+	cache.put("key", time.Unix(0, 0), []db.Article{{ID: "a1"}}, nil)
+	if _, _, ok := cache.get("key", time.Now(), &db.Source{ID: "s2"}); ok {
+		t.Errorf("Expected cache to be disabled")
+	}
+}
@@ -0,0 +1,124 @@
+package scheduler
+
+import (
+	"context"
+	"encoding/json"
+	"log/slog"
+	"net/http"
+	"sync"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/cheolwanpark/meows/collector/internal/searchindex"
+	"github.com/cheolwanpark/meows/collector/internal/source"
+)
+
+const (
+	indexTimeout   = 60 * time.Second // Bounds each bulk request
+	indexBatchSize = 500              // Documents per bulk request
+)
+
+// articleIndexer bulk-indexes what each source run stored into Elasticsearch or OpenSearch
+// A nil indexer (COLLECTOR_ELASTICSEARCH_URL unset) indexes nothing
+type articleIndexer struct {
+	client *searchindex.Client
+	wg     sync.WaitGroup // Bulk requests in flight
+}
+
+// indexedArticle is the document indexed for each stored article
+type indexedArticle struct {
+	UID        string          `json:"uid"`
+	SourceID   string          `json:"source_id"`
+	SourceType string          `json:"source_type"`
+	SourceKey  string          `json:"source_key"`
+	ExternalID string          `json:"external_id"`
+	ProfileID  string          `json:"profile_id"`
+	Job        string          `json:"job"`
+	RunID      string          `json:"run_id"`
+	Title      string          `json:"title"`
+	Author     string          `json:"author"`
+	Content    string          `json:"content"`
+	URL        string          `json:"url,omitempty"`
+	WrittenAt  time.Time       `json:"written_at"`
+	Metadata   json.RawMessage `json:"metadata,omitempty"`
+	IndexedAt  time.Time       `json:"indexed_at"`
+}
+
+func newArticleIndexer(cfg config.SearchIndexConfig) *articleIndexer {
+	if cfg.URL == "" {
+		return nil
+	}
+	// Separate from the sources' client, so bulk requests don't count as crawl requests
+	return &articleIndexer{client: searchindex.NewClient(cfg, &http.Client{Timeout: indexTimeout})}
+}
+
+// index sends a source run's stored articles to the index in the background, in batches of indexBatchSize
+// Documents are indexed under the article's UID, so re-crawled articles replace their earlier copies and the index
+// shares its keys with the API and exports; an item stored for several profiles is one document, last crawl wins
+// Failures are logged, not retried; the database stays the source of truth
+func (x *articleIndexer) index(job, runID string, src *db.Source, articles []db.Article) {
+	if x == nil || len(articles) == 0 {
+		return
+	}
+
+	now := time.Now().UTC()
+	sourceKey := source.SourceKey(src.Type, src.ExternalID)
+	docs := make([]searchindex.Document, 0, len(articles))
+	for _, article := range articles {
+		docs = append(docs, searchindex.Document{
+			ID: article.UID,
+			Body: indexedArticle{
+				UID:        article.UID,
+				SourceID:   src.ID,
+				SourceType: src.Type,
+				SourceKey:  sourceKey,
+				ExternalID: article.ExternalID,
+				ProfileID:  article.ProfileID,
+				Job:        job,
+				RunID:      runID,
+				Title:      article.Title,
+				Author:     article.Author,
+				Content:    article.Content,
+				URL:        article.URL,
+				WrittenAt:  article.WrittenAt,
+				Metadata:   article.Metadata,
+				IndexedAt:  now,
+			},
+		})
+	}
+
+	x.wg.Add(1)
+	go func() {
+		defer x.wg.Done()
+		for start := 0; start < len(docs); start += indexBatchSize {
+			batch := docs[start:min(start+indexBatchSize, len(docs))]
+			ctx, cancel := context.WithTimeout(context.Background(), indexTimeout)
+			result, err := x.client.Bulk(ctx, batch)
+			cancel()
+			if err != nil {
+				slog.Warn("Bulk indexing failed", "source_id", src.ID, "documents", len(batch), "error", err)
+				return
+			}
+			if result.Failed > 0 {
+				slog.Warn("Documents rejected by the search index", "source_id", src.ID, "indexed", result.Indexed, "failed", result.Failed, "reason", result.FirstFail)
+			}
+		}
+	}()
+}
+
+// wait blocks until bulk requests in flight finish or ctx is done
+func (x *articleIndexer) wait(ctx context.Context) {
+	if x == nil {
+		return
+	}
+	done := make(chan struct{})
+	go func() {
+		x.wg.Wait()
+		close(done)
+	}()
+	select {
+	case <-done:
+	case <-ctx.Done():
+	}
+}
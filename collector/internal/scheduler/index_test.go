@@ -0,0 +1,44 @@
+package scheduler
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"sync"
+	"testing"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/cheolwanpark/meows/collector/internal/source"
+)
+
+func TestArticleIndexer_UsesArticleUID(t *testing.T) {
+	var mu sync.Mutex
+	var ids []string
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		scanner := bufio.NewScanner(r.Body)
+		for scanner.Scan() {
+			var action map[string]map[string]string
+			if err := json.Unmarshal(scanner.Bytes(), &action); err == nil && action["index"] != nil {
+				mu.Lock()
+				ids = append(ids, action["index"]["_id"])
+				mu.Unlock()
+			}
+		}
+		w.Write([]byte(`{"errors":false,"items":[]}`))
+	}))
+	defer server.Close()
+
+	src := &db.Source{ID: "src", Type: "reddit", ExternalID: "golang"}
+	articles, _ := source.ApplyDedupKey(src.Type, []db.Article{{ExternalID: "abc"}, {ExternalID: "def"}}, nil, "")
+
+	x := newArticleIndexer(config.SearchIndexConfig{URL: server.URL, Index: "meows"})
+	x.index(defaultJobName, "run", src, articles)
+	x.wait(context.Background())
+
+	if len(ids) != 2 || ids[0] != articles[0].UID || ids[1] != articles[1].UID || ids[0] != db.ArticleUID("reddit", "abc") {
+		t.Errorf("Expected documents to be indexed under the article UIDs, got %v", ids)
+	}
+}
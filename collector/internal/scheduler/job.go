@@ -0,0 +1,84 @@
+package scheduler
+
+import (
+	"fmt"
+	"sync"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/source"
+)
+
+// defaultJobName names the job configured by COLLECTOR_CRON_EXPR, COLLECTOR_ONLY_SOURCES and friends
+const defaultJobName = "default"
+
+// job is one named crawl with its own schedule, source patterns, webhooks and run state
+// Jobs share the database, HTTP client and per-type rate limiters, but never each other's status or reports
+type job struct {
+	name      string
+	cronExpr  string
+	filter    source.SourceFilter // Source patterns selecting what the job crawls
+	status    *runStatus          // In-memory progress for status dumps and the run report
+	webhooks  *webhookNotifier    // Lifecycle event delivery, nil when the job has no webhook URLs
+	mu        sync.Mutex
+	isRunning bool
+}
+
+// newJob creates a job after checking its source patterns
+func newJob(name, cronExpr string, only, skip []string, webhooks config.WebhookConfig) (*job, error) {
+	for _, pattern := range append(append([]string{}, only...), skip...) {
+		if err := source.ValidatePattern(pattern); err != nil {
+			return nil, err
+		}
+	}
+
+	return &job{
+		name:     name,
+		cronExpr: cronExpr,
+		filter:   source.SourceFilter{Only: only, Skip: skip},
+		status:   newRunStatus(),
+		webhooks: newWebhookNotifier(name, webhooks),
+	}, nil
+}
+
+// newJobs creates the default job followed by the named jobs of the config
+func newJobs(cfg *config.ScheduleConfig, webhooks config.WebhookConfig) ([]*job, error) {
+	defaultJob, err := newJob(defaultJobName, cfg.CronExpr, cfg.OnlySources, cfg.SkipSources, webhooks)
+	if err != nil {
+		return nil, fmt.Errorf("invalid COLLECTOR_ONLY_SOURCES or COLLECTOR_SKIP_SOURCES: %w", err)
+	}
+
+	jobs := []*job{defaultJob}
+	for _, jc := range cfg.Jobs {
+		j, err := newJob(jc.Name, jc.CronExpr, jc.OnlySources, jc.SkipSources, jc.Webhooks)
+		if err != nil {
+			return nil, fmt.Errorf("invalid source patterns for job %q: %w", jc.Name, err)
+		}
+		jobs = append(jobs, j)
+	}
+	return jobs, nil
+}
+
+// begin marks the job as running, or returns false if a run is already in progress
+func (j *job) begin() bool {
+	j.mu.Lock()
+	defer j.mu.Unlock()
+	if j.isRunning {
+		return false
+	}
+	j.isRunning = true
+	return true
+}
+
+// end marks the job's run as over
+func (j *job) end() {
+	j.mu.Lock()
+	defer j.mu.Unlock()
+	j.isRunning = false
+}
+
+// running reports whether a run of the job is in progress
+func (j *job) running() bool {
+	j.mu.Lock()
+	defer j.mu.Unlock()
+	return j.isRunning
+}
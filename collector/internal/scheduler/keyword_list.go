@@ -0,0 +1,131 @@
+package scheduler
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"fmt"
+	"io"
+	"log/slog"
+	"net/http"
+	"strings"
+	"sync"
+)
+
+// maxKeywordListBytes bounds the size of a remote keyword list
+const maxKeywordListBytes = 1 << 20 // 1 MiB
+
+// keywordList is a parsed remote watchlist
+type keywordList struct {
+	include []string
+	exclude []string
+}
+
+// keywordListCache fetches remote keyword lists (keywords_url) and revalidates them with ETag/Last-Modified
+// Lists are re-checked every time a source using them runs; unchanged lists cost a 304
+type keywordListCache struct {
+	client *http.Client
+
+	mu      sync.Mutex
+	entries map[string]cachedKeywordList // URL -> last successful fetch
+}
+
+type cachedKeywordList struct {
+	list         keywordList
+	etag         string
+	lastModified string
+}
+
+func newKeywordListCache(client *http.Client) *keywordListCache {
+	return &keywordListCache{
+		client:  client,
+		entries: make(map[string]cachedKeywordList),
+	}
+}
+
+// get returns the current list at listURL
+// If the list can't be fetched, the last good copy is used and the refresh error is returned alongside it;
+// without a good copy the error is returned as err
+func (c *keywordListCache) get(ctx context.Context, listURL string) (list keywordList, refreshErr, err error) {
+	c.mu.Lock()
+	cached, hasCached := c.entries[listURL]
+	c.mu.Unlock()
+
+	list, etag, lastModified, notModified, err := c.fetch(ctx, listURL, cached, hasCached)
+	if err != nil {
+		if hasCached {
+			slog.Warn("Failed to refresh keyword list, using cached copy", "url", listURL, "error", err)
+			return cached.list, err, nil
+		}
+		return keywordList{}, nil, fmt.Errorf("failed to load keywords_url: %w", err)
+	}
+	if notModified {
+		return cached.list, nil, nil
+	}
+
+	c.mu.Lock()
+	c.entries[listURL] = cachedKeywordList{list: list, etag: etag, lastModified: lastModified}
+	c.mu.Unlock()
+
+	return list, nil, nil
+}
+
+// fetch performs a conditional GET, reporting notModified when the cached copy is still current
+func (c *keywordListCache) fetch(ctx context.Context, listURL string, cached cachedKeywordList, hasCached bool) (list keywordList, etag, lastModified string, notModified bool, err error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", listURL, nil)
+	if err != nil {
+		return list, "", "", false, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	if hasCached {
+		if cached.etag != "" {
+			req.Header.Set("If-None-Match", cached.etag)
+		}
+		if cached.lastModified != "" {
+			req.Header.Set("If-Modified-Since", cached.lastModified)
+		}
+	}
+
+	resp, err := c.client.Do(req)
+	if err != nil {
+		return list, "", "", false, err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode == http.StatusNotModified && hasCached {
+		return list, "", "", true, nil
+	}
+	if resp.StatusCode != http.StatusOK {
+		return list, "", "", false, fmt.Errorf("HTTP %d from %s", resp.StatusCode, listURL)
+	}
+
+	body, err := io.ReadAll(io.LimitReader(resp.Body, maxKeywordListBytes+1))
+	if err != nil {
+		return list, "", "", false, err
+	}
+	if len(body) > maxKeywordListBytes {
+		return list, "", "", false, fmt.Errorf("keyword list larger than %d bytes", maxKeywordListBytes)
+	}
+
+	return parseKeywordList(body), resp.Header.Get("ETag"), resp.Header.Get("Last-Modified"), false, nil
+}
+
+// parseKeywordList reads one lowercased keyword per line; "-" marks an exclude keyword and "#" starts a comment line
+func parseKeywordList(body []byte) keywordList {
+	var list keywordList
+	scanner := bufio.NewScanner(bytes.NewReader(body))
+	for scanner.Scan() {
+		line := strings.TrimSpace(scanner.Text()) // Also drops the \r of CRLF files
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		if keyword, ok := strings.CutPrefix(line, "-"); ok {
+			if keyword = strings.TrimSpace(keyword); keyword != "" {
+				list.exclude = append(list.exclude, strings.ToLower(keyword))
+			}
+			continue
+		}
+		list.include = append(list.include, strings.ToLower(line))
+	}
+	return list
+}
@@ -0,0 +1,66 @@
+package scheduler
+
+import (
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+func TestParseKeywordList(t *testing.T) {
+	list := parseKeywordList([]byte("# team watchlist\r\nRust\r\n\r\n- Hiring\r\nwasm\n"))
+	if strings.Join(list.include, ",") != "rust,wasm" {
+		t.Errorf("Unexpected include keywords: %v", list.include)
+	}
+	if strings.Join(list.exclude, ",") != "hiring" {
+		t.Errorf("Unexpected exclude keywords: %v", list.exclude)
+	}
+}
+
+func TestKeywordListCache_RevalidatesWithETag(t *testing.T) {
+	requests, failing := 0, false
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		requests++
+		if failing {
+			w.WriteHeader(http.StatusInternalServerError)
+			return
+		}
+		if r.Header.Get("If-None-Match") == `"v1"` {
+			w.WriteHeader(http.StatusNotModified)
+			return
+		}
+		w.Header().Set("ETag", `"v1"`)
+		w.Write([]byte("golang\n"))
+	}))
+	defer server.Close()
+
+	cache := newKeywordListCache(server.Client())
+	for i := 0; i < 2; i++ {
+		list, _, err := cache.get(context.Background(), server.URL)
+		if err != nil {
+			t.Fatalf("Unexpected error on fetch %d: %v", i+1, err)
+		}
+		if len(list.include) != 1 || list.include[0] != "golang" {
+			t.Errorf("Unexpected list on fetch %d: %v", i+1, list.include)
+		}
+	}
+
+	// An outage falls back to the last good copy
+	failing = true
+	list, refreshErr, err := cache.get(context.Background(), server.URL)
+	if err != nil || len(list.include) != 1 {
+		t.Errorf("Expected cached list during outage, got %v (err: %v)", list.include, err)
+	}
+	if refreshErr == nil {
+		t.Errorf("Expected the refresh error to be reported alongside the cached list")
+	}
+	if requests != 3 {
+		t.Errorf("Expected 3 requests, got %d", requests)
+	}
+
+	// Without a cached copy the error surfaces
+	if _, _, err := newKeywordListCache(server.Client()).get(context.Background(), server.URL); err == nil {
+		t.Errorf("Expected an error without a cached copy")
+	}
+}
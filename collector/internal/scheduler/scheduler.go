@@ -3,9 +3,13 @@ package scheduler
 import (
 	"context"
 	"database/sql"
+	"errors"
 	"fmt"
 	"log"
 	"log/slog"
+	"net/http"
+	"slices"
+	"sort"
 	"sync"
 	"time"
 
@@ -13,6 +17,7 @@ import (
 	"github.com/cheolwanpark/meows/collector/internal/db"
 	"github.com/cheolwanpark/meows/collector/internal/personalization"
 	"github.com/cheolwanpark/meows/collector/internal/source"
+	"github.com/google/uuid"
 	"github.com/robfig/cron/v3"
 	"golang.org/x/time/rate"
 )
@@ -23,10 +28,17 @@ type Scheduler struct {
 	db              *db.DB
 	config          *config.CollectorConfig          // Global configuration from file
 	rateLimiters    map[string]*rate.Limiter         // Long-lived rate limiters per source type
+	httpClient      *http.Client                     // Shared HTTP client for all sources
 	profileService  *personalization.UpdateService   // Profile update service
 	curationService *personalization.CurationService // Article curation service
-	mu              sync.RWMutex
-	isRunning       bool
+	fetchCache      *fetchCache                      // Opt-in cache shared by sources with identical configs
+	keywordLists    *keywordListCache                // Remote keyword watchlists (keywords_url), revalidated each run
+	jobs            []*job                           // The default job first, then COLLECTOR_JOBS in order
+	uploader        *resultUploader                  // Copies stored results to object storage, nil when COLLECTOR_S3_URL is unset
+	indexer         *articleIndexer                  // Bulk-indexes stored articles, nil when COLLECTOR_ELASTICSEARCH_URL is unset
+	mu              sync.Mutex
+	inFlight        map[string]string // Source ID -> job or manual trigger crawling it, so a source is never crawled twice at once
+	activeRuns      int               // Global runs in progress across jobs, see beginCycle
 }
 
 // New creates a new Scheduler with configuration from file
@@ -36,11 +48,36 @@ func New(cfg *config.CollectorConfig, database *db.DB, profService *personalizat
 		config:          cfg,
 		profileService:  profService,
 		curationService: curService,
+		fetchCache:      newFetchCache(time.Duration(cfg.Schedule.FetchCacheTTLSeconds) * time.Second),
+		inFlight:        make(map[string]string),
 	}
 
+	jobs, err := newJobs(&cfg.Schedule, cfg.Webhooks)
+	if err != nil {
+		return nil, err
+	}
+	s.jobs = jobs
+
+	uploader, err := newResultUploader(cfg.ObjectStore)
+	if err != nil {
+		return nil, fmt.Errorf("failed to create object storage uploader: %w", err)
+	}
+	s.uploader = uploader
+	s.indexer = newArticleIndexer(cfg.Search)
+
 	// Create long-lived rate limiters from config
 	s.rateLimiters = s.createRateLimiters()
 
+	// Create shared HTTP client so sources reuse pooled connections
+	httpClient, err := source.NewHTTPClient(&cfg.HTTP)
+	if err != nil {
+		return nil, fmt.Errorf("failed to create HTTP client: %w", err)
+	}
+	// Count outbound requests per job for status dumps and request budgets, and record per-item provenance
+	httpClient.Transport = source.CountRequests()(source.TrackRequests()(httpClient.Transport))
+	s.httpClient = httpClient
+	s.keywordLists = newKeywordListCache(httpClient)
+
 	// Create cron instance with schedule from config
 	if err := s.createCron(); err != nil {
 		return nil, err
@@ -58,21 +95,22 @@ func (s *Scheduler) createCron() error {
 		),
 	)
 
-	// Register the single global job with schedule from config
-	_, err := s.cron.AddFunc(s.config.Schedule.CronExpr, func() {
-		if err := s.runAllSources(); err != nil {
-			slog.Error("Global crawl job failed", "error", err)
+	// Register one crawl job per configured job, each on its own schedule
+	for _, j := range s.jobs {
+		_, err := s.cron.AddFunc(j.cronExpr, func() {
+			if err := s.runAllSources(j); err != nil {
+				slog.Error("Global crawl job failed", "job", j.name, "error", err)
+			}
+		})
+		if err != nil {
+			return fmt.Errorf("failed to register cron job %q: %w", j.name, err)
 		}
-	})
-	if err != nil {
-		return fmt.Errorf("failed to register global cron job: %w", err)
+		slog.Info("Registered global cron job", "job", j.name, "schedule", j.cronExpr)
 	}
 
-	slog.Info("Registered global cron job", "schedule", s.config.Schedule.CronExpr)
-
 	// Register profile update cron job if profile service is available
 	if s.profileService != nil && s.config.Profile.DailyCronExpr != "" {
-		_, err = s.cron.AddFunc(s.config.Profile.DailyCronExpr, func() {
+		_, err := s.cron.AddFunc(s.config.Profile.DailyCronExpr, func() {
 			s.CheckProfileUpdates()
 		})
 		if err != nil {
@@ -81,6 +119,19 @@ func (s *Scheduler) createCron() error {
 		slog.Info("Registered profile update cron job", "schedule", s.config.Profile.DailyCronExpr)
 	}
 
+	// Register database maintenance (retention pruning and compaction)
+	if s.config.Server.MaintenanceCronExpr != "" {
+		_, err := s.cron.AddFunc(s.config.Server.MaintenanceCronExpr, func() {
+			if _, _, err := s.MaintainStore(0); err != nil {
+				slog.Warn("Database maintenance skipped", "error", err)
+			}
+		})
+		if err != nil {
+			return fmt.Errorf("failed to register database maintenance cron job: %w", err)
+		}
+		slog.Info("Registered database maintenance cron job", "schedule", s.config.Server.MaintenanceCronExpr, "retention_days", s.config.Server.RetentionDays)
+	}
+
 	return nil
 }
 
@@ -96,6 +147,11 @@ func (s *Scheduler) Stop(ctx context.Context) error {
 
 	select {
 	case <-stopCtx.Done():
+		for _, j := range s.jobs {
+			j.webhooks.wait(ctx) // Let final run_completed deliveries go out
+		}
+		s.uploader.wait(ctx)
+		s.indexer.wait(ctx)
 		log.Println("Scheduler stopped gracefully")
 		return nil
 	case <-ctx.Done():
@@ -105,53 +161,94 @@ func (s *Scheduler) Stop(ctx context.Context) error {
 
 // Note: Config reload is not supported. Changes to .config.yaml require service restart.
 
-// RunNow manually triggers a crawl for all sources
-func (s *Scheduler) RunNow() error {
-	s.mu.Lock()
-	if s.isRunning {
-		s.mu.Unlock()
-		return fmt.Errorf("crawl job is already running")
+// ErrUnknownJob is returned for a job name that is neither "default" nor listed in COLLECTOR_JOBS
+var ErrUnknownJob = errors.New("unknown job")
+
+// job returns the job with the given name ("" for the default job)
+func (s *Scheduler) job(name string) (*job, error) {
+	if name == "" {
+		name = defaultJobName
+	}
+	for _, j := range s.jobs {
+		if j.name == name {
+			return j, nil
+		}
+	}
+	return nil, fmt.Errorf("%w: %q", ErrUnknownJob, name)
+}
+
+// RunNow manually triggers a crawl of the named job ("" for the default job)
+func (s *Scheduler) RunNow(name string) error {
+	j, err := s.job(name)
+	if err != nil {
+		return err
+	}
+	if j.running() {
+		return fmt.Errorf("crawl job %q is already running", j.name)
 	}
-	s.isRunning = true // Set BEFORE unlocking to prevent race
-	s.mu.Unlock()
 
 	// Run in goroutine to return immediately
 	go func() {
-		defer func() {
-			s.mu.Lock()
-			s.isRunning = false
-			s.mu.Unlock()
-		}()
-		if err := s.runAllSources(); err != nil {
-			slog.Error("Manual crawl job failed", "error", err)
+		if err := s.runAllSources(j); err != nil {
+			slog.Error("Manual crawl job failed", "job", j.name, "error", err)
 		}
 	}()
 
 	return nil
 }
 
-// RunSingleSource triggers an immediate crawl for a single source
-// This is a fire-and-forget operation that runs asynchronously
+// ErrSourceRunning is returned by ClaimSource while a job's run is crawling the source
+var ErrSourceRunning = errors.New("source is already running")
+
+// ClaimSource reserves a source for a manual trigger, or returns ErrSourceRunning while another run is crawling it
+// Claim before marking the source as running, so a refused trigger leaves the crawling run's status alone.
+// The claim passes to RunSingleSource; a trigger abandoned before that must call ReleaseSource
+func (s *Scheduler) ClaimSource(sourceID string) error {
+	if other := s.claimSource(nil, sourceID); other != "" {
+		return fmt.Errorf("%w in %s", ErrSourceRunning, other)
+	}
+	return nil
+}
+
+// ReleaseSource drops a claim taken by ClaimSource without crawling
+func (s *Scheduler) ReleaseSource(sourceID string) {
+	s.releaseSource(sourceID)
+}
+
+// RunSingleSource triggers an immediate crawl for a single source claimed with ClaimSource
+// This is a fire-and-forget operation that runs asynchronously and releases the claim when done
+// The outcome is recorded in the default job's run report
 // NOTE: Status should already be set to "running" by the caller
 func (s *Scheduler) RunSingleSource(src *db.Source) {
-	slog.Info("Triggering manual crawl for source", "source_id", src.ID, "type", src.Type)
+	go func() {
+		defer s.releaseSource(src.ID)
+		s.runTriggeredSource(src)
+	}()
+}
+
+// runTriggeredSource crawls a source claimed for a manual trigger
+func (s *Scheduler) runTriggeredSource(src *db.Source) {
+	j := s.jobs[0]
+	runID := uuid.New().String()
+	logger := newRunLogger(j, runID)
+	logger.Info("Triggering manual crawl for source", "source_id", src.ID, "type", src.Type)
 
 	// Get rate limiter for this source type
 	limiter := s.rateLimiters[src.Type]
 	if limiter == nil {
-		slog.Error("No rate limiter found for source type", "type", src.Type, "source_id", src.ID)
+		logger.Error("No rate limiter found for source type", "type", src.Type, "source_id", src.ID)
 		// Revert status on failure - best effort
 		if err := s.updateSourceStatus(src.ID, "idle"); err != nil {
-			slog.Error("Failed to revert source status to idle", "source_id", src.ID, "error", err)
+			logger.Error("Failed to revert source status to idle", "source_id", src.ID, "error", err)
 		}
 		return
 	}
 
 	// Run single source (status already set by caller)
-	if err := s.runSingleSource(src, limiter); err != nil {
-		slog.Error("Manual crawl failed", "source_id", src.ID, "error", err)
+	if err := s.runSingleSource(j, runID, src, limiter, logger); err != nil {
+		logger.Error("Manual crawl failed", "source_id", src.ID, "error", err)
 	} else {
-		slog.Info("Manual crawl completed successfully", "source_id", src.ID)
+		logger.Info("Manual crawl completed successfully", "source_id", src.ID)
 	}
 }
 
@@ -202,28 +299,78 @@ func (s *Scheduler) CheckProfileUpdates() {
 	}
 }
 
-// runAllSources orchestrates crawling all sources
+// ErrCrawlRunning is returned by MaintainStore while any job's run is in progress
+var ErrCrawlRunning = errors.New("a crawl is running")
+
+// MaintainStore prunes articles stored more than retentionDays ago and compacts the database once enough of it is unused
+// retentionDays <= 0 falls back to COLLECTOR_RETENTION_DAYS; when that is 0 too, nothing is pruned
+// Refused while a crawl is running, since compaction blocks its writes
+func (s *Scheduler) MaintainStore(retentionDays int) (*db.PruneResult, int64, error) {
+	for _, j := range s.jobs {
+		if j.running() {
+			return nil, 0, fmt.Errorf("%w in job %q", ErrCrawlRunning, j.name)
+		}
+	}
+	if retentionDays <= 0 {
+		retentionDays = s.config.Server.RetentionDays
+	}
+
+	var pruned *db.PruneResult
+	if retentionDays > 0 {
+		var err error
+		pruned, err = s.db.Prune(time.Now().AddDate(0, 0, -retentionDays))
+		if err != nil {
+			return nil, 0, err
+		}
+		slog.Info("Pruned expired articles",
+			"retention_days", retentionDays,
+			"articles", pruned.Articles,
+			"comments", pruned.Comments,
+			"curated", pruned.Curated)
+	}
+
+	reclaimed, err := s.db.Compact()
+	if err != nil {
+		return pruned, 0, err
+	}
+	if reclaimed > 0 {
+		slog.Info("Compacted database", "reclaimed_bytes", reclaimed)
+	}
+	return pruned, reclaimed, nil
+}
+
+// runAllSources orchestrates crawling all sources selected by a job
 // - Groups sources by type
 // - Runs different types in parallel (goroutines)
 // - Runs same-type sources sequentially
 // - Uses shared rate limiter per source type
-func (s *Scheduler) runAllSources() error {
-	s.mu.Lock()
-	if s.isRunning {
-		s.mu.Unlock()
-		log.Println("Crawl job already running, skipping this execution")
+func (s *Scheduler) runAllSources(j *job) error {
+	if !j.begin() {
+		log.Printf("Crawl job %q already running, skipping this execution", j.name)
 		return nil
 	}
-	s.isRunning = true
-	s.mu.Unlock()
+	defer j.end()
 
+	runID := uuid.New().String()
+	logger := newRunLogger(j, runID)
+	logger.Info("Starting global crawl job for all sources")
+	j.status.reset(runID)
+	j.webhooks.runStarted(runID, time.Now())
 	defer func() {
-		s.mu.Lock()
-		s.isRunning = false
-		s.mu.Unlock()
+		j.status.complete()
+		report, _ := s.RunReport(j.name)
+		j.webhooks.runCompleted(report)
 	}()
-
-	log.Println("Starting global crawl job for all sources")
+	newCycle := s.beginCycle()
+	defer s.endCycle()
+	if s.curationService != nil {
+		// Overlapping jobs share one curation budget, so only the run that opens a cycle resets it
+		if newCycle {
+			s.curationService.BeginRun()
+		}
+		// Skips already counted belong to other runs
+		j.status.setCurationBaseline(s.curationService.Usage().Skipped)
+	}
 
 	// Fetch all sources from DB
 	sources, err := s.getAllSources()
@@ -232,13 +379,26 @@ func (s *Scheduler) runAllSources() error {
 	}
 
 	if len(sources) == 0 {
-		log.Println("No sources configured, skipping crawl")
+		logger.Info("No sources configured, skipping crawl")
 		return nil
 	}
 
-	// Group sources by type
+	// Sources outside their active window sit this run out
+	sources = s.activeSources(j, sources, time.Now(), logger)
+	if len(sources) == 0 {
+		logger.Info("No sources inside their active window, skipping crawl")
+		return nil
+	}
+
+	// Group sources by type, highest priority first
 	typeGroups := s.groupSourcesByType(sources)
 
+	// Sources still waiting when the run deadline passes are skipped
+	var deadline time.Time
+	if minutes := s.config.Schedule.RunDeadlineMinutes; minutes > 0 {
+		deadline = time.Now().Add(time.Duration(minutes) * time.Minute)
+	}
+
 	// Launch goroutine per source type
 	var wg sync.WaitGroup
 	errChan := make(chan error, len(typeGroups))
@@ -247,8 +407,8 @@ func (s *Scheduler) runAllSources() error {
 		wg.Add(1)
 		go func(typ string, srcs []*db.Source) {
 			defer wg.Done()
-			log.Printf("Starting sequential crawl for %d %s sources", len(srcs), typ)
-			if err := s.runSourcesSequentially(srcs, s.rateLimiters[typ]); err != nil {
+			logger.Info("Starting sequential crawl", "type", typ, "sources", len(srcs))
+			if err := s.runSourcesSequentially(j, runID, srcs, s.rateLimiters[typ], deadline, logger); err != nil {
 				errChan <- fmt.Errorf("%s sources failed: %w", typ, err)
 			}
 		}(sourceType, typeSources)
@@ -259,34 +419,94 @@ func (s *Scheduler) runAllSources() error {
 	close(errChan)
 
 	// Aggregate errors
-	var errors []error
+	var errs []error
 	for err := range errChan {
-		errors = append(errors, err)
+		errs = append(errs, err)
 	}
 
-	if len(errors) > 0 {
-		log.Printf("Global crawl job completed with %d errors", len(errors))
-		return fmt.Errorf("%d source type(s) failed", len(errors))
+	// Per-source outcomes, then one consolidated list of failed sources and dropped items
+	s.logOutcomes(j, logger)
+	s.logDegradationReport(j, logger)
+
+	if len(errs) > 0 {
+		logger.Warn("Global crawl job completed with errors", "errors", len(errs))
+		return fmt.Errorf("%d source type(s) failed", len(errs))
 	}
 
-	log.Println("Global crawl job completed successfully")
+	logger.Info("Global crawl job completed successfully")
 	return nil
 }
 
+// loadWatchlist fetches the source's keywords_url list, if it has one
+// Falling back to a cached copy is noted in the run's degradation report
+func (s *Scheduler) loadWatchlist(ctx context.Context, j *job, src *db.Source) (keywordList, error) {
+	opts, err := source.ParseOptions(src.Config)
+	if err != nil || opts.KeywordsURL == "" {
+		return keywordList{}, nil
+	}
+	list, refreshErr, err := s.keywordLists.get(ctx, opts.KeywordsURL)
+	if refreshErr != nil {
+		j.status.degrade(degradation{
+			kind:       "stale_watchlist",
+			sourceID:   src.ID,
+			sourceType: src.Type,
+			reason:     fmt.Sprintf("using cached keywords_url copy: %v", refreshErr),
+		})
+	}
+	return list, err
+}
+
+// activeSources drops sources whose active_days/active_hours window excludes now
+// Manual triggers don't go through here, so a source can always be run on demand
+func (s *Scheduler) activeSources(j *job, sources []*db.Source, now time.Time, logger *slog.Logger) []*db.Source {
+	active := make([]*db.Source, 0, len(sources))
+	for _, src := range sources {
+		if ok, reason := j.filter.Allows(src.Type, src.ExternalID); !ok {
+			logger.Info("Skipping source excluded by source patterns", "source_id", src.ID, "source", source.SourceKey(src.Type, src.ExternalID), "reason", reason)
+			j.status.skip(src, "excluded: "+reason)
+			continue
+		}
+		opts, err := source.ParseOptions(src.Config)
+		if err == nil && !source.IsActive(opts, now) {
+			logger.Info("Skipping source outside its active window", "source_id", src.ID, "type", src.Type)
+			j.status.skip(src, "outside active window")
+			continue
+		}
+		active = append(active, src)
+	}
+	return active
+}
+
 // runSourcesSequentially executes sources of the same type one after another
 // Fetches each source then stores results in per-source atomic transaction
-func (s *Scheduler) runSourcesSequentially(sources []*db.Source, limiter *rate.Limiter) error {
+// Once the run deadline or request budget is reached, the remaining sources are skipped
+func (s *Scheduler) runSourcesSequentially(j *job, runID string, sources []*db.Source, limiter *rate.Limiter, deadline time.Time, logger *slog.Logger) error {
 	for _, src := range sources {
-		log.Printf("Processing source %s (type: %s)", src.ID, src.Type)
+		if reason := s.runLimitReason(j, deadline); reason != "" {
+			logger.Warn("Skipping source", "source_id", src.ID, "type", src.Type, "reason", reason)
+			j.status.skip(src, reason)
+			j.status.degrade(degradation{kind: "run_limit", sourceID: src.ID, sourceType: src.Type, reason: reason})
+			continue
+		}
+		if other := s.claimSource(j, src.ID); other != "" {
+			reason := "already running in " + other
+			logger.Warn("Skipping source", "source_id", src.ID, "type", src.Type, "reason", reason)
+			j.status.skip(src, reason)
+			continue
+		}
+
+		logger.Info("Processing source", "source_id", src.ID, "type", src.Type)
 
 		// Update status to running
 		if err := s.updateSourceStatus(src.ID, "running"); err != nil {
-			log.Printf("Failed to update status for source %s: %v", src.ID, err)
+			logger.Error("Failed to update source status", "source_id", src.ID, "error", err)
 		}
 
 		// Process single source
-		if err := s.runSingleSource(src, limiter); err != nil {
-			log.Printf("Source %s processing failed: %v", src.ID, err)
+		err := s.runSingleSource(j, runID, src, limiter, logger)
+		s.releaseSource(src.ID)
+		if err != nil {
+			logger.Error("Source processing failed", "source_id", src.ID, "error", err)
 			// Error already recorded by runSingleSource, continue with next source
 			continue
 		}
@@ -295,10 +515,76 @@ func (s *Scheduler) runSourcesSequentially(sources []*db.Source, limiter *rate.L
 	return nil
 }
 
+// claimSource marks a source as being crawled by j (nil for a manual trigger), or describes who is already crawling it
+// Jobs whose source patterns overlap share those sources, but take turns crawling them
+func (s *Scheduler) claimSource(j *job, sourceID string) string {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if other, ok := s.inFlight[sourceID]; ok {
+		return other
+	}
+	owner := "a manual trigger"
+	if j != nil {
+		owner = fmt.Sprintf("job %q", j.name)
+	}
+	s.inFlight[sourceID] = owner
+	return ""
+}
+
+// releaseSource clears the claim taken by claimSource
+func (s *Scheduler) releaseSource(sourceID string) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	delete(s.inFlight, sourceID)
+}
+
+// beginCycle counts a global run as started and reports whether it opens a new scheduler cycle,
+// i.e. no other job's run is in progress
+func (s *Scheduler) beginCycle() bool {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.activeRuns++
+	return s.activeRuns == 1
+}
+
+// endCycle counts a global run started by beginCycle as over
+func (s *Scheduler) endCycle() {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.activeRuns--
+}
+
+// filterDiagnostic reports a source whose keyword filters dropped every fetched article, with the nearest misses
+// A filter that silently matches nothing usually means a typo or a keyword the source never uses
+func (s *Scheduler) filterDiagnostic(j *job, src *db.Source, fetched []db.Article, include, exclude []string, logger *slog.Logger) *FilterDiagnostic {
+	diagnostic := &FilterDiagnostic{
+		Fetched:    len(fetched),
+		Include:    include,
+		Exclude:    exclude,
+		NearMisses: source.KeywordNearMisses(fetched, include, exclude, nearMissCount),
+	}
+	j.status.degrade(degradation{
+		kind:       "filtered_out",
+		sourceID:   src.ID,
+		sourceType: src.Type,
+		items:      len(fetched),
+		reason:     "keyword filters dropped every fetched article",
+	})
+
+	attrs := []any{"source_id", src.ID, "fetched", len(fetched)}
+	if best := diagnostic.NearMisses[0]; best.ExcludedBy != "" {
+		attrs = append(attrs, "nearest_miss", best.Title, "excluded_by", best.ExcludedBy)
+	} else {
+		attrs = append(attrs, "nearest_miss", best.Title, "overlap", best.Overlap)
+	}
+	logger.Warn("Keyword filters dropped every fetched article", attrs...)
+	return diagnostic
+}
+
 // runSingleSource processes a single source (fetch, store, update status)
 // Assumes status is already set to "running" by caller
 // This is the primitive operation that all source processing builds upon
-func (s *Scheduler) runSingleSource(src *db.Source, limiter *rate.Limiter) error {
+func (s *Scheduler) runSingleSource(j *job, runID string, src *db.Source, limiter *rate.Limiter, logger *slog.Logger) (err error) {
 	// Per-source timeout (60 minutes)
 	// Increased to accommodate sources with deep comment fetching (e.g., HackerNews)
 	// Calculation: ~3,000 API calls × 500ms rate limit ≈ 25 min + overhead/retries
@@ -307,9 +593,38 @@ func (s *Scheduler) runSingleSource(src *db.Source, limiter *rate.Limiter) error
 	//       will take 60 min to fail instead of 5 min. Consider source-specific timeouts.
 	ctx, cancel := context.WithTimeout(context.Background(), 60*time.Minute)
 	defer cancel()
+	// Record request counts for the source outcome and the request behind each article
+	ctx = source.WithRequestTrail(ctx)
+	// Count requests against the job's request budget
+	ctx = source.WithRequestCounter(ctx, &j.status.requests)
+
+	j.status.start(src)
+	var storedArticles, storedComments int
+	var storedAuthors []AuthorStat
+	var filterDiagnostic *FilterDiagnostic
+	defer func() {
+		for _, reason := range source.TrailMirrorSwitches(ctx) {
+			j.status.degrade(degradation{kind: "mirror_switched", sourceID: src.ID, sourceType: src.Type, reason: reason})
+		}
+		outcome := j.status.finish(runID, src, storedArticles, storedComments, storedAuthors, source.TrailStats(ctx), filterDiagnostic, err)
+		j.webhooks.sourceFinished(outcome)
+	}()
+
+	// Load the shared keyword watchlist first, so an unreachable list fails the run before any crawling
+	watchlist, fetchErr := s.loadWatchlist(ctx, j, src)
+
+	// Hand the keyword filters and target_matches to the source too, so paginated sources count in articles that pass them
+	if opts, err := source.ParseOptions(src.Config); err == nil {
+		ctx = source.WithKeywordFilter(ctx, append(opts.IncludeKeywords, watchlist.include...), append(opts.ExcludeKeywords, watchlist.exclude...), opts.Queries)
+		ctx = source.WithTargetMatches(ctx, opts.TargetMatches, opts.TargetRequests)
+	}
 
 	// Execute fetch with timeout
-	articles, comments, fetchErr := s.runSourceWithTimeout(ctx, src, limiter)
+	var articles []db.Article
+	var comments []db.Comment
+	if fetchErr == nil {
+		articles, comments, fetchErr = s.runSourceWithTimeout(ctx, src, limiter, logger)
+	}
 
 	// Handle fetch error
 	if fetchErr != nil {
@@ -317,7 +632,7 @@ func (s *Scheduler) runSingleSource(src *db.Source, limiter *rate.Limiter) error
 
 		// Update status back to idle
 		if err := s.updateSourceStatus(src.ID, "idle"); err != nil {
-			log.Printf("Failed to set source %s to idle: %v", src.ID, err)
+			logger.Error("Failed to set source to idle", "source_id", src.ID, "error", err)
 		}
 
 		return fetchErr
@@ -328,19 +643,41 @@ func (s *Scheduler) runSingleSource(src *db.Source, limiter *rate.Limiter) error
 		articles[i].ProfileID = src.ProfileID
 	}
 
+	// Apply per-source keyword filters and dedup key, then propagate user-defined source tags onto every article
+	dedupStrategy := ""
+	if opts, err := source.ParseOptions(src.Config); err != nil {
+		logger.Warn("Ignoring invalid source options", "source_id", src.ID, "error", err)
+	} else {
+		fetchedArticles := articles
+		include := append(opts.IncludeKeywords, watchlist.include...)
+		exclude := append(opts.ExcludeKeywords, watchlist.exclude...)
+		articles, comments = source.FilterKeywords(articles, comments, include, exclude, opts.Queries)
+		if dropped := len(fetchedArticles) - len(articles); dropped > 0 {
+			logger.Info("Filtered articles by keyword", "source_id", src.ID, "dropped", dropped, "kept", len(articles))
+		}
+		if len(fetchedArticles) > 0 && len(articles) == 0 {
+			// Query keywords count as include keywords here, so near misses still show their overlap
+			filterDiagnostic = s.filterDiagnostic(j, src, fetchedArticles, slices.Concat(include, source.QueryKeywords(opts.Queries)), exclude, logger)
+		}
+		dedupStrategy = opts.DedupKey
+		source.ApplyTags(articles, opts.Tags)
+	}
+	// Also gives every article its UID, the key the API, uploads and the search index share
+	articles, comments = source.ApplyDedupKey(src.Type, articles, comments, dedupStrategy)
+
 	// Store results in per-source atomic transaction
 	tx, err := s.db.Begin()
 	if err != nil {
 		s.recordError(src.ID, fmt.Errorf("failed to begin transaction: %w", err))
 
 		if err := s.updateSourceStatus(src.ID, "idle"); err != nil {
-			log.Printf("Failed to set source %s to idle: %v", src.ID, err)
+			logger.Error("Failed to set source to idle", "source_id", src.ID, "error", err)
 		}
 		return fmt.Errorf("failed to begin transaction: %w", err)
 	}
 	defer func() {
 		if err := tx.Rollback(); err != nil && err != sql.ErrTxDone {
-			slog.Error("Failed to rollback transaction", "source_id", src.ID, "error", err)
+			logger.Error("Failed to rollback transaction", "source_id", src.ID, "error", err)
 		}
 	}()
 
@@ -349,7 +686,7 @@ func (s *Scheduler) runSingleSource(src *db.Source, limiter *rate.Limiter) error
 		s.recordError(src.ID, fmt.Errorf("failed to store articles: %w", err))
 
 		if err := s.updateSourceStatus(src.ID, "idle"); err != nil {
-			log.Printf("Failed to set source %s to idle: %v", src.ID, err)
+			logger.Error("Failed to set source to idle", "source_id", src.ID, "error", err)
 		}
 		return fmt.Errorf("failed to store articles: %w", err)
 	}
@@ -359,7 +696,7 @@ func (s *Scheduler) runSingleSource(src *db.Source, limiter *rate.Limiter) error
 		s.recordError(src.ID, fmt.Errorf("failed to store comments: %w", err))
 
 		if err := s.updateSourceStatus(src.ID, "idle"); err != nil {
-			log.Printf("Failed to set source %s to idle: %v", src.ID, err)
+			logger.Error("Failed to set source to idle", "source_id", src.ID, "error", err)
 		}
 		return fmt.Errorf("failed to store comments: %w", err)
 	}
@@ -369,16 +706,39 @@ func (s *Scheduler) runSingleSource(src *db.Source, limiter *rate.Limiter) error
 		s.recordError(src.ID, fmt.Errorf("failed to commit transaction: %w", err))
 
 		if err := s.updateSourceStatus(src.ID, "idle"); err != nil {
-			log.Printf("Failed to set source %s to idle: %v", src.ID, err)
+			logger.Error("Failed to set source to idle", "source_id", src.ID, "error", err)
 		}
 		return fmt.Errorf("failed to commit transaction: %w", err)
 	}
 
 	// Enqueue articles for async curation (non-blocking, best-effort)
 	if s.curationService != nil && len(articles) > 0 {
-		s.curationService.EnqueueArticles(src.ProfileID, articles)
+		if !s.curationService.EnqueueArticles(src.ProfileID, articles) {
+			j.status.degrade(degradation{
+				kind:       "curation_queue_full",
+				sourceID:   src.ID,
+				sourceType: src.Type,
+				items:      len(articles),
+				reason:     "curation queue full, articles stored uncurated",
+			})
+		}
 	}
 
+	// Articles stored without some of their data (comments, scores) are kept, but listed in the report
+	if n := source.EnrichmentFailures(articles); n > 0 {
+		j.status.degrade(degradation{
+			kind:       "enrichment_failed",
+			sourceID:   src.ID,
+			sourceType: src.Type,
+			items:      n,
+			reason:     "articles stored without some of their data, see their enrichment_errors",
+		})
+	}
+
+	// Copy the stored results to object storage and the search index (background, best-effort)
+	s.uploader.upload(j.name, runID, src, articles, comments)
+	s.indexer.index(j.name, runID, src, articles)
+
 	// Transaction successful - update timestamps
 	now := time.Now()
 	_, err = s.db.Exec(
@@ -386,19 +746,23 @@ func (s *Scheduler) runSingleSource(src *db.Source, limiter *rate.Limiter) error
 		now, now, "idle", src.ID,
 	)
 	if err != nil {
-		log.Printf("Source %s: failed to update timestamps: %v", src.ID, err)
+		logger.Error("Failed to update source timestamps", "source_id", src.ID, "error", err)
 		// Don't treat this as a critical error - data was stored successfully
 	}
 
-	log.Printf("Source %s completed successfully: %d articles, %d comments inserted", src.ID, len(articles), len(comments))
+	storedArticles, storedComments = len(articles), len(comments)
+	if s.config.Schedule.ReportTopAuthors > 0 {
+		storedAuthors = tallyAuthors(articles)
+	}
+	logger.Info("Source completed successfully", "source_id", src.ID, "articles", len(articles), "comments", len(comments))
 	return nil
 }
 
 // runSourceWithTimeout executes a single source fetch with timeout
 // Returns fetched articles and comments for centralized storage
-func (s *Scheduler) runSourceWithTimeout(ctx context.Context, src *db.Source, limiter *rate.Limiter) ([]db.Article, []db.Comment, error) {
+func (s *Scheduler) runSourceWithTimeout(ctx context.Context, src *db.Source, limiter *rate.Limiter, logger *slog.Logger) ([]db.Article, []db.Comment, error) {
 	// Create source instance with credentials from config file
-	sourceImpl, err := source.Factory(src, &s.config.Credentials, limiter, s.config.Server.MaxCommentDepth)
+	sourceImpl, err := source.Factory(src, &s.config.Credentials, limiter, s.httpClient, s.config.Server.MaxCommentDepth)
 	if err != nil {
 		return nil, nil, fmt.Errorf("failed to create source: %w", err)
 	}
@@ -408,17 +772,36 @@ func (s *Scheduler) runSourceWithTimeout(ctx context.Context, src *db.Source, li
 	if src.LastSuccessAt != nil {
 		since = *src.LastSuccessAt
 	}
+	if opts, err := source.ParseOptions(src.Config); err == nil {
+		since = source.EffectiveSince(since, opts.MaxItemAgeHours)
+	}
+
+	// Reuse a recent fetch of an identical config if one covers this window
+	cacheKey := fetchFingerprint(src.Type, src.Config)
+	if articles, comments, ok := s.fetchCache.get(cacheKey, since, src); ok {
+		logger.Info("Source served from fetch cache", "source_id", src.ID, "articles", len(articles), "comments", len(comments))
+		return articles, comments, nil
+	}
 
 	// Fetch articles and comments
+	fetchedAt := time.Now()
 	articles, comments, err := sourceImpl.Fetch(ctx, since)
 	if err != nil {
 		return nil, nil, fmt.Errorf("fetch failed: %w", err)
 	}
+	source.StampFetchTime(articles, fetchedAt)
+	s.fetchCache.put(cacheKey, since, articles, comments)
 
-	log.Printf("Source %s fetched: %d articles, %d comments", src.ID, len(articles), len(comments))
+	logger.Info("Source fetched", "source_id", src.ID, "articles", len(articles), "comments", len(comments))
 	return articles, comments, nil
 }
 
+// newRunLogger returns a logger tagged with the run's ID and the job name
+// Every log line of one crawl run carries the same ID so runs can be correlated
+func newRunLogger(j *job, runID string) *slog.Logger {
+	return slog.With("run_id", runID, "job", j.name)
+}
+
 // createRateLimiters creates rate limiters for each source type from config file
 func (s *Scheduler) createRateLimiters() map[string]*rate.Limiter {
 	limiters := make(map[string]*rate.Limiter)
@@ -435,6 +818,89 @@ func (s *Scheduler) createRateLimiters() map[string]*rate.Limiter {
 	hnReqPerSec := 1000.0 / float64(s.config.RateLimits.HackerNewsDelayMs)
 	limiters["hackernews"] = rate.NewLimiter(rate.Limit(hnReqPerSec), 10)
 
+	// GitHub rate limiter (burst=10)
+	ghReqPerSec := 1000.0 / float64(s.config.RateLimits.GitHubDelayMs)
+	limiters["github"] = rate.NewLimiter(rate.Limit(ghReqPerSec), 10)
+
+	// Stack Exchange rate limiter (burst=10)
+	seReqPerSec := 1000.0 / float64(s.config.RateLimits.StackExchangeDelayMs)
+	limiters["stackexchange"] = rate.NewLimiter(rate.Limit(seReqPerSec), 10)
+
+	// Bluesky rate limiter (burst=10)
+	bskyReqPerSec := 1000.0 / float64(s.config.RateLimits.BlueskyDelayMs)
+	limiters["bluesky"] = rate.NewLimiter(rate.Limit(bskyReqPerSec), 10)
+
+	// OpenAlex rate limiter (burst=10)
+	oaReqPerSec := 1000.0 / float64(s.config.RateLimits.OpenAlexDelayMs)
+	limiters["openalex"] = rate.NewLimiter(rate.Limit(oaReqPerSec), 10)
+
+	// PubMed rate limiter (burst=10)
+	pmReqPerSec := 1000.0 / float64(s.config.RateLimits.PubMedDelayMs)
+	limiters["pubmed"] = rate.NewLimiter(rate.Limit(pmReqPerSec), 10)
+
+	// arXiv rate limiter (burst=1, arXiv asks for one request every 3 seconds)
+	arxivReqPerSec := 1000.0 / float64(s.config.RateLimits.ArxivDelayMs)
+	limiters["arxiv"] = rate.NewLimiter(rate.Limit(arxivReqPerSec), 1)
+
+	// Discourse rate limiter (burst=10)
+	discourseReqPerSec := 1000.0 / float64(s.config.RateLimits.DiscourseDelayMs)
+	limiters["discourse"] = rate.NewLimiter(rate.Limit(discourseReqPerSec), 10)
+
+	// Nitter rate limiter (burst=10)
+	nitterReqPerSec := 1000.0 / float64(s.config.RateLimits.NitterDelayMs)
+	limiters["nitter"] = rate.NewLimiter(rate.Limit(nitterReqPerSec), 10)
+
+	// Google Scholar rate limiter (burst=10)
+	gsReqPerSec := 1000.0 / float64(s.config.RateLimits.GoogleScholarDelayMs)
+	limiters["google_scholar"] = rate.NewLimiter(rate.Limit(gsReqPerSec), 10)
+
+	// Web rate limiter (burst=1, arbitrary sites get no request bursts)
+	webReqPerSec := 1000.0 / float64(s.config.RateLimits.WebDelayMs)
+	limiters["web"] = rate.NewLimiter(rate.Limit(webReqPerSec), 1)
+
+	// Generic JSON API rate limiter (burst=10)
+	jsonAPIReqPerSec := 1000.0 / float64(s.config.RateLimits.JSONAPIDelayMs)
+	limiters["json_api"] = rate.NewLimiter(rate.Limit(jsonAPIReqPerSec), 10)
+
+	// News rate limiter (burst=1, GDELT asks for one request every 5 seconds)
+	newsReqPerSec := 1000.0 / float64(s.config.RateLimits.NewsDelayMs)
+	limiters["news"] = rate.NewLimiter(rate.Limit(newsReqPerSec), 1)
+
+	// Wikipedia rate limiter (burst=10)
+	wikipediaReqPerSec := 1000.0 / float64(s.config.RateLimits.WikipediaDelayMs)
+	limiters["wikipedia"] = rate.NewLimiter(rate.Limit(wikipediaReqPerSec), 10)
+
+	// Kaggle rate limiter (burst=10)
+	kaggleReqPerSec := 1000.0 / float64(s.config.RateLimits.KaggleDelayMs)
+	limiters["kaggle"] = rate.NewLimiter(rate.Limit(kaggleReqPerSec), 10)
+
+	// Podcast Index rate limiter (burst=10)
+	podcastReqPerSec := 1000.0 / float64(s.config.RateLimits.PodcastDelayMs)
+	limiters["podcast"] = rate.NewLimiter(rate.Limit(podcastReqPerSec), 10)
+
+	// Hugging Face rate limiter (burst=10)
+	huggingFaceReqPerSec := 1000.0 / float64(s.config.RateLimits.HuggingFaceDelayMs)
+	limiters["huggingface"] = rate.NewLimiter(rate.Limit(huggingFaceReqPerSec), 10)
+
+	// Package registry rate limiter (burst=10)
+	packageRegistryReqPerSec := 1000.0 / float64(s.config.RateLimits.PackageRegistryDelayMs)
+	limiters["package_registry"] = rate.NewLimiter(rate.Limit(packageRegistryReqPerSec), 10)
+
+	// IMAP rate limiter (burst=10)
+	imapReqPerSec := 1000.0 / float64(s.config.RateLimits.ImapDelayMs)
+	limiters["imap"] = rate.NewLimiter(rate.Limit(imapReqPerSec), 10)
+
+	// File sources make no network requests, so they are never throttled
+	limiters["file"] = rate.NewLimiter(rate.Inf, 1)
+
+	// Feed rate limiter (burst=10)
+	feedReqPerSec := 1000.0 / float64(s.config.RateLimits.FeedDelayMs)
+	limiters["feed"] = rate.NewLimiter(rate.Limit(feedReqPerSec), 10)
+
+	// Zenodo rate limiter (burst=10)
+	zenodoReqPerSec := 1000.0 / float64(s.config.RateLimits.ZenodoDelayMs)
+	limiters["zenodo"] = rate.NewLimiter(rate.Limit(zenodoReqPerSec), 10)
+
 	return limiters
 }
 
@@ -444,9 +910,37 @@ func (s *Scheduler) groupSourcesByType(sources []*db.Source) map[string][]*db.So
 	for _, src := range sources {
 		groups[src.Type] = append(groups[src.Type], src)
 	}
+	for _, group := range groups {
+		sortByPriority(group)
+	}
 	return groups
 }
 
+// sortByPriority orders sources by their priority option, highest first
+// Sources with invalid options count as priority 0
+func sortByPriority(sources []*db.Source) {
+	priorities := make(map[string]int, len(sources))
+	for _, src := range sources {
+		if opts, err := source.ParseOptions(src.Config); err == nil {
+			priorities[src.ID] = opts.Priority
+		}
+	}
+	sort.SliceStable(sources, func(i, j int) bool {
+		return priorities[sources[i].ID] > priorities[sources[j].ID]
+	})
+}
+
+// runLimitReason returns why no further sources may start in this run, or "" while within the limits
+func (s *Scheduler) runLimitReason(j *job, deadline time.Time) string {
+	if !deadline.IsZero() && time.Now().After(deadline) {
+		return "run deadline reached"
+	}
+	if max := s.config.Schedule.RunMaxRequests; max > 0 && j.status.requests.Load() >= int64(max) {
+		return "run request budget exhausted"
+	}
+	return ""
+}
+
 // getAllSources fetches all sources from the database
 func (s *Scheduler) getAllSources() ([]*db.Source, error) {
 	rows, err := s.db.Query("SELECT id, type, config, external_id, profile_id, last_run_at, last_success_at, last_error, status, created_at FROM sources")
@@ -604,20 +1098,33 @@ func (s *Scheduler) storeCommentsInTx(tx *sql.Tx, comments []db.Comment) error {
 	return nil
 }
 
-// GetSchedule returns the global schedule information from config file
-func (s *Scheduler) GetSchedule() (*db.ScheduleEntry, error) {
+// GetSchedule returns the schedule of the named job ("" for the default job) from config file
+func (s *Scheduler) GetSchedule(name string) (*db.ScheduleEntry, error) {
+	j, err := s.job(name)
+	if err != nil {
+		return nil, err
+	}
+
 	// Parse cron expression from config
-	sched, err := cron.ParseStandard(s.config.Schedule.CronExpr)
+	sched, err := cron.ParseStandard(j.cronExpr)
 	if err != nil {
 		return nil, fmt.Errorf("invalid cron expression: %w", err)
 	}
 
-	// Get the latest last_run_at from all sources
-	var lastRunAt sql.NullTime
-	err = s.db.QueryRow("SELECT MAX(last_run_at) FROM sources").Scan(&lastRunAt)
-	if err != nil && err != sql.ErrNoRows {
+	// Get the latest last_run_at of the sources the job's patterns select, so other jobs' runs don't shift its schedule
+	sources, err := s.getAllSources()
+	if err != nil {
 		return nil, fmt.Errorf("failed to get last run time: %w", err)
 	}
+	var lastRunAt sql.NullTime
+	for _, src := range sources {
+		if ok, _ := j.filter.Allows(src.Type, src.ExternalID); !ok || src.LastRunAt == nil {
+			continue
+		}
+		if !lastRunAt.Valid || src.LastRunAt.After(lastRunAt.Time) {
+			lastRunAt = sql.NullTime{Time: *src.LastRunAt, Valid: true}
+		}
+	}
 
 	// Calculate next run
 	var nextRun time.Time
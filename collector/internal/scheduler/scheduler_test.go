@@ -0,0 +1,114 @@
+package scheduler
+
+import (
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestSortByPriority(t *testing.T) {
+	sources := []*db.Source{
+		{ID: "default", Config: []byte(`{"subreddit":"golang"}`)},
+		{ID: "high", Config: []byte(`{"subreddit":"rust","priority":10}`)},
+		{ID: "low", Config: []byte(`{"subreddit":"go","priority":-5}`)},
+		{ID: "invalid", Config: []byte(`{"priority":1,"max_item_age_hours":-1}`)}, // Invalid options count as 0
+	}
+
+	sortByPriority(sources)
+
+	want := []string{"high", "default", "invalid", "low"}
+	for i, src := range sources {
+		if src.ID != want[i] {
+			t.Fatalf("Expected order %v, got %s at %d", want, src.ID, i)
+		}
+	}
+}
+
+func TestRunLimitReason(t *testing.T) {
+	s := &Scheduler{config: &config.CollectorConfig{Schedule: config.ScheduleConfig{RunMaxRequests: 2}}}
+	j := &job{name: defaultJobName, status: newRunStatus()}
+
+	if reason := s.runLimitReason(j, time.Time{}); reason != "" {
+		t.Errorf("Expected no limit yet, got %q", reason)
+	}
+	if reason := s.runLimitReason(j, time.Now().Add(-time.Second)); reason != "run deadline reached" {
+		t.Errorf("Expected deadline, got %q", reason)
+	}
+
+	j.status.requests.Store(2)
+	if reason := s.runLimitReason(j, time.Time{}); reason != "run request budget exhausted" {
+		t.Errorf("Expected request budget, got %q", reason)
+	}
+}
+
+func TestNewJobs(t *testing.T) {
+	cfg := &config.ScheduleConfig{
+		CronExpr:    "0 */6 * * *",
+		SkipSources: []string{"reddit"},
+		Jobs: []config.JobConfig{
+			{Name: "papers", CronExpr: "0 7 * * *", OnlySources: []string{"arxiv", "semantic_scholar"}},
+		},
+	}
+
+	jobs, err := newJobs(cfg, config.WebhookConfig{})
+	if err != nil {
+		t.Fatalf("newJobs failed: %v", err)
+	}
+	if len(jobs) != 2 || jobs[0].name != defaultJobName || jobs[1].name != "papers" {
+		t.Fatalf("Expected the default job then papers, got %+v", jobs)
+	}
+	if jobs[0].status == jobs[1].status {
+		t.Error("Expected every job to keep its own run status")
+	}
+	if ok, _ := jobs[1].filter.Allows("arxiv", "cs.LG"); !ok {
+		t.Error("Expected papers to crawl arxiv")
+	}
+	if ok, _ := jobs[1].filter.Allows("reddit", "golang"); ok {
+		t.Error("Expected papers to leave reddit to the default job")
+	}
+
+	cfg.Jobs[0].SkipSources = []string{"redit"}
+	if _, err := newJobs(cfg, config.WebhookConfig{}); err == nil {
+		t.Error("Expected an invalid job source pattern to be rejected")
+	}
+}
+
+func TestBeginCycle(t *testing.T) {
+	s := &Scheduler{}
+
+	if !s.beginCycle() {
+		t.Fatalf("Expected the first run to open a cycle")
+	}
+	if s.beginCycle() {
+		t.Errorf("Expected an overlapping run to join the open cycle")
+	}
+	s.endCycle()
+	s.endCycle()
+	if !s.beginCycle() {
+		t.Errorf("Expected a run after the others ended to open a new cycle")
+	}
+}
+
+func TestClaimSource(t *testing.T) {
+	s := &Scheduler{inFlight: make(map[string]string)}
+	a, b := &job{name: "a"}, &job{name: "b"}
+
+	if other := s.claimSource(a, "src"); other != "" {
+		t.Fatalf("Expected the claim to succeed, got %q", other)
+	}
+	if other := s.claimSource(b, "src"); other != `job "a"` {
+		t.Errorf("Expected job a to hold the source, got %q", other)
+	}
+	if other := s.claimSource(nil, "src"); other != `job "a"` {
+		t.Errorf("Expected a manual trigger to be refused, got %q", other)
+	}
+	s.releaseSource("src")
+	if other := s.claimSource(nil, "src"); other != "" {
+		t.Errorf("Expected the released source to be claimable, got %q", other)
+	}
+	if other := s.claimSource(b, "src"); other != "a manual trigger" {
+		t.Errorf("Expected the manual trigger to hold the source, got %q", other)
+	}
+}
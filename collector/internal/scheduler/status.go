@@ -0,0 +1,338 @@
+package scheduler
+
+import (
+	"fmt"
+	"log/slog"
+	"sort"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/cheolwanpark/meows/collector/internal/source"
+)
+
+// runStatus tracks crawl progress in memory so it can be dumped on demand (SIGUSR1)
+// Counters cover the current global run plus any manual triggers since it started
+type runStatus struct {
+	requests atomic.Int64 // Outbound HTTP requests of the job's crawls, counted by the shared client
+
+	mu              sync.Mutex
+	runID           string // ID of the current run, shared by its log lines, report, webhooks, uploads and index documents
+	curationSkipped int    // Curation budget skips already counted when the run started
+	startedAt       time.Time
+	running         map[string]runningSource // Source ID -> crawl in flight
+	succeeded       int
+	failed          int
+	articles        int
+	comments        int
+	degraded        []degradation   // Everything the current run skipped or dropped, in order
+	outcomes        []SourceOutcome // One per source crawled or skipped, in completion order
+	finishedAt      *time.Time      // Set when the global run completes
+}
+
+// SourceOutcome is the typed result of one source crawl
+type SourceOutcome struct {
+	SourceID   string              `json:"source_id"`
+	SourceType string              `json:"source_type"`
+	SourceKey  string              `json:"source_key"` // "type:external_id", as matched by source patterns
+	RunID      string              `json:"run_id"`     // The run that crawled the source; manual triggers have their own
+	Status     string              `json:"status"`     // "succeeded", "failed" or "skipped"
+	Articles   int                 `json:"articles"`
+	Comments   int                 `json:"comments"`
+	StartedAt  time.Time           `json:"started_at"`
+	DurationMs int64               `json:"duration_ms"`
+	HTTP       source.RequestStats `json:"http"`
+	Error      string              `json:"error,omitempty"`             // Failure or skip reason
+	Filter     *FilterDiagnostic   `json:"filter_diagnostic,omitempty"` // Set when keyword filters dropped every fetched article
+	Authors    *AuthorReport       `json:"authors,omitempty"`           // Set when COLLECTOR_REPORT_TOP_AUTHORS is
+	authors    []AuthorStat                                             // Every author of the stored articles, ranked into Authors by RunReport
+}
+
+// nearMissCount is how many dropped articles a filter diagnostic lists
+const nearMissCount = 3
+
+// FilterDiagnostic explains a source whose keyword filters dropped everything it fetched
+type FilterDiagnostic struct {
+	Fetched    int                      `json:"fetched"`
+	Include    []string                 `json:"include_keywords,omitempty"` // Source, watchlist and query keywords combined
+	Exclude    []string                 `json:"exclude_keywords,omitempty"`
+	NearMisses []source.KeywordNearMiss `json:"near_misses"` // Closest dropped articles, best first
+}
+
+// RunReport lists the per-source outcomes of the current or most recent global run
+// Manual triggers since the run started are included, like the status dump counters
+type RunReport struct {
+	Job        string          `json:"job"`
+	RunID      string          `json:"run_id"`
+	StartedAt  time.Time       `json:"started_at"`
+	FinishedAt *time.Time      `json:"finished_at,omitempty"` // Nil while the run is in progress
+	Outcomes   []SourceOutcome `json:"outcomes"`
+	Authors    *AuthorReport   `json:"authors,omitempty"` // Across every source of the run, set when COLLECTOR_REPORT_TOP_AUTHORS is
+}
+
+// degradation records one thing a run skipped or dropped and why
+type degradation struct {
+	kind       string // source_failed, run_limit, stale_watchlist, filtered_out, curation_queue_full, curation_over_budget, enrichment_failed or mirror_switched
+	sourceID   string
+	sourceType string
+	items      int // Articles affected, 0 when unknown
+	reason     string
+}
+
+// runningSource describes one source crawl in flight
+type runningSource struct {
+	sourceType string
+	startedAt  time.Time
+}
+
+func newRunStatus() *runStatus {
+	return &runStatus{
+		startedAt: time.Now(),
+		running:   make(map[string]runningSource),
+	}
+}
+
+// reset clears the counters at the start of a global run
+func (r *runStatus) reset(runID string) {
+	r.requests.Store(0)
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.runID = runID
+	r.startedAt = time.Now()
+	r.succeeded, r.failed, r.articles, r.comments = 0, 0, 0, 0
+	r.degraded = nil
+	r.outcomes = nil
+	r.finishedAt = nil
+}
+
+// setCurationBaseline records the shared curation budget's skip count at the start of the run,
+// so the run's degradation report counts only the skips made since
+func (r *runStatus) setCurationBaseline(skipped int) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.curationSkipped = skipped
+}
+
+// complete marks the global run as finished
+func (r *runStatus) complete() {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	now := time.Now()
+	r.finishedAt = &now
+}
+
+// skip records a source that sat the run out
+func (r *runStatus) skip(src *db.Source, reason string) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.outcomes = append(r.outcomes, SourceOutcome{
+		SourceID:   src.ID,
+		SourceType: src.Type,
+		SourceKey:  source.SourceKey(src.Type, src.ExternalID),
+		RunID:      r.runID,
+		Status:     "skipped",
+		StartedAt:  time.Now(),
+		Error:      reason,
+	})
+}
+
+// degrade adds an entry to the run's degradation report
+func (r *runStatus) degrade(d degradation) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.degraded = append(r.degraded, d)
+}
+
+// start marks a source as in flight
+func (r *runStatus) start(src *db.Source) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.running[src.ID] = runningSource{sourceType: src.Type, startedAt: time.Now()}
+}
+
+// finish records and returns the outcome of a source crawl made by the given run
+// filter is non-nil when keyword filters dropped every fetched article
+func (r *runStatus) finish(runID string, src *db.Source, articles, comments int, authors []AuthorStat, stats source.RequestStats, filter *FilterDiagnostic, err error) SourceOutcome {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	startedAt := r.running[src.ID].startedAt
+	delete(r.running, src.ID)
+
+	outcome := SourceOutcome{
+		SourceID:   src.ID,
+		SourceType: src.Type,
+		SourceKey:  source.SourceKey(src.Type, src.ExternalID),
+		RunID:      runID,
+		Status:     "succeeded",
+		Articles:   articles,
+		Comments:   comments,
+		StartedAt:  startedAt,
+		DurationMs: time.Since(startedAt).Milliseconds(),
+		HTTP:       stats,
+		Filter:     filter,
+		authors:    authors,
+	}
+	if err != nil {
+		r.failed++
+		r.degraded = append(r.degraded, degradation{kind: "source_failed", sourceID: src.ID, sourceType: src.Type, reason: err.Error()})
+		outcome.Status, outcome.Articles, outcome.Comments, outcome.Error = "failed", 0, 0, err.Error()
+		r.outcomes = append(r.outcomes, outcome)
+		return outcome
+	}
+	r.succeeded++
+	r.articles += articles
+	r.comments += comments
+	r.outcomes = append(r.outcomes, outcome)
+	return outcome
+}
+
+// RunReport returns the per-source outcomes of the named job's current or most recent run ("" for the default job)
+func (s *Scheduler) RunReport(name string) (RunReport, error) {
+	j, err := s.job(name)
+	if err != nil {
+		return RunReport{}, err
+	}
+	r := j.status
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	report := RunReport{
+		Job:        j.name,
+		RunID:      r.runID,
+		StartedAt:  r.startedAt,
+		FinishedAt: r.finishedAt,
+		Outcomes:   append([]SourceOutcome{}, r.outcomes...),
+	}
+
+	if n := s.config.Schedule.ReportTopAuthors; n > 0 {
+		tallies := make([][]AuthorStat, 0, len(report.Outcomes))
+		for i := range report.Outcomes {
+			report.Outcomes[i].Authors = newAuthorReport(report.Outcomes[i].authors, n)
+			tallies = append(tallies, report.Outcomes[i].authors)
+		}
+		report.Authors = newAuthorReport(mergeAuthors(tallies...), n)
+	}
+
+	return report, nil
+}
+
+// logOutcomes logs one structured line per source at the end of a run
+func (s *Scheduler) logOutcomes(j *job, logger *slog.Logger) {
+	report, _ := s.RunReport(j.name)
+	for _, o := range report.Outcomes {
+		attrs := []any{
+			"source_id", o.SourceID,
+			"source", o.SourceKey,
+			"type", o.SourceType,
+			"status", o.Status,
+			"articles", o.Articles,
+			"comments", o.Comments,
+			"duration_ms", o.DurationMs,
+			"requests", o.HTTP.Requests,
+			"retries", o.HTTP.Retries,
+			"rate_limited", o.HTTP.RateLimited,
+		}
+		if o.Error != "" {
+			attrs = append(attrs, "error", o.Error)
+		}
+		logger.Info("Source outcome", attrs...)
+	}
+}
+
+// LogStatus logs a snapshot of every job's crawl progress without interrupting running crawls
+func (s *Scheduler) LogStatus() {
+	for _, j := range s.jobs {
+		s.logJobStatus(j)
+	}
+
+	if s.curationService != nil {
+		usage := s.curationService.Usage()
+		slog.Info("Curation spend",
+			"requests", usage.Requests,
+			"tokens", usage.Tokens,
+			"skipped_over_budget", usage.Skipped,
+			"estimated_cost_usd", fmt.Sprintf("%.4f", usage.EstimatedCostUSD))
+	}
+}
+
+// logJobStatus logs one job's progress, degradation entries and sources in flight
+func (s *Scheduler) logJobStatus(j *job) {
+	report := s.degradationReport(j) // Takes r.mu itself
+	globalRunning := j.running()
+	r := j.status
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	slog.Info("Crawl status",
+		"job", j.name,
+		"run_id", r.runID,
+		"global_run_active", globalRunning,
+		"since", r.startedAt.Format(time.RFC3339),
+		"in_flight", len(r.running),
+		"succeeded", r.succeeded,
+		"failed", r.failed,
+		"articles_stored", r.articles,
+		"comments_stored", r.comments,
+		"http_requests", r.requests.Load())
+
+	for _, d := range report {
+		slog.Warn("Degraded", append([]any{"job", j.name}, d.attrs()...)...)
+	}
+
+	ids := make([]string, 0, len(r.running))
+	for id := range r.running {
+		ids = append(ids, id)
+	}
+	sort.Strings(ids)
+	for _, id := range ids {
+		src := r.running[id]
+		slog.Info("Source in flight",
+			"job", j.name,
+			"source_id", id,
+			"type", src.sourceType,
+			"elapsed", time.Since(src.startedAt).Round(time.Second).String())
+	}
+}
+
+// degradationReport returns the current run's degradation entries, adding curation articles skipped over budget since it started
+// Curation runs asynchronously, so the over-budget count covers only what the workers have processed so far
+func (s *Scheduler) degradationReport(j *job) []degradation {
+	j.status.mu.Lock()
+	report := append([]degradation(nil), j.status.degraded...)
+	baseline := j.status.curationSkipped
+	j.status.mu.Unlock()
+
+	if s.curationService != nil {
+		if skipped := s.curationService.Usage().Skipped - baseline; skipped > 0 {
+			report = append(report, degradation{kind: "curation_over_budget", items: skipped, reason: "curation budget exhausted"})
+		}
+	}
+	return report
+}
+
+// logDegradationReport logs one structured line per skipped or dropped item group at the end of a run
+func (s *Scheduler) logDegradationReport(j *job, logger *slog.Logger) {
+	report := s.degradationReport(j)
+	if len(report) == 0 {
+		logger.Info("Degradation report: nothing skipped")
+		return
+	}
+
+	logger.Warn("Degradation report", "entries", len(report))
+	for _, d := range report {
+		logger.Warn("Degraded", d.attrs()...)
+	}
+}
+
+// attrs renders the entry as slog key-value pairs, leaving out empty fields
+func (d degradation) attrs() []any {
+	attrs := []any{"kind", d.kind}
+	if d.sourceID != "" {
+		attrs = append(attrs, "source_id", d.sourceID, "type", d.sourceType)
+	}
+	if d.items > 0 {
+		attrs = append(attrs, "items", d.items)
+	}
+	return append(attrs, "reason", d.reason)
+}
@@ -0,0 +1,111 @@
+package scheduler
+
+import (
+	"context"
+	"encoding/json"
+	"log/slog"
+	"net/http"
+	"sync"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/cheolwanpark/meows/collector/internal/objectstore"
+	"github.com/cheolwanpark/meows/collector/internal/source"
+)
+
+// uploadTimeout bounds each upload, so a slow bucket never holds up shutdown for long
+const uploadTimeout = 60 * time.Second
+
+// resultUploader copies what each source run stored to object storage
+// A nil uploader (COLLECTOR_S3_URL unset) uploads nothing
+type resultUploader struct {
+	client *objectstore.Client
+	wg     sync.WaitGroup // Uploads in flight
+}
+
+// uploadPayload is the JSON body of every uploaded object
+type uploadPayload struct {
+	Job        string       `json:"job"`
+	RunID      string       `json:"run_id"`
+	SourceID   string       `json:"source_id"`
+	SourceType string       `json:"source_type"`
+	SourceKey  string       `json:"source_key"`
+	UploadedAt time.Time    `json:"uploaded_at"`
+	Articles   []db.Article `json:"articles"`
+	Comments   []db.Comment `json:"comments"`
+}
+
+func newResultUploader(cfg config.ObjectStoreConfig) (*resultUploader, error) {
+	if cfg.URL == "" {
+		return nil, nil
+	}
+	// Separate from the sources' client, so uploads don't count as crawl requests
+	client, err := objectstore.NewClient(cfg, &http.Client{Timeout: uploadTimeout})
+	if err != nil {
+		return nil, err
+	}
+	return &resultUploader{client: client}, nil
+}
+
+// upload writes one object with the articles and comments a source run stored, in the background
+// Failures are logged, not retried; the database stays the source of truth
+func (u *resultUploader) upload(job, runID string, src *db.Source, articles []db.Article, comments []db.Comment) {
+	if u == nil || len(articles) == 0 {
+		return
+	}
+
+	now := time.Now().UTC()
+	payload := uploadPayload{
+		Job:        job,
+		RunID:      runID,
+		SourceID:   src.ID,
+		SourceType: src.Type,
+		SourceKey:  source.SourceKey(src.Type, src.ExternalID),
+		UploadedAt: now,
+		Articles:   articles,
+		Comments:   comments,
+	}
+	body, err := json.Marshal(payload)
+	if err != nil {
+		slog.Error("Failed to encode upload", "source_id", src.ID, "error", err)
+		return
+	}
+	key := u.client.Key(map[string]string{
+		"date":      now.Format("2006-01-02"),
+		"time":      now.Format("150405"),
+		"job":       job,
+		"run_id":    runID,
+		"source":    payload.SourceKey,
+		"type":      src.Type,
+		"source_id": src.ID,
+	})
+
+	u.wg.Add(1)
+	go func() {
+		defer u.wg.Done()
+		ctx, cancel := context.WithTimeout(context.Background(), uploadTimeout)
+		defer cancel()
+		if err := u.client.Put(ctx, key, body, "application/json"); err != nil {
+			slog.Warn("Upload to object storage failed", "source_id", src.ID, "key", key, "error", err)
+			return
+		}
+		slog.Debug("Uploaded source results", "source_id", src.ID, "key", key, "articles", len(articles))
+	}()
+}
+
+// wait blocks until uploads in flight finish or ctx is done
+func (u *resultUploader) wait(ctx context.Context) {
+	if u == nil {
+		return
+	}
+	done := make(chan struct{})
+	go func() {
+		u.wg.Wait()
+		close(done)
+	}()
+	select {
+	case <-done:
+	case <-ctx.Done():
+	}
+}
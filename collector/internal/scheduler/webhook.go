@@ -0,0 +1,191 @@
+package scheduler
+
+import (
+	"bytes"
+	"context"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"io"
+	"log/slog"
+	"net/http"
+	"sync"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+)
+
+// webhookTimeout bounds each delivery, so a slow endpoint never holds up shutdown for long
+const webhookTimeout = 10 * time.Second
+
+// webhookNotifier posts lifecycle events to the configured endpoints
+// A nil notifier (no URLs configured) sends nothing
+type webhookNotifier struct {
+	job    string // Name of the job whose events this notifier sends
+	urls   []string
+	events map[string]bool
+	secret string
+	client *http.Client   // Separate from the sources' client, so deliveries don't count as crawl requests
+	wg     sync.WaitGroup // Deliveries in flight
+}
+
+// webhookPayload is the JSON body of every webhook
+type webhookPayload struct {
+	Event     string         `json:"event"`
+	Job       string         `json:"job"`
+	RunID     string         `json:"run_id,omitempty"`
+	Timestamp time.Time      `json:"timestamp"`
+	Outcome   *SourceOutcome `json:"outcome,omitempty"` // source_failed and source_empty
+	Report    *RunReport     `json:"report,omitempty"`  // run_completed
+	Summary   *runSummary    `json:"summary,omitempty"` // run_completed
+}
+
+// runSummary totals a run's outcomes
+type runSummary struct {
+	Succeeded int `json:"succeeded"`
+	Failed    int `json:"failed"`
+	Skipped   int `json:"skipped"`
+	Articles  int `json:"articles"`
+	Comments  int `json:"comments"`
+}
+
+func newWebhookNotifier(job string, cfg config.WebhookConfig) *webhookNotifier {
+	if len(cfg.URLs) == 0 {
+		return nil
+	}
+
+	events := make(map[string]bool)
+	subscribed := cfg.Events
+	if len(subscribed) == 0 {
+		subscribed = config.WebhookEvents
+	}
+	for _, event := range subscribed {
+		events[event] = true
+	}
+
+	return &webhookNotifier{
+		job:    job,
+		urls:   cfg.URLs,
+		events: events,
+		secret: cfg.Secret,
+		client: &http.Client{Timeout: webhookTimeout},
+	}
+}
+
+// runStarted announces the start of a global run
+func (n *webhookNotifier) runStarted(runID string, startedAt time.Time) {
+	n.send(webhookPayload{Event: "run_started", RunID: runID, Timestamp: startedAt})
+}
+
+// runCompleted announces the end of a global run with its report
+func (n *webhookNotifier) runCompleted(report RunReport) {
+	summary := summarize(report)
+	n.send(webhookPayload{Event: "run_completed", RunID: report.RunID, Timestamp: time.Now(), Report: &report, Summary: &summary})
+}
+
+// sourceFinished announces failed sources and sources that produced no articles
+func (n *webhookNotifier) sourceFinished(outcome SourceOutcome) {
+	switch {
+	case outcome.Status == "failed":
+		n.send(webhookPayload{Event: "source_failed", RunID: outcome.RunID, Timestamp: time.Now(), Outcome: &outcome})
+	case outcome.Articles == 0:
+		n.send(webhookPayload{Event: "source_empty", RunID: outcome.RunID, Timestamp: time.Now(), Outcome: &outcome})
+	}
+}
+
+// send delivers the payload to every endpoint in the background, if the event is subscribed
+func (n *webhookNotifier) send(payload webhookPayload) {
+	if n == nil || !n.events[payload.Event] {
+		return
+	}
+	payload.Job = n.job
+
+	body, err := json.Marshal(payload)
+	if err != nil {
+		slog.Error("Failed to encode webhook payload", "event", payload.Event, "error", err)
+		return
+	}
+
+	for _, endpoint := range n.urls {
+		n.wg.Add(1)
+		go func(endpoint string) {
+			defer n.wg.Done()
+			if err := n.post(endpoint, payload.Event, body); err != nil {
+				slog.Warn("Webhook delivery failed", "event", payload.Event, "url", endpoint, "error", err)
+			}
+		}(endpoint)
+	}
+}
+
+// post makes one delivery attempt; failures are logged, not retried
+func (n *webhookNotifier) post(endpoint, event string, body []byte) error {
+	ctx, cancel := context.WithTimeout(context.Background(), webhookTimeout)
+	defer cancel()
+
+	req, err := http.NewRequestWithContext(ctx, "POST", endpoint, bytes.NewReader(body))
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	req.Header.Set("X-Meows-Event", event)
+	req.Header.Set("X-Meows-Job", n.job)
+	if n.secret != "" {
+		req.Header.Set("X-Meows-Signature", "sha256="+webhookSignature(n.secret, body))
+	}
+
+	resp, err := n.client.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	io.Copy(io.Discard, io.LimitReader(resp.Body, 64<<10)) // Let the connection be reused
+
+	if resp.StatusCode >= 300 {
+		return fmt.Errorf("endpoint returned %d", resp.StatusCode)
+	}
+	return nil
+}
+
+// wait blocks until deliveries in flight finish or ctx is done
+func (n *webhookNotifier) wait(ctx context.Context) {
+	if n == nil {
+		return
+	}
+	done := make(chan struct{})
+	go func() {
+		n.wg.Wait()
+		close(done)
+	}()
+	select {
+	case <-done:
+	case <-ctx.Done():
+	}
+}
+
+// webhookSignature returns the hex HMAC-SHA256 of body, sent as X-Meows-Signature: sha256=<hex>
+func webhookSignature(secret string, body []byte) string {
+	mac := hmac.New(sha256.New, []byte(secret))
+	mac.Write(body)
+	return hex.EncodeToString(mac.Sum(nil))
+}
+
+// summarize totals a run report's outcomes
+func summarize(report RunReport) runSummary {
+	var summary runSummary
+	for _, outcome := range report.Outcomes {
+		switch outcome.Status {
+		case "succeeded":
+			summary.Succeeded++
+			summary.Articles += outcome.Articles
+			summary.Comments += outcome.Comments
+		case "failed":
+			summary.Failed++
+		case "skipped":
+			summary.Skipped++
+		}
+	}
+	return summary
+}
@@ -0,0 +1,68 @@
+package scheduler
+
+import (
+	"context"
+	"encoding/json"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+)
+
+func TestWebhookNotifier_SignsAndFiltersEvents(t *testing.T) {
+	var mu sync.Mutex
+	var received []webhookPayload
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		body, _ := io.ReadAll(r.Body)
+		if got := r.Header.Get("X-Meows-Signature"); got != "sha256="+webhookSignature("s3cret", body) {
+			t.Errorf("Unexpected signature %q", got)
+		}
+		var payload webhookPayload
+		if err := json.Unmarshal(body, &payload); err != nil {
+			t.Errorf("Invalid payload: %v", err)
+		}
+		mu.Lock()
+		received = append(received, payload)
+		mu.Unlock()
+	}))
+	defer server.Close()
+
+	n := newWebhookNotifier(defaultJobName, config.WebhookConfig{URLs: []string{server.URL}, Events: []string{"run_completed", "source_empty"}, Secret: "s3cret"})
+	n.sourceFinished(SourceOutcome{SourceID: "a", Status: "failed", Error: "boom"}) // Not subscribed
+	n.sourceFinished(SourceOutcome{SourceID: "b", RunID: "run-1", Status: "succeeded"})
+	n.sourceFinished(SourceOutcome{SourceID: "c", Status: "succeeded", Articles: 3}) // Not empty
+	n.runCompleted(RunReport{RunID: "run-1", Outcomes: []SourceOutcome{
+		{Status: "succeeded", Articles: 3, Comments: 4},
+		{Status: "failed"},
+		{Status: "skipped"},
+	}})
+	n.wait(context.Background())
+
+	if len(received) != 2 {
+		t.Fatalf("Expected 2 deliveries, got %d: %+v", len(received), received)
+	}
+	byEvent := make(map[string]webhookPayload)
+	for _, payload := range received {
+		byEvent[payload.Event] = payload
+	}
+	if empty := byEvent["source_empty"]; empty.Outcome == nil || empty.Outcome.SourceID != "b" || empty.RunID != "run-1" {
+		t.Errorf("Unexpected source_empty payload: %+v", empty)
+	}
+	want := runSummary{Succeeded: 1, Failed: 1, Skipped: 1, Articles: 3, Comments: 4}
+	if completed := byEvent["run_completed"]; completed.Summary == nil || *completed.Summary != want || len(completed.Report.Outcomes) != 3 || completed.RunID != "run-1" {
+		t.Errorf("Unexpected run_completed payload: %+v", completed)
+	}
+}
+
+func TestWebhookNotifier_DisabledWithoutURLs(t *testing.T) {
+	n := newWebhookNotifier(defaultJobName, config.WebhookConfig{})
+	if n != nil {
+		t.Fatalf("Expected nil notifier without URLs")
+	}
+	n.runStarted("", time.Time{}) // Must not panic
+	n.wait(context.Background())
+}
@@ -0,0 +1,129 @@
+package searchindex
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+)
+
+// maxResponseBytes bounds the bulk response read; item results beyond it are not inspected
+const maxResponseBytes = 8 << 20
+
+// Document is one document to index under a fixed ID, so indexing it again replaces it
+type Document struct {
+	ID   string
+	Body interface{}
+}
+
+// BulkResult counts the documents a bulk request indexed and rejected
+type BulkResult struct {
+	Indexed   int
+	Failed    int
+	FirstFail string // Reason given for the first rejected document
+}
+
+// Client indexes documents into one Elasticsearch or OpenSearch index through the _bulk API
+type Client struct {
+	baseURL  string
+	index    string
+	username string
+	password string
+	apiKey   string
+	http     *http.Client
+}
+
+// NewClient creates a client for cfg, which is expected to have passed config validation
+func NewClient(cfg config.SearchIndexConfig, httpClient *http.Client) *Client {
+	return &Client{
+		baseURL:  strings.TrimSuffix(cfg.URL, "/"),
+		index:    cfg.Index,
+		username: cfg.Username,
+		password: cfg.Password,
+		apiKey:   cfg.APIKey,
+		http:     httpClient,
+	}
+}
+
+// bulkResponse is the part of a _bulk response needed to count per-document outcomes
+type bulkResponse struct {
+	Errors bool `json:"errors"`
+	Items  []map[string]struct {
+		Status int `json:"status"`
+		Error  *struct {
+			Type   string `json:"type"`
+			Reason string `json:"reason"`
+		} `json:"error"`
+	} `json:"items"`
+}
+
+// Bulk indexes docs in one _bulk request
+// A request the cluster refuses is an error; documents it rejects individually are counted in the result
+func (c *Client) Bulk(ctx context.Context, docs []Document) (*BulkResult, error) {
+	var body bytes.Buffer
+	encoder := json.NewEncoder(&body)
+	for _, doc := range docs {
+		action := map[string]map[string]string{"index": {"_index": c.index, "_id": doc.ID}}
+		if err := encoder.Encode(action); err != nil {
+			return nil, err
+		}
+		if err := encoder.Encode(doc.Body); err != nil {
+			return nil, fmt.Errorf("failed to encode document %s: %w", doc.ID, err)
+		}
+	}
+
+	req, err := http.NewRequestWithContext(ctx, "POST", c.baseURL+"/_bulk", &body)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("Content-Type", "application/x-ndjson")
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	if c.apiKey != "" {
+		req.Header.Set("Authorization", "ApiKey "+c.apiKey)
+	} else if c.username != "" {
+		req.SetBasicAuth(c.username, c.password)
+	}
+
+	resp, err := c.http.Do(req)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+	data, err := io.ReadAll(io.LimitReader(resp.Body, maxResponseBytes))
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode >= 300 {
+		message := string(data)
+		if len(message) > 512 {
+			message = message[:512]
+		}
+		return nil, fmt.Errorf("bulk request returned %d: %s", resp.StatusCode, strings.TrimSpace(message))
+	}
+
+	var parsed bulkResponse
+	if err := json.Unmarshal(data, &parsed); err != nil {
+		return nil, fmt.Errorf("failed to decode bulk response: %w", err)
+	}
+
+	result := &BulkResult{}
+	for _, item := range parsed.Items {
+		for _, outcome := range item {
+			if outcome.Error == nil && outcome.Status < 300 {
+				result.Indexed++
+				continue
+			}
+			result.Failed++
+			if result.FirstFail == "" && outcome.Error != nil {
+				result.FirstFail = outcome.Error.Type + ": " + outcome.Error.Reason
+			}
+		}
+	}
+	return result, nil
+}
@@ -0,0 +1,50 @@
+package searchindex
+
+import (
+	"context"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+)
+
+func TestBulk(t *testing.T) {
+	var body, auth string
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		data, _ := io.ReadAll(r.Body)
+		body, auth = string(data), r.Header.Get("Authorization")
+		if r.URL.Path != "/_bulk" || r.Header.Get("Content-Type") != "application/x-ndjson" {
+			w.WriteHeader(http.StatusBadRequest)
+			return
+		}
+		w.Write([]byte(`{"errors":true,"items":[
+			{"index":{"_id":"a","status":201}},
+			{"index":{"_id":"b","status":400,"error":{"type":"mapper_parsing_exception","reason":"failed to parse field [metadata.score]"}}}
+		]}`))
+	}))
+	defer server.Close()
+
+	c := NewClient(config.SearchIndexConfig{URL: server.URL + "/", Index: "meows-articles", APIKey: "key"}, server.Client())
+	result, err := c.Bulk(context.Background(), []Document{
+		{ID: "a", Body: map[string]string{"title": "Hello"}},
+		{ID: "b", Body: map[string]interface{}{"metadata": map[string]string{"score": "high"}}},
+	})
+	if err != nil {
+		t.Fatalf("Bulk failed: %v", err)
+	}
+
+	want := `{"index":{"_id":"a","_index":"meows-articles"}}` + "\n" + `{"title":"Hello"}` + "\n" +
+		`{"index":{"_id":"b","_index":"meows-articles"}}` + "\n" + `{"metadata":{"score":"high"}}` + "\n"
+	if body != want {
+		t.Errorf("Unexpected bulk body:\n%s", body)
+	}
+	if auth != "ApiKey key" {
+		t.Errorf("Expected API key auth, got %q", auth)
+	}
+	if result.Indexed != 1 || result.Failed != 1 || !strings.HasPrefix(result.FirstFail, "mapper_parsing_exception") {
+		t.Errorf("Unexpected result: %+v", result)
+	}
+}
@@ -0,0 +1,121 @@
+package source
+
+import (
+	"context"
+	"math"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+const (
+	// maxOverfetch caps how many raw items a keyword-filtered source reads per article its limit asks for
+	// A filter matching nothing would otherwise page through the whole listing
+	maxOverfetch = 10
+
+	maxTargetMatches      = 1000
+	maxTargetRequests     = 1000
+	defaultTargetRequests = 50
+)
+
+type keywordFilterKey struct{}
+
+// WithKeywordFilter returns a context carrying the source's (lowercased) keyword filters and queries
+// Paginated sources then count their limit in articles that pass the filters rather than raw items
+func WithKeywordFilter(ctx context.Context, include, exclude []string, queries []db.KeywordQuery) context.Context {
+	if len(include) == 0 && len(exclude) == 0 && len(queries) == 0 {
+		return ctx
+	}
+	return context.WithValue(ctx, keywordFilterKey{}, newKeywordFilter(include, exclude, queries))
+}
+
+type fetchTargetKey struct{}
+
+// fetchTarget is a source's target_matches setting
+type fetchTarget struct {
+	matches  int
+	requests int // Request budget for the run, counted by the request trail
+}
+
+// WithTargetMatches returns a context asking paginated sources for this many articles that pass the keyword
+// filters in place of their limit. They page until they have them, the listing ends or requests are spent.
+func WithTargetMatches(ctx context.Context, matches, requests int) context.Context {
+	if matches <= 0 {
+		return ctx
+	}
+	return context.WithValue(ctx, fetchTargetKey{}, &fetchTarget{matches: matches, requests: requests})
+}
+
+// matchTally tracks how many of the articles a paginated fetch collected pass the keyword filters
+// Without filters every article matches, so limit keeps counting raw items
+type matchTally struct {
+	filter  *keywordFilter // Nil without keyword filters
+	target  *fetchTarget   // Nil unless the source sets target_matches
+	trail   *requestTrail  // Counts the requests spent against the target's budget
+	limit   int
+	read    int // Articles collected
+	matched int // Articles collected that pass the filters
+}
+
+// newMatchTally starts a tally for a fetch of limit articles, or of the source's target_matches
+func newMatchTally(ctx context.Context, limit int) *matchTally {
+	filter, _ := ctx.Value(keywordFilterKey{}).(*keywordFilter)
+	target, _ := ctx.Value(fetchTargetKey{}).(*fetchTarget)
+	trail, _ := ctx.Value(requestTrailKey{}).(*requestTrail)
+	t := &matchTally{filter: filter, target: target, trail: trail, limit: limit}
+	if target != nil {
+		t.limit = target.matches
+	}
+	return t
+}
+
+// add counts a collected article and reports whether it passes the filters
+func (t *matchTally) add(article db.Article) bool {
+	t.read++
+	if t.filter != nil && !t.filter.matches(article) {
+		return false
+	}
+	t.matched++
+	return true
+}
+
+// budget is the most articles the fetch may collect: limit, or maxOverfetch times that with filters
+// A target is bounded by its request budget instead
+func (t *matchTally) budget() int {
+	if t.target != nil {
+		return math.MaxInt
+	}
+	if t.filter == nil {
+		return t.limit
+	}
+	return t.limit * maxOverfetch
+}
+
+// done reports whether the fetch has collected limit matching articles or used up its budget
+func (t *matchTally) done() bool {
+	return t.matched >= t.limit || t.read >= t.budget() || t.outOfRequests()
+}
+
+// outOfRequests reports whether a target's request budget is spent
+func (t *matchTally) outOfRequests() bool {
+	if t.target == nil || t.trail == nil {
+		return false
+	}
+	t.trail.mu.Lock()
+	defer t.trail.mu.Unlock()
+	return t.trail.stats.Requests >= t.target.requests
+}
+
+// pageSize picks the size of the next page for APIs that take one per request, up to maxSize
+// With filters it asks for enough items to reach limit at the pass rate observed so far,
+// and for as many as the budget allows until something has passed
+func (t *matchTally) pageSize(maxSize int) int {
+	size := t.limit - t.matched
+	if t.filter != nil {
+		size = t.budget() - t.read
+		if t.matched > 0 {
+			// Remaining matches divided by the pass rate so far, rounded up
+			size = min(size, ((t.limit-t.matched)*t.read+t.matched-1)/t.matched)
+		}
+	}
+	return max(1, min(size, maxSize))
+}
@@ -0,0 +1,73 @@
+package source
+
+import (
+	"context"
+	"testing"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestMatchTally(t *testing.T) {
+	tally := newMatchTally(context.Background(), 10)
+	if tally.budget() != 10 || tally.pageSize(100) != 10 {
+		t.Errorf("Expected an unfiltered tally to count raw items, got budget %d page size %d", tally.budget(), tally.pageSize(100))
+	}
+
+	ctx := WithKeywordFilter(context.Background(), []string{"rust"}, nil, nil)
+	tally = newMatchTally(ctx, 10)
+	if tally.budget() != 100 || tally.pageSize(25) != 25 {
+		t.Errorf("Expected a full first page, got budget %d page size %d", tally.budget(), tally.pageSize(25))
+	}
+
+	// 2 of 20 pass: 8 more matches at a 10% pass rate need 80 items
+	for i := 0; i < 20; i++ {
+		title := "Go news"
+		if i%10 == 0 {
+			title = "Rust news"
+		}
+		tally.add(db.Article{Title: title})
+	}
+	if tally.done() {
+		t.Fatal("Expected the tally to need more matches")
+	}
+	if got := tally.pageSize(100); got != 80 {
+		t.Errorf("Expected a page size of 80, got %d", got)
+	}
+	if got := tally.pageSize(50); got != 50 {
+		t.Errorf("Expected the page size capped at 50, got %d", got)
+	}
+
+	for i := 0; i < 80; i++ {
+		tally.add(db.Article{Title: "Go news"})
+	}
+	if !tally.done() {
+		t.Error("Expected the overfetch budget to end the fetch")
+	}
+}
+
+func TestMatchTallyTarget(t *testing.T) {
+	ctx := WithRequestTrail(context.Background())
+	ctx = WithKeywordFilter(ctx, []string{"rust"}, nil, nil)
+	ctx = WithTargetMatches(ctx, 3, 2)
+
+	tally := newMatchTally(ctx, 100)
+	for i := 0; i < 2000; i++ {
+		tally.add(db.Article{Title: "Go news"})
+	}
+	if tally.done() {
+		t.Fatal("Expected a target to ignore the overfetch cap")
+	}
+	for i := 0; i < 3; i++ {
+		tally.add(db.Article{Title: "Rust news"})
+	}
+	if !tally.done() {
+		t.Error("Expected 3 matches to reach the target")
+	}
+
+	tally = newMatchTally(ctx, 100)
+	trail := ctx.Value(requestTrailKey{}).(*requestTrail)
+	trail.stats.Requests = 2
+	if !tally.done() {
+		t.Error("Expected the request budget to end the fetch")
+	}
+}
@@ -0,0 +1,134 @@
+package source
+
+import (
+	"bytes"
+	"compress/gzip"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"log/slog"
+	"net/http"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+)
+
+// redactedParams lists query parameters that may carry credentials and must not be archived or stored in provenance
+var redactedParams = []string{"api_key", "apikey", "apiKey", "key", "token", "access_token"}
+
+// credentialPaths are path suffixes of endpoints whose responses carry access tokens; they are never archived
+var credentialPaths = []string{
+	"/api/v1/access_token",               // Reddit OAuth
+	"/com.atproto.server.createSession",  // Bluesky app password login (accessJwt, refreshJwt)
+	"/com.atproto.server.refreshSession", // Bluesky session refresh
+	"/oauth/token",
+	"/oauth2/token",
+}
+
+// archiveTransport saves every raw response body to disk before handing it to the source
+// Files are gzip-compressed; the gzip header records the request URL, status and fetch time
+type archiveTransport struct {
+	next http.RoundTripper
+	dir  string
+}
+
+// RoundTrip performs the request and archives the response body
+// Archive failures are logged and never fail the request itself
+func (t *archiveTransport) RoundTrip(req *http.Request) (*http.Response, error) {
+	resp, err := t.next.RoundTrip(req)
+	if err != nil {
+		return resp, err
+	}
+	if isCredentialEndpoint(req.URL) || resp.ContentLength > maxResponseBytes {
+		return resp, nil
+	}
+
+	// Read no more than sources would; a larger body is handed on unarchived for readBody to reject
+	original := resp.Body
+	body, err := io.ReadAll(io.LimitReader(original, maxResponseBytes+1))
+	if err != nil {
+		original.Close()
+		return nil, fmt.Errorf("failed to read response body: %w", err)
+	}
+	if len(body) > maxResponseBytes {
+		resp.Body = struct {
+			io.Reader
+			io.Closer
+		}{io.MultiReader(bytes.NewReader(body), original), original}
+		return resp, nil
+	}
+	original.Close()
+	resp.Body = io.NopCloser(bytes.NewReader(body))
+
+	if err := t.save(req, resp.StatusCode, body); err != nil {
+		slog.Warn("Failed to archive raw response",
+			"url", redactURL(req.URL),
+			"error", err)
+	}
+
+	return resp, nil
+}
+
+// isCredentialEndpoint reports whether u is a login or token endpoint listed in credentialPaths
+func isCredentialEndpoint(u *url.URL) bool {
+	for _, suffix := range credentialPaths {
+		if strings.HasSuffix(u.Path, suffix) {
+			return true
+		}
+	}
+	return false
+}
+
+// save writes a single response body to <dir>/<host>/<timestamp>-<url hash>.gz
+func (t *archiveTransport) save(req *http.Request, status int, body []byte) error {
+	fetchedAt := time.Now().UTC()
+	rawURL := redactURL(req.URL)
+
+	hostDir := filepath.Join(t.dir, req.URL.Hostname())
+	if err := os.MkdirAll(hostDir, 0o750); err != nil {
+		return fmt.Errorf("failed to create archive directory: %w", err)
+	}
+
+	sum := sha256.Sum256([]byte(rawURL))
+	name := fmt.Sprintf("%s-%s.gz", fetchedAt.Format("20060102T150405.000000000"), hex.EncodeToString(sum[:8]))
+
+	f, err := os.OpenFile(filepath.Join(hostDir, name), os.O_CREATE|os.O_EXCL|os.O_WRONLY, 0o640)
+	if err != nil {
+		return fmt.Errorf("failed to create archive file: %w", err)
+	}
+	defer f.Close()
+
+	gw := gzip.NewWriter(f)
+	gw.Header.Name = rawURL
+	gw.Header.Comment = fmt.Sprintf("%s %d", req.Method, status)
+	gw.Header.ModTime = fetchedAt
+
+	if _, err := gw.Write(body); err != nil {
+		return fmt.Errorf("failed to write archive: %w", err)
+	}
+	if err := gw.Close(); err != nil {
+		return fmt.Errorf("failed to finalize archive: %w", err)
+	}
+
+	return nil
+}
+
+// redactURL returns the URL as a string with credential-bearing query parameters masked
+func redactURL(u *url.URL) string {
+	redacted := *u
+	query := redacted.Query()
+	changed := false
+	for _, param := range redactedParams {
+		if query.Has(param) {
+			query.Set(param, "REDACTED")
+			changed = true
+		}
+	}
+	if changed {
+		redacted.RawQuery = query.Encode()
+	}
+	return redacted.String()
+}
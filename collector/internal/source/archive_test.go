@@ -0,0 +1,56 @@
+package source
+
+import (
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestArchiveTransport_SkipsTokensAndOversizedBodies(t *testing.T) {
+	large := strings.Repeat("x", maxResponseBytes+10)
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/xrpc/com.atproto.server.createSession":
+			w.Write([]byte(`{"accessJwt":"secret"}`))
+		case "/large":
+			w.(http.Flusher).Flush() // Chunked, so the size is only known by reading
+			io.WriteString(w, large)
+		default:
+			w.Write([]byte(`{"ok":true}`))
+		}
+	}))
+	defer server.Close()
+
+	dir := t.TempDir()
+	client := &http.Client{Transport: &archiveTransport{next: http.DefaultTransport, dir: dir}}
+
+	for _, path := range []string{"/xrpc/com.atproto.server.createSession", "/large", "/items"} {
+		resp, err := client.Get(server.URL + path)
+		if err != nil {
+			t.Fatalf("GET %s failed: %v", path, err)
+		}
+		body, err := io.ReadAll(resp.Body)
+		resp.Body.Close()
+		if err != nil {
+			t.Fatalf("Reading %s failed: %v", path, err)
+		}
+		if path == "/large" && len(body) != len(large) {
+			t.Errorf("Expected the oversized body to be passed through whole, got %d bytes", len(body))
+		}
+	}
+
+	files, err := filepath.Glob(filepath.Join(dir, "*", "*.gz"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(files) != 1 {
+		t.Fatalf("Expected only /items to be archived, got %v", files)
+	}
+	if info, err := os.Stat(files[0]); err != nil || info.Size() > 1024 {
+		t.Errorf("Unexpected archive file %s: %v", files[0], err)
+	}
+}
@@ -0,0 +1,260 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"encoding/xml"
+	"fmt"
+	"net/http"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+// arxivVersionSuffix matches the trailing version of an arXiv ID (e.g. "v2")
+var arxivVersionSuffix = regexp.MustCompile(`v\d+$`)
+
+// ArxivSource implements the Source interface for arXiv daily listings (RSS)
+type ArxivSource struct {
+	source *db.Source
+	config *db.ArxivConfig
+	client *http.Client
+}
+
+// arXiv RSS structures (https://rss.arxiv.org/rss/<category>)
+type arxivFeed struct {
+	Items []arxivItem `xml:"channel>item"`
+}
+
+type arxivItem struct {
+	Title        string   `xml:"title"`
+	Link         string   `xml:"link"`
+	Description  string   `xml:"description"` // "arXiv:2401.01234v1 Announce Type: new \nAbstract: ..."
+	GUID         string   `xml:"guid"`        // "oai:arXiv.org:2401.01234v1"
+	Categories   []string `xml:"category"`
+	PubDate      string   `xml:"pubDate"`
+	Creator      string   `xml:"creator"`       // dc:creator, comma-separated authors
+	AnnounceType string   `xml:"announce_type"` // "new", "cross", "replace", "replace-cross"
+}
+
+// arxivPaper is a deduplicated listing entry with every watched category it appeared in
+type arxivPaper struct {
+	item       arxivItem
+	id         string
+	categories []string
+}
+
+// NewArxivSource creates a new arXiv source
+func NewArxivSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*ArxivSource, error) {
+	var config db.ArxivConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid arxiv config: %w", err)
+	}
+
+	as := &ArxivSource{
+		source: source,
+		config: &config,
+		client: httpClient, // Shared client with pooled connections
+	}
+
+	// arXiv listings are public, no credentials needed
+	_ = credentials
+
+	return as, nil
+}
+
+// SourceType returns "arxiv"
+func (a *ArxivSource) SourceType() string {
+	return "arxiv"
+}
+
+// Validate checks if the configuration is valid
+func (a *ArxivSource) Validate() error {
+	if len(a.config.Categories) == 0 {
+		return fmt.Errorf("at least one category is required")
+	}
+
+	seen := make(map[string]bool)
+	for i := range a.config.Categories {
+		c := &a.config.Categories[i]
+		if c.Name == "" {
+			return fmt.Errorf("category name is required")
+		}
+		if seen[c.Name] {
+			return fmt.Errorf("duplicate category: %s", c.Name)
+		}
+		seen[c.Name] = true
+
+		if c.Limit <= 0 {
+			c.Limit = 50
+		}
+		if c.Limit > 500 {
+			return fmt.Errorf("limit for %s must be <= 500, got %d", c.Name, c.Limit)
+		}
+	}
+
+	return nil
+}
+
+// Fetch retrieves today's listing for every watched category
+// Papers cross-listed in several watched categories are emitted once
+func (a *ArxivSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := a.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	var papers []*arxivPaper
+	byID := make(map[string]*arxivPaper)
+
+	for _, category := range a.config.Categories {
+		items, err := a.fetchListing(ctx, category.Name)
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to fetch %s listing: %w", category.Name, err)
+		}
+
+		taken := 0
+		for _, item := range items {
+			if taken >= category.Limit {
+				break
+			}
+			if !a.wantAnnounceType(item.AnnounceType) {
+				continue
+			}
+
+			id := arxivID(item.GUID)
+			if id == "" {
+				continue
+			}
+
+			// Cross-list detection: record the extra category instead of emitting a duplicate
+			if existing, ok := byID[id]; ok {
+				existing.categories = append(existing.categories, category.Name)
+				continue
+			}
+
+			paper := &arxivPaper{item: item, id: id, categories: []string{category.Name}}
+			byID[id] = paper
+			papers = append(papers, paper)
+			taken++
+		}
+	}
+
+	articles := make([]db.Article, 0, len(papers))
+	for _, paper := range papers {
+		article := a.paperToArticle(paper)
+		if article.WrittenAt.Before(since) {
+			continue
+		}
+		articles = append(articles, article)
+	}
+
+	// arXiv doesn't have comments
+	return articles, []db.Comment{}, nil
+}
+
+// wantAnnounceType reports whether a listing entry of this type should be emitted
+func (a *ArxivSource) wantAnnounceType(announceType string) bool {
+	switch announceType {
+	case "new":
+		return true
+	case "cross":
+		return a.config.IncludeCrossLists
+	case "replace", "replace-cross":
+		return a.config.IncludeReplacements
+	default:
+		return false
+	}
+}
+
+// fetchListing fetches the daily RSS listing for one category
+func (a *ArxivSource) fetchListing(ctx context.Context, category string) ([]arxivItem, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", "https://rss.arxiv.org/rss/"+category, nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+
+	resp, err := a.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentXML)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("arXiv RSS returned %d", resp.StatusCode)
+	}
+
+	var feed arxivFeed
+	if err := xml.Unmarshal(body, &feed); err != nil {
+		return nil, fmt.Errorf("failed to decode RSS: %w", err)
+	}
+
+	return feed.Items, nil
+}
+
+// paperToArticle converts a deduplicated arXiv listing entry to an Article
+func (a *ArxivSource) paperToArticle(paper *arxivPaper) db.Article {
+	item := paper.item
+
+	authors := strings.Split(item.Creator, ",")
+	for i := range authors {
+		authors[i] = strings.TrimSpace(authors[i])
+	}
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"arxiv_id":           paper.id,
+		"announce_type":      item.AnnounceType,
+		"arxiv_categories":   item.Categories,
+		"matched_categories": paper.categories,
+		"authors":            authors,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	writtenAt, err := time.Parse(time.RFC1123Z, item.PubDate)
+	if err != nil {
+		writtenAt = time.Now()
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   a.source.ID,
+		ExternalID: paper.id,
+		Title:      strings.TrimSpace(item.Title),
+		Author:     authors[0],
+		Content:    arxivAbstract(item.Description),
+		URL:        item.Link,
+		WrittenAt:  writtenAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// arxivID extracts the version-less arXiv ID from an RSS guid ("oai:arXiv.org:2401.01234v1")
+func arxivID(guid string) string {
+	id := guid[strings.LastIndex(guid, ":")+1:]
+	return arxivVersionSuffix.ReplaceAllString(id, "")
+}
+
+// arxivAbstract strips the "arXiv:... Announce Type: ..." preamble from an RSS description
+func arxivAbstract(description string) string {
+	if _, abstract, found := strings.Cut(description, "Abstract:"); found {
+		return strings.TrimSpace(abstract)
+	}
+	return strings.TrimSpace(description)
+}
@@ -0,0 +1,51 @@
+package source
+
+import (
+	"encoding/xml"
+	"testing"
+)
+
+func TestArxivFeedParsing(t *testing.T) {
+	data := `<?xml version="1.0"?>
+<rss xmlns:arxiv="http://arxiv.org/schemas/atom" xmlns:dc="http://purl.org/dc/elements/1.1/" version="2.0">
+  <channel>
+    <item>
+      <title>Scaling Laws for Cats</title>
+      <link>https://arxiv.org/abs/2401.01234</link>
+      <description>arXiv:2401.01234v2 Announce Type: cross
+Abstract: We study cats at scale.</description>
+      <guid isPermaLink="false">oai:arXiv.org:2401.01234v2</guid>
+      <category>cs.LG</category>
+      <category>cs.AI</category>
+      <pubDate>Mon, 08 Jan 2024 00:00:00 -0500</pubDate>
+      <arxiv:announce_type>cross</arxiv:announce_type>
+      <dc:creator>Jane Doe, John Roe</dc:creator>
+    </item>
+  </channel>
+</rss>`
+
+	var feed arxivFeed
+	if err := xml.Unmarshal([]byte(data), &feed); err != nil {
+		t.Fatalf("Failed to parse RSS: %v", err)
+	}
+	if len(feed.Items) != 1 {
+		t.Fatalf("Expected 1 item, got %d", len(feed.Items))
+	}
+
+	item := feed.Items[0]
+	if item.AnnounceType != "cross" {
+		t.Errorf("Expected announce type %q, got %q", "cross", item.AnnounceType)
+	}
+	if item.Creator != "Jane Doe, John Roe" {
+		t.Errorf("Unexpected creator: %q", item.Creator)
+	}
+	if len(item.Categories) != 2 {
+		t.Errorf("Expected 2 categories, got %v", item.Categories)
+	}
+	if id := arxivID(item.GUID); id != "2401.01234" {
+		t.Errorf("Expected version-less ID %q, got %q", "2401.01234", id)
+	}
+	if abstract := arxivAbstract(item.Description); abstract != "We study cats at scale." {
+		t.Errorf("Unexpected abstract: %q", abstract)
+	}
+}
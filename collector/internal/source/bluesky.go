@@ -0,0 +1,348 @@
+package source
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+	"unicode/utf8"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	bskyPublicBaseURL = "https://public.api.bsky.app/xrpc" // Unauthenticated AppView
+	bskyPDSBaseURL    = "https://bsky.social/xrpc"         // Session endpoint, proxies AppView calls when authenticated
+	bskyTitleMaxLen   = 120
+)
+
+// BlueskySource implements the Source interface for Bluesky (AT Protocol)
+type BlueskySource struct {
+	source      *db.Source
+	config      *db.BlueskyConfig
+	client      *http.Client
+	identifier  string // From global config (environment variable)
+	appPassword string // From global config (environment variable)
+	accessJwt   string // Session token, empty when unauthenticated
+}
+
+// Bluesky XRPC response structures
+type bskySessionResponse struct {
+	AccessJwt string `json:"accessJwt"`
+	Handle    string `json:"handle"`
+}
+
+type bskySearchResponse struct {
+	Posts  []bskyPost `json:"posts"`
+	Cursor string     `json:"cursor"`
+}
+
+type bskyFeedResponse struct {
+	Feed []struct {
+		Post bskyPost `json:"post"`
+	} `json:"feed"`
+	Cursor string `json:"cursor"`
+}
+
+type bskyPost struct {
+	URI    string `json:"uri"` // at://did/app.bsky.feed.post/rkey
+	Author struct {
+		DID         string `json:"did"`
+		Handle      string `json:"handle"`
+		DisplayName string `json:"displayName"`
+	} `json:"author"`
+	Record struct {
+		Text      string    `json:"text"`
+		CreatedAt time.Time `json:"createdAt"`
+	} `json:"record"`
+	LikeCount   int `json:"likeCount"`
+	ReplyCount  int `json:"replyCount"`
+	RepostCount int `json:"repostCount"`
+}
+
+type bskyError struct {
+	Error   string `json:"error"`
+	Message string `json:"message"`
+}
+
+// NewBlueskySource creates a new Bluesky source
+// Uses the optional app password from config file
+func NewBlueskySource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*BlueskySource, error) {
+	var config db.BlueskyConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid bluesky config: %w", err)
+	}
+
+	bs := &BlueskySource{
+		source:      source,
+		config:      &config,
+		client:      httpClient, // Shared client with pooled connections
+		identifier:  credentials.BlueskyIdentifier,
+		appPassword: credentials.BlueskyAppPassword,
+	}
+
+	return bs, nil
+}
+
+// SourceType returns "bluesky"
+func (b *BlueskySource) SourceType() string {
+	return "bluesky"
+}
+
+// Validate checks if the configuration is valid
+func (b *BlueskySource) Validate() error {
+	if err := validateEnum(b.config.Mode, []string{"search", "author", "feed"}, "mode"); err != nil {
+		return err
+	}
+
+	switch b.config.Mode {
+	case "search":
+		if b.config.Query == "" {
+			return fmt.Errorf("query is required for search mode")
+		}
+		if b.config.Sort == "" {
+			b.config.Sort = "latest"
+		}
+		if err := validateEnum(b.config.Sort, []string{"latest", "top"}, "sort"); err != nil {
+			return err
+		}
+	case "author":
+		if b.config.Actor == "" {
+			return fmt.Errorf("actor (handle or DID) is required for author mode")
+		}
+	case "feed":
+		if !strings.HasPrefix(b.config.FeedURI, "at://") {
+			return fmt.Errorf("feed_uri must be an at:// URI for feed mode")
+		}
+	}
+
+	if b.config.Limit <= 0 {
+		b.config.Limit = 50
+	}
+	if b.config.Limit > 100 {
+		return fmt.Errorf("limit must be <= 100, got %d", b.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch retrieves posts from Bluesky
+func (b *BlueskySource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := b.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	// Authenticate only when an app password is configured
+	if b.identifier != "" && b.appPassword != "" && b.accessJwt == "" {
+		if err := b.createSession(ctx); err != nil {
+			return nil, nil, fmt.Errorf("bluesky authentication failed: %w", err)
+		}
+	}
+
+	var posts []bskyPost
+	var err error
+	switch b.config.Mode {
+	case "search":
+		posts, err = b.searchPosts(ctx, since)
+	case "author":
+		posts, err = b.fetchFeed(ctx, "app.bsky.feed.getAuthorFeed", "actor", b.config.Actor)
+	case "feed":
+		posts, err = b.fetchFeed(ctx, "app.bsky.feed.getFeed", "feed", b.config.FeedURI)
+	}
+	if err != nil {
+		return nil, nil, err
+	}
+
+	var articles []db.Article
+	for _, post := range posts {
+		if post.Record.CreatedAt.Before(since) {
+			continue
+		}
+		if post.LikeCount < b.config.MinLikes {
+			continue
+		}
+		articles = append(articles, b.postToArticle(post))
+	}
+
+	// Replies are not fetched, reply count is kept as num_comments
+	return articles, []db.Comment{}, nil
+}
+
+// createSession exchanges the identifier and app password for an access token
+func (b *BlueskySource) createSession(ctx context.Context) error {
+	payload, err := json.Marshal(map[string]string{
+		"identifier": b.identifier,
+		"password":   b.appPassword,
+	})
+	if err != nil {
+		return err
+	}
+
+	body, err := b.doRequest(ctx, "POST", bskyPDSBaseURL+"/com.atproto.server.createSession", payload)
+	if err != nil {
+		return err
+	}
+
+	var session bskySessionResponse
+	if err := json.Unmarshal(body, &session); err != nil {
+		return fmt.Errorf("failed to decode session: %w", err)
+	}
+	if session.AccessJwt == "" {
+		return fmt.Errorf("no access token in session response")
+	}
+
+	b.accessJwt = session.AccessJwt
+	return nil
+}
+
+// searchPosts calls app.bsky.feed.searchPosts
+func (b *BlueskySource) searchPosts(ctx context.Context, since time.Time) ([]bskyPost, error) {
+	params := url.Values{}
+	params.Set("q", b.config.Query)
+	params.Set("sort", b.config.Sort)
+	params.Set("limit", strconv.Itoa(b.config.Limit))
+	if since.Unix() > 0 {
+		params.Set("since", since.UTC().Format(time.RFC3339))
+	}
+	if b.config.Lang != "" {
+		params.Set("lang", b.config.Lang)
+	}
+
+	body, err := b.doRequest(ctx, "GET", b.baseURL()+"/app.bsky.feed.searchPosts?"+params.Encode(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	var response bskySearchResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode search results: %w", err)
+	}
+
+	return response.Posts, nil
+}
+
+// fetchFeed calls a feed-style XRPC method (author feed or custom feed generator)
+func (b *BlueskySource) fetchFeed(ctx context.Context, method, param, value string) ([]bskyPost, error) {
+	params := url.Values{}
+	params.Set(param, value)
+	params.Set("limit", strconv.Itoa(b.config.Limit))
+
+	body, err := b.doRequest(ctx, "GET", b.baseURL()+"/"+method+"?"+params.Encode(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	var response bskyFeedResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode feed: %w", err)
+	}
+
+	posts := make([]bskyPost, 0, len(response.Feed))
+	for _, item := range response.Feed {
+		posts = append(posts, item.Post)
+	}
+
+	return posts, nil
+}
+
+// baseURL returns the PDS when authenticated, otherwise the public AppView
+func (b *BlueskySource) baseURL() string {
+	if b.accessJwt != "" {
+		return bskyPDSBaseURL
+	}
+	return bskyPublicBaseURL
+}
+
+// doRequest performs a rate-limited XRPC request and returns the response body
+func (b *BlueskySource) doRequest(ctx context.Context, method, u string, payload []byte) ([]byte, error) {
+	var reqBody io.Reader
+	if payload != nil {
+		reqBody = bytes.NewReader(payload)
+	}
+
+	req, err := http.NewRequestWithContext(ctx, method, u, reqBody)
+	if err != nil {
+		return nil, err
+	}
+
+	req.Header.Set("Accept", "application/json")
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	if payload != nil {
+		req.Header.Set("Content-Type", "application/json")
+	}
+	if b.accessJwt != "" {
+		req.Header.Set("Authorization", "Bearer "+b.accessJwt)
+	}
+
+	resp, err := b.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		var xrpcErr bskyError
+		if json.Unmarshal(body, &xrpcErr) == nil && xrpcErr.Error != "" {
+			return nil, fmt.Errorf("Bluesky API returned %d (%s): %s", resp.StatusCode, xrpcErr.Error, xrpcErr.Message)
+		}
+		return nil, fmt.Errorf("Bluesky API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	return body, nil
+}
+
+// postToArticle converts a Bluesky post to an Article
+func (b *BlueskySource) postToArticle(post bskyPost) db.Article {
+	metadata, _ := json.Marshal(map[string]interface{}{
+		"score":        post.LikeCount,
+		"num_comments": post.ReplyCount,
+		"reposts":      post.RepostCount,
+		"at_uri":       post.URI,
+		"author_did":   post.Author.DID,
+	})
+
+	// Web URL: https://bsky.app/profile/{handle}/post/{rkey}
+	rkey := post.URI[strings.LastIndex(post.URI, "/")+1:]
+	postURL := fmt.Sprintf("https://bsky.app/profile/%s/post/%s", post.Author.Handle, rkey)
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   b.source.ID,
+		ExternalID: post.URI,
+		Title:      postTitle(post.Record.Text),
+		Author:     post.Author.Handle,
+		Content:    post.Record.Text,
+		URL:        postURL,
+		WrittenAt:  post.Record.CreatedAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// postTitle derives a title from the first line of a post, truncated to bskyTitleMaxLen runes
+func postTitle(text string) string {
+	title, _, _ := strings.Cut(strings.TrimSpace(text), "\n")
+	if utf8.RuneCountInString(title) <= bskyTitleMaxLen {
+		return title
+	}
+	runes := []rune(title)
+	return strings.TrimSpace(string(runes[:bskyTitleMaxLen])) + "…"
+}
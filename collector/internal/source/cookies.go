@@ -0,0 +1,123 @@
+package source
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/http/cookiejar"
+	"net/url"
+	"os"
+	"path/filepath"
+	"sync"
+)
+
+// fileCookieJar is an in-memory cookie jar that can be loaded from and saved to a JSON file
+// File format: {"https://news.ycombinator.com": [{"name": "user", "value": "..."}]}
+// Only name/value pairs are persisted, which is enough for simple login sessions
+type fileCookieJar struct {
+	path string
+	jar  *cookiejar.Jar
+	mu   sync.Mutex
+	seen map[string]*url.URL // Origins that received cookies, needed to enumerate on Save
+}
+
+type storedCookie struct {
+	Name  string `json:"name"`
+	Value string `json:"value"`
+}
+
+// loadCookieJar creates a jar backed by path, loading existing cookies if the file exists
+func loadCookieJar(path string) (*fileCookieJar, error) {
+	jar, err := cookiejar.New(nil)
+	if err != nil {
+		return nil, err
+	}
+
+	fj := &fileCookieJar{
+		path: path,
+		jar:  jar,
+		seen: make(map[string]*url.URL),
+	}
+
+	data, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return fj, nil // Start with an empty session
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read cookie file: %w", err)
+	}
+
+	var stored map[string][]storedCookie
+	if err := json.Unmarshal(data, &stored); err != nil {
+		return nil, fmt.Errorf("invalid cookie file %s: %w", path, err)
+	}
+
+	for origin, cookies := range stored {
+		u, err := url.Parse(origin)
+		if err != nil || u.Host == "" {
+			return nil, fmt.Errorf("invalid origin in cookie file: %q", origin)
+		}
+		httpCookies := make([]*http.Cookie, 0, len(cookies))
+		for _, c := range cookies {
+			httpCookies = append(httpCookies, &http.Cookie{Name: c.Name, Value: c.Value, Path: "/"})
+		}
+		fj.SetCookies(u, httpCookies)
+	}
+
+	return fj, nil
+}
+
+// SetCookies implements http.CookieJar
+func (f *fileCookieJar) SetCookies(u *url.URL, cookies []*http.Cookie) {
+	f.mu.Lock()
+	origin := &url.URL{Scheme: u.Scheme, Host: u.Host}
+	f.seen[origin.String()] = origin
+	f.mu.Unlock()
+
+	f.jar.SetCookies(u, cookies)
+}
+
+// Cookies implements http.CookieJar
+func (f *fileCookieJar) Cookies(u *url.URL) []*http.Cookie {
+	return f.jar.Cookies(u)
+}
+
+// Save writes the current cookies to the jar file atomically
+func (f *fileCookieJar) Save() error {
+	f.mu.Lock()
+	stored := make(map[string][]storedCookie, len(f.seen))
+	for origin, u := range f.seen {
+		var cookies []storedCookie
+		for _, c := range f.jar.Cookies(u) {
+			cookies = append(cookies, storedCookie{Name: c.Name, Value: c.Value})
+		}
+		if len(cookies) > 0 {
+			stored[origin] = cookies
+		}
+	}
+	f.mu.Unlock()
+
+	data, err := json.MarshalIndent(stored, "", "  ")
+	if err != nil {
+		return err
+	}
+
+	if err := os.MkdirAll(filepath.Dir(f.path), 0o755); err != nil {
+		return err
+	}
+
+	// Write to a temp file first so a crash never leaves a truncated session
+	tmp := f.path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0o600); err != nil {
+		return err
+	}
+	return os.Rename(tmp, f.path)
+}
+
+// withCookieJar returns a shallow copy of client that uses jar (the transport stays shared)
+func withCookieJar(client *http.Client, jar http.CookieJar) *http.Client {
+	c := *client
+	c.Jar = jar
+	return &c
+}
@@ -0,0 +1,44 @@
+package source
+
+import (
+	"net/http"
+	"net/url"
+	"path/filepath"
+	"testing"
+)
+
+func TestFileCookieJar_SaveAndLoad(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "cookies", "hn.json")
+
+	// Missing file starts an empty session
+	jar, err := loadCookieJar(path)
+	if err != nil {
+		t.Fatalf("Expected no error for missing cookie file, got %v", err)
+	}
+
+	u, _ := url.Parse("https://news.ycombinator.com/login")
+	jar.SetCookies(u, []*http.Cookie{{Name: "user", Value: "alice&token", Path: "/"}})
+
+	if err := jar.Save(); err != nil {
+		t.Fatalf("Failed to save cookie jar: %v", err)
+	}
+
+	reloaded, err := loadCookieJar(path)
+	if err != nil {
+		t.Fatalf("Failed to reload cookie jar: %v", err)
+	}
+
+	item, _ := url.Parse("https://news.ycombinator.com/item?id=1")
+	cookies := reloaded.Cookies(item)
+	if len(cookies) != 1 {
+		t.Fatalf("Expected 1 cookie after reload, got %d", len(cookies))
+	}
+	if cookies[0].Name != "user" || cookies[0].Value != "alice&token" {
+		t.Errorf("Unexpected cookie after reload: %s=%s", cookies[0].Name, cookies[0].Value)
+	}
+
+	other, _ := url.Parse("https://example.com/")
+	if len(reloaded.Cookies(other)) != 0 {
+		t.Errorf("Expected cookies to stay scoped to their origin")
+	}
+}
@@ -0,0 +1,116 @@
+package source
+
+import (
+	"net/url"
+	"strings"
+	"unicode"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+// DedupKeys lists the supported dedup_key strategies
+var DedupKeys = []string{"native", "url", "title", "content"}
+
+// trackingParams are query parameters dropped when canonicalizing URLs (utm_* is matched by prefix)
+var trackingParams = map[string]bool{
+	"fbclid": true, "gclid": true, "mc_cid": true, "mc_eid": true, "ref": true, "ref_src": true,
+}
+
+// ApplyDedupKey rewrites external IDs according to the dedup_key strategy, sets each article's UID from the result
+// (db.ArticleUID with sourceType) and drops in-batch duplicates by UID
+// Articles are stored with ON CONFLICT(source_id, external_id), so the external ID decides what counts as the same item:
+//   - native: the ID the source API assigns (default, unchanged)
+//   - url: the canonical article URL, so reposts of one link collapse
+//   - title: the normalized title, for clustering syndicated news
+//   - content: a hash of the normalized content, for exact reposts under different titles
+//
+// Articles missing the chosen field keep their native ID. The native ID is kept as metadata.native_id.
+// The first article of a duplicate group wins; comments of dropped duplicates are dropped with them.
+func ApplyDedupKey(sourceType string, articles []db.Article, comments []db.Comment, strategy string) ([]db.Article, []db.Comment) {
+	if strategy == "" || strategy == "native" {
+		for i := range articles {
+			articles[i].UID = db.ArticleUID(sourceType, articles[i].ExternalID)
+		}
+		return articles, comments
+	}
+
+	kept := make([]db.Article, 0, len(articles))
+	keptIDs := make(map[string]bool, len(articles))
+	seen := make(map[string]bool, len(articles))
+	for _, article := range articles {
+		if key := dedupKey(article, strategy); key != "" {
+			setMetadata(&article, "native_id", article.ExternalID)
+			article.ExternalID = key
+		}
+		article.UID = db.ArticleUID(sourceType, article.ExternalID)
+		if seen[article.UID] {
+			continue
+		}
+		seen[article.UID] = true
+		kept = append(kept, article)
+		keptIDs[article.ID] = true
+	}
+
+	keptComments := make([]db.Comment, 0, len(comments))
+	for _, comment := range comments {
+		if keptIDs[comment.ArticleID] {
+			keptComments = append(keptComments, comment)
+		}
+	}
+
+	return kept, keptComments
+}
+
+// dedupKey returns the external ID for strategy, or "" when the article lacks the field it needs
+func dedupKey(article db.Article, strategy string) string {
+	switch strategy {
+	case "url":
+		if canonical := canonicalURL(article.URL); canonical != "" {
+			return "url:" + canonical
+		}
+	case "title":
+		if normalized := normalizeText(article.Title); normalized != "" {
+			return "title:" + db.ShortHash(normalized)
+		}
+	case "content":
+		if normalized := normalizeText(article.Content); normalized != "" {
+			return "content:" + db.ShortHash(normalized)
+		}
+	}
+	return ""
+}
+
+// canonicalURL lowercases the host, drops "www.", fragments, tracking parameters and trailing slashes
+// and sorts the remaining query parameters; http and https collapse to the same key
+func canonicalURL(raw string) string {
+	u, err := url.Parse(strings.TrimSpace(raw))
+	if err != nil || u.Host == "" {
+		return ""
+	}
+
+	host := strings.TrimPrefix(strings.ToLower(u.Hostname()), "www.")
+	if port := u.Port(); port != "" && port != "80" && port != "443" {
+		host += ":" + port
+	}
+
+	query := u.Query()
+	for name := range query {
+		if strings.HasPrefix(strings.ToLower(name), "utm_") || trackingParams[strings.ToLower(name)] {
+			query.Del(name)
+		}
+	}
+
+	canonical := host + strings.TrimRight(u.EscapedPath(), "/")
+	if encoded := query.Encode(); encoded != "" { // Encode sorts by key
+		canonical += "?" + encoded
+	}
+	return canonical
+}
+
+// normalizeText lowercases text and reduces it to words separated by single spaces
+func normalizeText(text string) string {
+	words := strings.FieldsFunc(strings.ToLower(text), func(r rune) bool {
+		return !unicode.IsLetter(r) && !unicode.IsNumber(r)
+	})
+	return strings.Join(words, " ")
+}
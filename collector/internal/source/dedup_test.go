@@ -0,0 +1,55 @@
+package source
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestCanonicalURL(t *testing.T) {
+	cases := map[string]string{
+		"https://www.Example.com/post/1/?utm_source=x&b=2&a=1#top": "example.com/post/1?a=1&b=2",
+		"http://example.com:80/post/1":                             "example.com/post/1",
+		"https://example.com:8443/post/1?fbclid=abc":               "example.com:8443/post/1",
+		"not a url":                                                "",
+	}
+	for raw, want := range cases {
+		if got := canonicalURL(raw); got != want {
+			t.Errorf("canonicalURL(%q) = %q, want %q", raw, got, want)
+		}
+	}
+}
+
+func TestApplyDedupKey(t *testing.T) {
+	articles := []db.Article{
+		{ID: "a1", ExternalID: "1", Title: "Rust 1.80 released!", URL: "https://example.com/rust?utm_medium=rss", Metadata: []byte(`{}`)},
+		{ID: "a2", ExternalID: "2", Title: "rust 1.80 Released", URL: "https://www.example.com/rust"},
+		{ID: "a3", ExternalID: "3", Title: "Go 1.23", URL: ""},
+	}
+	comments := []db.Comment{{ID: "c1", ArticleID: "a1"}, {ID: "c2", ArticleID: "a2"}}
+
+	kept, keptComments := ApplyDedupKey("feed", articles, comments, "url")
+	if len(kept) != 2 || kept[0].ExternalID != "url:example.com/rust" || kept[1].ExternalID != "3" {
+		t.Fatalf("Unexpected url dedup result: %+v", kept)
+	}
+	if kept[0].UID != db.ArticleUID("feed", "url:example.com/rust") {
+		t.Errorf("Expected the UID to follow the rewritten external ID, got %s", kept[0].UID)
+	}
+	if !strings.Contains(string(kept[0].Metadata), `"native_id":"1"`) {
+		t.Errorf("Expected native_id in metadata, got %s", kept[0].Metadata)
+	}
+	if len(keptComments) != 1 || keptComments[0].ID != "c1" {
+		t.Errorf("Expected only c1 to be kept, got %+v", keptComments)
+	}
+
+	kept, _ = ApplyDedupKey("feed", articles, comments, "title")
+	if len(kept) != 2 || !strings.HasPrefix(kept[0].ExternalID, "title:") {
+		t.Errorf("Expected titles differing only in case and punctuation to collapse, got %+v", kept)
+	}
+
+	// Without a strategy nothing changes
+	if kept, _ := ApplyDedupKey("feed", articles, comments, ""); len(kept) != 3 || kept[0].ExternalID != "1" || kept[0].UID != db.ArticleUID("feed", "1") {
+		t.Errorf("Expected native IDs to be kept, got %+v", kept)
+	}
+}
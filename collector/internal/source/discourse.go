@@ -0,0 +1,273 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+// DiscourseSource implements the Source interface for any Discourse forum
+type DiscourseSource struct {
+	source *db.Source
+	config *db.DiscourseConfig
+	client *http.Client
+}
+
+// Discourse API structures (topic list and topic JSON endpoints)
+type discourseTopicList struct {
+	Users []struct {
+		ID       int    `json:"id"`
+		Username string `json:"username"`
+	} `json:"users"`
+	TopicList struct {
+		Topics        []discourseTopic `json:"topics"`
+		MoreTopicsURL string           `json:"more_topics_url"` // e.g. "/latest?page=1", empty on the last page
+	} `json:"topic_list"`
+}
+
+type discourseTopic struct {
+	ID         int       `json:"id"`
+	Title      string    `json:"title"`
+	Slug       string    `json:"slug"`
+	PostsCount int       `json:"posts_count"`
+	ReplyCount int       `json:"reply_count"`
+	LikeCount  int       `json:"like_count"`
+	Views      int       `json:"views"`
+	CategoryID int       `json:"category_id"`
+	Tags       []string  `json:"tags"`
+	Pinned     bool      `json:"pinned"`
+	CreatedAt  time.Time `json:"created_at"`
+	Posters    []struct {
+		UserID      int    `json:"user_id"`
+		Description string `json:"description"` // "Original Poster, Most Recent Poster"
+	} `json:"posters"`
+}
+
+type discourseTopicDetail struct {
+	PostStream struct {
+		Posts []struct {
+			Username string `json:"username"`
+			Cooked   string `json:"cooked"` // Rendered HTML
+		} `json:"posts"`
+	} `json:"post_stream"`
+}
+
+// NewDiscourseSource creates a new Discourse source
+func NewDiscourseSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*DiscourseSource, error) {
+	var config db.DiscourseConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid discourse config: %w", err)
+	}
+
+	ds := &DiscourseSource{
+		source: source,
+		config: &config,
+		client: httpClient, // Shared client with pooled connections
+	}
+
+	// Public Discourse topic lists don't need credentials
+	_ = credentials
+
+	return ds, nil
+}
+
+// SourceType returns "discourse"
+func (d *DiscourseSource) SourceType() string {
+	return "discourse"
+}
+
+// Validate checks if the configuration is valid
+func (d *DiscourseSource) Validate() error {
+	if d.config.BaseURL == "" {
+		return fmt.Errorf("base_url is required")
+	}
+	u, err := url.Parse(d.config.BaseURL)
+	if err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+		return fmt.Errorf("base_url must be an absolute http(s) URL, got %q", d.config.BaseURL)
+	}
+	d.config.BaseURL = strings.TrimRight(d.config.BaseURL, "/")
+	d.config.Category = strings.Trim(d.config.Category, "/")
+
+	// Set defaults
+	if d.config.Limit <= 0 {
+		d.config.Limit = 30
+	}
+	if d.config.Limit > 100 {
+		return fmt.Errorf("limit must be <= 100, got %d", d.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch retrieves recently active topics and their opening posts
+func (d *DiscourseSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := d.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	topics, usernames, err := d.fetchTopics(ctx, since)
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to fetch topics: %w", err)
+	}
+
+	articles := make([]db.Article, 0, len(topics))
+	for _, topic := range topics {
+		content, author, err := d.fetchOpeningPost(ctx, topic.ID)
+		if err != nil {
+			// Keep the topic with an empty body rather than failing the whole run
+			content = ""
+		}
+		if author == "" {
+			author = usernames[originalPoster(topic)]
+		}
+
+		articles = append(articles, d.topicToArticle(topic, author, content))
+	}
+
+	// Replies are not fetched, reply_count is kept as num_comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchTopics walks the latest (or category) topic list until the limit is reached
+// Lists are ordered by last activity, so topics created before since are skipped rather than ending the walk
+func (d *DiscourseSource) fetchTopics(ctx context.Context, since time.Time) ([]discourseTopic, map[int]string, error) {
+	path := "/latest.json"
+	if d.config.Category != "" {
+		path = "/c/" + d.config.Category + "/l/latest.json"
+	}
+
+	var topics []discourseTopic
+	usernames := make(map[int]string)
+
+	for page := 0; len(topics) < d.config.Limit; page++ {
+		body, err := d.get(ctx, fmt.Sprintf("%s%s?page=%d", d.config.BaseURL, path, page))
+		if err != nil {
+			return nil, nil, err
+		}
+
+		var list discourseTopicList
+		if err := json.Unmarshal(body, &list); err != nil {
+			return nil, nil, fmt.Errorf("failed to decode topic list: %w", err)
+		}
+
+		for _, user := range list.Users {
+			usernames[user.ID] = user.Username
+		}
+
+		for _, topic := range list.TopicList.Topics {
+			if len(topics) >= d.config.Limit {
+				break
+			}
+			// Pinned "about this category" topics show up on every listing
+			if topic.Pinned {
+				continue
+			}
+			if topic.CreatedAt.Before(since) {
+				continue
+			}
+			topics = append(topics, topic)
+		}
+
+		if list.TopicList.MoreTopicsURL == "" || len(list.TopicList.Topics) == 0 {
+			break
+		}
+	}
+
+	return topics, usernames, nil
+}
+
+// fetchOpeningPost returns the rendered HTML and author of a topic's first post
+func (d *DiscourseSource) fetchOpeningPost(ctx context.Context, topicID int) (string, string, error) {
+	body, err := d.get(ctx, fmt.Sprintf("%s/t/%d.json", d.config.BaseURL, topicID))
+	if err != nil {
+		return "", "", err
+	}
+
+	var detail discourseTopicDetail
+	if err := json.Unmarshal(body, &detail); err != nil {
+		return "", "", fmt.Errorf("failed to decode topic %d: %w", topicID, err)
+	}
+	if len(detail.PostStream.Posts) == 0 {
+		return "", "", nil
+	}
+
+	first := detail.PostStream.Posts[0]
+	return first.Cooked, first.Username, nil
+}
+
+// get performs a rate-limited GET request against the forum and returns the body
+func (d *DiscourseSource) get(ctx context.Context, u string) ([]byte, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", u, nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	req.Header.Set("Accept", "application/json")
+
+	resp, err := d.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("Discourse returned %d", resp.StatusCode)
+	}
+
+	return body, nil
+}
+
+// topicToArticle converts a Discourse topic to an Article
+func (d *DiscourseSource) topicToArticle(topic discourseTopic, author, content string) db.Article {
+	metadata, err := json.Marshal(map[string]interface{}{
+		"score":        topic.LikeCount,
+		"num_comments": topic.ReplyCount,
+		"views":        topic.Views,
+		"category_id":  topic.CategoryID,
+		"forum_tags":   topic.Tags,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   d.source.ID,
+		ExternalID: fmt.Sprintf("%s/t/%d", d.config.BaseURL, topic.ID),
+		Title:      topic.Title,
+		Author:     author,
+		Content:    content,
+		URL:        fmt.Sprintf("%s/t/%s/%d", d.config.BaseURL, topic.Slug, topic.ID),
+		WrittenAt:  topic.CreatedAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// originalPoster returns the user ID flagged as "Original Poster" in a topic's poster list
+func originalPoster(topic discourseTopic) int {
+	for _, poster := range topic.Posters {
+		if strings.Contains(poster.Description, "Original Poster") {
+			return poster.UserID
+		}
+	}
+	return 0
+}
@@ -0,0 +1,36 @@
+package source
+
+import (
+	"encoding/json"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+// EnrichmentError records a secondary fetch that failed for one article, which was kept without that data
+// Stored as a list under the "enrichment_errors" metadata key
+type EnrichmentError struct {
+	Stage string `json:"stage"` // What was missed: "comments", "page_views" or "download_count"
+	Error string `json:"error"`
+}
+
+// addEnrichmentError appends a failed stage to the article's enrichment_errors
+func addEnrichmentError(article *db.Article, stage string, err error) {
+	var metadata struct {
+		EnrichmentErrors []EnrichmentError `json:"enrichment_errors"`
+	}
+	if len(article.Metadata) > 0 {
+		json.Unmarshal(article.Metadata, &metadata) // A malformed list starts over
+	}
+	setMetadata(article, "enrichment_errors", append(metadata.EnrichmentErrors, EnrichmentError{Stage: stage, Error: err.Error()}))
+}
+
+// EnrichmentFailures counts the articles that carry enrichment_errors
+func EnrichmentFailures(articles []db.Article) int {
+	n := 0
+	for _, article := range articles {
+		if hasMetadata(article, "enrichment_errors") {
+			n++
+		}
+	}
+	return n
+}
@@ -0,0 +1,425 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"encoding/xml"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	feedDefaultLimit = 20
+	feedMaxLimit     = 200
+	feedMaxFeeds     = 500 // Per source, including feeds expanded from OPML
+	feedContentLen   = 5000
+)
+
+// feedDateLayouts are the date formats seen in RSS <pubDate>, <dc:date> and Atom timestamps
+var feedDateLayouts = []string{
+	time.RFC1123Z,
+	time.RFC1123,
+	time.RFC3339,
+	"Mon, 2 Jan 2006 15:04:05 -0700",
+	"Mon, 2 Jan 2006 15:04:05 MST",
+	"2 Jan 2006 15:04:05 -0700",
+	"2006-01-02",
+}
+
+// FeedSource implements the Source interface for RSS and Atom feeds, listed directly or through an OPML export
+type FeedSource struct {
+	source *db.Source
+	config *db.FeedConfig
+	client *http.Client
+	feeds  []feedRef // URLs plus the feeds expanded from opml_path
+}
+
+// feedRef is one feed to fetch
+type feedRef struct {
+	url      string
+	title    string // From the OPML outline, if any
+	category string // Enclosing OPML folder, if any
+}
+
+// feedItem is one entry from either format, normalized before conversion
+type feedItem struct {
+	id        string
+	title     string
+	link      string
+	author    string
+	content   string // HTML
+	published time.Time
+}
+
+// feedDoc decodes RSS 2.0, RSS 1.0 (RDF) and Atom documents
+type feedDoc struct {
+	XMLName xml.Name
+	Channel struct {
+		Title string    `xml:"title"`
+		Items []rssItem `xml:"item"`
+	} `xml:"channel"`
+	Items   []rssItem   `xml:"item"`  // RSS 1.0 puts items beside the channel
+	Title   string      `xml:"title"` // Atom
+	Entries []atomEntry `xml:"entry"` // Atom
+}
+
+type rssItem struct {
+	Title       string `xml:"title"`
+	Link        string `xml:"link"`
+	GUID        string `xml:"guid"`
+	Description string `xml:"description"`
+	Encoded     string `xml:"http://purl.org/rss/1.0/modules/content/ encoded"`
+	Author      string `xml:"author"`
+	Creator     string `xml:"http://purl.org/dc/elements/1.1/ creator"`
+	PubDate     string `xml:"pubDate"`
+	Date        string `xml:"http://purl.org/dc/elements/1.1/ date"`
+}
+
+type atomEntry struct {
+	ID    string `xml:"id"`
+	Title string `xml:"title"`
+	Links []struct {
+		Href string `xml:"href,attr"`
+		Rel  string `xml:"rel,attr"`
+	} `xml:"link"`
+	Summary   string `xml:"summary"`
+	Content   string `xml:"content"`
+	Author    string `xml:"author>name"`
+	Published string `xml:"published"`
+	Updated   string `xml:"updated"`
+}
+
+// OPML structures (outlines nest for folders)
+type opmlDoc struct {
+	Outlines []opmlOutline `xml:"body>outline"`
+}
+
+type opmlOutline struct {
+	Text     string        `xml:"text,attr"`
+	Title    string        `xml:"title,attr"`
+	XMLURL   string        `xml:"xmlUrl,attr"`
+	Outlines []opmlOutline `xml:"outline"`
+}
+
+// NewFeedSource creates a new feed source
+// An OPML export is expanded into its feeds here, so a changed export takes effect on the next run
+func NewFeedSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*FeedSource, error) {
+	var config db.FeedConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid feed config: %w", err)
+	}
+
+	fs := &FeedSource{
+		source: source,
+		config: &config,
+		client: httpClient, // Shared client with pooled connections
+	}
+
+	for _, u := range config.URLs {
+		fs.feeds = append(fs.feeds, feedRef{url: u})
+	}
+	if config.OPMLPath != "" {
+		if credentials.FileSourceRoot == "" {
+			return nil, fmt.Errorf("COLLECTOR_FILE_SOURCE_ROOT is required for opml_path")
+		}
+		feeds, err := loadOPML(credentials.FileSourceRoot, config.OPMLPath)
+		if err != nil {
+			return nil, fmt.Errorf("invalid opml_path: %w", err)
+		}
+		fs.feeds = append(fs.feeds, feeds...)
+	}
+
+	return fs, nil
+}
+
+// SourceType returns "feed"
+func (f *FeedSource) SourceType() string {
+	return "feed"
+}
+
+// Validate checks if the configuration is valid
+func (f *FeedSource) Validate() error {
+	if len(f.config.URLs) == 0 && f.config.OPMLPath == "" {
+		return fmt.Errorf("urls or opml_path is required")
+	}
+	for _, u := range f.config.URLs {
+		if !isHTTPURL(u) {
+			return fmt.Errorf("urls must be absolute http(s) URLs, got %q", u)
+		}
+	}
+	if len(f.feeds) == 0 {
+		return fmt.Errorf("opml_path lists no feeds")
+	}
+	if len(f.feeds) > feedMaxFeeds {
+		return fmt.Errorf("at most %d feeds per source, got %d", feedMaxFeeds, len(f.feeds))
+	}
+
+	// Set defaults
+	if f.config.Limit <= 0 {
+		f.config.Limit = feedDefaultLimit
+	}
+	if f.config.Limit > feedMaxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", feedMaxLimit, f.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch retrieves entries published since the last run from every feed
+// A failing feed is logged and skipped; the fetch fails only when every feed does
+func (f *FeedSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := f.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	articles := make([]db.Article, 0)
+	var firstErr error
+	failed := 0
+	for _, feed := range f.feeds {
+		pageStart := len(articles)
+		feedArticles, err := f.fetchFeed(ctx, feed, since)
+		if err != nil {
+			if ctx.Err() != nil {
+				return nil, nil, ctx.Err()
+			}
+			slog.Warn("Failed to fetch feed", "source_id", f.source.ID, "url", feed.url, "error", err)
+			if firstErr == nil {
+				firstErr = fmt.Errorf("failed to fetch %s: %w", feed.url, err)
+			}
+			failed++
+			continue
+		}
+		articles = append(articles, feedArticles...)
+		stampProvenance(ctx, articles[pageStart:], 0)
+	}
+	if failed == len(f.feeds) {
+		return nil, nil, firstErr
+	}
+
+	// Feeds have no comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchFeed fetches one feed and converts its entries published since the last run
+func (f *FeedSource) fetchFeed(ctx context.Context, feed feedRef, since time.Time) ([]db.Article, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", feed.url, nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	req.Header.Set("Accept", "application/rss+xml, application/atom+xml, application/xml;q=0.9, */*;q=0.8")
+
+	resp, err := f.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentXML)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("feed returned %d", resp.StatusCode)
+	}
+
+	title, items, err := parseFeed(body)
+	if err != nil {
+		return nil, err
+	}
+	if feed.title == "" {
+		feed.title = title
+	}
+
+	articles := make([]db.Article, 0, len(items))
+	for _, item := range items {
+		if len(articles) >= f.config.Limit {
+			break
+		}
+		// Undated entries are kept; already stored ones are deduplicated on insert
+		if !item.published.IsZero() && item.published.Before(since) {
+			continue
+		}
+		articles = append(articles, f.itemToArticle(feed, item))
+	}
+
+	return articles, nil
+}
+
+// parseFeed decodes an RSS or Atom document into its title and entries
+func parseFeed(body []byte) (string, []feedItem, error) {
+	var doc feedDoc
+	if err := xml.Unmarshal(body, &doc); err != nil {
+		return "", nil, fmt.Errorf("failed to decode feed: %w", err)
+	}
+
+	var items []feedItem
+	switch doc.XMLName.Local {
+	case "rss", "RDF":
+		title := doc.Channel.Title
+		for _, it := range append(doc.Channel.Items, doc.Items...) {
+			content := it.Encoded
+			if content == "" {
+				content = it.Description
+			}
+			author := it.Creator
+			if author == "" {
+				author = it.Author
+			}
+			date := it.PubDate
+			if date == "" {
+				date = it.Date
+			}
+			items = append(items, feedItem{
+				id:        strings.TrimSpace(it.GUID),
+				title:     it.Title,
+				link:      strings.TrimSpace(it.Link),
+				author:    author,
+				content:   content,
+				published: parseFeedDate(date),
+			})
+		}
+		return collapseSpace(title), items, nil
+
+	case "feed":
+		for _, entry := range doc.Entries {
+			link := ""
+			for _, l := range entry.Links {
+				if l.Rel == "" || l.Rel == "alternate" {
+					link = l.Href
+					break
+				}
+			}
+			content := entry.Content
+			if content == "" {
+				content = entry.Summary
+			}
+			date := entry.Published
+			if date == "" {
+				date = entry.Updated
+			}
+			items = append(items, feedItem{
+				id:        strings.TrimSpace(entry.ID),
+				title:     entry.Title,
+				link:      strings.TrimSpace(link),
+				author:    entry.Author,
+				content:   content,
+				published: parseFeedDate(date),
+			})
+		}
+		return collapseSpace(doc.Title), items, nil
+	}
+
+	return "", nil, fmt.Errorf("not an RSS or Atom feed (root element <%s>)", doc.XMLName.Local)
+}
+
+// parseFeedDate parses a feed timestamp, returning the zero time when no layout matches
+func parseFeedDate(value string) time.Time {
+	value = strings.TrimSpace(value)
+	for _, layout := range feedDateLayouts {
+		if t, err := time.Parse(layout, value); err == nil {
+			return t
+		}
+	}
+	return time.Time{}
+}
+
+// loadOPML reads an OPML export under root and returns its feeds, folders flattened
+// Outlines without an http(s) xmlUrl are folders or links and are skipped; duplicate feeds are listed once
+func loadOPML(root, opmlPath string) ([]feedRef, error) {
+	_, target, err := resolveUnderRoot(root, opmlPath)
+	if err != nil {
+		return nil, err
+	}
+	data, err := os.ReadFile(target)
+	if err != nil {
+		return nil, err
+	}
+
+	var doc opmlDoc
+	if err := xml.Unmarshal(data, &doc); err != nil {
+		return nil, fmt.Errorf("failed to decode OPML: %w", err)
+	}
+
+	var feeds []feedRef
+	seen := make(map[string]bool)
+	var walk func(outlines []opmlOutline, category string)
+	walk = func(outlines []opmlOutline, category string) {
+		for _, o := range outlines {
+			name := o.Title
+			if name == "" {
+				name = o.Text
+			}
+			if isHTTPURL(o.XMLURL) && !seen[o.XMLURL] {
+				seen[o.XMLURL] = true
+				feeds = append(feeds, feedRef{url: o.XMLURL, title: name, category: category})
+			}
+			if len(o.Outlines) > 0 {
+				walk(o.Outlines, name)
+			}
+		}
+	}
+	walk(doc.Outlines, "")
+
+	return feeds, nil
+}
+
+// itemToArticle converts a feed entry to an Article
+func (f *FeedSource) itemToArticle(feed feedRef, item feedItem) db.Article {
+	externalID := item.id
+	if externalID == "" {
+		externalID = item.link
+	}
+	if externalID == "" {
+		externalID = db.ShortHash(feed.url + "\n" + item.title)
+	}
+
+	writtenAt := item.published
+	if writtenAt.IsZero() {
+		writtenAt = time.Now()
+	}
+
+	meta := map[string]interface{}{
+		"feed_url":   feed.url,
+		"feed_title": feed.title,
+	}
+	if feed.category != "" {
+		meta["category"] = feed.category
+	}
+	metadata, err := json.Marshal(meta)
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	author := collapseSpace(item.author)
+	if author == "" {
+		author = feed.title
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   f.source.ID,
+		ExternalID: externalID,
+		Title:      htmlText(item.title),
+		Author:     author,
+		Content:    truncateRunes(htmlText(item.content), feedContentLen),
+		URL:        item.link,
+		WrittenAt:  writtenAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
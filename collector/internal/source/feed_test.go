@@ -0,0 +1,110 @@
+package source
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+)
+
+func TestParseFeed(t *testing.T) {
+	rss := `<?xml version="1.0"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <channel>
+    <title> Go Blog </title>
+    <item>
+      <title>Go 1.24 is released</title>
+      <link>https://go.dev/blog/go1.24</link>
+      <guid>tag:go.dev,2025:go1.24</guid>
+      <description>Short summary</description>
+      <content:encoded><![CDATA[<p>Full <b>post</b></p>]]></content:encoded>
+      <dc:creator>Ada</dc:creator>
+      <pubDate>Tue, 11 Feb 2025 10:00:00 +0000</pubDate>
+    </item>
+  </channel>
+</rss>`
+
+	title, items, err := parseFeed([]byte(rss))
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if title != "Go Blog" || len(items) != 1 {
+		t.Fatalf("Unexpected feed: %q, %+v", title, items)
+	}
+	item := items[0]
+	if item.id != "tag:go.dev,2025:go1.24" || item.author != "Ada" || item.content != "<p>Full <b>post</b></p>" {
+		t.Errorf("Unexpected RSS item: %+v", item)
+	}
+	if want := time.Date(2025, 2, 11, 10, 0, 0, 0, time.UTC); !item.published.Equal(want) {
+		t.Errorf("Expected published %v, got %v", want, item.published)
+	}
+
+	atom := `<?xml version="1.0"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Rust Blog</title>
+  <entry>
+    <id>urn:rust:1.85</id>
+    <title>Announcing Rust 1.85</title>
+    <link rel="self" href="https://blog.rust-lang.org/feed.xml"/>
+    <link href="https://blog.rust-lang.org/1.85"/>
+    <summary>Edition 2024</summary>
+    <author><name>The Release Team</name></author>
+    <updated>2025-02-20T00:00:00Z</updated>
+  </entry>
+</feed>`
+
+	title, items, err = parseFeed([]byte(atom))
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if title != "Rust Blog" || len(items) != 1 {
+		t.Fatalf("Unexpected feed: %q, %+v", title, items)
+	}
+	item = items[0]
+	if item.link != "https://blog.rust-lang.org/1.85" || item.author != "The Release Team" || item.content != "Edition 2024" || item.published.IsZero() {
+		t.Errorf("Unexpected Atom entry: %+v", item)
+	}
+
+	if _, _, err := parseFeed([]byte(`<html><body></body></html>`)); err == nil {
+		t.Error("Expected an error for a non-feed document")
+	}
+}
+
+func TestLoadOPML(t *testing.T) {
+	root := t.TempDir()
+	opml := `<?xml version="1.0"?>
+<opml version="2.0">
+  <body>
+    <outline text="Go Blog" type="rss" xmlUrl="https://go.dev/blog/feed.atom"/>
+    <outline text="Research">
+      <outline title="arXiv cs.LG" text="cs.LG" type="rss" xmlUrl="https://rss.arxiv.org/rss/cs.LG"/>
+      <outline text="Go Blog again" type="rss" xmlUrl="https://go.dev/blog/feed.atom"/>
+      <outline text="Local" type="rss" xmlUrl="file:///etc/passwd"/>
+    </outline>
+  </body>
+</opml>`
+	if err := os.WriteFile(filepath.Join(root, "subs.opml"), []byte(opml), 0o644); err != nil {
+		t.Fatal(err)
+	}
+
+	feeds, err := loadOPML(root, "subs.opml")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	want := []feedRef{
+		{url: "https://go.dev/blog/feed.atom", title: "Go Blog"},
+		{url: "https://rss.arxiv.org/rss/cs.LG", title: "arXiv cs.LG", category: "Research"},
+	}
+	if len(feeds) != len(want) {
+		t.Fatalf("Expected %d feeds, got %+v", len(want), feeds)
+	}
+	for i := range want {
+		if feeds[i] != want[i] {
+			t.Errorf("Feed %d: expected %+v, got %+v", i, want[i], feeds[i])
+		}
+	}
+
+	if _, err := loadOPML(root, "missing.opml"); err == nil {
+		t.Error("Expected an error for a missing export")
+	}
+}
@@ -0,0 +1,312 @@
+package source
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io/fs"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	fileMaxSize     = 50 << 20 // Larger files are skipped
+	fileMaxLineSize = 10 << 20 // Longest JSONL record
+)
+
+// FileSource implements the Source interface for local files: exported JSON/JSONL articles or Markdown/text notes
+// Replays stored data through keyword filtering and curation without network access
+type FileSource struct {
+	source *db.Source
+	config *db.FileConfig
+	root   string // From global config (environment variable), the only directory file sources may read
+}
+
+// fileRecord is one exported article; the db.Article and GET /articles shapes both decode into it
+type fileRecord struct {
+	ID         string          `json:"id"`
+	ExternalID string          `json:"external_id"`
+	Title      string          `json:"title"`
+	Author     string          `json:"author"`
+	Content    string          `json:"content"`
+	URL        string          `json:"url"`
+	WrittenAt  time.Time       `json:"written_at"`
+	Metadata   json.RawMessage `json:"metadata"`
+}
+
+// NewFileSource creates a new file source
+// Reads are confined to the file source root from config file
+func NewFileSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+) (*FileSource, error) {
+	var config db.FileConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid file config: %w", err)
+	}
+
+	fl := &FileSource{
+		source: source,
+		config: &config,
+		root:   credentials.FileSourceRoot,
+	}
+
+	return fl, nil
+}
+
+// SourceType returns "file"
+func (f *FileSource) SourceType() string {
+	return "file"
+}
+
+// Validate checks if the configuration is valid
+func (f *FileSource) Validate() error {
+	if f.root == "" {
+		return fmt.Errorf("COLLECTOR_FILE_SOURCE_ROOT is required for file sources")
+	}
+	if strings.TrimSpace(f.config.Path) == "" {
+		return fmt.Errorf("path is required")
+	}
+	return nil
+}
+
+// Fetch reads every supported file under the configured path that changed since the last run
+// Records inside a changed JSON/JSONL file are all re-emitted; already stored ones are deduplicated on insert
+func (f *FileSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := f.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	root, target, err := f.resolve()
+	if err != nil {
+		return nil, nil, err
+	}
+	f.root = root // Article paths are reported relative to the resolved root
+
+	articles := make([]db.Article, 0)
+	err = filepath.WalkDir(target, func(path string, entry fs.DirEntry, err error) error {
+		if err != nil {
+			return err
+		}
+		if ctx.Err() != nil {
+			return ctx.Err()
+		}
+		if entry.IsDir() || fileFormat(path) == "" {
+			return nil
+		}
+
+		info, err := entry.Info()
+		if err != nil {
+			return err
+		}
+		if info.Size() > fileMaxSize || info.ModTime().Before(since) {
+			return nil
+		}
+
+		fileArticles, err := f.readFile(path, info)
+		if err != nil {
+			return fmt.Errorf("failed to read %s: %w", path, err)
+		}
+		articles = append(articles, fileArticles...)
+		return nil
+	})
+	if err != nil {
+		return nil, nil, err
+	}
+
+	// Exports are read without their comments
+	return articles, []db.Comment{}, nil
+}
+
+// resolve returns the root and the configured path inside it, both with symlinks followed
+func (f *FileSource) resolve() (root, target string, err error) {
+	return resolveUnderRoot(f.root, f.config.Path)
+}
+
+// resolveUnderRoot returns rootDir and rel inside it, both with symlinks followed
+// Paths that would leave the root (via ".." or a symlink) are rejected
+func resolveUnderRoot(rootDir, rel string) (root, target string, err error) {
+	root, err = filepath.EvalSymlinks(rootDir)
+	if err != nil {
+		return "", "", fmt.Errorf("invalid COLLECTOR_FILE_SOURCE_ROOT: %w", err)
+	}
+
+	target, err = filepath.EvalSymlinks(filepath.Join(root, filepath.Clean("/"+rel)))
+	if err != nil {
+		return "", "", fmt.Errorf("invalid path: %w", err)
+	}
+	if r, err := filepath.Rel(root, target); err != nil || r == ".." || strings.HasPrefix(r, ".."+string(filepath.Separator)) {
+		return "", "", fmt.Errorf("path %q is outside COLLECTOR_FILE_SOURCE_ROOT", rel)
+	}
+	return root, target, nil
+}
+
+// fileFormat maps a file extension to "jsonl", "json" or "text", or "" for unsupported files
+func fileFormat(path string) string {
+	switch strings.ToLower(filepath.Ext(path)) {
+	case ".jsonl", ".ndjson":
+		return "jsonl"
+	case ".json":
+		return "json"
+	case ".md", ".markdown", ".txt":
+		return "text"
+	}
+	return ""
+}
+
+// readFile converts one file to articles according to its format
+func (f *FileSource) readFile(path string, info fs.FileInfo) ([]db.Article, error) {
+	switch fileFormat(path) {
+	case "jsonl":
+		return f.readJSONL(path)
+	case "json":
+		return f.readJSON(path)
+	default:
+		data, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+		return []db.Article{f.textToArticle(path, string(data), info.ModTime())}, nil
+	}
+}
+
+// readJSONL reads one exported article per line, skipping blank lines
+func (f *FileSource) readJSONL(path string) ([]db.Article, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer file.Close()
+
+	var articles []db.Article
+	scanner := bufio.NewScanner(file)
+	scanner.Buffer(make([]byte, 64*1024), fileMaxLineSize)
+	for line := 1; scanner.Scan(); line++ {
+		if strings.TrimSpace(scanner.Text()) == "" {
+			continue
+		}
+		var record fileRecord
+		if err := json.Unmarshal(scanner.Bytes(), &record); err != nil {
+			return nil, fmt.Errorf("line %d: %w", line, err)
+		}
+		if article, ok := f.recordToArticle(record); ok {
+			articles = append(articles, article)
+		}
+	}
+	return articles, scanner.Err()
+}
+
+// readJSON reads an array of exported articles, or a GET /articles response ({"articles": [...]})
+func (f *FileSource) readJSON(path string) ([]db.Article, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+
+	var records []fileRecord
+	if err := json.Unmarshal(data, &records); err != nil {
+		var list struct {
+			Articles []fileRecord `json:"articles"`
+		}
+		if err := json.Unmarshal(data, &list); err != nil {
+			return nil, fmt.Errorf("expected an array of articles or an object with \"articles\": %w", err)
+		}
+		records = list.Articles
+	}
+
+	articles := make([]db.Article, 0, len(records))
+	for _, record := range records {
+		if article, ok := f.recordToArticle(record); ok {
+			articles = append(articles, article)
+		}
+	}
+	return articles, nil
+}
+
+// recordToArticle converts an exported article, keeping its original identity and metadata
+// Records with neither a title nor content are skipped
+func (f *FileSource) recordToArticle(record fileRecord) (db.Article, bool) {
+	if record.Title == "" && record.Content == "" {
+		return db.Article{}, false
+	}
+
+	externalID := record.ExternalID
+	if externalID == "" {
+		externalID = record.URL
+	}
+	if externalID == "" {
+		externalID = record.ID
+	}
+	if externalID == "" {
+		externalID = db.ShortHash(record.Title + "\n" + record.Content)
+	}
+
+	writtenAt := record.WrittenAt
+	if writtenAt.IsZero() {
+		writtenAt = time.Now()
+	}
+
+	metadata := []byte(record.Metadata)
+	if len(metadata) == 0 || string(metadata) == "null" {
+		metadata = []byte("{}")
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   f.source.ID,
+		ExternalID: externalID,
+		Title:      record.Title,
+		Author:     record.Author,
+		Content:    record.Content,
+		URL:        record.URL,
+		WrittenAt:  writtenAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}, true
+}
+
+// textToArticle converts a Markdown or text file to an Article
+// The title is the first Markdown heading, or else the file name
+func (f *FileSource) textToArticle(path, text string, modTime time.Time) db.Article {
+	title := strings.TrimSuffix(filepath.Base(path), filepath.Ext(path))
+	for _, line := range strings.Split(text, "\n") {
+		if heading, ok := strings.CutPrefix(strings.TrimSpace(line), "# "); ok {
+			title = strings.TrimSpace(heading)
+			break
+		}
+	}
+
+	rel, err := filepath.Rel(f.root, path)
+	if err != nil {
+		rel = path
+	}
+	hash := db.ShortHash(text)
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"path":         rel,
+		"content_hash": hash,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   f.source.ID,
+		ExternalID: rel + "@" + hash[:12], // An edited file is a new item
+		Title:      title,
+		Content:    strings.TrimSpace(text),
+		URL:        (&url.URL{Scheme: "file", Path: filepath.ToSlash(path)}).String(),
+		WrittenAt:  modTime,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
@@ -0,0 +1,91 @@
+package source
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestFileSource_Fetch(t *testing.T) {
+	root := t.TempDir()
+	dir := filepath.Join(root, "replay")
+	if err := os.MkdirAll(filepath.Join(dir, "notes"), 0o755); err != nil {
+		t.Fatal(err)
+	}
+	files := map[string]string{
+		"export.jsonl": `{"external_id":"abc","title":"Go 1.24","content":"Released","url":"https://go.dev","written_at":"2024-11-15T08:00:00Z","metadata":{"score":5}}` + "\n\n" +
+			`{"title":"","content":""}` + "\n",
+		"list.json":        `{"articles":[{"id":"x1","title":"From the API"}],"has_more":false}`,
+		"notes/idea.md":    "intro\n# Rust notes\nBody text\n",
+		"notes/ignored.go": "package main",
+	}
+	for name, content := range files {
+		if err := os.WriteFile(filepath.Join(dir, name), []byte(content), 0o644); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	src := &FileSource{source: &db.Source{ID: "src"}, config: &db.FileConfig{Path: "replay"}, root: root}
+	articles, _, err := src.Fetch(context.Background(), time.Unix(0, 0))
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	byTitle := make(map[string]db.Article)
+	for _, article := range articles {
+		byTitle[article.Title] = article
+	}
+	if len(articles) != 3 {
+		t.Fatalf("Expected 3 articles, got %d: %+v", len(articles), articles)
+	}
+	if a := byTitle["Go 1.24"]; a.ExternalID != "abc" || string(a.Metadata) != `{"score":5}` || a.WrittenAt.Year() != 2024 {
+		t.Errorf("Unexpected JSONL article: %+v", a)
+	}
+	if a := byTitle["From the API"]; a.ExternalID != "x1" || string(a.Metadata) != "{}" {
+		t.Errorf("Unexpected JSON article: %+v", a)
+	}
+	if a := byTitle["Rust notes"]; a.Content != "intro\n# Rust notes\nBody text" || a.ExternalID[:len("replay/notes/idea.md@")] != "replay/notes/idea.md@" {
+		t.Errorf("Unexpected note article: %+v", a)
+	}
+
+	// Nothing changed since the last run
+	articles, _, err = src.Fetch(context.Background(), time.Now().Add(time.Hour))
+	if err != nil || len(articles) != 0 {
+		t.Errorf("Expected no articles for unchanged files, got %d (err %v)", len(articles), err)
+	}
+}
+
+func TestFileSource_RejectsPathsOutsideRoot(t *testing.T) {
+	parent := t.TempDir()
+	root := filepath.Join(parent, "root")
+	if err := os.Mkdir(root, 0o755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Symlink(parent, filepath.Join(root, "escape")); err != nil {
+		t.Skipf("Symlinks unavailable: %v", err)
+	}
+
+	src := &FileSource{source: &db.Source{ID: "src"}, config: &db.FileConfig{Path: "escape"}, root: root}
+	if _, _, err := src.Fetch(context.Background(), time.Unix(0, 0)); err == nil {
+		t.Error("Expected a symlink out of the root to be rejected")
+	}
+
+	// ".." is cleaned away, so it stays inside the root
+	src = &FileSource{source: &db.Source{ID: "src"}, config: &db.FileConfig{Path: "../../"}, root: root}
+	if _, target, err := src.resolve(); err != nil || target != mustEvalSymlinks(t, root) {
+		t.Errorf("Expected .. to resolve to the root, got %q (err %v)", target, err)
+	}
+}
+
+func mustEvalSymlinks(t *testing.T, path string) string {
+	t.Helper()
+	resolved, err := filepath.EvalSymlinks(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	return resolved
+}
@@ -0,0 +1,448 @@
+package source
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+// GitHubSource implements the Source interface for GitHub
+type GitHubSource struct {
+	source *db.Source
+	config *db.GitHubConfig
+	client *http.Client
+	token  string // From global config (environment variable)
+}
+
+// GitHub API response structures
+type ghRepoSearchResponse struct {
+	TotalCount int      `json:"total_count"`
+	Items      []ghRepo `json:"items"`
+}
+
+type ghRepo struct {
+	ID              int64     `json:"id"`
+	FullName        string    `json:"full_name"`
+	Description     string    `json:"description"`
+	HTMLURL         string    `json:"html_url"`
+	StargazersCount int       `json:"stargazers_count"`
+	ForksCount      int       `json:"forks_count"`
+	OpenIssuesCount int       `json:"open_issues_count"`
+	Language        string    `json:"language"`
+	Topics          []string  `json:"topics"`
+	CreatedAt       time.Time `json:"created_at"`
+	Owner           ghUser    `json:"owner"`
+}
+
+type ghUser struct {
+	Login string `json:"login"`
+}
+
+type ghIssue struct {
+	Number      int              `json:"number"`
+	Title       string           `json:"title"`
+	Body        string           `json:"body"`
+	HTMLURL     string           `json:"html_url"`
+	State       string           `json:"state"`
+	Comments    int              `json:"comments"`
+	CreatedAt   time.Time        `json:"created_at"`
+	User        ghUser           `json:"user"`
+	PullRequest *json.RawMessage `json:"pull_request"` // Present when the issue is a pull request
+	Reactions   struct {
+		TotalCount int `json:"total_count"`
+	} `json:"reactions"`
+}
+
+type ghDiscussionsResponse struct {
+	Data struct {
+		Repository *struct {
+			Discussions struct {
+				Nodes []ghDiscussion `json:"nodes"`
+			} `json:"discussions"`
+		} `json:"repository"`
+	} `json:"data"`
+	Errors []struct {
+		Message string `json:"message"`
+	} `json:"errors"`
+}
+
+type ghDiscussion struct {
+	Number      int       `json:"number"`
+	Title       string    `json:"title"`
+	Body        string    `json:"body"`
+	URL         string    `json:"url"`
+	CreatedAt   time.Time `json:"createdAt"`
+	UpvoteCount int       `json:"upvoteCount"`
+	Author      *ghUser   `json:"author"` // Null for deleted accounts
+	Comments    struct {
+		TotalCount int `json:"totalCount"`
+	} `json:"comments"`
+}
+
+// ghDiscussionsQuery fetches the most recent discussions of a repository
+const ghDiscussionsQuery = `query($owner: String!, $name: String!, $first: Int!) {
+  repository(owner: $owner, name: $name) {
+    discussions(first: $first, orderBy: {field: CREATED_AT, direction: DESC}) {
+      nodes { number title body url createdAt upvoteCount author { login } comments { totalCount } }
+    }
+  }
+}`
+
+// NewGitHubSource creates a new GitHub source
+// Uses the optional token from config file
+func NewGitHubSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*GitHubSource, error) {
+	var config db.GitHubConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid github config: %w", err)
+	}
+
+	gs := &GitHubSource{
+		source: source,
+		config: &config,
+		client: httpClient,              // Shared client with pooled connections
+		token:  credentials.GitHubToken, // Optional, raises rate limits
+	}
+
+	return gs, nil
+}
+
+// SourceType returns "github"
+func (g *GitHubSource) SourceType() string {
+	return "github"
+}
+
+// Validate checks if the configuration is valid
+func (g *GitHubSource) Validate() error {
+	if err := validateEnum(g.config.Mode, []string{"search", "issues", "discussions"}, "mode"); err != nil {
+		return err
+	}
+
+	if g.config.Mode == "search" {
+		if g.config.Query == "" {
+			return fmt.Errorf("query is required for search mode")
+		}
+		if g.config.Sort != "" {
+			if err := validateEnum(g.config.Sort, []string{"stars", "forks", "updated"}, "sort"); err != nil {
+				return err
+			}
+		}
+	} else {
+		if _, _, ok := splitRepo(g.config.Repo); !ok {
+			return fmt.Errorf("repo must be in 'owner/name' format for %s mode", g.config.Mode)
+		}
+	}
+
+	// GraphQL API (used for discussions) rejects anonymous requests
+	if g.config.Mode == "discussions" && g.token == "" {
+		return fmt.Errorf("discussions mode requires COLLECTOR_GITHUB_TOKEN")
+	}
+
+	if g.config.Limit <= 0 {
+		g.config.Limit = 30
+	}
+	if g.config.Limit > 100 {
+		return fmt.Errorf("limit must be <= 100, got %d", g.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch retrieves repositories, issues, or discussions from GitHub
+func (g *GitHubSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := g.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	var articles []db.Article
+
+	switch g.config.Mode {
+	case "search":
+		repos, err := g.fetchRepositories(ctx)
+		if err != nil {
+			return nil, nil, err
+		}
+		for _, repo := range repos {
+			if repo.StargazersCount < g.config.MinStars {
+				continue
+			}
+			articles = append(articles, g.repoToArticle(repo))
+		}
+
+	case "issues":
+		issues, err := g.fetchIssues(ctx)
+		if err != nil {
+			return nil, nil, err
+		}
+		for _, issue := range issues {
+			// Issues are sorted by creation time, newest first
+			if issue.CreatedAt.Before(since) {
+				break
+			}
+			// The issues endpoint also returns pull requests
+			if issue.PullRequest != nil {
+				continue
+			}
+			articles = append(articles, g.issueToArticle(issue))
+		}
+
+	case "discussions":
+		discussions, err := g.fetchDiscussions(ctx)
+		if err != nil {
+			return nil, nil, err
+		}
+		for _, discussion := range discussions {
+			// Discussions are sorted by creation time, newest first
+			if discussion.CreatedAt.Before(since) {
+				break
+			}
+			articles = append(articles, g.discussionToArticle(discussion))
+		}
+	}
+
+	// Comments are not fetched for GitHub items
+	return articles, []db.Comment{}, nil
+}
+
+// fetchRepositories searches repositories matching the configured query
+func (g *GitHubSource) fetchRepositories(ctx context.Context) ([]ghRepo, error) {
+	params := url.Values{}
+	params.Set("q", g.config.Query)
+	params.Set("per_page", strconv.Itoa(g.config.Limit))
+	if g.config.Sort != "" {
+		params.Set("sort", g.config.Sort)
+		params.Set("order", "desc")
+	}
+
+	body, err := g.doRequest(ctx, "GET", "https://api.github.com/search/repositories?"+params.Encode(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	var response ghRepoSearchResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode response: %w", err)
+	}
+
+	return response.Items, nil
+}
+
+// fetchIssues fetches the most recently created issues of the configured repository
+func (g *GitHubSource) fetchIssues(ctx context.Context) ([]ghIssue, error) {
+	params := url.Values{}
+	params.Set("state", "all")
+	params.Set("sort", "created")
+	params.Set("direction", "desc")
+	params.Set("per_page", strconv.Itoa(g.config.Limit))
+
+	u := fmt.Sprintf("https://api.github.com/repos/%s/issues?%s", g.config.Repo, params.Encode())
+	body, err := g.doRequest(ctx, "GET", u, nil)
+	if err != nil {
+		return nil, err
+	}
+
+	var issues []ghIssue
+	if err := json.Unmarshal(body, &issues); err != nil {
+		return nil, fmt.Errorf("failed to decode issues: %w", err)
+	}
+
+	return issues, nil
+}
+
+// fetchDiscussions fetches the most recent discussions via the GraphQL API
+func (g *GitHubSource) fetchDiscussions(ctx context.Context) ([]ghDiscussion, error) {
+	owner, name, _ := splitRepo(g.config.Repo)
+
+	payload, err := json.Marshal(map[string]interface{}{
+		"query": ghDiscussionsQuery,
+		"variables": map[string]interface{}{
+			"owner": owner,
+			"name":  name,
+			"first": g.config.Limit,
+		},
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to encode GraphQL query: %w", err)
+	}
+
+	body, err := g.doRequest(ctx, "POST", "https://api.github.com/graphql", payload)
+	if err != nil {
+		return nil, err
+	}
+
+	var response ghDiscussionsResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode discussions: %w", err)
+	}
+
+	if len(response.Errors) > 0 {
+		return nil, fmt.Errorf("GitHub GraphQL error: %s", response.Errors[0].Message)
+	}
+	if response.Data.Repository == nil {
+		return nil, fmt.Errorf("repository %s not found", g.config.Repo)
+	}
+
+	return response.Data.Repository.Discussions.Nodes, nil
+}
+
+// doRequest performs a rate-limited GitHub API request and returns the response body
+func (g *GitHubSource) doRequest(ctx context.Context, method, u string, payload []byte) ([]byte, error) {
+	var reqBody io.Reader
+	if payload != nil {
+		reqBody = bytes.NewReader(payload)
+	}
+
+	req, err := http.NewRequestWithContext(ctx, method, u, reqBody)
+	if err != nil {
+		return nil, err
+	}
+
+	req.Header.Set("Accept", "application/vnd.github+json")
+	req.Header.Set("X-GitHub-Api-Version", "2022-11-28")
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	if payload != nil {
+		req.Header.Set("Content-Type", "application/json")
+	}
+	if g.token != "" {
+		req.Header.Set("Authorization", "Bearer "+g.token)
+	}
+
+	resp, err := g.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if err := checkGitHubRateLimit(resp); err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("GitHub API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	return body, nil
+}
+
+// checkGitHubRateLimit turns GitHub's primary and secondary rate limit responses into descriptive errors
+func checkGitHubRateLimit(resp *http.Response) error {
+	if resp.StatusCode != http.StatusForbidden && resp.StatusCode != http.StatusTooManyRequests {
+		return nil
+	}
+
+	// Secondary rate limits send Retry-After (seconds)
+	if retryAfter := resp.Header.Get("Retry-After"); retryAfter != "" {
+		return fmt.Errorf("GitHub secondary rate limit hit, retry after: %ss", retryAfter)
+	}
+
+	// Primary rate limit exhausted until the reset timestamp
+	if resp.Header.Get("X-RateLimit-Remaining") == "0" {
+		reset, _ := strconv.ParseInt(resp.Header.Get("X-RateLimit-Reset"), 10, 64)
+		return fmt.Errorf("GitHub rate limit exceeded, resets at %s", time.Unix(reset, 0).UTC().Format(time.RFC3339))
+	}
+
+	return nil
+}
+
+// repoToArticle converts a GitHub repository to an Article (stars become score)
+func (g *GitHubSource) repoToArticle(repo ghRepo) db.Article {
+	metadata, _ := json.Marshal(map[string]interface{}{
+		"score":       repo.StargazersCount,
+		"forks":       repo.ForksCount,
+		"open_issues": repo.OpenIssuesCount,
+		"language":    repo.Language,
+		"topics":      repo.Topics,
+	})
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   g.source.ID,
+		ExternalID: strconv.FormatInt(repo.ID, 10),
+		Title:      repo.FullName,
+		Author:     repo.Owner.Login,
+		Content:    repo.Description,
+		URL:        repo.HTMLURL,
+		WrittenAt:  repo.CreatedAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// issueToArticle converts a GitHub issue to an Article
+func (g *GitHubSource) issueToArticle(issue ghIssue) db.Article {
+	metadata, _ := json.Marshal(map[string]interface{}{
+		"score":        issue.Reactions.TotalCount,
+		"num_comments": issue.Comments,
+		"state":        issue.State,
+		"repo":         g.config.Repo,
+	})
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   g.source.ID,
+		ExternalID: strconv.Itoa(issue.Number),
+		Title:      issue.Title,
+		Author:     issue.User.Login,
+		Content:    issue.Body,
+		URL:        issue.HTMLURL,
+		WrittenAt:  issue.CreatedAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// discussionToArticle converts a GitHub discussion to an Article
+func (g *GitHubSource) discussionToArticle(discussion ghDiscussion) db.Article {
+	metadata, _ := json.Marshal(map[string]interface{}{
+		"score":        discussion.UpvoteCount,
+		"num_comments": discussion.Comments.TotalCount,
+		"repo":         g.config.Repo,
+	})
+
+	author := ""
+	if discussion.Author != nil {
+		author = discussion.Author.Login
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   g.source.ID,
+		ExternalID: strconv.Itoa(discussion.Number),
+		Title:      discussion.Title,
+		Author:     author,
+		Content:    discussion.Body,
+		URL:        discussion.URL,
+		WrittenAt:  discussion.CreatedAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// splitRepo splits an "owner/name" repository reference
+func splitRepo(repo string) (string, string, bool) {
+	owner, name, found := strings.Cut(repo, "/")
+	if !found || owner == "" || name == "" || strings.Contains(name, "/") {
+		return "", "", false
+	}
+	return owner, name, true
+}
@@ -0,0 +1,247 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+// scholarPageSize is the maximum number of results SerpAPI returns per Google Scholar page
+const scholarPageSize = 20
+
+// scholarYear finds publication years in a result summary ("J Doe - Nature, 2021 - nature.com")
+var scholarYear = regexp.MustCompile(`\b(19|20)\d{2}\b`)
+
+// GoogleScholarSource implements the Source interface for Google Scholar via SerpAPI
+type GoogleScholarSource struct {
+	source *db.Source
+	config *db.GoogleScholarConfig
+	client *http.Client
+	apiKey string // From global config (environment variable)
+}
+
+// SerpAPI Google Scholar response structures
+type scholarResponse struct {
+	Error          string          `json:"error"`
+	OrganicResults []scholarResult `json:"organic_results"`
+}
+
+type scholarResult struct {
+	ResultID        string `json:"result_id"`
+	Title           string `json:"title"`
+	Link            string `json:"link"`
+	Snippet         string `json:"snippet"`
+	PublicationInfo struct {
+		Summary string `json:"summary"`
+		Authors []struct {
+			Name string `json:"name"`
+		} `json:"authors"`
+	} `json:"publication_info"`
+	InlineLinks struct {
+		CitedBy struct {
+			Total int `json:"total"`
+		} `json:"cited_by"`
+		Versions struct {
+			Total int `json:"total"`
+		} `json:"versions"`
+	} `json:"inline_links"`
+}
+
+// NewGoogleScholarSource creates a new Google Scholar source
+// Uses the SerpAPI key from config file
+func NewGoogleScholarSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*GoogleScholarSource, error) {
+	var config db.GoogleScholarConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid google_scholar config: %w", err)
+	}
+
+	gs := &GoogleScholarSource{
+		source: source,
+		config: &config,
+		client: httpClient,             // Shared client with pooled connections
+		apiKey: credentials.SerpAPIKey, // Required, every search costs one SerpAPI credit
+	}
+
+	return gs, nil
+}
+
+// SourceType returns "google_scholar"
+func (g *GoogleScholarSource) SourceType() string {
+	return "google_scholar"
+}
+
+// Validate checks if the configuration is valid
+func (g *GoogleScholarSource) Validate() error {
+	if g.apiKey == "" {
+		return fmt.Errorf("COLLECTOR_SERPAPI_API_KEY is required for google_scholar sources")
+	}
+	if g.config.Query == "" {
+		return fmt.Errorf("query is required")
+	}
+
+	if g.config.YearLow < 0 || g.config.YearHigh < 0 {
+		return fmt.Errorf("year_low and year_high must be non-negative")
+	}
+	if g.config.YearLow > 0 && g.config.YearHigh > 0 && g.config.YearLow > g.config.YearHigh {
+		return fmt.Errorf("year_low (%d) must be <= year_high (%d)", g.config.YearLow, g.config.YearHigh)
+	}
+
+	// Set defaults
+	if g.config.MaxResults <= 0 {
+		g.config.MaxResults = 20
+	}
+	if g.config.MaxResults > 100 {
+		return fmt.Errorf("max_results must be <= 100, got %d", g.config.MaxResults)
+	}
+
+	return nil
+}
+
+// Fetch retrieves Google Scholar results page by page
+func (g *GoogleScholarSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := g.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	var articles []db.Article
+	for start := 0; len(articles) < g.config.MaxResults; start += scholarPageSize {
+		results, err := g.fetchPage(ctx, start)
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to fetch results: %w", err)
+		}
+
+		pageStart := len(articles)
+		for _, result := range results {
+			if len(articles) >= g.config.MaxResults {
+				break
+			}
+			articles = append(articles, g.resultToArticle(result))
+		}
+		stampProvenance(ctx, articles[pageStart:], start/scholarPageSize+1)
+
+		// Short page means there are no more results
+		if len(results) < scholarPageSize {
+			break
+		}
+	}
+
+	// Google Scholar has no comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchPage requests one page of results starting at offset start
+func (g *GoogleScholarSource) fetchPage(ctx context.Context, start int) ([]scholarResult, error) {
+	params := url.Values{}
+	params.Set("engine", "google_scholar")
+	params.Set("q", g.config.Query)
+	params.Set("num", strconv.Itoa(scholarPageSize))
+	params.Set("start", strconv.Itoa(start))
+	if g.config.YearLow > 0 {
+		params.Set("as_ylo", strconv.Itoa(g.config.YearLow))
+	}
+	if g.config.YearHigh > 0 {
+		params.Set("as_yhi", strconv.Itoa(g.config.YearHigh))
+	}
+	if g.config.SortByDate {
+		params.Set("scisbd", "1")
+	}
+	params.Set("api_key", g.apiKey)
+
+	req, err := http.NewRequestWithContext(ctx, "GET", "https://serpapi.com/search.json?"+params.Encode(), nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+
+	resp, err := g.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	var response scholarResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode response (status %d): %w", resp.StatusCode, err)
+	}
+
+	// "Google hasn't returned any results for this query." is reported as an error on an empty page
+	if response.Error != "" && len(response.OrganicResults) == 0 && resp.StatusCode == http.StatusOK {
+		return nil, nil
+	}
+	if response.Error != "" {
+		return nil, fmt.Errorf("SerpAPI error (status %d): %s", resp.StatusCode, response.Error)
+	}
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("SerpAPI returned %d", resp.StatusCode)
+	}
+
+	return response.OrganicResults, nil
+}
+
+// resultToArticle converts a Google Scholar result to an Article
+func (g *GoogleScholarSource) resultToArticle(result scholarResult) db.Article {
+	metadata, err := json.Marshal(map[string]interface{}{
+		"score":        result.InlineLinks.CitedBy.Total,
+		"num_comments": 0,
+		"citations":    result.InlineLinks.CitedBy.Total,
+		"versions":     result.InlineLinks.Versions.Total,
+		"venue":        result.PublicationInfo.Summary,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	authors := make([]string, 0, len(result.PublicationInfo.Authors))
+	for _, a := range result.PublicationInfo.Authors {
+		authors = append(authors, a.Name)
+	}
+	author := strings.Join(authors, ", ")
+	if author == "" {
+		// Authors without Scholar profiles only appear in the summary, before the first " - "
+		author, _, _ = strings.Cut(result.PublicationInfo.Summary, " - ")
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   g.source.ID,
+		ExternalID: result.ResultID,
+		Title:      result.Title,
+		Author:     author,
+		Content:    result.Snippet,
+		URL:        result.Link,
+		WrittenAt:  scholarPublished(result.PublicationInfo.Summary),
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// scholarPublished returns January 1st of the last year found in a result summary
+// Google Scholar only exposes the publication year; falls back to now when none is found
+func scholarPublished(summary string) time.Time {
+	years := scholarYear.FindAllString(summary, -1)
+	if len(years) == 0 {
+		return time.Now()
+	}
+	year, _ := strconv.Atoi(years[len(years)-1])
+	return time.Date(year, time.January, 1, 0, 0, 0, 0, time.UTC)
+}
@@ -4,7 +4,6 @@ import (
 	"context"
 	"encoding/json"
 	"fmt"
-	"io"
 	"log/slog"
 	"net/http"
 	"strconv"
@@ -15,7 +14,12 @@ import (
 	"github.com/cheolwanpark/meows/collector/internal/config"
 	"github.com/cheolwanpark/meows/collector/internal/db"
 	"github.com/google/uuid"
-	"golang.org/x/time/rate"
+)
+
+const (
+	hnHiringDefaultLimit = 500
+	hnHiringMaxLimit     = 1000 // Monthly threads rarely exceed this many top-level posts
+	hnHiringMaxLookback  = 10   // Submissions of the whoishiring account searched for the latest thread
 )
 
 // HackerNewsSource implements the Source interface for Hacker News
@@ -23,8 +27,10 @@ type HackerNewsSource struct {
 	source          *db.Source
 	config          *db.HackerNewsConfig
 	client          *http.Client
-	limiter         *rate.Limiter
 	maxCommentDepth int
+	userAgents      []string       // Optional pool for HTML comment scraping
+	htmlClient      *http.Client   // Client for HTML scraping (carries the cookie jar if configured)
+	cookieJar       *fileCookieJar // Optional persistent session for HTML scraping
 }
 
 // HackerNews API response structures
@@ -44,6 +50,11 @@ type hnItem struct {
 	Descendants int     `json:"descendants"`
 }
 
+// hnUser is the part of a HN user profile used to find hiring threads
+type hnUser struct {
+	Submitted []int `json:"submitted"` // Newest first
+}
+
 // commentQueueItem represents an item in the BFS queue for comment fetching
 type commentQueueItem struct {
 	hnID       int
@@ -64,7 +75,7 @@ type htmlComment struct {
 func NewHackerNewsSource(
 	source *db.Source,
 	credentials *config.CredentialsConfig,
-	sharedLimiter *rate.Limiter,
+	httpClient *http.Client,
 	maxCommentDepth int,
 ) (*HackerNewsSource, error) {
 	var config db.HackerNewsConfig
@@ -75,14 +86,27 @@ func NewHackerNewsSource(
 	hs := &HackerNewsSource{
 		source:          source,
 		config:          &config,
-		client:          &http.Client{Timeout: 30 * time.Second},
-		limiter:         sharedLimiter, // Use shared rate limiter per source type
+		client:          httpClient, // Shared client with pooled connections
 		maxCommentDepth: maxCommentDepth,
 	}
 
 	// HN API is public, no credentials needed
 	_ = credentials
 
+	// User agent rotation and cookie sessions only apply to HTML scraping (opt-in)
+	hs.htmlClient = httpClient
+	if opts, err := ParseOptions(source.Config); err == nil {
+		hs.userAgents = opts.UserAgents
+		if opts.CookieFile != "" {
+			jar, err := loadCookieJar(opts.CookieFile)
+			if err != nil {
+				return nil, err
+			}
+			hs.cookieJar = jar
+			hs.htmlClient = withCookieJar(httpClient, jar)
+		}
+	}
+
 	return hs, nil
 }
 
@@ -94,16 +118,20 @@ func (h *HackerNewsSource) SourceType() string {
 // Validate checks if the configuration is valid
 func (h *HackerNewsSource) Validate() error {
 	// Validate ItemType
-	if err := validateEnum(h.config.ItemType, []string{"top", "new", "best", "ask", "show", "job"}, "item_type"); err != nil {
+	if err := validateEnum(h.config.ItemType, []string{"top", "new", "best", "ask", "show", "job", "hiring"}, "item_type"); err != nil {
 		return err
 	}
 
-	// Set defaults
+	// Set defaults (hiring threads have hundreds of top-level posts)
+	defaultLimit, maxLimit := 30, 100
+	if h.config.ItemType == "hiring" {
+		defaultLimit, maxLimit = hnHiringDefaultLimit, hnHiringMaxLimit
+	}
 	if h.config.Limit <= 0 {
-		h.config.Limit = 30
+		h.config.Limit = defaultLimit
 	}
-	if h.config.Limit > 100 {
-		return fmt.Errorf("limit must be <= 100, got %d", h.config.Limit)
+	if h.config.Limit > maxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", maxLimit, h.config.Limit)
 	}
 
 	// Set default for MaxCommentDepth if not configured
@@ -134,6 +162,24 @@ func (h *HackerNewsSource) Fetch(ctx context.Context, since time.Time) ([]db.Art
 		return nil, nil, err
 	}
 
+	// Persist session cookies for the next run, even after a partial fetch
+	if h.cookieJar != nil {
+		defer func() {
+			if err := h.cookieJar.Save(); err != nil {
+				slog.Warn("Failed to save cookie file", "path", h.cookieJar.path, "error", err)
+			}
+		}()
+	}
+
+	// Hiring threads are split into one article per job post instead
+	if h.config.ItemType == "hiring" {
+		articles, err := h.fetchHiringPosts(ctx, since)
+		if err != nil {
+			return nil, nil, err
+		}
+		return articles, []db.Comment{}, nil
+	}
+
 	var allArticles []db.Article
 	var allComments []db.Comment
 
@@ -159,11 +205,6 @@ func (h *HackerNewsSource) Fetch(ctx context.Context, since time.Time) ([]db.Art
 		default:
 		}
 
-		// Rate limiting
-		if err := h.limiter.Wait(ctx); err != nil {
-			return allArticles, allComments, err
-		}
-
 		// Fetch item details
 		item, err := h.fetchItem(ctx, id)
 		if err != nil {
@@ -260,6 +301,7 @@ func (h *HackerNewsSource) Fetch(ctx context.Context, since time.Time) ([]db.Art
 				slog.Warn("Failed to fetch comments",
 					"item_id", id,
 					"error", err)
+				addEnrichmentError(&allArticles[len(allArticles)-1], "comments", err)
 			} else {
 				allComments = append(allComments, comments...)
 			}
@@ -271,11 +313,6 @@ func (h *HackerNewsSource) Fetch(ctx context.Context, since time.Time) ([]db.Art
 
 // fetchStoryIDs fetches the list of story IDs for the configured item type
 func (h *HackerNewsSource) fetchStoryIDs(ctx context.Context) ([]int, error) {
-	// Rate limiting
-	if err := h.limiter.Wait(ctx); err != nil {
-		return nil, err
-	}
-
 	u := fmt.Sprintf("https://hacker-news.firebaseio.com/v0/%sstories.json", h.config.ItemType)
 
 	req, err := http.NewRequestWithContext(ctx, "GET", u, nil)
@@ -291,19 +328,158 @@ func (h *HackerNewsSource) fetchStoryIDs(ctx context.Context) ([]int, error) {
 	}
 	defer resp.Body.Close()
 
+	body, err := readBody(resp, contentJSON)
+	if err != nil {
+		return nil, err
+	}
+
 	if resp.StatusCode != http.StatusOK {
-		body, _ := io.ReadAll(resp.Body)
 		return nil, fmt.Errorf("HN API returned %d: %s", resp.StatusCode, string(body))
 	}
 
 	var ids []int
-	if err := json.NewDecoder(resp.Body).Decode(&ids); err != nil {
+	if err := json.Unmarshal(body, &ids); err != nil {
 		return nil, fmt.Errorf("failed to decode story IDs: %w", err)
 	}
 
 	return ids, nil
 }
 
+// fetchHiringPosts converts the top-level comments of the latest "Who is hiring?" thread into articles
+// Top-level comments are ranked, not chronological, so every one up to the limit is checked against since
+func (h *HackerNewsSource) fetchHiringPosts(ctx context.Context, since time.Time) ([]db.Article, error) {
+	thread, err := h.findHiringThread(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("failed to find hiring thread: %w", err)
+	}
+
+	kids := thread.Kids
+	if len(kids) > h.config.Limit {
+		kids = kids[:h.config.Limit]
+	}
+
+	articles := make([]db.Article, 0)
+	for _, id := range kids {
+		item, err := h.fetchItem(ctx, id)
+		if err != nil {
+			slog.Warn("Failed to fetch hiring post", "item_id", id, "error", err)
+			continue
+		}
+		if item == nil || item.Deleted || item.Dead || item.Text == "" {
+			continue
+		}
+		if time.Unix(item.Time, 0).Before(since) {
+			continue
+		}
+
+		article := hiringPostToArticle(h.source.ID, thread, item)
+		stampProvenance(ctx, []db.Article{article}, 0)
+		articles = append(articles, article)
+	}
+
+	return articles, nil
+}
+
+// findHiringThread returns the latest "Ask HN: Who is hiring?" story posted by the whoishiring account
+// The account also posts "Who wants to be hired?" and "Freelancer?" threads each month, which are skipped
+func (h *HackerNewsSource) findHiringThread(ctx context.Context) (*hnItem, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", "https://hacker-news.firebaseio.com/v0/user/whoishiring.json", nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+
+	resp, err := h.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("HN API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	var user hnUser
+	if err := json.Unmarshal(body, &user); err != nil {
+		return nil, fmt.Errorf("failed to decode user: %w", err)
+	}
+
+	for i, id := range user.Submitted {
+		if i >= hnHiringMaxLookback {
+			break
+		}
+		item, err := h.fetchItem(ctx, id)
+		if err != nil {
+			return nil, err
+		}
+		if item != nil && item.Type == "story" && strings.HasPrefix(strings.ToLower(item.Title), "ask hn: who is hiring") {
+			return item, nil
+		}
+	}
+
+	return nil, fmt.Errorf("no hiring thread among the last %d submissions", hnHiringMaxLookback)
+}
+
+// hiringPostToArticle converts a top-level comment of a hiring thread to an Article
+// Posts conventionally start with a "Company | Role | Location | ..." line, which becomes the title
+func hiringPostToArticle(sourceID string, thread, item *hnItem) db.Article {
+	// HN separates paragraphs with a bare <p>
+	var paragraphs []string
+	for _, p := range strings.Split(item.Text, "<p>") {
+		if text := htmlText(p); text != "" {
+			paragraphs = append(paragraphs, text)
+		}
+	}
+	var header string
+	if len(paragraphs) > 0 {
+		header = paragraphs[0]
+	}
+
+	var fields []string
+	for _, field := range strings.Split(header, "|") {
+		if field = strings.TrimSpace(field); field != "" {
+			fields = append(fields, field)
+		}
+	}
+	var company string
+	if len(fields) > 0 {
+		company = fields[0]
+	}
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"hn_id":         item.ID,
+		"hn_type":       "hiring",
+		"by":            item.By,
+		"thread_id":     thread.ID,
+		"thread_title":  thread.Title,
+		"company":       company,
+		"header_fields": fields,
+		"remote":        strings.Contains(strings.ToLower(header), "remote"),
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   sourceID,
+		ExternalID: fmt.Sprintf("%d", item.ID),
+		Title:      truncateRunes(header, 200),
+		Author:     item.By,
+		Content:    strings.Join(paragraphs, "\n\n"),
+		URL:        fmt.Sprintf("https://news.ycombinator.com/item?id=%d", item.ID),
+		WrittenAt:  time.Unix(item.Time, 0),
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
 // fetchItem fetches a single item by ID
 // Returns nil if the item doesn't exist (without error)
 func (h *HackerNewsSource) fetchItem(ctx context.Context, id int) (*hnItem, error) {
@@ -322,16 +498,15 @@ func (h *HackerNewsSource) fetchItem(ctx context.Context, id int) (*hnItem, erro
 	}
 	defer resp.Body.Close()
 
-	if resp.StatusCode != http.StatusOK {
-		body, _ := io.ReadAll(resp.Body)
-		return nil, fmt.Errorf("HN API returned %d: %s", resp.StatusCode, string(body))
-	}
-
-	body, err := io.ReadAll(resp.Body)
+	body, err := readBody(resp, contentJSON)
 	if err != nil {
 		return nil, err
 	}
 
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("HN API returned %d: %s", resp.StatusCode, string(body))
+	}
+
 	// HN API returns "null" for deleted/non-existent items
 	if string(body) == "null" {
 		return nil, nil
@@ -380,11 +555,6 @@ func (h *HackerNewsSource) fetchCommentsViaAPI(ctx context.Context, rootKids []i
 			continue
 		}
 
-		// Rate limiting
-		if err := h.limiter.Wait(ctx); err != nil {
-			return comments, err
-		}
-
 		// Fetch comment item
 		item, err := h.fetchItem(ctx, current.hnID)
 		if err != nil {
@@ -465,20 +635,15 @@ func (h *HackerNewsSource) itemToArticle(item *hnItem) db.Article {
 // This is 45-83x faster than the API approach and eliminates timeout issues
 // Returns (nil, error) on ANY failure to ensure clean API fallback (atomic operation)
 func (h *HackerNewsSource) fetchCommentsViaHTML(ctx context.Context, storyID int, articleID string) ([]db.Comment, error) {
-	// Rate limiting
-	if err := h.limiter.Wait(ctx); err != nil {
-		return nil, fmt.Errorf("rate limit error: %w", err)
-	}
-
 	// Fetch HTML page
 	url := fmt.Sprintf("https://news.ycombinator.com/item?id=%d", storyID)
 	req, err := http.NewRequestWithContext(ctx, "GET", url, nil)
 	if err != nil {
 		return nil, fmt.Errorf("failed to create request: %w", err)
 	}
-	req.Header.Set("User-Agent", "meows-collector/1.0")
+	req.Header.Set("User-Agent", pickUserAgent(h.userAgents, "meows-collector/1.0"))
 
-	resp, err := h.client.Do(req)
+	resp, err := h.htmlClient.Do(req)
 	if err != nil {
 		return nil, fmt.Errorf("HTTP request failed: %w", err)
 	}
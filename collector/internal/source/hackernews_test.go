@@ -0,0 +1,39 @@
+package source
+
+import (
+	"encoding/json"
+	"testing"
+)
+
+func TestHiringPostToArticle(t *testing.T) {
+	thread := &hnItem{ID: 100, Title: "Ask HN: Who is hiring? (March 2025)"}
+	item := &hnItem{
+		ID:   101,
+		By:   "acme_jobs",
+		Time: 1740787200,
+		Text: "Acme Corp | Senior Go Engineer | Berlin or REMOTE (EU) | Full-time<p>We build &quot;boring&quot; infrastructure.<p>Apply: <a href=\"https:&#x2F;&#x2F;acme.example&#x2F;jobs\">acme.example/jobs</a>",
+	}
+
+	article := hiringPostToArticle("src", thread, item)
+	if article.Title != "Acme Corp | Senior Go Engineer | Berlin or REMOTE (EU) | Full-time" {
+		t.Errorf("Unexpected title: %q", article.Title)
+	}
+	want := "Acme Corp | Senior Go Engineer | Berlin or REMOTE (EU) | Full-time\n\nWe build \"boring\" infrastructure.\n\nApply: acme.example/jobs"
+	if article.Content != want {
+		t.Errorf("Unexpected content:\n%q\nwant:\n%q", article.Content, want)
+	}
+	if article.ExternalID != "101" || article.URL != "https://news.ycombinator.com/item?id=101" || article.Author != "acme_jobs" {
+		t.Errorf("Unexpected article: %+v", article)
+	}
+
+	var metadata map[string]interface{}
+	if err := json.Unmarshal(article.Metadata, &metadata); err != nil {
+		t.Fatal(err)
+	}
+	if metadata["company"] != "Acme Corp" || metadata["remote"] != true || metadata["thread_id"] != float64(100) {
+		t.Errorf("Unexpected metadata: %v", metadata)
+	}
+	if fields, _ := metadata["header_fields"].([]interface{}); len(fields) != 4 {
+		t.Errorf("Expected 4 header fields, got %v", metadata["header_fields"])
+	}
+}
@@ -0,0 +1,104 @@
+package source
+
+import (
+	"crypto/tls"
+	"crypto/x509"
+	"fmt"
+	"log/slog"
+	"net"
+	"net/http"
+	"os"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+)
+
+// NewHTTPClient creates the HTTP client shared by all sources
+// A single transport lets paginated requests to the same host reuse pooled connections
+// Factory layers per-source middleware (rate limiting) on top of it, see sourceClient
+func NewHTTPClient(cfg *config.HTTPConfig) (*http.Client, error) {
+	tlsConfig, err := newTLSConfig(cfg)
+	if err != nil {
+		return nil, err
+	}
+
+	dialer := &net.Dialer{
+		Timeout:   time.Duration(cfg.ConnectTimeoutSeconds) * time.Second,
+		KeepAlive: time.Duration(cfg.KeepAliveSeconds) * time.Second,
+	}
+
+	transport := &http.Transport{
+		Proxy:                 http.ProxyFromEnvironment,
+		DialContext:           dialer.DialContext,
+		TLSClientConfig:       tlsConfig,
+		ForceAttemptHTTP2:     cfg.EnableHTTP2,
+		MaxIdleConns:          100,
+		MaxIdleConnsPerHost:   cfg.MaxIdleConnsPerHost,
+		IdleConnTimeout:       90 * time.Second,
+		TLSHandshakeTimeout:   10 * time.Second,
+		ExpectContinueTimeout: 1 * time.Second,
+	}
+
+	timeout := time.Duration(cfg.TimeoutSeconds) * time.Second
+
+	// Middleware stack shared by every source, outermost first:
+	// retries wrap logging so each attempt is logged, archiving records every raw response
+	var middlewares []Middleware
+	if cfg.MaxRetries > 0 {
+		middlewares = append(middlewares, withRetry(cfg.MaxRetries, time.Duration(cfg.RetryBaseDelayMs)*time.Millisecond, retryAfterCap(timeout)))
+	}
+	middlewares = append(middlewares, withLogging())
+
+	// Optionally keep raw response bodies so they can be re-parsed later
+	if cfg.ArchiveRawDir != "" {
+		if err := os.MkdirAll(cfg.ArchiveRawDir, 0o750); err != nil {
+			return nil, fmt.Errorf("failed to create raw archive directory: %w", err)
+		}
+		middlewares = append(middlewares, withArchive(cfg.ArchiveRawDir))
+		slog.Info("Archiving raw API responses", "dir", cfg.ArchiveRawDir)
+	}
+
+	client := &http.Client{
+		Timeout:   timeout,
+		Transport: chainMiddleware(transport, middlewares...),
+	}
+
+	return client, nil
+}
+
+// newTLSConfig builds the TLS settings for outbound requests
+// Extra CAs are added on top of the system pool so public APIs keep working
+func newTLSConfig(cfg *config.HTTPConfig) (*tls.Config, error) {
+	tlsConfig := &tls.Config{MinVersion: tls.VersionTLS12}
+
+	if cfg.CABundlePath != "" {
+		pemData, err := os.ReadFile(cfg.CABundlePath)
+		if err != nil {
+			return nil, fmt.Errorf("failed to read CA bundle: %w", err)
+		}
+
+		pool, err := x509.SystemCertPool()
+		if err != nil {
+			pool = x509.NewCertPool()
+		}
+		if !pool.AppendCertsFromPEM(pemData) {
+			return nil, fmt.Errorf("no certificates found in CA bundle %s", cfg.CABundlePath)
+		}
+		tlsConfig.RootCAs = pool
+	}
+
+	if cfg.ClientCertPath != "" {
+		cert, err := tls.LoadX509KeyPair(cfg.ClientCertPath, cfg.ClientKeyPath)
+		if err != nil {
+			return nil, fmt.Errorf("failed to load client certificate: %w", err)
+		}
+		tlsConfig.Certificates = []tls.Certificate{cert}
+	}
+
+	if cfg.InsecureSkipVerify {
+		slog.Warn("TLS certificate verification is disabled for all sources (COLLECTOR_HTTP_INSECURE_SKIP_VERIFY=true)")
+		tlsConfig.InsecureSkipVerify = true
+	}
+
+	return tlsConfig, nil
+}
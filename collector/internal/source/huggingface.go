@@ -0,0 +1,253 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	huggingFaceBaseURL      = "https://huggingface.co"
+	huggingFaceDefaultLimit = 50
+	huggingFaceMaxLimit     = 1000
+)
+
+// huggingFaceSorts maps config sort values to Hub API sort keys
+var huggingFaceSorts = map[string]string{
+	"created":   "createdAt",
+	"modified":  "lastModified",
+	"downloads": "downloads",
+	"likes":     "likes",
+}
+
+// HuggingFaceSource implements the Source interface for models, datasets and spaces on the Hugging Face Hub
+type HuggingFaceSource struct {
+	source *db.Source
+	config *db.HuggingFaceConfig
+	client *http.Client
+	token  string // From global config (environment variable), optional
+}
+
+// Hub API list item (shared by /api/models, /api/datasets and /api/spaces)
+type huggingFaceRepo struct {
+	ID           string    `json:"id"` // "owner/name"
+	Author       string    `json:"author"`
+	Likes        int       `json:"likes"`
+	Downloads    int       `json:"downloads"` // Last 30 days, absent for spaces
+	Tags         []string  `json:"tags"`
+	PipelineTag  string    `json:"pipeline_tag"` // Models only
+	LibraryName  string    `json:"library_name"` // Models only
+	SDK          string    `json:"sdk"`          // Spaces only
+	CreatedAt    time.Time `json:"createdAt"`
+	LastModified time.Time `json:"lastModified"`
+	Private      bool      `json:"private"`
+}
+
+// NewHuggingFaceSource creates a new Hugging Face Hub source
+// Uses the optional Hugging Face token from config file
+func NewHuggingFaceSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*HuggingFaceSource, error) {
+	var config db.HuggingFaceConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid huggingface config: %w", err)
+	}
+
+	hs := &HuggingFaceSource{
+		source: source,
+		config: &config,
+		client: httpClient, // Shared client with pooled connections
+		token:  credentials.HuggingFaceToken,
+	}
+
+	return hs, nil
+}
+
+// SourceType returns "huggingface"
+func (h *HuggingFaceSource) SourceType() string {
+	return "huggingface"
+}
+
+// Validate checks if the configuration is valid
+func (h *HuggingFaceSource) Validate() error {
+	if h.config.Kind == "" {
+		h.config.Kind = "models"
+	}
+	if err := validateEnum(h.config.Kind, []string{"models", "datasets", "spaces"}, "kind"); err != nil {
+		return err
+	}
+	if h.config.Search == "" && h.config.Tag == "" && h.config.Author == "" {
+		return fmt.Errorf("at least one of search, tag or author is required")
+	}
+	if h.config.Sort == "" {
+		h.config.Sort = "created"
+	}
+	if err := validateEnum(h.config.Sort, []string{"created", "modified", "downloads", "likes"}, "sort"); err != nil {
+		return err
+	}
+
+	// Set defaults
+	if h.config.Limit <= 0 {
+		h.config.Limit = huggingFaceDefaultLimit
+	}
+	if h.config.Limit > huggingFaceMaxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", huggingFaceMaxLimit, h.config.Limit)
+	}
+	if h.config.MinLikes < 0 {
+		return fmt.Errorf("min_likes must be non-negative, got %d", h.config.MinLikes)
+	}
+
+	return nil
+}
+
+// Fetch lists repos matching the search, tag and author filters in one request
+func (h *HuggingFaceSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := h.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	params := url.Values{}
+	if h.config.Search != "" {
+		params.Set("search", h.config.Search)
+	}
+	if h.config.Tag != "" {
+		params.Set("filter", h.config.Tag)
+	}
+	if h.config.Author != "" {
+		params.Set("author", h.config.Author)
+	}
+	params.Set("sort", huggingFaceSorts[h.config.Sort])
+	params.Set("direction", "-1")
+	params.Set("limit", strconv.Itoa(h.config.Limit))
+	params.Set("full", "true") // Includes lastModified and tags
+
+	repos, err := h.list(ctx, params)
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to list %s: %w", h.config.Kind, err)
+	}
+
+	articles := make([]db.Article, 0, len(repos))
+	for _, repo := range repos {
+		if repo.Private || repo.Likes < h.config.MinLikes {
+			continue
+		}
+		if h.repoTime(repo).Before(since) {
+			// Time-sorted listings are newest first, so the rest is older too
+			if h.config.Sort == "created" || h.config.Sort == "modified" {
+				break
+			}
+			continue
+		}
+		articles = append(articles, h.repoToArticle(repo))
+	}
+	stampProvenance(ctx, articles, 0)
+
+	// Hub discussions are not fetched
+	return articles, []db.Comment{}, nil
+}
+
+// list performs the rate-limited Hub API request
+func (h *HuggingFaceSource) list(ctx context.Context, params url.Values) ([]huggingFaceRepo, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", huggingFaceBaseURL+"/api/"+h.config.Kind+"?"+params.Encode(), nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	if h.token != "" {
+		req.Header.Set("Authorization", "Bearer "+h.token)
+	}
+
+	resp, err := h.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("Hugging Face API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	var repos []huggingFaceRepo
+	if err := json.Unmarshal(body, &repos); err != nil {
+		return nil, fmt.Errorf("failed to decode response: %w", err)
+	}
+
+	return repos, nil
+}
+
+// repoTime is the timestamp the listing is filtered by: last modification for "modified", creation otherwise
+func (h *HuggingFaceSource) repoTime(repo huggingFaceRepo) time.Time {
+	if h.config.Sort == "modified" && !repo.LastModified.IsZero() {
+		return repo.LastModified
+	}
+	return repo.CreatedAt
+}
+
+// repoToArticle converts a Hub repo to an Article
+// Likes are the score for every kind, since spaces have no download counts
+func (h *HuggingFaceSource) repoToArticle(repo huggingFaceRepo) db.Article {
+	metadata, err := json.Marshal(map[string]interface{}{
+		"kind":          h.config.Kind,
+		"likes":         repo.Likes,
+		"downloads":     repo.Downloads,
+		"pipeline_tag":  repo.PipelineTag,
+		"library":       repo.LibraryName,
+		"sdk":           repo.SDK,
+		"hf_tags":       repo.Tags,
+		"last_modified": repo.LastModified,
+		"score":         repo.Likes,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	// Models live at the root, datasets and spaces under their kind
+	repoURL := huggingFaceBaseURL + "/" + repo.ID
+	if h.config.Kind != "models" {
+		repoURL = huggingFaceBaseURL + "/" + h.config.Kind + "/" + repo.ID
+	}
+
+	author := repo.Author
+	if author == "" {
+		author, _, _ = strings.Cut(repo.ID, "/")
+	}
+
+	// List responses carry no description, so the task and tags summarize the repo
+	var content []string
+	if repo.PipelineTag != "" {
+		content = append(content, "Task: "+repo.PipelineTag)
+	}
+	if len(repo.Tags) > 0 {
+		content = append(content, "Tags: "+strings.Join(repo.Tags, ", "))
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   h.source.ID,
+		ExternalID: repo.ID,
+		Title:      repo.ID,
+		Author:     author,
+		Content:    strings.Join(content, "\n"),
+		URL:        repoURL,
+		WrittenAt:  h.repoTime(repo),
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
@@ -0,0 +1,34 @@
+package source
+
+import (
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestHuggingFaceRepoToArticle(t *testing.T) {
+	repo := huggingFaceRepo{
+		ID:           "acme/tiny-llm",
+		Likes:        12,
+		PipelineTag:  "text-generation",
+		Tags:         []string{"transformers", "license:mit"},
+		CreatedAt:    time.Date(2024, 11, 1, 0, 0, 0, 0, time.UTC),
+		LastModified: time.Date(2024, 11, 15, 0, 0, 0, 0, time.UTC),
+	}
+
+	src := &HuggingFaceSource{source: &db.Source{ID: "src"}, config: &db.HuggingFaceConfig{Kind: "models", Sort: "created"}}
+	article := src.repoToArticle(repo)
+	if article.URL != "https://huggingface.co/acme/tiny-llm" || article.Author != "acme" || !article.WrittenAt.Equal(repo.CreatedAt) {
+		t.Errorf("Unexpected model article: %+v", article)
+	}
+	if article.Content != "Task: text-generation\nTags: transformers, license:mit" {
+		t.Errorf("Unexpected content: %q", article.Content)
+	}
+
+	src.config = &db.HuggingFaceConfig{Kind: "spaces", Sort: "modified"}
+	article = src.repoToArticle(repo)
+	if article.URL != "https://huggingface.co/spaces/acme/tiny-llm" || !article.WrittenAt.Equal(repo.LastModified) {
+		t.Errorf("Unexpected space article: %+v", article)
+	}
+}
@@ -0,0 +1,500 @@
+package source
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"crypto/tls"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"io"
+	"mime"
+	"mime/multipart"
+	"mime/quotedprintable"
+	"net"
+	"net/mail"
+	"net/url"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+	"golang.org/x/time/rate"
+)
+
+const (
+	imapDefaultLimit = 50
+	imapMaxLimit     = 500
+	imapMaxBody      = 5 << 20 // Longer message bodies are truncated
+)
+
+// ImapSource implements the Source interface for newsletters delivered to an IMAP mailbox
+// Only the commands needed to read a folder are implemented (LOGIN, EXAMINE, UID SEARCH, UID FETCH)
+type ImapSource struct {
+	source   *db.Source
+	config   *db.ImapConfig
+	limiter  *rate.Limiter
+	host     string // From global config (environment variable), "host" or "host:port"
+	username string // From global config (environment variable)
+	password string // From global config (environment variable)
+}
+
+// imapMessage is one fetched message
+type imapMessage struct {
+	uid string
+	raw []byte
+}
+
+// NewImapSource creates a new IMAP source
+// Uses the IMAP host and login from config file; the shared HTTP client is not used
+func NewImapSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	sharedLimiter *rate.Limiter,
+) (*ImapSource, error) {
+	var config db.ImapConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid imap config: %w", err)
+	}
+
+	is := &ImapSource{
+		source:   source,
+		config:   &config,
+		limiter:  sharedLimiter, // Use shared rate limiter per source type
+		host:     credentials.IMAPHost,
+		username: credentials.IMAPUsername,
+		password: credentials.IMAPPassword,
+	}
+
+	return is, nil
+}
+
+// SourceType returns "imap"
+func (i *ImapSource) SourceType() string {
+	return "imap"
+}
+
+// Validate checks if the configuration is valid
+func (i *ImapSource) Validate() error {
+	if i.host == "" || i.username == "" || i.password == "" {
+		return fmt.Errorf("COLLECTOR_IMAP_HOST, COLLECTOR_IMAP_USERNAME and COLLECTOR_IMAP_PASSWORD are required for imap sources")
+	}
+
+	// Set defaults
+	if i.config.Folder == "" {
+		i.config.Folder = "INBOX"
+	}
+	if i.config.Limit <= 0 {
+		i.config.Limit = imapDefaultLimit
+	}
+	if i.config.Limit > imapMaxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", imapMaxLimit, i.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch reads messages received since the last run from the configured folder
+// The folder is opened read-only, so messages are not marked as seen
+func (i *ImapSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := i.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	// One connection per fetch, so a single wait covers it
+	if err := i.limiter.Wait(ctx); err != nil {
+		return nil, nil, err
+	}
+
+	messages, err := i.fetchMessages(ctx, since)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	articles := make([]db.Article, 0, len(messages))
+	for _, message := range messages {
+		article, ok := i.messageToArticle(message, since)
+		if ok {
+			articles = append(articles, article)
+		}
+	}
+
+	// Newsletters have no comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchMessages logs in, finds messages since the given day and fetches the newest Limit of them
+func (i *ImapSource) fetchMessages(ctx context.Context, since time.Time) ([]imapMessage, error) {
+	conn, err := dialIMAP(ctx, i.host)
+	if err != nil {
+		return nil, fmt.Errorf("failed to connect to IMAP server: %w", err)
+	}
+	defer conn.close()
+
+	if _, err := conn.command("LOGIN %s %s", imapQuote(i.username), imapQuote(i.password)); err != nil {
+		return nil, fmt.Errorf("IMAP login failed: %w", err)
+	}
+	if _, err := conn.command("EXAMINE %s", imapQuote(i.config.Folder)); err != nil {
+		return nil, fmt.Errorf("failed to open folder %q: %w", i.config.Folder, err)
+	}
+
+	// SINCE has day granularity, exact times are checked after parsing
+	criteria := imapSinceCriteria(since)
+	if len(i.config.From) > 0 {
+		criteria += " " + imapOrFrom(i.config.From)
+	}
+
+	responses, err := conn.command("UID SEARCH %s", criteria)
+	if err != nil {
+		return nil, fmt.Errorf("IMAP search failed: %w", err)
+	}
+	uids := imapSearchUIDs(responses)
+	if len(uids) == 0 {
+		return nil, nil
+	}
+	if len(uids) > i.config.Limit {
+		uids = uids[len(uids)-i.config.Limit:] // UIDs ascend with delivery order
+	}
+
+	set := make([]string, len(uids))
+	for j, uid := range uids {
+		set[j] = strconv.FormatUint(uid, 10)
+	}
+	responses, err = conn.command("UID FETCH %s (UID BODY.PEEK[])", strings.Join(set, ","))
+	if err != nil {
+		return nil, fmt.Errorf("IMAP fetch failed: %w", err)
+	}
+
+	messages := make([]imapMessage, 0, len(responses))
+	for _, response := range responses {
+		if !strings.Contains(response.text, " FETCH ") || len(response.literals) == 0 {
+			continue
+		}
+		messages = append(messages, imapMessage{uid: imapFetchUID(response.text), raw: response.literals[0]})
+	}
+
+	conn.command("LOGOUT") // Best effort, the connection is closed either way
+	return messages, nil
+}
+
+// messageToArticle converts a raw RFC 822 message to an Article
+// Returns false for messages that can't be parsed or are older than since
+func (i *ImapSource) messageToArticle(message imapMessage, since time.Time) (db.Article, bool) {
+	msg, err := mail.ReadMessage(bytes.NewReader(message.raw))
+	if err != nil {
+		return db.Article{}, false
+	}
+
+	writtenAt, err := msg.Header.Date()
+	if err != nil {
+		writtenAt = time.Now()
+	}
+	if writtenAt.Before(since) {
+		return db.Article{}, false
+	}
+
+	decoder := new(mime.WordDecoder)
+	subject, err := decoder.DecodeHeader(msg.Header.Get("Subject"))
+	if err != nil {
+		subject = msg.Header.Get("Subject")
+	}
+	author := msg.Header.Get("From")
+	if addr, err := mail.ParseAddress(author); err == nil {
+		author = addr.Address
+		if addr.Name != "" {
+			author = addr.Name
+		}
+	}
+
+	content := mailText(msg.Header, msg.Body)
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"folder":     i.config.Folder,
+		"uid":        message.uid,
+		"from":       msg.Header.Get("From"),
+		"list_id":    msg.Header.Get("List-Id"),
+		"message_id": msg.Header.Get("Message-Id"),
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	externalID := strings.Trim(msg.Header.Get("Message-Id"), "<> ")
+	if externalID == "" {
+		externalID = i.config.Folder + ":" + message.uid
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   i.source.ID,
+		ExternalID: externalID,
+		Title:      subject,
+		Author:     author,
+		Content:    content,
+		URL:        i.messageURL(message.uid),
+		WrittenAt:  writtenAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}, true
+}
+
+// messageURL builds an RFC 5092 IMAP URL for the message, as newsletters have no canonical web link
+func (i *ImapSource) messageURL(uid string) string {
+	return "imap://" + url.PathEscape(i.username) + "@" + i.host + "/" + url.PathEscape(i.config.Folder) + "/;UID=" + uid
+}
+
+// mailText extracts readable text from a message body, preferring HTML parts (stripped) over plain text
+// Charsets other than UTF-8 and ASCII are read as is
+func mailText(header map[string][]string, body io.Reader) string {
+	get := func(key string) string {
+		if values := header[key]; len(values) > 0 {
+			return values[0]
+		}
+		return ""
+	}
+
+	mediaType, params, err := mime.ParseMediaType(get("Content-Type"))
+	if err != nil {
+		mediaType = "text/plain"
+	}
+
+	if strings.HasPrefix(mediaType, "multipart/") {
+		reader := multipart.NewReader(body, params["boundary"])
+		var plain, html string
+		for {
+			part, err := reader.NextRawPart() // Raw, so transfer encoding is handled below like single-part bodies
+			if err != nil {
+				break
+			}
+			text := mailText(part.Header, part)
+			partType, _, _ := mime.ParseMediaType(part.Header.Get("Content-Type"))
+			switch {
+			case partType == "text/html" && html == "":
+				html = text
+			case (partType == "text/plain" || partType == "") && plain == "":
+				plain = text
+			case strings.HasPrefix(partType, "multipart/") && html == "":
+				html = text // Nested alternative: already resolved to its best part
+			}
+		}
+		if html != "" {
+			return html
+		}
+		return plain
+	}
+
+	switch strings.ToLower(get("Content-Transfer-Encoding")) {
+	case "quoted-printable":
+		body = quotedprintable.NewReader(body)
+	case "base64":
+		body = base64.NewDecoder(base64.StdEncoding, &newlineSkipper{r: body})
+	}
+	data, err := io.ReadAll(io.LimitReader(body, imapMaxBody))
+	if err != nil && len(data) == 0 {
+		return ""
+	}
+
+	switch mediaType {
+	case "text/html":
+		return htmlText(string(data))
+	case "text/plain":
+		return strings.TrimSpace(string(data))
+	}
+	return "" // Attachments and other media
+}
+
+// newlineSkipper drops line breaks so base64 bodies wrapped at 76 columns decode
+type newlineSkipper struct {
+	r io.Reader
+}
+
+func (n *newlineSkipper) Read(p []byte) (int, error) {
+	count, err := n.r.Read(p)
+	kept := 0
+	for _, b := range p[:count] {
+		if b != '\r' && b != '\n' {
+			p[kept] = b
+			kept++
+		}
+	}
+	return kept, err
+}
+
+// imapConn is a minimal IMAP4rev1 client connection over TLS
+type imapConn struct {
+	conn net.Conn
+	r    *bufio.Reader
+	tag  int
+}
+
+// imapResponse is one untagged server response, with any literals it carried
+type imapResponse struct {
+	text     string
+	literals [][]byte
+}
+
+// dialIMAP connects with implicit TLS (port 993 unless host has one) and reads the greeting
+func dialIMAP(ctx context.Context, host string) (*imapConn, error) {
+	addr := host
+	if _, _, err := net.SplitHostPort(host); err != nil {
+		addr = net.JoinHostPort(host, "993")
+	}
+	serverName, _, _ := net.SplitHostPort(addr)
+
+	dialer := &tls.Dialer{Config: &tls.Config{ServerName: serverName}}
+	conn, err := dialer.DialContext(ctx, "tcp", addr)
+	if err != nil {
+		return nil, err
+	}
+	if deadline, ok := ctx.Deadline(); ok {
+		conn.SetDeadline(deadline)
+	}
+
+	c := &imapConn{conn: conn, r: bufio.NewReader(conn)}
+	greeting, err := c.readResponse()
+	if err != nil {
+		conn.Close()
+		return nil, err
+	}
+	if !strings.HasPrefix(greeting.text, "* OK") && !strings.HasPrefix(greeting.text, "* PREAUTH") {
+		conn.Close()
+		return nil, fmt.Errorf("unexpected greeting: %s", greeting.text)
+	}
+	return c, nil
+}
+
+func (c *imapConn) close() error {
+	return c.conn.Close()
+}
+
+// command sends a tagged command and returns the untagged responses before its completion
+func (c *imapConn) command(format string, args ...interface{}) ([]imapResponse, error) {
+	c.tag++
+	tag := fmt.Sprintf("a%03d", c.tag)
+	if _, err := fmt.Fprintf(c.conn, "%s %s\r\n", tag, fmt.Sprintf(format, args...)); err != nil {
+		return nil, err
+	}
+	return readTagged(c.readResponse, tag)
+}
+
+// readTagged collects untagged responses until the one completing tag
+func readTagged(next func() (imapResponse, error), tag string) ([]imapResponse, error) {
+	var responses []imapResponse
+	for {
+		response, err := next()
+		if err != nil {
+			return nil, err
+		}
+		if !strings.HasPrefix(response.text, tag+" ") {
+			responses = append(responses, response)
+			continue
+		}
+		status := strings.TrimPrefix(response.text, tag+" ")
+		if !strings.HasPrefix(status, "OK") {
+			return nil, fmt.Errorf("%s", status)
+		}
+		return responses, nil
+	}
+}
+
+func (c *imapConn) readResponse() (imapResponse, error) {
+	return readIMAPResponse(c.r)
+}
+
+// readIMAPResponse reads one response line, including literals ("{size}" followed by size raw bytes)
+func readIMAPResponse(r *bufio.Reader) (imapResponse, error) {
+	var response imapResponse
+	var text strings.Builder
+	for {
+		line, err := r.ReadString('\n')
+		if err != nil {
+			return response, err
+		}
+		line = strings.TrimRight(line, "\r\n")
+
+		size, ok := imapLiteralSize(line)
+		if !ok {
+			text.WriteString(line)
+			response.text = text.String()
+			return response, nil
+		}
+
+		text.WriteString(line[:strings.LastIndex(line, "{")])
+		literal := make([]byte, size)
+		if _, err := io.ReadFull(r, literal); err != nil {
+			return response, err
+		}
+		response.literals = append(response.literals, literal)
+	}
+}
+
+// imapLiteralSize reports the size of a literal announced at the end of line
+func imapLiteralSize(line string) (int, bool) {
+	if !strings.HasSuffix(line, "}") {
+		return 0, false
+	}
+	open := strings.LastIndex(line, "{")
+	if open < 0 {
+		return 0, false
+	}
+	size, err := strconv.Atoi(line[open+1 : len(line)-1])
+	if err != nil || size < 0 {
+		return 0, false
+	}
+	return size, true
+}
+
+// imapSearchUIDs collects the UIDs of "* SEARCH" responses in ascending order
+func imapSearchUIDs(responses []imapResponse) []uint64 {
+	var uids []uint64
+	for _, response := range responses {
+		fields := strings.Fields(response.text)
+		if len(fields) < 2 || fields[0] != "*" || fields[1] != "SEARCH" {
+			continue
+		}
+		for _, field := range fields[2:] {
+			if uid, err := strconv.ParseUint(field, 10, 32); err == nil {
+				uids = append(uids, uid)
+			}
+		}
+	}
+	sort.Slice(uids, func(a, b int) bool { return uids[a] < uids[b] })
+	return uids
+}
+
+// imapFetchUID extracts the UID from a FETCH response ("* 12 FETCH (UID 345 BODY[] ...")
+func imapFetchUID(text string) string {
+	fields := strings.Fields(strings.NewReplacer("(", " ", ")", " ").Replace(text))
+	for j := 0; j+1 < len(fields); j++ {
+		if fields[j] == "UID" {
+			return fields[j+1]
+		}
+	}
+	return ""
+}
+
+// imapSinceCriteria returns the SINCE search key for since, or ALL for the first run
+func imapSinceCriteria(since time.Time) string {
+	if since.Unix() > 0 {
+		return "SINCE " + since.UTC().Format("2-Jan-2006")
+	}
+	return "ALL"
+}
+
+// imapOrFrom builds a search key matching any of the senders ("OR FROM a OR FROM b FROM c")
+// Search keys are ANDed by default, so senders are nested in pairwise ORs
+func imapOrFrom(senders []string) string {
+	if len(senders) == 1 {
+		return "FROM " + imapQuote(senders[0])
+	}
+	return "OR FROM " + imapQuote(senders[0]) + " " + imapOrFrom(senders[1:])
+}
+
+// imapQuote renders s as an IMAP quoted string
+func imapQuote(s string) string {
+	return `"` + strings.NewReplacer(`\`, `\\`, `"`, `\"`).Replace(s) + `"`
+}
@@ -0,0 +1,86 @@
+package source
+
+import (
+	"bufio"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestReadIMAPResponse_Literal(t *testing.T) {
+	stream := "* 1 FETCH (UID 42 BODY[] {11}\r\nHello\r\nThere)\r\na003 OK done\r\n"
+	r := bufio.NewReader(strings.NewReader(stream))
+
+	responses, err := readTagged(func() (imapResponse, error) { return readIMAPResponse(r) }, "a003")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if len(responses) != 1 || len(responses[0].literals) != 1 {
+		t.Fatalf("Expected one response with one literal, got %+v", responses)
+	}
+	if string(responses[0].literals[0]) != "Hello\r\nThere" || imapFetchUID(responses[0].text) != "42" {
+		t.Errorf("Unexpected response: %q, literal %q", responses[0].text, responses[0].literals[0])
+	}
+
+	r = bufio.NewReader(strings.NewReader("a004 NO [AUTHENTICATIONFAILED] Invalid credentials\r\n"))
+	if _, err := readTagged(func() (imapResponse, error) { return readIMAPResponse(r) }, "a004"); err == nil {
+		t.Error("Expected NO to be an error")
+	}
+}
+
+func TestImapSearchCriteria(t *testing.T) {
+	if got := imapOrFrom([]string{"a", "b", `c"d`}); got != `OR FROM "a" OR FROM "b" FROM "c\"d"` {
+		t.Errorf("Unexpected sender criteria: %s", got)
+	}
+	if got := imapSinceCriteria(time.Date(2024, 11, 5, 23, 0, 0, 0, time.UTC)); got != "SINCE 5-Nov-2024" {
+		t.Errorf("Unexpected since criteria: %s", got)
+	}
+	if uids := imapSearchUIDs([]imapResponse{{text: "* SEARCH 9 3 7"}}); len(uids) != 3 || uids[0] != 3 || uids[2] != 9 {
+		t.Errorf("Expected sorted UIDs, got %v", uids)
+	}
+}
+
+func TestImapMessageToArticle(t *testing.T) {
+	raw := strings.Join([]string{
+		"From: Weekly Digest <digest@example.com>",
+		"Subject: =?UTF-8?Q?Issue_42:_Caf=C3=A9?=",
+		"Date: Tue, 05 Nov 2024 09:30:00 +0000",
+		"Message-ID: <abc@example.com>",
+		"MIME-Version: 1.0",
+		`Content-Type: multipart/alternative; boundary="b1"`,
+		"",
+		"--b1",
+		"Content-Type: text/plain; charset=utf-8",
+		"",
+		"Plain version",
+		"--b1",
+		"Content-Type: text/html; charset=utf-8",
+		"Content-Transfer-Encoding: quoted-printable",
+		"",
+		"<p>Rich <b>version</b>=",
+		" with a soft line break</p>",
+		"--b1--",
+		"",
+	}, "\r\n")
+
+	src := &ImapSource{source: &db.Source{ID: "src"}, config: &db.ImapConfig{Folder: "INBOX"}, username: "me", host: "imap.example.com"}
+	article, ok := src.messageToArticle(imapMessage{uid: "7", raw: []byte(raw)}, time.Unix(0, 0))
+	if !ok {
+		t.Fatal("Expected message to convert")
+	}
+	if article.Title != "Issue 42: Café" || article.Author != "Weekly Digest" || article.ExternalID != "abc@example.com" {
+		t.Errorf("Unexpected article: %+v", article)
+	}
+	if article.Content != "Rich version with a soft line break" {
+		t.Errorf("Expected stripped HTML part, got %q", article.Content)
+	}
+	if article.URL != "imap://me@imap.example.com/INBOX/;UID=7" {
+		t.Errorf("Unexpected URL: %s", article.URL)
+	}
+
+	if _, ok := src.messageToArticle(imapMessage{uid: "7", raw: []byte(raw)}, time.Date(2025, 1, 1, 0, 0, 0, 0, time.UTC)); ok {
+		t.Error("Expected messages older than since to be skipped")
+	}
+}
@@ -0,0 +1,336 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"math"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+// jsonAPITimeLayouts are the string timestamp formats tried in order
+var jsonAPITimeLayouts = []string{time.RFC3339, "2006-01-02T15:04:05", "2006-01-02 15:04:05", "2006-01-02", time.RFC1123Z, time.RFC1123}
+
+// JSONAPISource implements the Source interface for any JSON API described by field mappings
+type JSONAPISource struct {
+	source *db.Source
+	config *db.JSONAPIConfig
+	client *http.Client
+}
+
+// NewJSONAPISource creates a new generic JSON API source
+func NewJSONAPISource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*JSONAPISource, error) {
+	var config db.JSONAPIConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid json_api config: %w", err)
+	}
+
+	js := &JSONAPISource{
+		source: source,
+		config: &config,
+		client: httpClient, // Shared client with pooled connections
+	}
+
+	// Per-API keys go in config headers, there are no global credentials
+	_ = credentials
+
+	return js, nil
+}
+
+// SourceType returns "json_api"
+func (j *JSONAPISource) SourceType() string {
+	return "json_api"
+}
+
+// Validate checks if the configuration is valid
+func (j *JSONAPISource) Validate() error {
+	u, err := url.Parse(j.config.URL)
+	if err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+		return fmt.Errorf("url must be an absolute http(s) URL, got %q", j.config.URL)
+	}
+	if j.config.Fields.Title == "" {
+		return fmt.Errorf("fields.title is required")
+	}
+	if j.config.Fields.ID == "" && j.config.Fields.URL == "" {
+		return fmt.Errorf("fields.id or fields.url is required to identify items")
+	}
+
+	// Set defaults
+	if j.config.Limit <= 0 {
+		j.config.Limit = 50
+	}
+	if j.config.Limit > 500 {
+		return fmt.Errorf("limit must be <= 500, got %d", j.config.Limit)
+	}
+
+	if p := j.config.Pagination; p != nil {
+		if err := validateEnum(p.Type, []string{"page", "offset", "cursor"}, "pagination.type"); err != nil {
+			return err
+		}
+		if p.Param == "" {
+			return fmt.Errorf("pagination.param is required")
+		}
+		if p.Type == "cursor" && p.CursorPath == "" {
+			return fmt.Errorf("pagination.cursor_path is required for cursor pagination")
+		}
+		if p.Type == "page" && p.Start == 0 {
+			p.Start = 1
+		}
+		if p.MaxPages <= 0 {
+			p.MaxPages = 5
+		}
+		if p.MaxPages > 50 {
+			return fmt.Errorf("pagination.max_pages must be <= 50, got %d", p.MaxPages)
+		}
+	}
+
+	return nil
+}
+
+// Fetch pages through the API and maps each item to an Article
+func (j *JSONAPISource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := j.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	maxPages := 1
+	var position int // Page number or offset
+	cursor := ""
+	if p := j.config.Pagination; p != nil {
+		maxPages = p.MaxPages
+		position = p.Start
+	}
+
+	var articles []db.Article
+	tally := newMatchTally(ctx, j.config.Limit)
+	for page := 0; page < maxPages && !tally.done(); page++ {
+		response, err := j.getPage(ctx, position, cursor)
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to fetch page %d: %w", page+1, err)
+		}
+
+		items, ok := lookupPath(response, j.config.ItemsPath).([]interface{})
+		if !ok {
+			return nil, nil, fmt.Errorf("items_path %q does not point to an array", j.config.ItemsPath)
+		}
+		if len(items) == 0 {
+			break
+		}
+
+		pageStart := len(articles)
+		for _, item := range items {
+			if tally.done() {
+				break
+			}
+			article, ok := j.itemToArticle(item)
+			if !ok || article.WrittenAt.Before(since) {
+				continue
+			}
+			articles = append(articles, article)
+			tally.add(article)
+		}
+		stampProvenance(ctx, articles[pageStart:], page+1)
+
+		// Advance the pagination state
+		p := j.config.Pagination
+		if p == nil {
+			break
+		}
+		switch p.Type {
+		case "page":
+			position++
+		case "offset":
+			position += len(items)
+		case "cursor":
+			cursor = stringValue(lookupPath(response, p.CursorPath))
+			if cursor == "" {
+				return articles, []db.Comment{}, nil
+			}
+		}
+	}
+
+	// Generic items have no comments
+	return articles, []db.Comment{}, nil
+}
+
+// getPage requests one page and decodes the JSON body
+func (j *JSONAPISource) getPage(ctx context.Context, position int, cursor string) (interface{}, error) {
+	u, err := url.Parse(j.config.URL)
+	if err != nil {
+		return nil, err
+	}
+	if p := j.config.Pagination; p != nil {
+		query := u.Query()
+		switch p.Type {
+		case "page", "offset":
+			query.Set(p.Param, strconv.Itoa(position))
+		case "cursor":
+			if cursor != "" {
+				query.Set(p.Param, cursor)
+			}
+		}
+		u.RawQuery = query.Encode()
+	}
+
+	req, err := http.NewRequestWithContext(ctx, "GET", u.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("Accept", "application/json")
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	for name, value := range j.config.Headers {
+		req.Header.Set(name, value)
+	}
+
+	resp, err := j.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	var response interface{}
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode response: %w", err)
+	}
+
+	return response, nil
+}
+
+// itemToArticle maps one API item to an Article, reporting false for items without a title or identity
+func (j *JSONAPISource) itemToArticle(item interface{}) (db.Article, bool) {
+	fields := j.config.Fields
+
+	title := strings.TrimSpace(stringValue(lookupPath(item, fields.Title)))
+	itemURL := stringValue(lookupPath(item, fields.URL))
+	externalID := stringValue(lookupPath(item, fields.ID))
+	if externalID == "" {
+		externalID = itemURL
+	}
+	if title == "" || externalID == "" {
+		return db.Article{}, false
+	}
+
+	writtenAt, ok := parseTimestamp(lookupPath(item, fields.Timestamp))
+	if !ok {
+		writtenAt = time.Now() // Undated items are stamped with the crawl time
+	}
+
+	meta := map[string]interface{}{}
+	if score, ok := numberValue(lookupPath(item, fields.Score)); ok {
+		meta["score"] = int(math.Round(score))
+	}
+	metadata, err := json.Marshal(meta)
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   j.source.ID,
+		ExternalID: externalID,
+		Title:      title,
+		Author:     stringValue(lookupPath(item, fields.Author)),
+		Content:    stringValue(lookupPath(item, fields.Body)),
+		URL:        itemURL,
+		WrittenAt:  writtenAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}, true
+}
+
+// lookupPath resolves a JSONPath-style dotted path ("$.data.items[0].title", "data.items.0.title")
+// An empty path (or "$") returns the value itself, a missing key returns nil
+func lookupPath(value interface{}, path string) interface{} {
+	path = strings.TrimPrefix(strings.TrimPrefix(path, "$"), ".")
+	if path == "" {
+		return value
+	}
+
+	// "items[0]" is the same as "items.0"
+	path = strings.NewReplacer("[", ".", "]", "").Replace(path)
+	for _, key := range strings.Split(path, ".") {
+		switch v := value.(type) {
+		case map[string]interface{}:
+			value = v[key]
+		case []interface{}:
+			i, err := strconv.Atoi(key)
+			if err != nil || i < 0 || i >= len(v) {
+				return nil
+			}
+			value = v[i]
+		default:
+			return nil
+		}
+	}
+	return value
+}
+
+// stringValue renders a JSON scalar as a string ("" for null, objects and arrays)
+func stringValue(value interface{}) string {
+	switch v := value.(type) {
+	case string:
+		return v
+	case float64:
+		return strconv.FormatFloat(v, 'f', -1, 64)
+	case bool:
+		return strconv.FormatBool(v)
+	default:
+		return ""
+	}
+}
+
+// numberValue reads a JSON number, also accepting numeric strings
+func numberValue(value interface{}) (float64, bool) {
+	switch v := value.(type) {
+	case float64:
+		return v, true
+	case string:
+		f, err := strconv.ParseFloat(strings.TrimSpace(v), 64)
+		return f, err == nil
+	default:
+		return 0, false
+	}
+}
+
+// parseTimestamp reads a date string or a Unix timestamp (seconds, or milliseconds when very large)
+func parseTimestamp(value interface{}) (time.Time, bool) {
+	if s, ok := value.(string); ok {
+		s = strings.TrimSpace(s)
+		for _, layout := range jsonAPITimeLayouts {
+			if t, err := time.Parse(layout, s); err == nil {
+				return t, true
+			}
+		}
+	}
+
+	n, ok := numberValue(value)
+	if !ok || n <= 0 {
+		return time.Time{}, false
+	}
+	if n > 1e12 {
+		return time.UnixMilli(int64(n)), true
+	}
+	return time.Unix(int64(n), 0), true
+}
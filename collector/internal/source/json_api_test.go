@@ -0,0 +1,54 @@
+package source
+
+import (
+	"encoding/json"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestJSONAPIItemMapping(t *testing.T) {
+	var response interface{}
+	raw := `{"data": {"children": [
+		{"data": {"id": "abc", "title": "Hello", "ups": "42", "created_utc": 1700000000, "authors": [{"name": "ada"}]}},
+		{"data": {"id": "def", "title": ""}}
+	]}}`
+	if err := json.Unmarshal([]byte(raw), &response); err != nil {
+		t.Fatalf("Failed to decode fixture: %v", err)
+	}
+
+	items, ok := lookupPath(response, "$.data.children").([]interface{})
+	if !ok || len(items) != 2 {
+		t.Fatalf("Expected 2 items, got %v", lookupPath(response, "$.data.children"))
+	}
+
+	src := &JSONAPISource{
+		source: &db.Source{ID: "src"},
+		config: &db.JSONAPIConfig{Fields: db.JSONAPIFields{
+			ID:        "data.id",
+			Title:     "data.title",
+			Author:    "data.authors[0].name",
+			Score:     "data.ups",
+			Timestamp: "data.created_utc",
+		}},
+	}
+
+	article, ok := src.itemToArticle(items[0])
+	if !ok {
+		t.Fatalf("Expected first item to map")
+	}
+	if article.ExternalID != "abc" || article.Title != "Hello" || article.Author != "ada" {
+		t.Errorf("Unexpected article: %+v", article)
+	}
+	if !article.WrittenAt.Equal(time.Unix(1700000000, 0)) {
+		t.Errorf("Unexpected timestamp: %v", article.WrittenAt)
+	}
+	if string(article.Metadata) != `{"score":42}` {
+		t.Errorf("Unexpected metadata: %s", article.Metadata)
+	}
+
+	if _, ok := src.itemToArticle(items[1]); ok {
+		t.Errorf("Expected an item without a title to be skipped")
+	}
+}
@@ -0,0 +1,306 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	kaggleBaseURL      = "https://www.kaggle.com/api/v1"
+	kagglePageSize     = 20 // Fixed by the list endpoints
+	kaggleDefaultLimit = 20
+	kaggleMaxLimit     = 100
+)
+
+// KaggleSource implements the Source interface for Kaggle datasets and competitions
+// Competition discussions have no public API endpoint, so competitions themselves stand in for them
+type KaggleSource struct {
+	source   *db.Source
+	config   *db.KaggleConfig
+	client   *http.Client
+	username string // From global config (environment variable)
+	key      string // From global config (environment variable)
+}
+
+// Kaggle API response structures
+type kaggleDataset struct {
+	Ref           string `json:"ref"` // "owner/slug"
+	Title         string `json:"title"`
+	Subtitle      string `json:"subtitle"`
+	CreatorName   string `json:"creatorName"`
+	OwnerName     string `json:"ownerName"`
+	URL           string `json:"url"`
+	LastUpdated   string `json:"lastUpdated"` // RFC 3339, sometimes without a zone
+	DownloadCount int    `json:"downloadCount"`
+	VoteCount     int    `json:"voteCount"`
+	TotalBytes    int64  `json:"totalBytes"`
+	Tags          []struct {
+		Name string `json:"name"`
+	} `json:"tags"`
+}
+
+type kaggleCompetition struct {
+	Ref         string `json:"ref"` // Competition slug
+	Title       string `json:"title"`
+	Description string `json:"description"`
+	URL         string `json:"url"`
+	Category    string `json:"category"`
+	Reward      string `json:"reward"`
+	TeamCount   int    `json:"teamCount"`
+	EnabledDate string `json:"enabledDate"`
+	Deadline    string `json:"deadline"`
+}
+
+// NewKaggleSource creates a new Kaggle source
+// Uses the Kaggle username and API key from config file
+func NewKaggleSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*KaggleSource, error) {
+	var config db.KaggleConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid kaggle config: %w", err)
+	}
+
+	ks := &KaggleSource{
+		source:   source,
+		config:   &config,
+		client:   httpClient, // Shared client with pooled connections
+		username: credentials.KaggleUsername,
+		key:      credentials.KaggleKey,
+	}
+
+	return ks, nil
+}
+
+// SourceType returns "kaggle"
+func (k *KaggleSource) SourceType() string {
+	return "kaggle"
+}
+
+// Validate checks if the configuration is valid
+func (k *KaggleSource) Validate() error {
+	if k.username == "" || k.key == "" {
+		return fmt.Errorf("COLLECTOR_KAGGLE_USERNAME and COLLECTOR_KAGGLE_KEY are required for kaggle sources")
+	}
+
+	if k.config.Kind == "" {
+		k.config.Kind = "datasets"
+	}
+	if err := validateEnum(k.config.Kind, []string{"datasets", "competitions"}, "kind"); err != nil {
+		return err
+	}
+	if k.config.Kind == "datasets" {
+		if k.config.Sort == "" {
+			k.config.Sort = "updated"
+		}
+		if err := validateEnum(k.config.Sort, []string{"hottest", "votes", "updated", "active", "published"}, "sort"); err != nil {
+			return err
+		}
+	} else if k.config.Sort != "" {
+		return fmt.Errorf("sort is only supported for datasets")
+	}
+
+	// Set defaults
+	if k.config.Limit <= 0 {
+		k.config.Limit = kaggleDefaultLimit
+	}
+	if k.config.Limit > kaggleMaxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", kaggleMaxLimit, k.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch retrieves datasets or competitions matching the search term
+func (k *KaggleSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := k.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	var articles []db.Article
+	tally := newMatchTally(ctx, k.config.Limit)
+	for page := 1; !tally.done(); page++ {
+		params := url.Values{}
+		params.Set("page", strconv.Itoa(page))
+		if k.config.Search != "" {
+			params.Set("search", k.config.Search)
+		}
+
+		var count int
+		pageStart := len(articles)
+		if k.config.Kind == "competitions" {
+			var competitions []kaggleCompetition
+			if err := k.get(ctx, "/competitions/list", params, &competitions); err != nil {
+				return nil, nil, fmt.Errorf("failed to list competitions: %w", err)
+			}
+			count = len(competitions)
+			for _, competition := range competitions {
+				if tally.done() {
+					break
+				}
+				if kaggleTime(competition.EnabledDate).Before(since) {
+					continue
+				}
+				article := k.competitionToArticle(competition)
+				articles = append(articles, article)
+				tally.add(article)
+			}
+		} else {
+			params.Set("sortBy", k.config.Sort)
+			var datasets []kaggleDataset
+			if err := k.get(ctx, "/datasets/list", params, &datasets); err != nil {
+				return nil, nil, fmt.Errorf("failed to list datasets: %w", err)
+			}
+			count = len(datasets)
+			for _, dataset := range datasets {
+				if tally.done() {
+					break
+				}
+				if kaggleTime(dataset.LastUpdated).Before(since) {
+					continue
+				}
+				article := k.datasetToArticle(dataset)
+				articles = append(articles, article)
+				tally.add(article)
+			}
+		}
+		stampProvenance(ctx, articles[pageStart:], page)
+
+		// Short page means there are no more results
+		if count < kagglePageSize {
+			break
+		}
+	}
+
+	// Kaggle discussions are not part of the public API
+	return articles, []db.Comment{}, nil
+}
+
+// get performs a rate-limited, authenticated GET and decodes the JSON response into v
+func (k *KaggleSource) get(ctx context.Context, path string, params url.Values, v interface{}) error {
+	req, err := http.NewRequestWithContext(ctx, "GET", kaggleBaseURL+path+"?"+params.Encode(), nil)
+	if err != nil {
+		return err
+	}
+	req.SetBasicAuth(k.username, k.key)
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+
+	resp, err := k.client.Do(req)
+	if err != nil {
+		return err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("Kaggle API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	if err := json.Unmarshal(body, v); err != nil {
+		return fmt.Errorf("failed to decode response: %w", err)
+	}
+	return nil
+}
+
+// datasetToArticle converts a Kaggle dataset to an Article
+func (k *KaggleSource) datasetToArticle(dataset kaggleDataset) db.Article {
+	tags := make([]string, 0, len(dataset.Tags))
+	for _, tag := range dataset.Tags {
+		tags = append(tags, tag.Name)
+	}
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"kind":        "dataset",
+		"votes":       dataset.VoteCount,
+		"downloads":   dataset.DownloadCount,
+		"size_bytes":  dataset.TotalBytes,
+		"kaggle_tags": tags,
+		"score":       dataset.VoteCount,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	author := dataset.CreatorName
+	if author == "" {
+		author = dataset.OwnerName
+	}
+	datasetURL := dataset.URL
+	if datasetURL == "" {
+		datasetURL = "https://www.kaggle.com/datasets/" + dataset.Ref
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   k.source.ID,
+		ExternalID: "dataset:" + dataset.Ref,
+		Title:      dataset.Title,
+		Author:     author,
+		Content:    dataset.Subtitle,
+		URL:        datasetURL,
+		WrittenAt:  kaggleTime(dataset.LastUpdated),
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// competitionToArticle converts a Kaggle competition to an Article
+// Competitions have no votes, so the number of entered teams is the score
+func (k *KaggleSource) competitionToArticle(competition kaggleCompetition) db.Article {
+	metadata, err := json.Marshal(map[string]interface{}{
+		"kind":     "competition",
+		"category": competition.Category,
+		"reward":   competition.Reward,
+		"teams":    competition.TeamCount,
+		"deadline": competition.Deadline,
+		"score":    competition.TeamCount,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	// The list endpoint returns the full competition URL as ref on some API versions
+	slug := competition.Ref[strings.LastIndex(competition.Ref, "/")+1:]
+	competitionURL := competition.URL
+	if competitionURL == "" {
+		competitionURL = "https://www.kaggle.com/competitions/" + slug
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   k.source.ID,
+		ExternalID: "competition:" + slug,
+		Title:      competition.Title,
+		Author:     "Kaggle",
+		Content:    competition.Description,
+		URL:        competitionURL,
+		WrittenAt:  kaggleTime(competition.EnabledDate),
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// kaggleTime parses a Kaggle timestamp, falling back to the crawl time
+func kaggleTime(value string) time.Time {
+	if t, ok := parseTimestamp(value); ok {
+		return t
+	}
+	return time.Now()
+}
@@ -0,0 +1,235 @@
+package source
+
+import (
+	"unicode"
+	"unicode/utf8"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+// keywordMatcher finds which of a set of (lowercased) keywords occur in a text in one Aho-Corasick pass
+// The text is lowercased rune by rune as it is scanned, so no lowercased copy is allocated per article
+type keywordMatcher struct {
+	next []map[byte]int32 // Trie edges per state; a missing edge follows fail
+	fail []int32          // State of the longest proper suffix that is also a trie prefix
+	out  [][]int32        // Keywords ending at the state, including those reached through fail links
+}
+
+// newKeywordMatcher builds the automaton for keywords; scan reports them by index
+func newKeywordMatcher(keywords []string) *keywordMatcher {
+	m := &keywordMatcher{next: []map[byte]int32{{}}, fail: []int32{0}, out: [][]int32{nil}}
+	for i, keyword := range keywords {
+		state := int32(0)
+		for j := 0; j < len(keyword); j++ {
+			child, ok := m.next[state][keyword[j]]
+			if !ok {
+				child = int32(len(m.next))
+				m.next = append(m.next, map[byte]int32{})
+				m.fail = append(m.fail, 0)
+				m.out = append(m.out, nil)
+				m.next[state][keyword[j]] = child
+			}
+			state = child
+		}
+		m.out[state] = append(m.out[state], int32(i))
+	}
+
+	// Breadth-first, so a state's fail target is complete before its children use it
+	queue := make([]int32, 0, len(m.next))
+	for _, child := range m.next[0] {
+		queue = append(queue, child)
+	}
+	for len(queue) > 0 {
+		state := queue[0]
+		queue = queue[1:]
+		for b, child := range m.next[state] {
+			queue = append(queue, child)
+
+			f := m.fail[state]
+			for f != 0 {
+				if _, ok := m.next[f][b]; ok {
+					break
+				}
+				f = m.fail[f]
+			}
+			if target, ok := m.next[f][b]; ok {
+				m.fail[child] = target
+			}
+			m.out[child] = append(m.out[child], m.out[m.fail[child]]...)
+		}
+	}
+
+	return m
+}
+
+// scan sets found[i] for every keyword i occurring in texts, which are matched as if joined by newlines
+func (m *keywordMatcher) scan(found []bool, texts ...string) {
+	var buf [utf8.UTFMax]byte
+	state := int32(0)
+	for i, text := range texts {
+		if i > 0 {
+			state = m.step(state, '\n', found)
+		}
+		for _, r := range text {
+			r = unicode.ToLower(r)
+			if r < utf8.RuneSelf {
+				state = m.step(state, byte(r), found)
+				continue
+			}
+			n := utf8.EncodeRune(buf[:], r)
+			for _, b := range buf[:n] {
+				state = m.step(state, b, found)
+			}
+		}
+	}
+}
+
+// step follows byte b from state and marks the keywords ending there
+func (m *keywordMatcher) step(state int32, b byte, found []bool) int32 {
+	for {
+		if next, ok := m.next[state][b]; ok {
+			state = next
+			break
+		}
+		if state == 0 {
+			return 0
+		}
+		state = m.fail[state]
+	}
+	for _, i := range m.out[state] {
+		found[i] = true
+	}
+	return state
+}
+
+// keywordFilter is a source's include and exclude keywords and queries compiled into one matcher
+// Built once per fetch, so each article is scanned once however many keywords there are
+type keywordFilter struct {
+	keywords []string // Distinct keywords, indexed by the fields below
+	matcher  *keywordMatcher
+	include  []int
+	exclude  []int
+	queries  []keywordQuery
+}
+
+// keywordQuery is a db.KeywordQuery with its keywords as matcher indexes
+type keywordQuery struct {
+	name     string
+	all      bool
+	keywords []int
+}
+
+// newKeywordFilter compiles (lowercased) keywords and queries, as normalized by ParseOptions
+func newKeywordFilter(include, exclude []string, queries []db.KeywordQuery) *keywordFilter {
+	f := &keywordFilter{}
+	index := make(map[string]int)
+	add := func(keyword string) int {
+		i, ok := index[keyword]
+		if !ok {
+			i = len(f.keywords)
+			index[keyword] = i
+			f.keywords = append(f.keywords, keyword)
+		}
+		return i
+	}
+
+	for _, keyword := range include {
+		f.include = append(f.include, add(keyword))
+	}
+	for _, keyword := range exclude {
+		f.exclude = append(f.exclude, add(keyword))
+	}
+	for _, query := range queries {
+		compiled := keywordQuery{name: query.Name, all: query.Match == "all"}
+		for _, keyword := range query.Keywords {
+			compiled.keywords = append(compiled.keywords, add(keyword))
+		}
+		f.queries = append(f.queries, compiled)
+	}
+
+	f.matcher = newKeywordMatcher(f.keywords)
+	return f
+}
+
+// scan reports which of the filter's keywords occur in the article's title or content
+func (f *keywordFilter) scan(article db.Article) []bool {
+	found := make([]bool, len(f.keywords))
+	f.matcher.scan(found, article.Title, article.Content)
+	return found
+}
+
+// matches reports whether the article passes the filter
+func (f *keywordFilter) matches(article db.Article) bool {
+	return f.passes(f.scan(article))
+}
+
+// passes reports whether scanned keywords let an article through
+// With include keywords or queries, matching either an include keyword or one query is enough
+func (f *keywordFilter) passes(found []bool) bool {
+	if anyFound(found, f.exclude) {
+		return false
+	}
+	if (len(f.include) == 0 && len(f.queries) == 0) || anyFound(found, f.include) {
+		return true
+	}
+	for _, query := range f.queries {
+		if query.matches(found) {
+			return true
+		}
+	}
+	return false
+}
+
+// matchedQueries returns the names of the queries the scanned keywords satisfy, in config order
+func (f *keywordFilter) matchedQueries(found []bool) []string {
+	var names []string
+	for _, query := range f.queries {
+		if query.matches(found) {
+			names = append(names, query.name)
+		}
+	}
+	return names
+}
+
+// hits returns the include keywords found, then the query keywords found, each once
+func (f *keywordFilter) hits(found []bool) []string {
+	var hits []string
+	seen := make(map[int]bool)
+	collect := func(indexes []int) {
+		for _, i := range indexes {
+			if found[i] && !seen[i] {
+				seen[i] = true
+				hits = append(hits, f.keywords[i])
+			}
+		}
+	}
+
+	collect(f.include)
+	for _, query := range f.queries {
+		collect(query.keywords)
+	}
+	return hits
+}
+
+// matches reports whether the scanned keywords contain any keyword of the query, or every one with match "all"
+func (q keywordQuery) matches(found []bool) bool {
+	if !q.all {
+		return anyFound(found, q.keywords)
+	}
+	for _, i := range q.keywords {
+		if !found[i] {
+			return false
+		}
+	}
+	return true
+}
+
+// anyFound reports whether any of the keyword indexes was found
+func anyFound(found []bool, indexes []int) bool {
+	for _, i := range indexes {
+		if found[i] {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,90 @@
+package source
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestKeywordMatcher_AgreesWithContains(t *testing.T) {
+	keywords := []string{"he", "she", "his", "hers", "rust async", "ünïcode", "c++", "e\ncon"}
+	m := newKeywordMatcher(keywords)
+
+	texts := [][2]string{
+		{"USHERS", ""},
+		{"Rust Async runtime", "nothing"},
+		{"ÜNÏCODE title", "his"},
+		{"C++ news", ""},
+		{"split title", "content"}, // Only matches across the newline joining title and content
+		{"", ""},
+	}
+	for _, text := range texts {
+		found := make([]bool, len(keywords))
+		m.scan(found, text[0], text[1])
+
+		joined := strings.ToLower(text[0] + "\n" + text[1])
+		for i, keyword := range keywords {
+			if want := strings.Contains(joined, keyword); found[i] != want {
+				t.Errorf("%q in %q: got %v, want %v", keyword, joined, found[i], want)
+			}
+		}
+	}
+}
+
+func TestKeywordFilter(t *testing.T) {
+	queries := []db.KeywordQuery{
+		{Name: "async-rust", Keywords: []string{"rust", "async"}, Match: "all"},
+		{Name: "python", Keywords: []string{"python"}, Match: "any"},
+	}
+	filter := newKeywordFilter([]string{"wasm"}, []string{"hiring"}, queries)
+
+	cases := []struct {
+		title string
+		want  bool
+	}{
+		{"Rust async runtimes", true},
+		{"Rust 1.80", false},
+		{"Python and WASM", true},
+		{"WASM team hiring", false},
+	}
+	for _, c := range cases {
+		if got := filter.matches(db.Article{Title: c.title}); got != c.want {
+			t.Errorf("matches(%q) = %v, want %v", c.title, got, c.want)
+		}
+	}
+
+	found := filter.scan(db.Article{Title: "Python", Content: "async Rust and wasm"})
+	if got := strings.Join(filter.hits(found), ","); got != "wasm,rust,async,python" {
+		t.Errorf("Unexpected hits: %s", got)
+	}
+	if got := strings.Join(filter.matchedQueries(found), ","); got != "async-rust,python" {
+		t.Errorf("Unexpected matched queries: %s", got)
+	}
+}
+
+// BenchmarkFilterKeywords filters 10k articles with 300 keywords
+func BenchmarkFilterKeywords(b *testing.B) {
+	include := make([]string, 300)
+	for i := range include {
+		include[i] = fmt.Sprintf("keyword%d", i)
+	}
+	articles := make([]db.Article, 10000)
+	for i := range articles {
+		articles[i] = db.Article{
+			ID:      fmt.Sprint(i),
+			Title:   fmt.Sprintf("Article %d about Go and Rust", i),
+			Content: strings.Repeat("Some ordinary content without any of the watched terms. ", 20),
+		}
+	}
+	articles[42].Content += " keyword299"
+
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		kept, _ := FilterKeywords(articles, nil, include, nil, nil)
+		if len(kept) != 1 {
+			b.Fatalf("Expected 1 article kept, got %d", len(kept))
+		}
+	}
+}
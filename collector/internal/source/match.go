@@ -0,0 +1,108 @@
+package source
+
+import (
+	"fmt"
+	"strings"
+)
+
+// SourceKey returns the "type:external_id" name that source patterns match against
+// e.g. "reddit:golang", "hackernews:top", "semantic_scholar:bulk:llm agents"
+func SourceKey(sourceType, externalID string) string {
+	return sourceType + ":" + externalID
+}
+
+// MatchPattern reports whether a source pattern matches a source key
+// Patterns are ':'-separated like keys. In a segment, "*" matches any run of characters and "?" one character,
+// neither crossing ':'; a "**" segment matches any number of segments. A pattern also matches every key
+// it is a segment prefix of, so "reddit" and "reddit:*" match all Reddit sources and "reddit:golang" also
+// matches "reddit:golang:comments".
+func MatchPattern(pattern, key string) bool {
+	return matchSegments(strings.Split(pattern, ":"), strings.Split(key, ":"))
+}
+
+// ValidatePattern checks that a source pattern is well-formed and names a known type (or a wildcard)
+func ValidatePattern(pattern string) error {
+	if strings.TrimSpace(pattern) == "" {
+		return fmt.Errorf("source pattern must not be empty")
+	}
+	sourceType, _, _ := strings.Cut(pattern, ":")
+	if !strings.ContainsAny(sourceType, "*?") && !IsValidType(sourceType) {
+		return fmt.Errorf("source pattern %q: unknown source type %q", pattern, sourceType)
+	}
+	return nil
+}
+
+// SourceFilter selects sources by pattern, shared by every feature that addresses sources by name
+type SourceFilter struct {
+	Only []string // If set, a source must match one of these
+	Skip []string // A source matching any of these is excluded
+}
+
+// Allows reports whether the filter keeps the source, and otherwise why not
+func (f SourceFilter) Allows(sourceType, externalID string) (bool, string) {
+	key := SourceKey(sourceType, externalID)
+	if len(f.Only) > 0 && !matchAny(f.Only, key) {
+		return false, "not matched by only patterns"
+	}
+	for _, pattern := range f.Skip {
+		if MatchPattern(pattern, key) {
+			return false, fmt.Sprintf("matched skip pattern %q", pattern)
+		}
+	}
+	return true, ""
+}
+
+func matchAny(patterns []string, key string) bool {
+	for _, pattern := range patterns {
+		if MatchPattern(pattern, key) {
+			return true
+		}
+	}
+	return false
+}
+
+// matchSegments matches pattern segments against key segments, pattern running out first being a prefix match
+func matchSegments(pattern, key []string) bool {
+	for len(pattern) > 0 {
+		if pattern[0] == "**" {
+			for i := 0; i <= len(key); i++ {
+				if matchSegments(pattern[1:], key[i:]) {
+					return true
+				}
+			}
+			return false
+		}
+		if len(key) == 0 || !matchSegment(pattern[0], key[0]) {
+			return false
+		}
+		pattern, key = pattern[1:], key[1:]
+	}
+	return true
+}
+
+// matchSegment matches one segment with "*" and "?" wildcards (case-sensitive, runes not bytes)
+func matchSegment(pattern, s string) bool {
+	p, t := []rune(pattern), []rune(s)
+	star, mark := -1, 0
+	i, j := 0, 0
+	for j < len(t) {
+		switch {
+		case i < len(p) && (p[i] == '?' || p[i] == t[j]):
+			i++
+			j++
+		case i < len(p) && p[i] == '*':
+			star, mark = i, j
+			i++
+		case star >= 0:
+			// Let the last "*" absorb one more character
+			mark++
+			i, j = star+1, mark
+		default:
+			return false
+		}
+	}
+	for i < len(p) && p[i] == '*' {
+		i++
+	}
+	return i == len(p)
+}
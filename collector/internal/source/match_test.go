@@ -0,0 +1,56 @@
+package source
+
+import "testing"
+
+func TestMatchPattern(t *testing.T) {
+	tests := []struct {
+		pattern string
+		key     string
+		want    bool
+	}{
+		{"reddit", "reddit:golang", true},
+		{"reddit:*", "reddit:golang:comments", true},
+		{"reddit:golang", "reddit:golang:comments", true},
+		{"reddit:go", "reddit:golang", false},
+		{"reddit:go*", "reddit:golang", true},
+		{"reddit:*:comments", "reddit:golang:comments", true},
+		{"reddit:*:comments", "reddit:golang", false},
+		{"semantic_scholar:bulk:*", "semantic_scholar:bulk:llm agents", true},
+		{"semantic_scholar:bulk:*", "semantic_scholar:author:123", false},
+		{"*:top", "hackernews:top", true},
+		{"**:comments", "reddit:u:spez:comments", true},
+		{"**:comments", "reddit:golang", false},
+		{"github:issues:golang/g?", "github:issues:golang/go", true},
+		{"hackernews", "hacker:news", false},
+	}
+
+	for _, tt := range tests {
+		if got := MatchPattern(tt.pattern, tt.key); got != tt.want {
+			t.Errorf("MatchPattern(%q, %q) = %v, want %v", tt.pattern, tt.key, got, tt.want)
+		}
+	}
+}
+
+func TestSourceFilter(t *testing.T) {
+	filter := SourceFilter{Only: []string{"reddit", "hackernews:top"}, Skip: []string{"reddit:*:comments"}}
+
+	if ok, _ := filter.Allows("reddit", "golang"); !ok {
+		t.Error("Expected reddit:golang to be allowed")
+	}
+	if ok, reason := filter.Allows("reddit", "golang:comments"); ok || reason == "" {
+		t.Errorf("Expected reddit:golang:comments to be skipped, got %v %q", ok, reason)
+	}
+	if ok, _ := filter.Allows("hackernews", "new"); ok {
+		t.Error("Expected hackernews:new to be excluded by only patterns")
+	}
+	if ok, _ := (SourceFilter{}).Allows("arxiv", "cs.LG"); !ok {
+		t.Error("Expected an empty filter to allow everything")
+	}
+
+	if err := ValidatePattern("redit:*"); err == nil {
+		t.Error("Expected an unknown type to be rejected")
+	}
+	if err := ValidatePattern("*:top"); err != nil {
+		t.Errorf("Expected a wildcard type to be accepted, got %v", err)
+	}
+}
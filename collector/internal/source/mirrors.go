@@ -0,0 +1,77 @@
+package source
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"log/slog"
+)
+
+// mirrorSwitchFailures is how many requests in a row the active mirror must fail before another one replaces it
+const mirrorSwitchFailures = 3
+
+// mirrorSet is an ordered list of interchangeable base URLs for one upstream (e.g. Nitter instances)
+// Requests go to the active mirror; when it fails (after the transport's own retries), the others are tried in order.
+// Once the active mirror has failed mirrorSwitchFailures requests in a row, the next one that answers stays active
+// for the rest of the run, so one flaky response doesn't move the whole run off the primary
+type mirrorSet struct {
+	sourceID   string
+	bases      []string
+	active     int
+	served     int              // Mirror that answered the last request
+	failures   int              // Requests in a row the active mirror failed
+	fallbackOn func(error) bool // Errors worth trying another mirror for (nil: any error)
+}
+
+func newMirrorSet(sourceID, primary string, fallbacks []string) *mirrorSet {
+	return &mirrorSet{
+		sourceID: sourceID,
+		bases:    append([]string{primary}, fallbacks...),
+	}
+}
+
+// try calls fn with each base URL, starting at the active one, until fn succeeds
+func (m *mirrorSet) try(ctx context.Context, fn func(base string) error) error {
+	var errs []error
+	for i := range m.bases {
+		idx := (m.active + i) % len(m.bases)
+		err := fn(m.bases[idx])
+		if err == nil {
+			m.served = idx
+			if idx == m.active {
+				m.failures = 0
+			} else if m.failures >= mirrorSwitchFailures {
+				m.switchTo(ctx, idx)
+			}
+			return nil
+		}
+
+		// A cancelled run is not a mirror failure, and other mirrors would fail the same way on some errors
+		if ctx.Err() != nil || (m.fallbackOn != nil && !m.fallbackOn(err)) {
+			return err
+		}
+		if idx == m.active {
+			m.failures++
+		}
+		errs = append(errs, fmt.Errorf("%s: %w", m.bases[idx], err))
+	}
+	return errors.Join(errs...)
+}
+
+// switchTo makes the mirror at idx active and records the switch in the run's request trail
+func (m *mirrorSet) switchTo(ctx context.Context, idx int) {
+	reason := fmt.Sprintf("switched from %s to %s after %d failed requests in a row", m.bases[m.active], m.bases[idx], m.failures)
+	slog.Warn("Switched to fallback mirror",
+		"source_id", m.sourceID,
+		"from", m.bases[m.active],
+		"to", m.bases[idx],
+		"failures", m.failures)
+	noteMirrorSwitch(ctx, reason)
+	m.active = idx
+	m.failures = 0
+}
+
+// current returns the base URL that answered the last request
+func (m *mirrorSet) current() string {
+	return m.bases[m.served]
+}
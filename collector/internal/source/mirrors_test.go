@@ -0,0 +1,72 @@
+package source
+
+import (
+	"context"
+	"errors"
+	"testing"
+)
+
+func TestMirrorSet_SwitchesAfterRepeatedFailures(t *testing.T) {
+	mirrors := newMirrorSet("src", "https://a", []string{"https://b", "https://c"})
+	down := map[string]bool{"https://a": true, "https://b": true}
+	ctx := WithRequestTrail(context.Background())
+
+	var tried []string
+	call := func(base string) error {
+		tried = append(tried, base)
+		if down[base] {
+			return errors.New("unavailable")
+		}
+		return nil
+	}
+
+	// Each request falls back, but the primary stays active until it has failed often enough
+	for i := 1; i <= mirrorSwitchFailures; i++ {
+		tried = nil
+		if err := mirrors.try(ctx, call); err != nil {
+			t.Fatalf("Expected fallback to succeed, got %v", err)
+		}
+		if len(tried) != 3 || tried[0] != "https://a" || mirrors.current() != "https://c" {
+			t.Errorf("Request %d: unexpected attempts %v, served by %s", i, tried, mirrors.current())
+		}
+	}
+	if stats := TrailStats(ctx); stats.MirrorSwitches != 1 || len(TrailMirrorSwitches(ctx)) != 1 {
+		t.Errorf("Expected one recorded switch, got %+v %v", stats, TrailMirrorSwitches(ctx))
+	}
+
+	// The next request starts at the mirror that took over
+	tried = nil
+	if err := mirrors.try(ctx, call); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if len(tried) != 1 || tried[0] != "https://c" {
+		t.Errorf("Expected only the active mirror to be tried, got %v", tried)
+	}
+
+	down["https://c"] = true
+	if err := mirrors.try(ctx, call); err == nil {
+		t.Errorf("Expected an error when every mirror fails")
+	}
+}
+
+func TestMirrorSet_SuccessResetsFailures(t *testing.T) {
+	mirrors := newMirrorSet("src", "https://a", []string{"https://b"})
+	ctx := context.Background()
+	primaryDown := true
+	call := func(base string) error {
+		if base == "https://a" && primaryDown {
+			return errors.New("unavailable")
+		}
+		return nil
+	}
+
+	for i := 0; i < mirrorSwitchFailures+2; i++ {
+		primaryDown = i%2 == 0 // The primary fails every other request, never enough in a row
+		if err := mirrors.try(ctx, call); err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+	}
+	if mirrors.active != 0 {
+		t.Errorf("Expected the primary to stay active, got %s", mirrors.bases[mirrors.active])
+	}
+}
@@ -0,0 +1,390 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	newsAPIBaseURL   = "https://newsapi.org/v2/everything"
+	gdeltDocBaseURL  = "https://api.gdeltproject.org/api/v2/doc/doc"
+	newsAPIPageSize  = 100                // NewsAPI maximum per request
+	gdeltMaxRecords  = 250                // GDELT ArtList maximum per request
+	gdeltTimeLayout  = "20060102150405"   // startdatetime/enddatetime
+	gdeltSeenLayout  = "20060102T150405Z" // seendate in responses
+	newsDateLayout   = "2006-01-02"       // from/to in config
+	newsDefaultLimit = 50
+	newsMaxLimit     = 250
+)
+
+// gdeltLanguages maps common ISO 639-1 codes to GDELT's sourcelang names, other values pass through
+var gdeltLanguages = map[string]string{
+	"en": "english", "de": "german", "fr": "french", "es": "spanish", "it": "italian", "pt": "portuguese",
+	"nl": "dutch", "ru": "russian", "zh": "chinese", "ja": "japanese", "ko": "korean", "ar": "arabic",
+}
+
+// NewsSource implements the Source interface for news articles from NewsAPI.org or the GDELT DOC 2.0 API
+type NewsSource struct {
+	source *db.Source
+	config *db.NewsConfig
+	client *http.Client
+	apiKey string // From global config (environment variable), NewsAPI only
+}
+
+// NewsAPI response structures
+type newsAPIResponse struct {
+	Status       string           `json:"status"`
+	Code         string           `json:"code"`    // Set when status is "error"
+	Message      string           `json:"message"` // Set when status is "error"
+	TotalResults int              `json:"totalResults"`
+	Articles     []newsAPIArticle `json:"articles"`
+}
+
+type newsAPIArticle struct {
+	Source struct {
+		Name string `json:"name"`
+	} `json:"source"`
+	Author      string    `json:"author"`
+	Title       string    `json:"title"`
+	Description string    `json:"description"`
+	URL         string    `json:"url"`
+	PublishedAt time.Time `json:"publishedAt"`
+	Content     string    `json:"content"` // Truncated to ~200 chars by NewsAPI
+}
+
+// GDELT DOC 2.0 ArtList response structures
+type gdeltResponse struct {
+	Articles []gdeltArticle `json:"articles"`
+}
+
+type gdeltArticle struct {
+	URL           string `json:"url"`
+	Title         string `json:"title"`
+	SeenDate      string `json:"seendate"`
+	Domain        string `json:"domain"`
+	Language      string `json:"language"`
+	SourceCountry string `json:"sourcecountry"`
+}
+
+// NewNewsSource creates a new news source
+// Uses the NewsAPI key from config file when the newsapi provider is selected
+func NewNewsSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*NewsSource, error) {
+	var config db.NewsConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid news config: %w", err)
+	}
+
+	ns := &NewsSource{
+		source: source,
+		config: &config,
+		client: httpClient, // Shared client with pooled connections
+		apiKey: credentials.NewsAPIKey,
+	}
+
+	return ns, nil
+}
+
+// SourceType returns "news"
+func (n *NewsSource) SourceType() string {
+	return "news"
+}
+
+// Validate checks if the configuration is valid
+func (n *NewsSource) Validate() error {
+	if n.config.Provider == "" {
+		n.config.Provider = "gdelt"
+	}
+	if err := validateEnum(n.config.Provider, []string{"newsapi", "gdelt"}, "provider"); err != nil {
+		return err
+	}
+	if n.config.Provider == "newsapi" && n.apiKey == "" {
+		return fmt.Errorf("COLLECTOR_NEWSAPI_API_KEY is required for the newsapi provider")
+	}
+	if n.config.Query == "" {
+		return fmt.Errorf("query is required")
+	}
+
+	from, to, err := n.dateRange()
+	if err != nil {
+		return err
+	}
+	if !from.IsZero() && !to.IsZero() && from.After(to) {
+		return fmt.Errorf("from (%s) must not be after to (%s)", n.config.From, n.config.To)
+	}
+
+	// Set defaults
+	if n.config.Limit <= 0 {
+		n.config.Limit = newsDefaultLimit
+	}
+	if n.config.Limit > newsMaxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", newsMaxLimit, n.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch retrieves news articles newest first
+func (n *NewsSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := n.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	// The configured start date and the last run both bound the window, whichever is later
+	from, to, _ := n.dateRange()
+	if since.After(from) {
+		from = since
+	}
+
+	var articles []db.Article
+	var err error
+	if n.config.Provider == "newsapi" {
+		articles, err = n.fetchNewsAPI(ctx, from, to)
+	} else {
+		articles, err = n.fetchGDELT(ctx, from, to)
+	}
+	if err != nil {
+		return nil, nil, err
+	}
+
+	// News articles have no comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchNewsAPI pages through the NewsAPI /v2/everything endpoint
+func (n *NewsSource) fetchNewsAPI(ctx context.Context, from, to time.Time) ([]db.Article, error) {
+	var articles []db.Article
+	tally := newMatchTally(ctx, n.config.Limit)
+	// Pages are numbered, so the size is picked once: full pages when keyword filters will thin them out
+	pageSize := tally.pageSize(newsAPIPageSize)
+	for page := 1; !tally.done(); page++ {
+		params := url.Values{}
+		params.Set("q", n.config.Query)
+		params.Set("sortBy", "publishedAt")
+		params.Set("pageSize", strconv.Itoa(pageSize))
+		params.Set("page", strconv.Itoa(page))
+		if n.config.Language != "" {
+			params.Set("language", n.config.Language)
+		}
+		if from.Unix() > 0 {
+			params.Set("from", from.UTC().Format(time.RFC3339))
+		}
+		if !to.IsZero() {
+			params.Set("to", to.UTC().Format(time.RFC3339))
+		}
+
+		body, status, err := n.get(ctx, newsAPIBaseURL+"?"+params.Encode())
+		if err != nil {
+			return nil, err
+		}
+
+		var response newsAPIResponse
+		if err := json.Unmarshal(body, &response); err != nil {
+			return nil, fmt.Errorf("failed to decode NewsAPI response: %w", err)
+		}
+		if response.Status == "error" || status != http.StatusOK {
+			return nil, fmt.Errorf("NewsAPI returned %d (%s): %s", status, response.Code, response.Message)
+		}
+
+		pageStart := len(articles)
+		for _, item := range response.Articles {
+			if tally.done() {
+				break
+			}
+			// Removed articles come back as "[Removed]" placeholders
+			if item.Title == "" || item.Title == "[Removed]" || item.URL == "" {
+				continue
+			}
+			article := n.newsAPIToArticle(item)
+			articles = append(articles, article)
+			tally.add(article)
+		}
+		stampProvenance(ctx, articles[pageStart:], page)
+
+		if len(response.Articles) < pageSize || page*pageSize >= response.TotalResults {
+			break
+		}
+	}
+
+	return articles, nil
+}
+
+// fetchGDELT queries the GDELT DOC 2.0 article list (single request, up to 250 records)
+func (n *NewsSource) fetchGDELT(ctx context.Context, from, to time.Time) ([]db.Article, error) {
+	query := n.config.Query
+	if n.config.Language != "" {
+		lang := strings.ToLower(n.config.Language)
+		if name, ok := gdeltLanguages[lang]; ok {
+			lang = name
+		}
+		query += " sourcelang:" + lang
+	}
+
+	params := url.Values{}
+	params.Set("query", query)
+	params.Set("mode", "ArtList")
+	params.Set("format", "json")
+	params.Set("sort", "DateDesc")
+	params.Set("maxrecords", strconv.Itoa(min(gdeltMaxRecords, n.config.Limit)))
+	if from.Unix() > 0 {
+		params.Set("startdatetime", from.UTC().Format(gdeltTimeLayout))
+	}
+	if !to.IsZero() {
+		params.Set("enddatetime", to.UTC().Format(gdeltTimeLayout))
+	}
+
+	body, status, err := n.get(ctx, gdeltDocBaseURL+"?"+params.Encode())
+	if err != nil {
+		return nil, err
+	}
+	if status != http.StatusOK {
+		return nil, fmt.Errorf("GDELT API returned %d: %s", status, string(body))
+	}
+
+	// GDELT answers query errors with a plain-text message and an empty body when nothing matches
+	trimmed := strings.TrimSpace(string(body))
+	if trimmed == "" || trimmed == "{}" {
+		return nil, nil
+	}
+	if !strings.HasPrefix(trimmed, "{") {
+		return nil, fmt.Errorf("GDELT API error: %s", trimmed)
+	}
+
+	var response gdeltResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode GDELT response: %w", err)
+	}
+
+	articles := make([]db.Article, 0, len(response.Articles))
+	for _, item := range response.Articles {
+		if len(articles) >= n.config.Limit {
+			break
+		}
+		if item.Title == "" || item.URL == "" {
+			continue
+		}
+		articles = append(articles, n.gdeltToArticle(item))
+	}
+	stampProvenance(ctx, articles, 0)
+
+	return articles, nil
+}
+
+// get performs a rate-limited GET, returning the body and status code
+// Plain-text bodies (GDELT query errors) pass the JSON check and are handled by the caller
+func (n *NewsSource) get(ctx context.Context, u string) ([]byte, int, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", u, nil)
+	if err != nil {
+		return nil, 0, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	if n.config.Provider == "newsapi" {
+		req.Header.Set("X-Api-Key", n.apiKey)
+	}
+
+	resp, err := n.client.Do(req)
+	if err != nil {
+		return nil, 0, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, resp.StatusCode, err
+	}
+
+	return body, resp.StatusCode, nil
+}
+
+// dateRange parses the optional from/to dates (to is inclusive, so it covers the whole day)
+func (n *NewsSource) dateRange() (from, to time.Time, err error) {
+	if n.config.From != "" {
+		if from, err = time.Parse(newsDateLayout, n.config.From); err != nil {
+			return from, to, fmt.Errorf("from must be YYYY-MM-DD, got %q", n.config.From)
+		}
+	}
+	if n.config.To != "" {
+		if to, err = time.Parse(newsDateLayout, n.config.To); err != nil {
+			return from, to, fmt.Errorf("to must be YYYY-MM-DD, got %q", n.config.To)
+		}
+		to = to.Add(24*time.Hour - time.Second)
+	}
+	return from, to, nil
+}
+
+// newsAPIToArticle converts a NewsAPI article to an Article
+func (n *NewsSource) newsAPIToArticle(item newsAPIArticle) db.Article {
+	metadata, err := json.Marshal(map[string]interface{}{
+		"provider":    "newsapi",
+		"publication": item.Source.Name,
+		"description": item.Description,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	// Prefer the description, NewsAPI truncates content with a "[+N chars]" suffix
+	content := item.Description
+	if content == "" {
+		content = item.Content
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   n.source.ID,
+		ExternalID: item.URL,
+		Title:      item.Title,
+		Author:     item.Author,
+		Content:    content,
+		URL:        item.URL,
+		WrittenAt:  item.PublishedAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// gdeltToArticle converts a GDELT article to an Article
+// GDELT only indexes titles, so content is empty and written_at is when GDELT first saw the article
+func (n *NewsSource) gdeltToArticle(item gdeltArticle) db.Article {
+	metadata, err := json.Marshal(map[string]interface{}{
+		"provider":       "gdelt",
+		"publication":    item.Domain,
+		"language":       item.Language,
+		"source_country": item.SourceCountry,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	seen, err := time.Parse(gdeltSeenLayout, item.SeenDate)
+	if err != nil {
+		seen = time.Now()
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   n.source.ID,
+		ExternalID: item.URL,
+		Title:      item.Title,
+		Author:     item.Domain,
+		Content:    "",
+		URL:        item.URL,
+		WrittenAt:  seen,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
@@ -0,0 +1,304 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/PuerkitoBio/goquery"
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+// nitterDateLayout is the format of the title attribute on Nitter's tweet date links
+const nitterDateLayout = "Jan 2, 2006 · 3:04 PM MST"
+
+// NitterSource implements the Source interface for X (Twitter) search through a Nitter instance
+type NitterSource struct {
+	source     *db.Source
+	config     *db.NitterConfig
+	userAgents []string       // Optional pool for HTML scraping
+	htmlClient *http.Client   // Client for HTML scraping (carries the cookie jar if configured)
+	cookieJar  *fileCookieJar // Optional persistent session for HTML scraping
+}
+
+// nitterTweet represents a tweet parsed from a Nitter timeline page
+type nitterTweet struct {
+	id        string
+	username  string
+	fullname  string
+	text      string
+	html      string
+	timestamp time.Time
+	replies   int
+	retweets  int
+	quotes    int
+	likes     int
+}
+
+// NewNitterSource creates a new Nitter source
+func NewNitterSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*NitterSource, error) {
+	var config db.NitterConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid nitter config: %w", err)
+	}
+
+	ns := &NitterSource{
+		source:     source,
+		config:     &config,
+		htmlClient: httpClient, // Shared client with pooled connections
+	}
+
+	// Nitter instances are public, no credentials needed
+	_ = credentials
+
+	// Every Nitter request is HTML scraping, so user agent rotation and cookie sessions apply (opt-in)
+	if opts, err := ParseOptions(source.Config); err == nil {
+		ns.userAgents = opts.UserAgents
+		if opts.CookieFile != "" {
+			jar, err := loadCookieJar(opts.CookieFile)
+			if err != nil {
+				return nil, err
+			}
+			ns.cookieJar = jar
+			ns.htmlClient = withCookieJar(httpClient, jar)
+		}
+	}
+
+	return ns, nil
+}
+
+// SourceType returns "nitter"
+func (n *NitterSource) SourceType() string {
+	return "nitter"
+}
+
+// Validate checks if the configuration is valid
+func (n *NitterSource) Validate() error {
+	if n.config.Query == "" {
+		return fmt.Errorf("query is required")
+	}
+
+	// Set defaults
+	if n.config.Instance == "" {
+		n.config.Instance = "https://nitter.net"
+	}
+	u, err := url.Parse(n.config.Instance)
+	if err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+		return fmt.Errorf("instance must be an absolute http(s) URL, got %q", n.config.Instance)
+	}
+	n.config.Instance = strings.TrimRight(n.config.Instance, "/")
+
+	for i, instance := range n.config.FallbackInstances {
+		u, err := url.Parse(instance)
+		if err != nil || (u.Scheme != "http" && u.Scheme != "https") || u.Host == "" {
+			return fmt.Errorf("fallback_instances must be absolute http(s) URLs, got %q", instance)
+		}
+		n.config.FallbackInstances[i] = strings.TrimRight(instance, "/")
+	}
+
+	if n.config.Limit <= 0 {
+		n.config.Limit = 40
+	}
+	if n.config.Limit > 200 {
+		return fmt.Errorf("limit must be <= 200, got %d", n.config.Limit)
+	}
+	if n.config.MinLikes < 0 {
+		return fmt.Errorf("min_likes must be non-negative, got %d", n.config.MinLikes)
+	}
+
+	return nil
+}
+
+// Fetch searches tweets page by page until the limit or the since cutoff is reached
+func (n *NitterSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := n.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	// Persist session cookies for the next run, even after a partial fetch
+	if n.cookieJar != nil {
+		defer func() {
+			if err := n.cookieJar.Save(); err != nil {
+				slog.Warn("Failed to save cookie file", "path", n.cookieJar.path, "error", err)
+			}
+		}()
+	}
+
+	params := url.Values{}
+	params.Set("f", "tweets")
+	params.Set("q", n.config.Query)
+	pagePath := "/search?" + params.Encode()
+
+	// Public instances go down often, so fall back to the configured alternatives in order
+	mirrors := newMirrorSet(n.source.ID, n.config.Instance, n.config.FallbackInstances)
+
+	var articles []db.Article
+	for page := 1; pagePath != "" && len(articles) < n.config.Limit; page++ {
+		var doc *goquery.Document
+		err := mirrors.try(ctx, func(base string) error {
+			var err error
+			doc, err = n.fetchPage(ctx, base, base+pagePath)
+			return err
+		})
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to fetch search page: %w", err)
+		}
+
+		tweets := parseNitterTimeline(doc)
+		if len(tweets) == 0 {
+			break
+		}
+
+		pageStart := len(articles)
+		reachedSince := false
+		for _, tweet := range tweets {
+			if len(articles) >= n.config.Limit {
+				break
+			}
+			// Search results are newest first
+			if tweet.timestamp.Before(since) {
+				reachedSince = true
+				break
+			}
+			if tweet.likes < n.config.MinLikes {
+				continue
+			}
+			articles = append(articles, n.tweetToArticle(tweet, mirrors.current()))
+		}
+		stampProvenance(ctx, articles[pageStart:], page)
+		if reachedSince {
+			break
+		}
+
+		pagePath = ""
+		if next, ok := doc.Find("div.show-more a").Last().Attr("href"); ok && strings.Contains(next, "cursor=") {
+			pagePath = "/search" + next
+		}
+	}
+
+	// Replies are not fetched, reply count is kept as num_comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchPage downloads and parses one Nitter HTML page
+func (n *NitterSource) fetchPage(ctx context.Context, instance, pageURL string) (*goquery.Document, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", pageURL, nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", pickUserAgent(n.userAgents, "meows-collector/1.0"))
+
+	resp, err := n.htmlClient.Do(req)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("HTTP %d from %s", resp.StatusCode, instance)
+	}
+
+	return goquery.NewDocumentFromReader(resp.Body)
+}
+
+// parseNitterTimeline extracts original tweets from a Nitter timeline or search page
+// Retweets and "show this thread" placeholders are skipped
+func parseNitterTimeline(doc *goquery.Document) []nitterTweet {
+	var tweets []nitterTweet
+
+	doc.Find("div.timeline-item").Each(func(i int, item *goquery.Selection) {
+		if item.Find("div.retweet-header").Length() > 0 {
+			return
+		}
+
+		// "/jack/status/20#m"
+		href, ok := item.Find("a.tweet-link").Attr("href")
+		if !ok {
+			return
+		}
+		path, _, _ := strings.Cut(href, "#")
+		parts := strings.Split(strings.Trim(path, "/"), "/")
+		if len(parts) != 3 || parts[1] != "status" {
+			return
+		}
+
+		tweet := nitterTweet{
+			id:       parts[2],
+			username: parts[0],
+			fullname: strings.TrimSpace(item.Find("a.fullname").First().Text()),
+		}
+
+		content := item.Find("div.tweet-content").First()
+		tweet.text = strings.TrimSpace(content.Text())
+		tweet.html, _ = content.Html()
+
+		if title, ok := item.Find("span.tweet-date a").Attr("title"); ok {
+			t, err := time.Parse(nitterDateLayout, title)
+			if err != nil {
+				slog.Warn("Failed to parse tweet date", "tweet_id", tweet.id, "date", title, "error", err)
+				return
+			}
+			tweet.timestamp = t
+		}
+
+		tweet.replies = nitterStat(item, "icon-comment")
+		tweet.retweets = nitterStat(item, "icon-retweet")
+		tweet.quotes = nitterStat(item, "icon-quote")
+		tweet.likes = nitterStat(item, "icon-heart")
+
+		tweets = append(tweets, tweet)
+	})
+
+	return tweets
+}
+
+// nitterStat reads the counter next to a stat icon ("12,345"), returning 0 when absent
+func nitterStat(item *goquery.Selection, icon string) int {
+	text := item.Find("div.tweet-stats span." + icon).Parent().Text()
+	text = strings.ReplaceAll(strings.TrimSpace(text), ",", "")
+	count, err := strconv.Atoi(text)
+	if err != nil {
+		return 0
+	}
+	return count
+}
+
+// tweetToArticle converts a parsed tweet to an Article
+func (n *NitterSource) tweetToArticle(tweet nitterTweet, instance string) db.Article {
+	metadata, err := json.Marshal(map[string]interface{}{
+		"score":        tweet.likes,
+		"num_comments": tweet.replies,
+		"retweets":     tweet.retweets,
+		"quotes":       tweet.quotes,
+		"display_name": tweet.fullname,
+		"instance":     instance, // Which mirror served the tweet
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   n.source.ID,
+		ExternalID: tweet.id,
+		Title:      postTitle(tweet.text),
+		Author:     tweet.username,
+		Content:    tweet.html,
+		URL:        fmt.Sprintf("https://x.com/%s/status/%s", tweet.username, tweet.id),
+		WrittenAt:  tweet.timestamp,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
@@ -0,0 +1,59 @@
+package source
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/PuerkitoBio/goquery"
+)
+
+func TestParseNitterTimeline(t *testing.T) {
+	html := `<div class="timeline">
+  <div class="timeline-item">
+    <a class="tweet-link" href="/jack/status/20#m"></a>
+    <div class="tweet-body">
+      <div class="tweet-header">
+        <a class="fullname" href="/jack" title="jack">jack</a>
+        <span class="tweet-date"><a href="/jack/status/20#m" title="Mar 21, 2006 · 8:50 PM UTC">21 Mar 2006</a></span>
+      </div>
+      <div class="tweet-content media-body">just setting up my twttr</div>
+      <div class="tweet-stats">
+        <span class="tweet-stat"><div class="icon-container"><span class="icon-comment"></span> 12,345</div></span>
+        <span class="tweet-stat"><div class="icon-container"><span class="icon-retweet"></span> 7</div></span>
+        <span class="tweet-stat"><div class="icon-container"><span class="icon-quote"></span></div></span>
+        <span class="tweet-stat"><div class="icon-container"><span class="icon-heart"></span> 1,024</div></span>
+      </div>
+    </div>
+  </div>
+  <div class="timeline-item">
+    <div class="retweet-header">biz retweeted</div>
+    <a class="tweet-link" href="/jack/status/21#m"></a>
+  </div>
+</div>`
+
+	doc, err := goquery.NewDocumentFromReader(strings.NewReader(html))
+	if err != nil {
+		t.Fatalf("Failed to parse HTML: %v", err)
+	}
+
+	tweets := parseNitterTimeline(doc)
+	if len(tweets) != 1 {
+		t.Fatalf("Expected 1 tweet (retweet skipped), got %d", len(tweets))
+	}
+
+	tweet := tweets[0]
+	if tweet.id != "20" || tweet.username != "jack" {
+		t.Errorf("Unexpected tweet identity: %s/%s", tweet.username, tweet.id)
+	}
+	if tweet.text != "just setting up my twttr" {
+		t.Errorf("Unexpected text: %q", tweet.text)
+	}
+	if want := time.Date(2006, 3, 21, 20, 50, 0, 0, time.UTC); !tweet.timestamp.Equal(want) {
+		t.Errorf("Expected timestamp %v, got %v", want, tweet.timestamp)
+	}
+	if tweet.replies != 12345 || tweet.retweets != 7 || tweet.quotes != 0 || tweet.likes != 1024 {
+		t.Errorf("Unexpected stats: replies=%d retweets=%d quotes=%d likes=%d",
+			tweet.replies, tweet.retweets, tweet.quotes, tweet.likes)
+	}
+}
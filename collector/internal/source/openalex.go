@@ -0,0 +1,310 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+// OpenAlexSource implements the Source interface for OpenAlex works
+type OpenAlexSource struct {
+	source *db.Source
+	config *db.OpenAlexConfig
+	client *http.Client
+	mailto string // From global config (environment variable), joins the polite pool
+}
+
+// OpenAlex API response structures
+type oaWorksResponse struct {
+	Meta struct {
+		Count      int    `json:"count"`
+		NextCursor string `json:"next_cursor"`
+	} `json:"meta"`
+	Results []oaWork `json:"results"`
+}
+
+type oaWork struct {
+	ID                    string           `json:"id"` // https://openalex.org/W...
+	DOI                   string           `json:"doi"`
+	DisplayName           string           `json:"display_name"`
+	PublicationDate       string           `json:"publication_date"` // YYYY-MM-DD
+	CitedByCount          int              `json:"cited_by_count"`
+	AbstractInvertedIndex map[string][]int `json:"abstract_inverted_index"`
+	Authorships           []struct {
+		Author struct {
+			DisplayName string `json:"display_name"`
+		} `json:"author"`
+	} `json:"authorships"`
+	PrimaryLocation *struct {
+		LandingPageURL string `json:"landing_page_url"`
+	} `json:"primary_location"`
+	Concepts []struct {
+		DisplayName string `json:"display_name"`
+	} `json:"concepts"`
+}
+
+// NewOpenAlexSource creates a new OpenAlex source
+// Uses the optional polite-pool email from config file
+func NewOpenAlexSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*OpenAlexSource, error) {
+	var config db.OpenAlexConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid openalex config: %w", err)
+	}
+
+	oas := &OpenAlexSource{
+		source: source,
+		config: &config,
+		client: httpClient,                 // Shared client with pooled connections
+		mailto: credentials.OpenAlexMailto, // Optional, faster and more reliable responses
+	}
+
+	return oas, nil
+}
+
+// SourceType returns "openalex"
+func (o *OpenAlexSource) SourceType() string {
+	return "openalex"
+}
+
+// Validate checks if the configuration is valid
+func (o *OpenAlexSource) Validate() error {
+	if o.config.Query == "" && len(o.config.Concepts) == 0 {
+		return fmt.Errorf("query or concepts is required")
+	}
+
+	if o.config.Sort == "" {
+		o.config.Sort = "relevance"
+	}
+	if err := validateEnum(o.config.Sort, []string{"relevance", "publication_date", "cited_by_count"}, "sort"); err != nil {
+		return err
+	}
+	if o.config.Sort == "relevance" && o.config.Query == "" {
+		return fmt.Errorf("sort 'relevance' requires a query")
+	}
+
+	if o.config.FromPublicationDate != "" {
+		if _, err := time.Parse("2006-01-02", o.config.FromPublicationDate); err != nil {
+			return fmt.Errorf("from_publication_date must be YYYY-MM-DD, got %q", o.config.FromPublicationDate)
+		}
+	}
+
+	if o.config.MaxResults <= 0 {
+		o.config.MaxResults = 100
+	}
+
+	return nil
+}
+
+// Fetch retrieves works from OpenAlex
+func (o *OpenAlexSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := o.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	works, err := o.fetchWorks(ctx)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	articles := make([]db.Article, 0, len(works))
+	for _, work := range works {
+		articles = append(articles, o.workToArticle(work))
+	}
+
+	// OpenAlex doesn't have comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchWorks pages through /works with cursor pagination until MaxResults is reached
+func (o *OpenAlexSource) fetchWorks(ctx context.Context) ([]oaWork, error) {
+	var allWorks []oaWork
+	cursor := "*"
+	perPage := 200 // API limit per request
+
+	for len(allWorks) < o.config.MaxResults && cursor != "" {
+		// Check context cancellation
+		select {
+		case <-ctx.Done():
+			return nil, ctx.Err()
+		default:
+		}
+
+		if remaining := o.config.MaxResults - len(allWorks); remaining < perPage {
+			perPage = remaining
+		}
+
+		params := o.buildParams()
+		params.Set("per-page", strconv.Itoa(perPage))
+		params.Set("cursor", cursor)
+
+		req, err := http.NewRequestWithContext(ctx, "GET", "https://api.openalex.org/works?"+params.Encode(), nil)
+		if err != nil {
+			return nil, err
+		}
+
+		resp, err := o.client.Do(req)
+		if err != nil {
+			return nil, err
+		}
+
+		body, err := readBody(resp, contentJSON)
+		resp.Body.Close()
+
+		if err != nil {
+			return nil, err
+		}
+
+		if resp.StatusCode != http.StatusOK {
+			return nil, fmt.Errorf("OpenAlex API returned %d: %s", resp.StatusCode, string(body))
+		}
+
+		var response oaWorksResponse
+		if err := json.Unmarshal(body, &response); err != nil {
+			return nil, fmt.Errorf("failed to decode response: %w", err)
+		}
+
+		if len(response.Results) == 0 {
+			break
+		}
+
+		allWorks = append(allWorks, response.Results...)
+		cursor = response.Meta.NextCursor
+	}
+
+	// Trim to max results
+	if len(allWorks) > o.config.MaxResults {
+		allWorks = allWorks[:o.config.MaxResults]
+	}
+
+	return allWorks, nil
+}
+
+// buildParams builds the search, filter, sort and mailto query parameters
+func (o *OpenAlexSource) buildParams() url.Values {
+	params := url.Values{}
+	if o.config.Query != "" {
+		params.Set("search", o.config.Query)
+	}
+
+	var filters []string
+	if o.config.FromPublicationDate != "" {
+		filters = append(filters, "from_publication_date:"+o.config.FromPublicationDate)
+	}
+	if o.config.MinCitations > 0 {
+		filters = append(filters, fmt.Sprintf("cited_by_count:>%d", o.config.MinCitations-1))
+	}
+	if len(o.config.Concepts) > 0 {
+		// Pipe means OR within a single filter
+		filters = append(filters, "concepts.id:"+strings.Join(o.config.Concepts, "|"))
+	}
+	if len(filters) > 0 {
+		params.Set("filter", strings.Join(filters, ","))
+	}
+
+	switch o.config.Sort {
+	case "publication_date", "cited_by_count":
+		params.Set("sort", o.config.Sort+":desc")
+	default:
+		params.Set("sort", "relevance_score:desc")
+	}
+
+	if o.mailto != "" {
+		params.Set("mailto", o.mailto)
+	}
+
+	return params
+}
+
+// workToArticle converts an OpenAlex work to an Article
+func (o *OpenAlexSource) workToArticle(work oaWork) db.Article {
+	authorNames := make([]string, len(work.Authorships))
+	for i, authorship := range work.Authorships {
+		authorNames[i] = authorship.Author.DisplayName
+	}
+
+	var primaryAuthor string
+	if len(authorNames) > 0 {
+		primaryAuthor = authorNames[0]
+	}
+
+	concepts := make([]string, 0, len(work.Concepts))
+	for _, c := range work.Concepts {
+		concepts = append(concepts, c.DisplayName)
+	}
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"citations": work.CitedByCount,
+		"doi":       work.DOI,
+		"authors":   authorNames,
+		"concepts":  concepts,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	// Prefer the publisher landing page, fall back to DOI, then the OpenAlex page
+	articleURL := work.ID
+	if work.DOI != "" {
+		articleURL = work.DOI
+	}
+	if work.PrimaryLocation != nil && work.PrimaryLocation.LandingPageURL != "" {
+		articleURL = work.PrimaryLocation.LandingPageURL
+	}
+
+	writtenAt, err := time.Parse("2006-01-02", work.PublicationDate)
+	if err != nil {
+		writtenAt = time.Now()
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   o.source.ID,
+		ExternalID: strings.TrimPrefix(work.ID, "https://openalex.org/"),
+		Title:      work.DisplayName,
+		Author:     primaryAuthor,
+		Content:    reconstructAbstract(work.AbstractInvertedIndex),
+		URL:        articleURL,
+		WrittenAt:  writtenAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// reconstructAbstract rebuilds plain text from OpenAlex's inverted index (word -> positions)
+func reconstructAbstract(index map[string][]int) string {
+	if len(index) == 0 {
+		return ""
+	}
+
+	type wordPos struct {
+		word string
+		pos  int
+	}
+	var words []wordPos
+	for word, positions := range index {
+		for _, pos := range positions {
+			words = append(words, wordPos{word: word, pos: pos})
+		}
+	}
+	sort.Slice(words, func(i, j int) bool { return words[i].pos < words[j].pos })
+
+	parts := make([]string, len(words))
+	for i, w := range words {
+		parts[i] = w.word
+	}
+	return strings.Join(parts, " ")
+}
@@ -0,0 +1,25 @@
+package source
+
+import "testing"
+
+func TestReconstructAbstract(t *testing.T) {
+	index := map[string][]int{
+		"Attention": {0},
+		"is":        {1},
+		"all":       {2},
+		"you":       {3},
+		"need.":     {4},
+		"attention": {6},
+		"Use":       {5},
+	}
+
+	got := reconstructAbstract(index)
+	want := "Attention is all you need. Use attention"
+	if got != want {
+		t.Errorf("Expected %q, got %q", want, got)
+	}
+
+	if reconstructAbstract(nil) != "" {
+		t.Errorf("Expected empty abstract for missing index")
+	}
+}
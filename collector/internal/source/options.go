@@ -0,0 +1,418 @@
+package source
+
+import (
+	"encoding/json"
+	"fmt"
+	"math"
+	"math/rand/v2"
+	"slices"
+	"sort"
+	"strings"
+	"time"
+	"unicode"
+	"unicode/utf8"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+// ParseOptions extracts the settings shared by all source types from a source config
+func ParseOptions(raw json.RawMessage) (db.SourceOptions, error) {
+	var opts db.SourceOptions
+	if len(raw) == 0 {
+		return opts, nil
+	}
+	if err := json.Unmarshal(raw, &opts); err != nil {
+		return opts, fmt.Errorf("invalid source options: %w", err)
+	}
+	if opts.MaxItemAgeHours < 0 {
+		return opts, fmt.Errorf("max_item_age_hours must be non-negative, got %d", opts.MaxItemAgeHours)
+	}
+	if opts.TargetMatches < 0 || opts.TargetMatches > maxTargetMatches {
+		return opts, fmt.Errorf("target_matches must be between 0 and %d, got %d", maxTargetMatches, opts.TargetMatches)
+	}
+	if opts.TargetRequests < 0 || opts.TargetRequests > maxTargetRequests {
+		return opts, fmt.Errorf("target_requests must be between 0 and %d, got %d", maxTargetRequests, opts.TargetRequests)
+	}
+	if opts.TargetMatches > 0 && opts.TargetRequests == 0 {
+		opts.TargetRequests = defaultTargetRequests
+	}
+	if opts.KeywordsURL != "" && !isHTTPURL(opts.KeywordsURL) {
+		return opts, fmt.Errorf("keywords_url must be an absolute http(s) URL, got %q", opts.KeywordsURL)
+	}
+	if err := validateActiveWindow(&opts); err != nil {
+		return opts, err
+	}
+	if opts.DedupKey != "" {
+		if err := validateEnum(opts.DedupKey, DedupKeys, "dedup_key"); err != nil {
+			return opts, err
+		}
+	}
+	opts.Tags = normalizeStrings(opts.Tags)
+	opts.UserAgents = normalizeStrings(opts.UserAgents)
+	opts.IncludeKeywords = normalizeKeywords(opts.IncludeKeywords)
+	opts.ExcludeKeywords = normalizeKeywords(opts.ExcludeKeywords)
+	if err := validateQueries(opts.Queries); err != nil {
+		return opts, err
+	}
+	return opts, nil
+}
+
+// QueryMatchModes lists the valid match values of a keyword query
+var QueryMatchModes = []string{"any", "all"}
+
+// validateQueries checks and normalizes the queries option in place
+// Names must be unique since matched_queries lists them; keywords are lowercased like include_keywords
+func validateQueries(queries []db.KeywordQuery) error {
+	seen := make(map[string]bool, len(queries))
+	for i := range queries {
+		query := &queries[i]
+		query.Name = strings.TrimSpace(query.Name)
+		if query.Name == "" {
+			return fmt.Errorf("queries[%d] needs a name", i)
+		}
+		if seen[query.Name] {
+			return fmt.Errorf("duplicate query name %q", query.Name)
+		}
+		seen[query.Name] = true
+
+		query.Keywords = normalizeKeywords(query.Keywords)
+		if len(query.Keywords) == 0 {
+			return fmt.Errorf("query %q needs at least one keyword", query.Name)
+		}
+		if query.Match == "" {
+			query.Match = "any"
+		}
+		if err := validateEnum(query.Match, QueryMatchModes, "match"); err != nil {
+			return fmt.Errorf("query %q: %w", query.Name, err)
+		}
+	}
+	return nil
+}
+
+// EffectiveSince moves since forward to the source's max item age, if one is set
+// Sources stop paginating time-ordered listings at since, so this bounds first runs and long gaps
+func EffectiveSince(since time.Time, maxItemAgeHours int) time.Time {
+	if maxItemAgeHours <= 0 {
+		return since
+	}
+	if cutoff := time.Now().Add(-time.Duration(maxItemAgeHours) * time.Hour); cutoff.After(since) {
+		return cutoff
+	}
+	return since
+}
+
+// weekdayNames maps active_days entries to weekdays
+var weekdayNames = map[string]time.Weekday{
+	"sun": time.Sunday, "mon": time.Monday, "tue": time.Tuesday, "wed": time.Wednesday,
+	"thu": time.Thursday, "fri": time.Friday, "sat": time.Saturday,
+}
+
+// validateActiveWindow checks and normalizes active_days, active_hours and timezone
+func validateActiveWindow(opts *db.SourceOptions) error {
+	for i, day := range opts.ActiveDays {
+		day = strings.ToLower(strings.TrimSpace(day))
+		if _, ok := weekdayNames[day]; !ok {
+			return fmt.Errorf("active_days must contain mon, tue, wed, thu, fri, sat or sun, got %q", opts.ActiveDays[i])
+		}
+		opts.ActiveDays[i] = day
+	}
+	opts.ActiveDays = normalizeStrings(opts.ActiveDays)
+
+	if opts.ActiveHours != "" {
+		if _, _, err := parseActiveHours(opts.ActiveHours); err != nil {
+			return err
+		}
+	}
+
+	if opts.Timezone != "" {
+		if _, err := time.LoadLocation(opts.Timezone); err != nil {
+			return fmt.Errorf("invalid timezone %q: %w", opts.Timezone, err)
+		}
+	}
+
+	return nil
+}
+
+// parseActiveHours parses "HH:MM-HH:MM" into minutes since midnight
+func parseActiveHours(window string) (start, end int, err error) {
+	from, to, ok := strings.Cut(strings.TrimSpace(window), "-")
+	if !ok {
+		return 0, 0, fmt.Errorf("active_hours must be HH:MM-HH:MM, got %q", window)
+	}
+	if start, err = parseClock(from); err == nil {
+		end, err = parseClock(to)
+	}
+	if err != nil || start == end {
+		return 0, 0, fmt.Errorf("active_hours must be HH:MM-HH:MM with distinct times, got %q", window)
+	}
+	return start, end, nil
+}
+
+// parseClock parses "HH:MM" (24h) into minutes since midnight
+func parseClock(value string) (int, error) {
+	t, err := time.Parse("15:04", strings.TrimSpace(value))
+	if err != nil {
+		return 0, err
+	}
+	return t.Hour()*60 + t.Minute(), nil
+}
+
+// IsActive reports whether a source's active window (if any) includes now
+// Options are expected to come from ParseOptions, so parse errors count as always active
+func IsActive(opts db.SourceOptions, now time.Time) bool {
+	if opts.Timezone != "" {
+		if loc, err := time.LoadLocation(opts.Timezone); err == nil {
+			now = now.In(loc)
+		}
+	}
+
+	if len(opts.ActiveDays) > 0 {
+		active := false
+		for _, day := range opts.ActiveDays {
+			if weekdayNames[day] == now.Weekday() {
+				active = true
+				break
+			}
+		}
+		if !active {
+			return false
+		}
+	}
+
+	if opts.ActiveHours != "" {
+		start, end, err := parseActiveHours(opts.ActiveHours)
+		if err != nil {
+			return true
+		}
+		minute := now.Hour()*60 + now.Minute()
+		if start < end {
+			return minute >= start && minute < end
+		}
+		// Window wraps past midnight, e.g. 22:00-06:00
+		return minute >= start || minute < end
+	}
+
+	return true
+}
+
+// keywordSnippetRadius is how many characters of context keyword_snippet keeps on each side of the hit
+const keywordSnippetRadius = 80
+
+// FilterKeywords drops articles that miss every include keyword and query, or hit any exclude keyword
+// Comments belonging to dropped articles are dropped with them, and kept articles record their hits (see markKeywordHits)
+func FilterKeywords(articles []db.Article, comments []db.Comment, include, exclude []string, queries []db.KeywordQuery) ([]db.Article, []db.Comment) {
+	if len(include) == 0 && len(exclude) == 0 && len(queries) == 0 {
+		return articles, comments
+	}
+
+	filter := newKeywordFilter(include, exclude, queries)
+	kept := make([]db.Article, 0, len(articles))
+	keptIDs := make(map[string]bool, len(articles))
+	for _, article := range articles {
+		found := filter.scan(article)
+		if !filter.passes(found) {
+			continue
+		}
+		markKeywordHits(&article, filter, found)
+		kept = append(kept, article)
+		keptIDs[article.ID] = true
+	}
+
+	keptComments := make([]db.Comment, 0, len(comments))
+	for _, comment := range comments {
+		if keptIDs[comment.ArticleID] {
+			keptComments = append(keptComments, comment)
+		}
+	}
+
+	return kept, keptComments
+}
+
+// QueryKeywords lists the keywords of every query, for reports that treat them as include keywords
+func QueryKeywords(queries []db.KeywordQuery) []string {
+	var keywords []string
+	for _, query := range queries {
+		keywords = append(keywords, query.Keywords...)
+	}
+	return normalizeStrings(keywords)
+}
+
+// markKeywordHits stores the include and query keywords an article matched as keyword_hits, the content around the
+// earliest of them as keyword_snippet and the queries it matched as matched_queries, so digests can show why it was kept
+func markKeywordHits(article *db.Article, filter *keywordFilter, found []bool) {
+	if matched := filter.matchedQueries(found); len(matched) > 0 {
+		addMatchedQueries(article, matched)
+	}
+
+	hits := filter.hits(found)
+	if len(hits) == 0 {
+		return
+	}
+
+	setMetadata(article, "keyword_hits", hits)
+	if snippet := keywordSnippet(article.Content, hits); snippet != "" {
+		setMetadata(article, "keyword_snippet", snippet)
+	}
+}
+
+// addMatchedQueries adds query names to matched_queries, keeping those a multi-query Semantic Scholar source put there
+func addMatchedQueries(article *db.Article, names []string) {
+	var existing struct {
+		MatchedQueries []string `json:"matched_queries"`
+	}
+	if json.Unmarshal(article.Metadata, &existing) != nil {
+		existing.MatchedQueries = nil
+	}
+	for _, name := range names {
+		if !slices.Contains(existing.MatchedQueries, name) {
+			existing.MatchedQueries = append(existing.MatchedQueries, name)
+		}
+	}
+	setMetadata(article, "matched_queries", existing.MatchedQueries)
+}
+
+// keywordSnippet returns the text around the earliest keyword hit on one line, with ellipses where it was cut
+// Returns "" when no keyword occurs in text
+func keywordSnippet(text string, keywords []string) string {
+	// Lowercase rune by rune so positions line up with the original text
+	runes := []rune(text)
+	lower := make([]rune, len(runes))
+	for i, r := range runes {
+		lower[i] = unicode.ToLower(r)
+	}
+	lowerText := string(lower)
+
+	at, length := -1, 0
+	for _, keyword := range keywords {
+		if i := strings.Index(lowerText, keyword); i >= 0 {
+			if pos := utf8.RuneCountInString(lowerText[:i]); at < 0 || pos < at {
+				at, length = pos, utf8.RuneCountInString(keyword)
+			}
+		}
+	}
+	if at < 0 {
+		return ""
+	}
+
+	start, end := max(at-keywordSnippetRadius, 0), min(at+length+keywordSnippetRadius, len(runes))
+	snippet := collapseSpace(string(runes[start:end]))
+	if start > 0 {
+		snippet = "…" + snippet
+	}
+	if end < len(runes) {
+		snippet += "…"
+	}
+	return snippet
+}
+
+// KeywordNearMiss describes a dropped article that came closest to passing the keyword filters
+type KeywordNearMiss struct {
+	Title      string   `json:"title"`
+	URL        string   `json:"url,omitempty"`
+	Overlap    float64  `json:"overlap"`               // Share of include keyword words found, 1 when an include keyword matched
+	Matched    []string `json:"matched,omitempty"`     // Include keyword words found in the title or content
+	ExcludedBy string   `json:"excluded_by,omitempty"` // Exclude keyword that dropped the article
+}
+
+// KeywordNearMisses ranks articles by partial include keyword overlap and returns the top n
+// Meant for articles FilterKeywords dropped entirely, to show why a filter matched nothing.
+// Articles that matched an include keyword but hit an exclude keyword rank first (overlap 1).
+func KeywordNearMisses(articles []db.Article, include, exclude []string, n int) []KeywordNearMiss {
+	var words []string
+	seen := make(map[string]bool)
+	for _, keyword := range include {
+		for _, word := range strings.Fields(keyword) {
+			if !seen[word] {
+				seen[word] = true
+				words = append(words, word)
+			}
+		}
+	}
+
+	misses := make([]KeywordNearMiss, 0, len(articles))
+	for _, article := range articles {
+		text := strings.ToLower(article.Title + "\n" + article.Content)
+		miss := KeywordNearMiss{Title: article.Title, URL: article.URL}
+		for _, word := range words {
+			if strings.Contains(text, word) {
+				miss.Matched = append(miss.Matched, word)
+			}
+		}
+		switch {
+		case len(include) == 0 || containsAny(text, include):
+			miss.Overlap = 1
+		case len(words) > 0:
+			miss.Overlap = math.Round(float64(len(miss.Matched))/float64(len(words))*100) / 100
+		}
+		for _, keyword := range exclude {
+			if strings.Contains(text, keyword) {
+				miss.ExcludedBy = keyword
+				break
+			}
+		}
+		misses = append(misses, miss)
+	}
+
+	sort.SliceStable(misses, func(i, j int) bool { return misses[i].Overlap > misses[j].Overlap })
+	if len(misses) > n {
+		misses = misses[:n]
+	}
+	return misses
+}
+
+// containsAny reports whether text contains any of the (lowercased) keywords
+func containsAny(text string, keywords []string) bool {
+	for _, keyword := range keywords {
+		if strings.Contains(text, keyword) {
+			return true
+		}
+	}
+	return false
+}
+
+// ApplyTags copies the source tags into the metadata of every article
+func ApplyTags(articles []db.Article, tags []string) {
+	if len(tags) == 0 {
+		return
+	}
+
+	for i := range articles {
+		setMetadata(&articles[i], "tags", tags)
+	}
+}
+
+// pickUserAgent returns a random entry of the user agent pool, or fallback when the pool is empty
+// Only HTML scraping requests rotate user agents; API requests keep their fixed identifier
+func pickUserAgent(pool []string, fallback string) string {
+	if len(pool) == 0 {
+		return fallback
+	}
+	return pool[rand.IntN(len(pool))]
+}
+
+// normalizeKeywords lowercases keywords for case-insensitive matching, then normalizes them like other lists
+func normalizeKeywords(keywords []string) []string {
+	for i := range keywords {
+		keywords[i] = strings.ToLower(keywords[i])
+	}
+	return normalizeStrings(keywords)
+}
+
+// normalizeStrings trims whitespace and drops empty or duplicate values, preserving order
+func normalizeStrings(values []string) []string {
+	if len(values) == 0 {
+		return nil
+	}
+
+	seen := make(map[string]bool, len(values))
+	normalized := make([]string, 0, len(values))
+	for _, v := range values {
+		v = strings.TrimSpace(v)
+		if v == "" || seen[v] {
+			continue
+		}
+		seen[v] = true
+		normalized = append(normalized, v)
+	}
+	return normalized
+}
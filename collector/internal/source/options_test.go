@@ -0,0 +1,186 @@
+package source
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestFilterKeywords(t *testing.T) {
+	articles := []db.Article{
+		{ID: "a1", Title: "Rust 2024 edition released", Content: "Async closures land"},
+		{ID: "a2", Title: "Go generics deep dive", Content: "Type parameters"},
+		{ID: "a3", Title: "Rust job board", Content: "We are HIRING"},
+	}
+	comments := []db.Comment{
+		{ID: "c1", ArticleID: "a1"},
+		{ID: "c2", ArticleID: "a2"},
+		{ID: "c3", ArticleID: "a3"},
+	}
+
+	opts, err := ParseOptions([]byte(`{"include_keywords": [" RUST "], "exclude_keywords": ["hiring"]}`))
+	if err != nil {
+		t.Fatalf("ParseOptions failed: %v", err)
+	}
+
+	kept, keptComments := FilterKeywords(articles, comments, opts.IncludeKeywords, opts.ExcludeKeywords, opts.Queries)
+
+	if len(kept) != 1 || kept[0].ID != "a1" {
+		t.Fatalf("Expected only a1 to be kept, got %+v", kept)
+	}
+	if len(keptComments) != 1 || keptComments[0].ID != "c1" {
+		t.Errorf("Expected only c1 to be kept, got %+v", keptComments)
+	}
+
+	var metadata map[string]interface{}
+	if err := json.Unmarshal(kept[0].Metadata, &metadata); err != nil {
+		t.Fatal(err)
+	}
+	if hits, _ := metadata["keyword_hits"].([]interface{}); len(hits) != 1 || hits[0] != "rust" {
+		t.Errorf("Expected keyword_hits [rust], got %v", metadata["keyword_hits"])
+	}
+	if _, ok := metadata["keyword_snippet"]; ok {
+		t.Errorf("Expected no snippet for a title-only hit, got %v", metadata["keyword_snippet"])
+	}
+}
+
+func TestFilterKeywords_Queries(t *testing.T) {
+	articles := []db.Article{
+		{ID: "a1", Title: "Rust async runtime benchmarks", Content: "Tokio vs smol"},
+		{ID: "a2", Title: "Async Python", Content: "asyncio tips"},
+		{ID: "a3", Title: "Go generics", Content: "Type parameters"},
+	}
+
+	opts, err := ParseOptions([]byte(`{"queries": [
+		{"name": "async-rust", "keywords": ["Rust", "async"], "match": "all"},
+		{"name": "python", "keywords": ["python", "django"]}
+	]}`))
+	if err != nil {
+		t.Fatalf("ParseOptions failed: %v", err)
+	}
+
+	kept, _ := FilterKeywords(articles, nil, opts.IncludeKeywords, opts.ExcludeKeywords, opts.Queries)
+	if len(kept) != 2 || kept[0].ID != "a1" || kept[1].ID != "a2" {
+		t.Fatalf("Expected a1 and a2 to be kept, got %+v", kept)
+	}
+
+	for i, want := range []string{"async-rust", "python"} {
+		var metadata map[string]interface{}
+		if err := json.Unmarshal(kept[i].Metadata, &metadata); err != nil {
+			t.Fatal(err)
+		}
+		if queries, _ := metadata["matched_queries"].([]interface{}); len(queries) != 1 || queries[0] != want {
+			t.Errorf("Expected matched_queries [%s] on %s, got %v", want, kept[i].ID, metadata["matched_queries"])
+		}
+	}
+
+	for _, raw := range []string{
+		`{"queries": [{"keywords": ["rust"]}]}`,
+		`{"queries": [{"name": "a", "keywords": ["rust"]}, {"name": "a", "keywords": ["go"]}]}`,
+		`{"queries": [{"name": "a", "keywords": [" "]}]}`,
+		`{"queries": [{"name": "a", "keywords": ["rust"], "match": "most"}]}`,
+	} {
+		if _, err := ParseOptions([]byte(raw)); err == nil {
+			t.Errorf("Expected %s to be rejected", raw)
+		}
+	}
+}
+
+func TestKeywordSnippet(t *testing.T) {
+	text := strings.Repeat("filler ", 30) + "the Tokio   runtime is async" + strings.Repeat(" tail", 30)
+	snippet := keywordSnippet(text, []string{"async", "tokio"})
+	if !strings.HasPrefix(snippet, "…") || !strings.HasSuffix(snippet, "…") || !strings.Contains(snippet, "the Tokio runtime is async") {
+		t.Errorf("Unexpected snippet: %q", snippet)
+	}
+	if got := keywordSnippet("Ünïcode TOKIO", []string{"tokio"}); got != "Ünïcode TOKIO" {
+		t.Errorf("Expected the whole short text, got %q", got)
+	}
+	if got := keywordSnippet("nothing here", []string{"tokio"}); got != "" {
+		t.Errorf("Expected no snippet, got %q", got)
+	}
+}
+
+func TestEffectiveSince(t *testing.T) {
+	epoch := time.Unix(0, 0)
+	recent := time.Now().Add(-time.Hour)
+
+	if got := EffectiveSince(epoch, 0); !got.Equal(epoch) {
+		t.Errorf("Expected since to be unchanged without max age, got %v", got)
+	}
+	if got := EffectiveSince(epoch, 24); time.Since(got) > 25*time.Hour {
+		t.Errorf("Expected since to be clamped to about 24h ago, got %v", got)
+	}
+	if got := EffectiveSince(recent, 24); !got.Equal(recent) {
+		t.Errorf("Expected a more recent since to win, got %v", got)
+	}
+	if _, err := ParseOptions([]byte(`{"max_item_age_hours": -1}`)); err == nil {
+		t.Errorf("Expected negative max_item_age_hours to be rejected")
+	}
+}
+
+func TestIsActive(t *testing.T) {
+	opts, err := ParseOptions([]byte(`{"active_days": ["Mon", "fri"], "active_hours": "22:00-06:00", "timezone": "UTC"}`))
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	// 2024-01-01 is a Monday
+	cases := []struct {
+		at   time.Time
+		want bool
+	}{
+		{time.Date(2024, 1, 1, 23, 0, 0, 0, time.UTC), true},  // Monday, inside the wrapped window
+		{time.Date(2024, 1, 1, 5, 59, 0, 0, time.UTC), true},  // Monday, before the window ends
+		{time.Date(2024, 1, 1, 12, 0, 0, 0, time.UTC), false}, // Monday, outside hours
+		{time.Date(2024, 1, 2, 23, 0, 0, 0, time.UTC), false}, // Tuesday
+	}
+	for _, c := range cases {
+		if got := IsActive(opts, c.at); got != c.want {
+			t.Errorf("IsActive(%v) = %v, want %v", c.at, got, c.want)
+		}
+	}
+
+	if !IsActive(db.SourceOptions{}, time.Now()) {
+		t.Errorf("Expected a source without a window to always be active")
+	}
+	for _, raw := range []string{`{"active_days": ["someday"]}`, `{"active_hours": "9-17"}`, `{"timezone": "Mars/Olympus"}`} {
+		if _, err := ParseOptions([]byte(raw)); err == nil {
+			t.Errorf("Expected %s to be rejected", raw)
+		}
+	}
+}
+
+func TestKeywordNearMisses(t *testing.T) {
+	articles := []db.Article{
+		{Title: "Go generics deep dive", Content: "Type parameters"},
+		{Title: "Tokio 1.40 released", Content: "An async runtime update"},
+		{Title: "Async runtime hiring", Content: "Rust async runtime team"},
+	}
+
+	misses := KeywordNearMisses(articles, []string{"rust async runtime"}, []string{"hiring"}, 2)
+	if len(misses) != 2 {
+		t.Fatalf("Expected 2 near misses, got %d", len(misses))
+	}
+	if misses[0].Title != "Async runtime hiring" || misses[0].Overlap != 1 || misses[0].ExcludedBy != "hiring" {
+		t.Errorf("Expected the excluded article first, got %+v", misses[0])
+	}
+	if misses[1].Title != "Tokio 1.40 released" || misses[1].Overlap != 0.67 || len(misses[1].Matched) != 2 {
+		t.Errorf("Expected the partial match second, got %+v", misses[1])
+	}
+}
+
+func TestParseOptionsTargetMatches(t *testing.T) {
+	opts, err := ParseOptions([]byte(`{"target_matches": 20}`))
+	if err != nil {
+		t.Fatalf("ParseOptions failed: %v", err)
+	}
+	if opts.TargetRequests != defaultTargetRequests {
+		t.Errorf("Expected the default request budget, got %d", opts.TargetRequests)
+	}
+	if _, err := ParseOptions([]byte(`{"target_matches": -1}`)); err == nil {
+		t.Error("Expected a negative target_matches to be rejected")
+	}
+}
@@ -0,0 +1,387 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"encoding/xml"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	cratesBaseURL       = "https://crates.io"
+	pypiBaseURL         = "https://pypi.org"
+	pypiStatsBaseURL    = "https://pypistats.org/api"
+	packageDefaultLimit = 50
+	packageMaxLimit     = 100 // crates.io maximum per_page
+	packageMaxKeywords  = 20
+)
+
+// PackageRegistrySource implements the Source interface for new package releases on crates.io or PyPI
+type PackageRegistrySource struct {
+	source *db.Source
+	config *db.PackageRegistryConfig
+	client *http.Client
+}
+
+// packageRelease is one release from either registry, normalized before conversion
+type packageRelease struct {
+	name        string
+	version     string
+	description string
+	url         string
+	releasedAt  time.Time
+	downloads   int // Recent downloads, the score
+	metadata    map[string]interface{}
+}
+
+// crates.io response structures
+type cratesResponse struct {
+	Crates []crate `json:"crates"`
+}
+
+type crate struct {
+	Name            string    `json:"name"`
+	Description     string    `json:"description"`
+	MaxVersion      string    `json:"max_version"`
+	NewestVersion   string    `json:"newest_version"`
+	Downloads       int       `json:"downloads"`
+	RecentDownloads int       `json:"recent_downloads"` // Last 90 days
+	Repository      string    `json:"repository"`
+	CreatedAt       time.Time `json:"created_at"`
+	UpdatedAt       time.Time `json:"updated_at"`
+}
+
+// PyPI RSS response structures
+type pypiFeed struct {
+	Items []pypiItem `xml:"channel>item"`
+}
+
+type pypiItem struct {
+	Title       string `xml:"title"` // "name 1.2.3"
+	Link        string `xml:"link"`
+	Description string `xml:"description"` // Package summary
+	Author      string `xml:"author"`
+	PubDate     string `xml:"pubDate"`
+}
+
+// pypistats.org response structure
+type pypiStatsResponse struct {
+	Data struct {
+		LastMonth int `json:"last_month"`
+	} `json:"data"`
+}
+
+// NewPackageRegistrySource creates a new package registry source
+// Neither registry needs credentials
+func NewPackageRegistrySource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*PackageRegistrySource, error) {
+	var config db.PackageRegistryConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid package_registry config: %w", err)
+	}
+
+	ps := &PackageRegistrySource{
+		source: source,
+		config: &config,
+		client: httpClient, // Shared client with pooled connections
+	}
+
+	return ps, nil
+}
+
+// SourceType returns "package_registry"
+func (p *PackageRegistrySource) SourceType() string {
+	return "package_registry"
+}
+
+// Validate checks if the configuration is valid
+func (p *PackageRegistrySource) Validate() error {
+	if err := validateEnum(p.config.Registry, []string{"crates", "pypi"}, "registry"); err != nil {
+		return err
+	}
+	if len(p.config.Keywords) == 0 {
+		return fmt.Errorf("at least one keyword is required")
+	}
+	if len(p.config.Keywords) > packageMaxKeywords {
+		return fmt.Errorf("keywords must have at most %d entries, got %d", packageMaxKeywords, len(p.config.Keywords))
+	}
+	for _, keyword := range p.config.Keywords {
+		if strings.TrimSpace(keyword) == "" {
+			return fmt.Errorf("keywords must not be empty")
+		}
+	}
+
+	// Set defaults
+	if p.config.Limit <= 0 {
+		p.config.Limit = packageDefaultLimit
+	}
+	if p.config.Limit > packageMaxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", packageMaxLimit, p.config.Limit)
+	}
+	if p.config.MinDownloads < 0 {
+		return fmt.Errorf("min_downloads must be non-negative, got %d", p.config.MinDownloads)
+	}
+
+	return nil
+}
+
+// Fetch retrieves releases published since the last run that match any keyword
+func (p *PackageRegistrySource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := p.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	var articles []db.Article
+	var err error
+	if p.config.Registry == "crates" {
+		articles, err = p.fetchCrates(ctx, since)
+	} else {
+		articles, err = p.fetchPyPI(ctx, since)
+	}
+	if err != nil {
+		return nil, nil, err
+	}
+
+	// Package registries have no comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchCrates searches crates.io once per keyword, most recently updated first
+func (p *PackageRegistrySource) fetchCrates(ctx context.Context, since time.Time) ([]db.Article, error) {
+	articles := make([]db.Article, 0)
+	seen := make(map[string]bool)
+
+	for _, keyword := range p.config.Keywords {
+		params := url.Values{}
+		params.Set("q", keyword)
+		params.Set("sort", "recent-updates")
+		params.Set("per_page", strconv.Itoa(p.config.Limit))
+
+		var response cratesResponse
+		if err := p.getJSON(ctx, cratesBaseURL+"/api/v1/crates?"+params.Encode(), &response); err != nil {
+			return nil, fmt.Errorf("failed to search crates for %q: %w", keyword, err)
+		}
+
+		pageStart := len(articles)
+		for _, c := range response.Crates {
+			// Sorted by update time, so the rest is older too
+			if c.UpdatedAt.Before(since) {
+				break
+			}
+			version := c.MaxVersion
+			if c.NewestVersion != "" {
+				version = c.NewestVersion // Includes pre-releases
+			}
+			if seen[c.Name+"@"+version] || c.RecentDownloads < p.config.MinDownloads {
+				continue
+			}
+			seen[c.Name+"@"+version] = true
+
+			articles = append(articles, p.releaseToArticle(packageRelease{
+				name:        c.Name,
+				version:     version,
+				description: c.Description,
+				url:         cratesBaseURL + "/crates/" + c.Name + "/" + version,
+				releasedAt:  c.UpdatedAt,
+				downloads:   c.RecentDownloads,
+				metadata: map[string]interface{}{
+					"total_downloads": c.Downloads,
+					"repository":      c.Repository,
+					"created_at":      c.CreatedAt,
+					"matched_keyword": keyword,
+				},
+			}))
+		}
+		stampProvenance(ctx, articles[pageStart:], 0)
+	}
+
+	return articles, nil
+}
+
+// fetchPyPI matches keywords against PyPI's feed of the latest releases
+// PyPI has no search API, so only releases still in the feed (the last 100 across PyPI) are seen
+func (p *PackageRegistrySource) fetchPyPI(ctx context.Context, since time.Time) ([]db.Article, error) {
+	items, err := p.fetchPyPIUpdates(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("failed to fetch PyPI updates: %w", err)
+	}
+	feedProv, _ := lastRequest(ctx)
+
+	articles := make([]db.Article, 0)
+	seen := make(map[string]bool)
+	for _, item := range items {
+		name, version, _ := strings.Cut(strings.TrimSpace(item.Title), " ")
+		keyword, ok := matchPackageKeyword(p.config.Keywords, name, item.Description)
+		if !ok || seen[name+"@"+version] {
+			continue
+		}
+		releasedAt, err := time.Parse(time.RFC1123, item.PubDate)
+		if err != nil {
+			releasedAt = time.Now()
+		}
+		if releasedAt.Before(since) {
+			continue
+		}
+		seen[name+"@"+version] = true
+
+		// Download counts come from pypistats.org, one request per matched release
+		downloads, downloadsErr := p.pypiDownloads(ctx, name)
+		if downloadsErr != nil {
+			// An unknown count doesn't count against min_downloads
+			slog.Warn("Failed to fetch PyPI download count", "package", name, "error", downloadsErr)
+		} else if downloads < p.config.MinDownloads {
+			continue
+		}
+
+		article := p.releaseToArticle(packageRelease{
+			name:        name,
+			version:     version,
+			description: item.Description,
+			url:         item.Link,
+			releasedAt:  releasedAt,
+			downloads:   downloads,
+			metadata: map[string]interface{}{
+				"author":          item.Author,
+				"matched_keyword": keyword,
+			},
+		})
+		if !feedProv.FetchedAt.IsZero() {
+			setMetadata(&article, "provenance", feedProv) // The feed, not the stats request
+		}
+		if downloadsErr != nil {
+			addEnrichmentError(&article, "download_count", downloadsErr)
+		}
+		articles = append(articles, article)
+	}
+
+	return articles, nil
+}
+
+// fetchPyPIUpdates fetches the RSS feed of the latest releases
+func (p *PackageRegistrySource) fetchPyPIUpdates(ctx context.Context) ([]pypiItem, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", pypiBaseURL+"/rss/updates.xml", nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+
+	resp, err := p.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentXML)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("PyPI RSS returned %d", resp.StatusCode)
+	}
+
+	var feed pypiFeed
+	if err := xml.Unmarshal(body, &feed); err != nil {
+		return nil, fmt.Errorf("failed to decode RSS: %w", err)
+	}
+
+	return feed.Items, nil
+}
+
+// pypiDownloads returns a package's downloads over the last month
+func (p *PackageRegistrySource) pypiDownloads(ctx context.Context, name string) (int, error) {
+	var response pypiStatsResponse
+	if err := p.getJSON(ctx, pypiStatsBaseURL+"/packages/"+url.PathEscape(strings.ToLower(name))+"/recent?period=month", &response); err != nil {
+		return 0, err
+	}
+	return response.Data.LastMonth, nil
+}
+
+// getJSON performs a rate-limited GET and decodes the JSON response into v
+func (p *PackageRegistrySource) getJSON(ctx context.Context, reqURL string, v interface{}) error {
+	req, err := http.NewRequestWithContext(ctx, "GET", reqURL, nil)
+	if err != nil {
+		return err
+	}
+	// crates.io rejects requests without a User-Agent
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+
+	resp, err := p.client.Do(req)
+	if err != nil {
+		return err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	if err := json.Unmarshal(body, v); err != nil {
+		return fmt.Errorf("failed to decode response: %w", err)
+	}
+
+	return nil
+}
+
+// matchPackageKeyword returns the first keyword found in the package name or summary (case-insensitive)
+func matchPackageKeyword(keywords []string, name, summary string) (string, bool) {
+	text := strings.ToLower(name + " " + summary)
+	for _, keyword := range keywords {
+		if strings.Contains(text, strings.ToLower(strings.TrimSpace(keyword))) {
+			return keyword, true
+		}
+	}
+	return "", false
+}
+
+// releaseToArticle converts a package release to an Article
+// The name and summary become the content, recent downloads the score
+func (p *PackageRegistrySource) releaseToArticle(release packageRelease) db.Article {
+	release.metadata["registry"] = p.config.Registry
+	release.metadata["package"] = release.name
+	release.metadata["version"] = release.version
+	release.metadata["downloads"] = release.downloads
+	release.metadata["score"] = release.downloads
+	metadata, err := json.Marshal(release.metadata)
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	content := release.name
+	if release.description != "" {
+		content = release.name + ": " + strings.TrimSpace(release.description)
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   p.source.ID,
+		ExternalID: release.name + "@" + release.version,
+		Title:      strings.TrimSpace(release.name + " " + release.version),
+		Author:     release.name,
+		Content:    content,
+		URL:        release.url,
+		WrittenAt:  release.releasedAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
@@ -0,0 +1,48 @@
+package source
+
+import (
+	"encoding/json"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestMatchPackageKeyword(t *testing.T) {
+	keywords := []string{"Tokio", "async runtime"}
+
+	if keyword, ok := matchPackageKeyword(keywords, "tokio-util", "Utilities"); !ok || keyword != "Tokio" {
+		t.Errorf("Expected name match on Tokio, got %q, %v", keyword, ok)
+	}
+	if keyword, ok := matchPackageKeyword(keywords, "smol", "A small and fast Async Runtime"); !ok || keyword != "async runtime" {
+		t.Errorf("Expected summary match, got %q, %v", keyword, ok)
+	}
+	if _, ok := matchPackageKeyword(keywords, "serde", "Serialization framework"); ok {
+		t.Error("Expected no match")
+	}
+}
+
+func TestPackageReleaseToArticle(t *testing.T) {
+	src := &PackageRegistrySource{source: &db.Source{ID: "src"}, config: &db.PackageRegistryConfig{Registry: "pypi"}}
+	article := src.releaseToArticle(packageRelease{
+		name:        "httpx",
+		version:     "0.28.0",
+		description: " The next generation HTTP client. ",
+		url:         "https://pypi.org/project/httpx/0.28.0/",
+		releasedAt:  time.Date(2024, 11, 28, 0, 0, 0, 0, time.UTC),
+		downloads:   1200,
+		metadata:    map[string]interface{}{"matched_keyword": "http"},
+	})
+
+	if article.ExternalID != "httpx@0.28.0" || article.Title != "httpx 0.28.0" || article.Content != "httpx: The next generation HTTP client." {
+		t.Errorf("Unexpected article: %+v", article)
+	}
+
+	var metadata map[string]interface{}
+	if err := json.Unmarshal(article.Metadata, &metadata); err != nil {
+		t.Fatalf("Invalid metadata: %v", err)
+	}
+	if metadata["score"] != float64(1200) || metadata["registry"] != "pypi" || metadata["matched_keyword"] != "http" {
+		t.Errorf("Unexpected metadata: %v", metadata)
+	}
+}
@@ -0,0 +1,242 @@
+package source
+
+import (
+	"context"
+	"crypto/sha1"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	podcastIndexBaseURL = "https://api.podcastindex.org/api/1.0"
+	podcastDefaultLimit = 50
+	podcastMaxLimit     = 1000 // Podcast Index maximum per request
+	podcastMaxFeeds     = 50
+)
+
+// PodcastSource implements the Source interface for podcast episodes from the Podcast Index API
+type PodcastSource struct {
+	source    *db.Source
+	config    *db.PodcastConfig
+	client    *http.Client
+	apiKey    string // From global config (environment variable)
+	apiSecret string // From global config (environment variable)
+}
+
+// Podcast Index response structures
+type podcastEpisodesResponse struct {
+	Status      interface{}      `json:"status"` // "true"/"false" on search, bool on episodes
+	Description string           `json:"description"`
+	Items       []podcastEpisode `json:"items"`
+}
+
+type podcastEpisode struct {
+	ID            int64  `json:"id"`
+	Title         string `json:"title"`
+	Link          string `json:"link"`
+	Description   string `json:"description"` // Usually HTML show notes
+	GUID          string `json:"guid"`
+	DatePublished int64  `json:"datePublished"` // Unix seconds
+	EnclosureURL  string `json:"enclosureUrl"`
+	EnclosureType string `json:"enclosureType"`
+	Duration      int    `json:"duration"` // Seconds
+	Image         string `json:"image"`
+	FeedID        int64  `json:"feedId"`
+	FeedTitle     string `json:"feedTitle"`
+	FeedAuthor    string `json:"feedAuthor"`
+	FeedLanguage  string `json:"feedLanguage"`
+}
+
+// NewPodcastSource creates a new podcast source
+// Uses the Podcast Index API key and secret from config file
+func NewPodcastSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*PodcastSource, error) {
+	var config db.PodcastConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid podcast config: %w", err)
+	}
+
+	ps := &PodcastSource{
+		source:    source,
+		config:    &config,
+		client:    httpClient, // Shared client with pooled connections
+		apiKey:    credentials.PodcastIndexAPIKey,
+		apiSecret: credentials.PodcastIndexAPISecret,
+	}
+
+	return ps, nil
+}
+
+// SourceType returns "podcast"
+func (p *PodcastSource) SourceType() string {
+	return "podcast"
+}
+
+// Validate checks if the configuration is valid
+func (p *PodcastSource) Validate() error {
+	if p.apiKey == "" || p.apiSecret == "" {
+		return fmt.Errorf("COLLECTOR_PODCASTINDEX_API_KEY and COLLECTOR_PODCASTINDEX_API_SECRET are required for podcast sources")
+	}
+	if (p.config.Query == "") == (len(p.config.FeedIDs) == 0) {
+		return fmt.Errorf("exactly one of query or feed_ids is required")
+	}
+	if len(p.config.FeedIDs) > podcastMaxFeeds {
+		return fmt.Errorf("feed_ids must have at most %d entries, got %d", podcastMaxFeeds, len(p.config.FeedIDs))
+	}
+
+	// Set defaults
+	if p.config.Limit <= 0 {
+		p.config.Limit = podcastDefaultLimit
+	}
+	if p.config.Limit > podcastMaxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", podcastMaxLimit, p.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch retrieves episodes matching the query, or the latest episodes of the configured feeds
+func (p *PodcastSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := p.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	params := url.Values{}
+	params.Set("max", strconv.Itoa(p.config.Limit))
+	params.Set("fulltext", "") // Full descriptions instead of the first 100 words
+
+	path := "/episodes/byfeedid"
+	if p.config.Query != "" {
+		// byperson matches episode titles and descriptions as well as person tags, unlike byterm which only finds feeds
+		path = "/search/byperson"
+		params.Set("q", p.config.Query)
+	} else {
+		ids := make([]string, len(p.config.FeedIDs))
+		for i, id := range p.config.FeedIDs {
+			ids[i] = strconv.FormatInt(id, 10)
+		}
+		params.Set("id", strings.Join(ids, ","))
+		if since.Unix() > 0 {
+			params.Set("since", strconv.FormatInt(since.Unix(), 10))
+		}
+	}
+
+	response, err := p.get(ctx, path, params)
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to fetch episodes: %w", err)
+	}
+
+	articles := make([]db.Article, 0, len(response.Items))
+	for _, episode := range response.Items {
+		if episode.Title == "" || time.Unix(episode.DatePublished, 0).Before(since) {
+			continue
+		}
+		articles = append(articles, p.episodeToArticle(episode))
+	}
+	stampProvenance(ctx, articles, 0)
+
+	// Podcast Index has no comments
+	return articles, []db.Comment{}, nil
+}
+
+// get performs a rate-limited, signed GET against the Podcast Index API
+func (p *PodcastSource) get(ctx context.Context, path string, params url.Values) (*podcastEpisodesResponse, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", podcastIndexBaseURL+path+"?"+params.Encode(), nil)
+	if err != nil {
+		return nil, err
+	}
+	for name, value := range podcastAuthHeaders(p.apiKey, p.apiSecret, time.Now()) {
+		req.Header.Set(name, value)
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+
+	resp, err := p.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("Podcast Index API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	var response podcastEpisodesResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode response: %w", err)
+	}
+	if status := fmt.Sprint(response.Status); status == "false" {
+		return nil, fmt.Errorf("Podcast Index API error: %s", response.Description)
+	}
+
+	return &response, nil
+}
+
+// podcastAuthHeaders returns the Podcast Index request signature headers
+// Authorization is the hex SHA-1 of key + secret + the Unix time sent in X-Auth-Date
+func podcastAuthHeaders(key, secret string, now time.Time) map[string]string {
+	date := strconv.FormatInt(now.Unix(), 10)
+	sum := sha1.Sum([]byte(key + secret + date))
+	return map[string]string{
+		"X-Auth-Key":    key,
+		"X-Auth-Date":   date,
+		"Authorization": hex.EncodeToString(sum[:]),
+	}
+}
+
+// episodeToArticle converts a podcast episode to an Article
+func (p *PodcastSource) episodeToArticle(episode podcastEpisode) db.Article {
+	metadata, err := json.Marshal(map[string]interface{}{
+		"feed_id":          episode.FeedID,
+		"feed_title":       episode.FeedTitle,
+		"language":         episode.FeedLanguage,
+		"duration_seconds": episode.Duration,
+		"enclosure_url":    episode.EnclosureURL,
+		"enclosure_type":   episode.EnclosureType,
+		"image":            episode.Image,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	// Episodes without a web page link to the audio file
+	link := episode.Link
+	if link == "" {
+		link = episode.EnclosureURL
+	}
+	author := episode.FeedAuthor
+	if author == "" {
+		author = episode.FeedTitle
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   p.source.ID,
+		ExternalID: strconv.FormatInt(episode.ID, 10),
+		Title:      episode.Title,
+		Author:     author,
+		Content:    htmlText(episode.Description),
+		URL:        link,
+		WrittenAt:  time.Unix(episode.DatePublished, 0),
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
@@ -0,0 +1,181 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"sync"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+// Provenance describes the HTTP exchange an article was read from
+// Stored under the "provenance" metadata key so odd items can be traced back to a specific API call
+type Provenance struct {
+	RequestURL string    `json:"request_url,omitempty"`
+	Status     int       `json:"status,omitempty"`
+	FetchedAt  time.Time `json:"fetched_at"`
+	Page       int       `json:"page,omitempty"` // 1-based page index for paginated sources
+}
+
+// RequestStats counts the HTTP traffic of one source run
+type RequestStats struct {
+	Requests       int `json:"requests"`        // Each request counts once, however often it was retried
+	Retries        int `json:"retries"`         // Extra attempts made by the retry transport
+	RateLimited    int `json:"rate_limited"`    // 429 responses, including ones that were retried
+	MirrorSwitches int `json:"mirror_switches"` // Times a fallback mirror or host replaced the active one
+}
+
+// requestTrail remembers the last completed request and the request counts of one source run
+type requestTrail struct {
+	mu       sync.Mutex
+	last     Provenance
+	stats    RequestStats
+	switches []string // Reasons of the mirror switches, in order
+}
+
+type requestTrailKey struct{}
+
+// WithRequestTrail returns a context whose outbound requests are recorded by TrackRequests
+func WithRequestTrail(ctx context.Context) context.Context {
+	return context.WithValue(ctx, requestTrailKey{}, &requestTrail{})
+}
+
+// TrailStats returns the request counts recorded in ctx so far
+func TrailStats(ctx context.Context) RequestStats {
+	trail, ok := ctx.Value(requestTrailKey{}).(*requestTrail)
+	if !ok {
+		return RequestStats{}
+	}
+	trail.mu.Lock()
+	defer trail.mu.Unlock()
+	return trail.stats
+}
+
+// TrailMirrorSwitches returns why each mirror switch recorded in ctx so far happened
+func TrailMirrorSwitches(ctx context.Context) []string {
+	trail, ok := ctx.Value(requestTrailKey{}).(*requestTrail)
+	if !ok {
+		return nil
+	}
+	trail.mu.Lock()
+	defer trail.mu.Unlock()
+	return append([]string(nil), trail.switches...)
+}
+
+// noteMirrorSwitch records a switch to a fallback mirror
+func noteMirrorSwitch(ctx context.Context, reason string) {
+	trail, ok := ctx.Value(requestTrailKey{}).(*requestTrail)
+	if !ok {
+		return
+	}
+	trail.mu.Lock()
+	defer trail.mu.Unlock()
+	trail.stats.MirrorSwitches++
+	trail.switches = append(trail.switches, reason)
+}
+
+// noteAttempt counts one attempt of the retry transport
+func noteAttempt(ctx context.Context, resp *http.Response, attempt int) {
+	trail, ok := ctx.Value(requestTrailKey{}).(*requestTrail)
+	if !ok {
+		return
+	}
+	trail.mu.Lock()
+	defer trail.mu.Unlock()
+	if attempt > 0 {
+		trail.stats.Retries++
+	}
+	if resp != nil && resp.StatusCode == http.StatusTooManyRequests {
+		trail.stats.RateLimited++
+	}
+}
+
+// TrackRequests records the final URL and status of every response into the request context's trail
+// The URL is stored with credential query parameters masked, as it ends up in article metadata
+// Install it outside the retry transport so only the attempt that produced the body is recorded
+// (the retry transport counts retries and 429s itself)
+func TrackRequests() Middleware {
+	return func(next http.RoundTripper) http.RoundTripper {
+		return roundTripperFunc(func(req *http.Request) (*http.Response, error) {
+			resp, err := next.RoundTrip(req)
+			trail, ok := req.Context().Value(requestTrailKey{}).(*requestTrail)
+			if !ok {
+				return resp, err
+			}
+
+			trail.mu.Lock()
+			trail.stats.Requests++
+			if err == nil {
+				trail.last = Provenance{RequestURL: redactURL(req.URL), Status: resp.StatusCode, FetchedAt: time.Now().UTC()}
+			}
+			trail.mu.Unlock()
+			return resp, err
+		})
+	}
+}
+
+// lastRequest returns the most recent request recorded in ctx
+func lastRequest(ctx context.Context) (Provenance, bool) {
+	trail, ok := ctx.Value(requestTrailKey{}).(*requestTrail)
+	if !ok {
+		return Provenance{}, false
+	}
+	trail.mu.Lock()
+	defer trail.mu.Unlock()
+	return trail.last, !trail.last.FetchedAt.IsZero()
+}
+
+// stampProvenance attributes articles to the request that was just made
+// Call it right after the page they were parsed from was fetched; page <= 0 omits the page index
+func stampProvenance(ctx context.Context, articles []db.Article, page int) {
+	prov, ok := lastRequest(ctx)
+	if !ok {
+		return
+	}
+	if page > 0 {
+		prov.Page = page
+	}
+	for i := range articles {
+		setMetadata(&articles[i], "provenance", prov)
+	}
+}
+
+// StampFetchTime records fetchedAt as provenance on articles that weren't attributed to a specific request
+func StampFetchTime(articles []db.Article, fetchedAt time.Time) {
+	for i := range articles {
+		if !hasMetadata(articles[i], "provenance") {
+			setMetadata(&articles[i], "provenance", Provenance{FetchedAt: fetchedAt.UTC()})
+		}
+	}
+}
+
+// hasMetadata reports whether the article metadata object has key
+func hasMetadata(article db.Article, key string) bool {
+	var metadata map[string]json.RawMessage
+	if err := json.Unmarshal(article.Metadata, &metadata); err != nil {
+		return false
+	}
+	_, ok := metadata[key]
+	return ok
+}
+
+// setMetadata sets key in the article metadata object
+// Metadata that isn't a JSON object is left untouched
+func setMetadata(article *db.Article, key string, value interface{}) {
+	metadata := make(map[string]interface{})
+	if len(article.Metadata) > 0 {
+		if err := json.Unmarshal(article.Metadata, &metadata); err != nil {
+			return
+		}
+	}
+
+	metadata[key] = value
+
+	updated, err := json.Marshal(metadata)
+	if err != nil {
+		return
+	}
+	article.Metadata = updated
+}
@@ -0,0 +1,116 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestProvenance_RecordsLastRequest(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusAccepted)
+	}))
+	defer server.Close()
+
+	client := &http.Client{Transport: chainMiddleware(http.DefaultTransport, TrackRequests())}
+	ctx := WithRequestTrail(context.Background())
+
+	req, _ := http.NewRequestWithContext(ctx, "GET", server.URL+"/items?page=2", nil)
+	resp, err := client.Do(req)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	resp.Body.Close()
+
+	articles := []db.Article{{Metadata: []byte(`{"score":3}`)}, {}}
+	stampProvenance(ctx, articles, 2)
+	StampFetchTime(articles, time.Unix(0, 0)) // Must not overwrite request provenance
+
+	for i, article := range articles {
+		var metadata struct {
+			Score      int        `json:"score"`
+			Provenance Provenance `json:"provenance"`
+		}
+		if err := json.Unmarshal(article.Metadata, &metadata); err != nil {
+			t.Fatalf("Invalid metadata on article %d: %v", i, err)
+		}
+		prov := metadata.Provenance
+		if prov.RequestURL != server.URL+"/items?page=2" || prov.Status != http.StatusAccepted || prov.Page != 2 || prov.FetchedAt.IsZero() {
+			t.Errorf("Unexpected provenance on article %d: %+v", i, prov)
+		}
+		if i == 0 && metadata.Score != 3 {
+			t.Errorf("Expected existing metadata to be kept, got %s", article.Metadata)
+		}
+	}
+}
+
+func TestProvenance_RedactsCredentials(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {}))
+	defer server.Close()
+
+	client := &http.Client{Transport: chainMiddleware(http.DefaultTransport, TrackRequests())}
+	ctx := WithRequestTrail(context.Background())
+
+	req, _ := http.NewRequestWithContext(ctx, "GET", server.URL+"/search.json?api_key=secret&q=llm", nil)
+	resp, err := client.Do(req)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	resp.Body.Close()
+
+	articles := []db.Article{{}}
+	stampProvenance(ctx, articles, 0)
+	if got := string(articles[0].Metadata); strings.Contains(got, "secret") || !strings.Contains(got, "api_key=REDACTED") {
+		t.Errorf("Expected the API key to be redacted, got %s", got)
+	}
+}
+
+func TestStampFetchTime_WithoutTrail(t *testing.T) {
+	articles := []db.Article{{}}
+	stampProvenance(context.Background(), articles, 1) // No trail: no-op
+	if len(articles[0].Metadata) != 0 {
+		t.Errorf("Expected no provenance without a trail, got %s", articles[0].Metadata)
+	}
+
+	fetchedAt := time.Date(2024, 11, 15, 10, 0, 0, 0, time.UTC)
+	StampFetchTime(articles, fetchedAt)
+	if string(articles[0].Metadata) != `{"provenance":{"fetched_at":"2024-11-15T10:00:00Z"}}` {
+		t.Errorf("Unexpected metadata: %s", articles[0].Metadata)
+	}
+}
+
+func TestTrailStats_CountsRetriesAndRateLimits(t *testing.T) {
+	var calls int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if atomic.AddInt32(&calls, 1) == 1 {
+			w.WriteHeader(http.StatusTooManyRequests)
+			return
+		}
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer server.Close()
+
+	client := &http.Client{Transport: chainMiddleware(http.DefaultTransport, TrackRequests(), withRetry(2, time.Millisecond, maxRetryAfter))}
+	ctx := WithRequestTrail(context.Background())
+
+	req, _ := http.NewRequestWithContext(ctx, "GET", server.URL, nil)
+	resp, err := client.Do(req)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	resp.Body.Close()
+
+	if stats := TrailStats(ctx); stats != (RequestStats{Requests: 1, Retries: 1, RateLimited: 1}) {
+		t.Errorf("Unexpected stats: %+v", stats)
+	}
+	if stats := TrailStats(context.Background()); stats != (RequestStats{}) {
+		t.Errorf("Expected zero stats without a trail, got %+v", stats)
+	}
+}
@@ -0,0 +1,365 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"encoding/xml"
+	"fmt"
+	"html"
+	"net/http"
+	"net/url"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const eutilsBaseURL = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils"
+
+// pubmedDateFormat is the E-utilities date format for mindate/maxdate
+const pubmedDateFormat = "2006/01/02"
+
+// xmlTagPattern matches inline markup (e.g. <i>, <sup>) inside PubMed titles and abstracts
+var xmlTagPattern = regexp.MustCompile(`<[^>]+>`)
+
+// PubMedSource implements the Source interface for PubMed via NCBI E-utilities
+type PubMedSource struct {
+	source *db.Source
+	config *db.PubMedConfig
+	client *http.Client
+	apiKey string // From global config (environment variable)
+}
+
+// E-utilities response structures
+type pmSearchResponse struct {
+	Result struct {
+		Count  string   `json:"count"`
+		IDList []string `json:"idlist"`
+	} `json:"esearchresult"`
+}
+
+type pmArticleSet struct {
+	Articles []pmArticle `xml:"PubmedArticle"`
+}
+
+type pmArticle struct {
+	PMID    string `xml:"MedlineCitation>PMID"`
+	Article struct {
+		Title    pmText `xml:"ArticleTitle"`
+		Abstract struct {
+			Texts []pmAbstractText `xml:"AbstractText"`
+		} `xml:"Abstract"`
+		Authors []struct {
+			LastName       string `xml:"LastName"`
+			ForeName       string `xml:"ForeName"`
+			CollectiveName string `xml:"CollectiveName"`
+		} `xml:"AuthorList>Author"`
+		Journal struct {
+			Title   string `xml:"Title"`
+			PubDate struct {
+				Year        string `xml:"Year"`
+				Month       string `xml:"Month"`
+				Day         string `xml:"Day"`
+				MedlineDate string `xml:"MedlineDate"`
+			} `xml:"JournalIssue>PubDate"`
+		} `xml:"Journal"`
+	} `xml:"MedlineCitation>Article"`
+	ArticleIDs []struct {
+		IDType string `xml:"IdType,attr"`
+		Value  string `xml:",chardata"`
+	} `xml:"PubmedData>ArticleIdList>ArticleId"`
+}
+
+type pmAbstractText struct {
+	Label string `xml:"Label,attr"`
+	pmText
+}
+
+// pmText keeps the raw inner XML so inline markup doesn't drop words
+type pmText struct {
+	Inner string `xml:",innerxml"`
+}
+
+// String returns the text with inline markup removed and entities decoded
+func (t pmText) String() string {
+	return strings.TrimSpace(html.UnescapeString(xmlTagPattern.ReplaceAllString(t.Inner, "")))
+}
+
+// NewPubMedSource creates a new PubMed source
+// Uses the optional NCBI API key from config file
+func NewPubMedSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*PubMedSource, error) {
+	var config db.PubMedConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid pubmed config: %w", err)
+	}
+
+	ps := &PubMedSource{
+		source: source,
+		config: &config,
+		client: httpClient,             // Shared client with pooled connections
+		apiKey: credentials.NCBIAPIKey, // Optional, raises limit from 3 to 10 requests/second
+	}
+
+	return ps, nil
+}
+
+// SourceType returns "pubmed"
+func (p *PubMedSource) SourceType() string {
+	return "pubmed"
+}
+
+// Validate checks if the configuration is valid
+func (p *PubMedSource) Validate() error {
+	if p.config.Query == "" {
+		return fmt.Errorf("query is required")
+	}
+
+	if p.config.Sort == "" {
+		p.config.Sort = "pub_date"
+	}
+	if err := validateEnum(p.config.Sort, []string{"pub_date", "relevance"}, "sort"); err != nil {
+		return err
+	}
+
+	for field, value := range map[string]string{"min_date": p.config.MinDate, "max_date": p.config.MaxDate} {
+		if value == "" {
+			continue
+		}
+		if _, err := time.Parse(pubmedDateFormat, value); err != nil {
+			return fmt.Errorf("%s must be YYYY/MM/DD, got %q", field, value)
+		}
+	}
+
+	if p.config.MaxAgeDays < 0 {
+		return fmt.Errorf("max_age_days must be non-negative, got %d", p.config.MaxAgeDays)
+	}
+
+	if p.config.MaxResults <= 0 {
+		p.config.MaxResults = 50
+	}
+	if p.config.MaxResults > 200 {
+		return fmt.Errorf("max_results must be <= 200, got %d", p.config.MaxResults)
+	}
+
+	return nil
+}
+
+// Fetch searches PubMed (esearch) and fetches the matching records (efetch)
+func (p *PubMedSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := p.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	ids, err := p.search(ctx)
+	if err != nil {
+		return nil, nil, err
+	}
+	if len(ids) == 0 {
+		return []db.Article{}, []db.Comment{}, nil
+	}
+
+	records, err := p.fetchRecords(ctx, ids)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	articles := make([]db.Article, 0, len(records))
+	for _, record := range records {
+		articles = append(articles, p.recordToArticle(record))
+	}
+
+	// PubMed doesn't have comments
+	return articles, []db.Comment{}, nil
+}
+
+// search returns the PMIDs matching the query and date range
+func (p *PubMedSource) search(ctx context.Context) ([]string, error) {
+	params := url.Values{}
+	params.Set("db", "pubmed")
+	params.Set("term", p.config.Query)
+	params.Set("retmode", "json")
+	params.Set("retmax", strconv.Itoa(p.config.MaxResults))
+	params.Set("sort", p.config.Sort)
+	params.Set("datetype", "pdat")
+
+	if p.config.MaxAgeDays > 0 {
+		params.Set("reldate", strconv.Itoa(p.config.MaxAgeDays))
+	} else if p.config.MinDate != "" || p.config.MaxDate != "" {
+		// E-utilities requires both ends of the range
+		minDate, maxDate := p.config.MinDate, p.config.MaxDate
+		if minDate == "" {
+			minDate = "1800/01/01"
+		}
+		if maxDate == "" {
+			maxDate = time.Now().Format(pubmedDateFormat)
+		}
+		params.Set("mindate", minDate)
+		params.Set("maxdate", maxDate)
+	}
+
+	body, err := p.get(ctx, eutilsBaseURL+"/esearch.fcgi", params)
+	if err != nil {
+		return nil, err
+	}
+
+	var response pmSearchResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode esearch response: %w", err)
+	}
+
+	return response.Result.IDList, nil
+}
+
+// fetchRecords fetches full PubMed records (title, abstract, authors) as XML
+func (p *PubMedSource) fetchRecords(ctx context.Context, ids []string) ([]pmArticle, error) {
+	params := url.Values{}
+	params.Set("db", "pubmed")
+	params.Set("id", strings.Join(ids, ","))
+	params.Set("retmode", "xml")
+
+	body, err := p.get(ctx, eutilsBaseURL+"/efetch.fcgi", params)
+	if err != nil {
+		return nil, err
+	}
+
+	var set pmArticleSet
+	if err := xml.Unmarshal(body, &set); err != nil {
+		return nil, fmt.Errorf("failed to decode efetch response: %w", err)
+	}
+
+	return set.Articles, nil
+}
+
+// get performs a rate-limited E-utilities request
+func (p *PubMedSource) get(ctx context.Context, endpoint string, params url.Values) ([]byte, error) {
+	params.Set("tool", "meows-collector")
+	if p.apiKey != "" {
+		params.Set("api_key", p.apiKey)
+	}
+
+	req, err := http.NewRequestWithContext(ctx, "GET", endpoint+"?"+params.Encode(), nil)
+	if err != nil {
+		return nil, err
+	}
+
+	resp, err := p.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	want := contentXML
+	if params.Get("retmode") == "json" {
+		want = contentJSON
+	}
+	body, err := readBody(resp, want)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode == http.StatusTooManyRequests {
+		return nil, fmt.Errorf("rate limited by NCBI (set COLLECTOR_NCBI_API_KEY for higher limits)")
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("E-utilities returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	return body, nil
+}
+
+// recordToArticle converts a PubMed record to an Article
+func (p *PubMedSource) recordToArticle(record pmArticle) db.Article {
+	authorNames := make([]string, 0, len(record.Article.Authors))
+	for _, a := range record.Article.Authors {
+		if a.CollectiveName != "" {
+			authorNames = append(authorNames, a.CollectiveName)
+			continue
+		}
+		authorNames = append(authorNames, strings.TrimSpace(a.ForeName+" "+a.LastName))
+	}
+
+	var primaryAuthor string
+	if len(authorNames) > 0 {
+		primaryAuthor = authorNames[0]
+	}
+
+	// Structured abstracts have one AbstractText per section (BACKGROUND, METHODS, ...)
+	sections := make([]string, 0, len(record.Article.Abstract.Texts))
+	for _, t := range record.Article.Abstract.Texts {
+		text := t.String()
+		if t.Label != "" {
+			text = t.Label + ": " + text
+		}
+		sections = append(sections, text)
+	}
+
+	var doi string
+	for _, id := range record.ArticleIDs {
+		if id.IDType == "doi" {
+			doi = id.Value
+		}
+	}
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"journal": record.Article.Journal.Title,
+		"doi":     doi,
+		"authors": authorNames,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   p.source.ID,
+		ExternalID: record.PMID,
+		Title:      record.Article.Title.String(),
+		Author:     primaryAuthor,
+		Content:    strings.Join(sections, "\n\n"),
+		URL:        "https://pubmed.ncbi.nlm.nih.gov/" + record.PMID + "/",
+		WrittenAt:  pubmedDate(record),
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// pubmedDate parses the journal issue date, which may lack month/day or use text months
+func pubmedDate(record pmArticle) time.Time {
+	d := record.Article.Journal.PubDate
+	year, err := strconv.Atoi(d.Year)
+	if err != nil {
+		// MedlineDate looks like "2023 Nov-Dec"; the leading year is enough
+		if len(d.MedlineDate) >= 4 {
+			year, err = strconv.Atoi(d.MedlineDate[:4])
+		}
+		if err != nil {
+			return time.Now()
+		}
+	}
+
+	month := time.January
+	if d.Month != "" {
+		if m, err := time.Parse("Jan", d.Month); err == nil {
+			month = m.Month()
+		} else if n, err := strconv.Atoi(d.Month); err == nil && n >= 1 && n <= 12 {
+			month = time.Month(n)
+		}
+	}
+
+	day := 1
+	if n, err := strconv.Atoi(d.Day); err == nil && n >= 1 && n <= 31 {
+		day = n
+	}
+
+	return time.Date(year, month, day, 0, 0, 0, 0, time.UTC)
+}
@@ -0,0 +1,61 @@
+package source
+
+import (
+	"encoding/xml"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestPubMedRecordParsing(t *testing.T) {
+	data := `<?xml version="1.0"?>
+<PubmedArticleSet>
+  <PubmedArticle>
+    <MedlineCitation>
+      <PMID Version="1">38000001</PMID>
+      <Article>
+        <Journal>
+          <Title>Nature Medicine</Title>
+          <JournalIssue><PubDate><Year>2024</Year><Month>Mar</Month><Day>05</Day></PubDate></JournalIssue>
+        </Journal>
+        <ArticleTitle>Effects of <i>in vivo</i> editing &amp; repair</ArticleTitle>
+        <Abstract>
+          <AbstractText Label="BACKGROUND">Gene editing is <b>promising</b>.</AbstractText>
+          <AbstractText Label="RESULTS">It works.</AbstractText>
+        </Abstract>
+        <AuthorList>
+          <Author><LastName>Doe</LastName><ForeName>Jane</ForeName></Author>
+        </AuthorList>
+      </Article>
+    </MedlineCitation>
+    <PubmedData>
+      <ArticleIdList><ArticleId IdType="doi">10.1000/xyz</ArticleId></ArticleIdList>
+    </PubmedData>
+  </PubmedArticle>
+</PubmedArticleSet>`
+
+	var set pmArticleSet
+	if err := xml.Unmarshal([]byte(data), &set); err != nil {
+		t.Fatalf("Failed to parse XML: %v", err)
+	}
+	if len(set.Articles) != 1 {
+		t.Fatalf("Expected 1 article, got %d", len(set.Articles))
+	}
+
+	p := &PubMedSource{source: &db.Source{ID: "src"}, config: &db.PubMedConfig{}}
+	article := p.recordToArticle(set.Articles[0])
+
+	if article.Title != "Effects of in vivo editing & repair" {
+		t.Errorf("Unexpected title: %q", article.Title)
+	}
+	if article.Content != "BACKGROUND: Gene editing is promising.\n\nRESULTS: It works." {
+		t.Errorf("Unexpected content: %q", article.Content)
+	}
+	if article.Author != "Jane Doe" {
+		t.Errorf("Unexpected author: %q", article.Author)
+	}
+	if !article.WrittenAt.Equal(time.Date(2024, time.March, 5, 0, 0, 0, 0, time.UTC)) {
+		t.Errorf("Unexpected publication date: %v", article.WrittenAt)
+	}
+}
@@ -3,25 +3,51 @@ package source
 import (
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
-	"io"
+	"log/slog"
 	"net/http"
 	"net/url"
+	"strings"
 	"time"
 
 	"github.com/cheolwanpark/meows/collector/internal/config"
 	"github.com/cheolwanpark/meows/collector/internal/db"
 	"github.com/google/uuid"
-	"golang.org/x/time/rate"
 )
 
+// redditHost serves Reddit's JSON endpoints; redditFallbackHosts serve the same paths
+// and are tried when Reddit blocks unauthenticated requests to it
+const redditHost = "https://www.reddit.com"
+
+var redditFallbackHosts = []string{"https://old.reddit.com", "https://api.reddit.com"}
+
 // RedditSource implements the Source interface for Reddit
 type RedditSource struct {
 	source          *db.Source
 	config          *db.RedditConfig
 	client          *http.Client
-	limiter         *rate.Limiter
 	maxCommentDepth int
+	hosts           *mirrorSet // Hosts JSON endpoints are requested from, moving on when one blocks the client
+}
+
+// redditBlockedError is a host refusing the request (403 or an HTML block page), which another host may still serve
+type redditBlockedError struct {
+	status int
+}
+
+func (e *redditBlockedError) Error() string {
+	return fmt.Sprintf("reddit API returned %d: blocked for unauthenticated clients", e.status)
+}
+
+// newRedditHosts builds the host fallback chain, which moves on only when a host blocks the client
+func newRedditHosts(sourceID, primary string, fallbacks []string) *mirrorSet {
+	hosts := newMirrorSet(sourceID, primary, fallbacks)
+	hosts.fallbackOn = func(err error) bool {
+		var blocked *redditBlockedError
+		return errors.As(err, &blocked)
+	}
+	return hosts
 }
 
 // RedditResponse structures
@@ -50,12 +76,36 @@ type redditPost struct {
 
 type redditCommentsResponse []interface{}
 
+// redditComment is a comment as returned by /r/{sub}/comments.json (and inside comment trees)
+type redditComment struct {
+	ID         string  `json:"id"`
+	Body       string  `json:"body"`
+	Author     string  `json:"author"`
+	CreatedUTC float64 `json:"created_utc"`
+	Score      int     `json:"score"`
+	Permalink  string  `json:"permalink"`
+	LinkID     string  `json:"link_id"`    // "t3_<post id>"
+	LinkTitle  string  `json:"link_title"` // Only present in subreddit comment listings
+	Subreddit  string  `json:"subreddit"`
+	Depth      int     `json:"-"`
+}
+
+type redditCommentListingResponse struct {
+	Data struct {
+		Children []struct {
+			Kind string        `json:"kind"`
+			Data redditComment `json:"data"`
+		} `json:"children"`
+		After string `json:"after"`
+	} `json:"data"`
+}
+
 // NewRedditSource creates a new Reddit source
 // Uses credentials from config file
 func NewRedditSource(
 	source *db.Source,
 	credentials *config.CredentialsConfig,
-	sharedLimiter *rate.Limiter,
+	httpClient *http.Client,
 	maxCommentDepth int,
 ) (*RedditSource, error) {
 	var config db.RedditConfig
@@ -66,9 +116,9 @@ func NewRedditSource(
 	rs := &RedditSource{
 		source:          source,
 		config:          &config,
-		client:          &http.Client{Timeout: 30 * time.Second},
-		limiter:         sharedLimiter, // Use shared rate limiter per source type
+		client:          httpClient, // Shared client with pooled connections
 		maxCommentDepth: maxCommentDepth,
+		hosts:           newRedditHosts(source.ID, redditHost, redditFallbackHosts),
 	}
 
 	// OAuth authentication using credentials from config file
@@ -87,13 +137,33 @@ func (r *RedditSource) SourceType() string {
 
 // Validate checks if the configuration is valid
 func (r *RedditSource) Validate() error {
-	if r.config.Subreddit == "" {
-		return fmt.Errorf("subreddit is required")
+	if r.config.FetchMode == "" {
+		r.config.FetchMode = "posts"
+	}
+	if err := validateEnum(r.config.FetchMode, []string{"posts", "comments"}, "fetch_mode"); err != nil {
+		return err
+	}
+	if r.config.PostID != "" && r.config.FetchMode != "comments" {
+		return fmt.Errorf("post_id requires fetch_mode 'comments'")
+	}
+	if r.config.Subreddit != "" && r.config.User != "" {
+		return fmt.Errorf("subreddit and user are mutually exclusive")
+	}
+	if r.config.Subreddit == "" && r.config.User == "" && r.config.PostID == "" {
+		return fmt.Errorf("subreddit or user is required")
+	}
+	if strings.HasPrefix(r.config.Subreddit, "+") || strings.HasSuffix(r.config.Subreddit, "+") || strings.Contains(r.config.Subreddit, "++") {
+		return fmt.Errorf("subreddit %q has an empty name in its multireddit list", r.config.Subreddit)
 	}
 	if r.config.Sort == "" {
 		r.config.Sort = "hot"
 	}
-	if err := validateEnum(r.config.Sort, []string{"hot", "new", "top", "rising"}, "sort"); err != nil {
+	sorts := []string{"hot", "new", "top", "rising"}
+	if r.config.User != "" {
+		// User listings have no "rising"
+		sorts = []string{"hot", "new", "top"}
+	}
+	if err := validateEnum(r.config.Sort, sorts, "sort"); err != nil {
 		return err
 	}
 	if r.config.Limit <= 0 {
@@ -111,13 +181,18 @@ func (r *RedditSource) Fetch(ctx context.Context, since time.Time) ([]db.Article
 		return nil, nil, err
 	}
 
+	if r.config.FetchMode == "comments" {
+		return r.fetchCommentArticles(ctx, since)
+	}
+
 	var allArticles []db.Article
 	var allComments []db.Comment
 
 	after := ""
-	remaining := r.config.Limit
+	tally := newMatchTally(ctx, r.config.Limit)
+	remaining := tally.budget()
 
-	for remaining > 0 {
+	for remaining > 0 && !tally.done() {
 		// Check context cancellation
 		select {
 		case <-ctx.Done():
@@ -125,13 +200,8 @@ func (r *RedditSource) Fetch(ctx context.Context, since time.Time) ([]db.Article
 		default:
 		}
 
-		// Rate limiting
-		if err := r.limiter.Wait(ctx); err != nil {
-			return nil, nil, err
-		}
-
 		// Fetch posts
-		posts, nextAfter, err := r.fetchPosts(ctx, after, min(remaining, 100))
+		posts, nextAfter, err := r.fetchPosts(ctx, after, min(remaining, tally.pageSize(100)))
 		if err != nil {
 			return nil, nil, err
 		}
@@ -141,10 +211,16 @@ func (r *RedditSource) Fetch(ctx context.Context, since time.Time) ([]db.Article
 		}
 
 		// Convert posts to articles and fetch comments
+		reachedSince := false
 		for _, post := range posts {
 			// Skip if older than since
 			postTime := time.Unix(int64(post.CreatedUTC), 0)
 			if postTime.Before(since) {
+				// "new" is ordered by creation time, so every later post is older too
+				if r.config.Sort == "new" {
+					reachedSince = true
+					break
+				}
 				continue
 			}
 
@@ -156,22 +232,26 @@ func (r *RedditSource) Fetch(ctx context.Context, since time.Time) ([]db.Article
 			article := r.postToArticle(post)
 			allArticles = append(allArticles, article)
 
-			// Fetch comments for this post
-			if r.maxCommentDepth > 0 && post.NumComments > 0 {
+			// Fetch comments for this post, unless keyword filters will drop it anyway
+			if tally.add(article) && r.maxCommentDepth > 0 && post.NumComments > 0 {
 				comments, err := r.fetchComments(ctx, post.ID, article.ID)
 				if err != nil {
-					// Log error but continue
-					fmt.Printf("Warning: failed to fetch comments for post %s: %v\n", post.ID, err)
+					// Keep the post and record what it's missing
+					slog.Warn("Failed to fetch comments", "source_id", r.source.ID, "post_id", post.ID, "error", err)
+					addEnrichmentError(&allArticles[len(allArticles)-1], "comments", err)
 				} else {
 					allComments = append(allComments, comments...)
 				}
 			}
+			if tally.done() {
+				break
+			}
 		}
 
 		remaining -= len(posts)
 		after = nextAfter
 
-		if after == "" {
+		if after == "" || reachedSince {
 			break
 		}
 	}
@@ -181,9 +261,14 @@ func (r *RedditSource) Fetch(ctx context.Context, since time.Time) ([]db.Article
 
 // fetchPosts fetches a page of Reddit posts
 func (r *RedditSource) fetchPosts(ctx context.Context, after string, limit int) ([]redditPost, string, error) {
-	u := fmt.Sprintf("https://www.reddit.com/r/%s/%s.json", r.config.Subreddit, r.config.Sort)
+	path := fmt.Sprintf("/r/%s/%s.json", r.config.Subreddit, r.config.Sort)
 
 	params := url.Values{}
+	if r.config.User != "" {
+		// User listings take the sort as a parameter
+		path = fmt.Sprintf("/user/%s/submitted.json", r.config.User)
+		params.Set("sort", r.config.Sort)
+	}
 	params.Set("limit", fmt.Sprintf("%d", limit))
 	if after != "" {
 		params.Set("after", after)
@@ -192,27 +277,9 @@ func (r *RedditSource) fetchPosts(ctx context.Context, after string, limit int)
 		params.Set("t", r.config.TimeFilter)
 	}
 
-	req, err := http.NewRequestWithContext(ctx, "GET", u+"?"+params.Encode(), nil)
-	if err != nil {
-		return nil, "", err
-	}
-
-	req.Header.Set("User-Agent", r.config.UserAgent)
-
-	resp, err := r.client.Do(req)
-	if err != nil {
-		return nil, "", err
-	}
-	defer resp.Body.Close()
-
-	if resp.StatusCode != http.StatusOK {
-		body, _ := io.ReadAll(resp.Body)
-		return nil, "", fmt.Errorf("reddit API returned %d: %s", resp.StatusCode, string(body))
-	}
-
 	var listing redditListingResponse
-	if err := json.NewDecoder(resp.Body).Decode(&listing); err != nil {
-		return nil, "", fmt.Errorf("failed to decode response: %w", err)
+	if err := r.getJSON(ctx, path+"?"+params.Encode(), &listing); err != nil {
+		return nil, "", err
 	}
 
 	posts := make([]redditPost, len(listing.Data.Children))
@@ -225,38 +292,9 @@ func (r *RedditSource) fetchPosts(ctx context.Context, after string, limit int)
 
 // fetchComments fetches comments for a Reddit post
 func (r *RedditSource) fetchComments(ctx context.Context, postID string, articleID string) ([]db.Comment, error) {
-	// Rate limiting
-	if err := r.limiter.Wait(ctx); err != nil {
-		return nil, err
-	}
-
-	u := fmt.Sprintf("https://www.reddit.com/comments/%s.json", postID)
-
-	req, err := http.NewRequestWithContext(ctx, "GET", u, nil)
-	if err != nil {
-		return nil, err
-	}
-
-	req.Header.Set("User-Agent", r.config.UserAgent)
-
-	resp, err := r.client.Do(req)
-	if err != nil {
-		return nil, err
-	}
-	defer resp.Body.Close()
-
-	if resp.StatusCode != http.StatusOK {
-		return nil, fmt.Errorf("reddit API returned %d", resp.StatusCode)
-	}
-
-	body, err := io.ReadAll(resp.Body)
-	if err != nil {
-		return nil, err
-	}
-
 	var response redditCommentsResponse
-	if err := json.Unmarshal(body, &response); err != nil {
-		return nil, fmt.Errorf("failed to decode comments: %w", err)
+	if err := r.getJSON(ctx, fmt.Sprintf("/comments/%s.json", postID), &response); err != nil {
+		return nil, err
 	}
 
 	// Extract comments from response
@@ -345,6 +383,224 @@ func (r *RedditSource) extractComments(listing interface{}, articleID string, pa
 	}
 }
 
+// fetchCommentArticles crawls comments (a post's tree or a subreddit's recent comments)
+// and returns each one as an article, so filters and curation see discussion text
+func (r *RedditSource) fetchCommentArticles(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	var comments []redditComment
+	var err error
+	if r.config.PostID != "" {
+		comments, err = r.fetchCommentTree(ctx, r.config.PostID)
+	} else {
+		comments, err = r.fetchSubredditComments(ctx, since)
+	}
+	if err != nil {
+		return nil, nil, err
+	}
+
+	var articles []db.Article
+	for _, c := range comments {
+		if len(articles) >= r.config.Limit {
+			break
+		}
+		if time.Unix(int64(c.CreatedUTC), 0).Before(since) {
+			continue
+		}
+		if c.Score < r.config.MinScore {
+			continue
+		}
+		articles = append(articles, r.commentToArticle(c))
+	}
+
+	// The comments themselves are the articles
+	return articles, []db.Comment{}, nil
+}
+
+// fetchSubredditComments pages through /r/{sub}/comments.json (or /user/{name}/comments.json),
+// newest first, until the limit or since
+func (r *RedditSource) fetchSubredditComments(ctx context.Context, since time.Time) ([]redditComment, error) {
+	var comments []redditComment
+	after := ""
+
+	listingPath := fmt.Sprintf("/r/%s/comments.json", r.config.Subreddit)
+	if r.config.User != "" {
+		listingPath = fmt.Sprintf("/user/%s/comments.json", r.config.User)
+	}
+
+	for len(comments) < r.config.Limit {
+		params := url.Values{}
+		params.Set("limit", fmt.Sprintf("%d", min(r.config.Limit-len(comments), 100)))
+		if after != "" {
+			params.Set("after", after)
+		}
+
+		var listing redditCommentListingResponse
+		if r.config.User != "" {
+			params.Set("sort", "new")
+		}
+		if err := r.getJSON(ctx, listingPath+"?"+params.Encode(), &listing); err != nil {
+			return nil, err
+		}
+
+		reachedSince := false
+		for _, child := range listing.Data.Children {
+			if child.Kind != "t1" {
+				continue
+			}
+			if time.Unix(int64(child.Data.CreatedUTC), 0).Before(since) {
+				reachedSince = true
+				break
+			}
+			comments = append(comments, child.Data)
+		}
+
+		after = listing.Data.After
+		if after == "" || reachedSince || len(listing.Data.Children) == 0 {
+			break
+		}
+	}
+
+	return comments, nil
+}
+
+// fetchCommentTree flattens a post's comment tree, bounded by the global max comment depth
+func (r *RedditSource) fetchCommentTree(ctx context.Context, postID string) ([]redditComment, error) {
+	var response []json.RawMessage
+	if err := r.getJSON(ctx, fmt.Sprintf("/comments/%s.json", postID), &response); err != nil {
+		return nil, err
+	}
+	// Response is an array: [post_listing, comments_listing]
+	if len(response) < 2 {
+		return nil, nil
+	}
+
+	var post redditListingResponse
+	if err := json.Unmarshal(response[0], &post); err != nil {
+		return nil, fmt.Errorf("failed to decode post: %w", err)
+	}
+	linkTitle := ""
+	if len(post.Data.Children) > 0 {
+		linkTitle = post.Data.Children[0].Data.Title
+	}
+
+	var comments []redditComment
+	r.flattenCommentTree(response[1], 0, linkTitle, &comments)
+	return comments, nil
+}
+
+// flattenCommentTree walks a comment listing depth-first, collecting comments in thread order
+func (r *RedditSource) flattenCommentTree(raw json.RawMessage, depth int, linkTitle string, comments *[]redditComment) {
+	if depth > r.maxCommentDepth {
+		return
+	}
+
+	var listing struct {
+		Data struct {
+			Children []struct {
+				Kind string `json:"kind"`
+				Data struct {
+					redditComment
+					Replies json.RawMessage `json:"replies"` // Listing object, or "" when there are none
+				} `json:"data"`
+			} `json:"children"`
+		} `json:"data"`
+	}
+	if err := json.Unmarshal(raw, &listing); err != nil {
+		return
+	}
+
+	for _, child := range listing.Data.Children {
+		if child.Kind != "t1" { // t1 is comment, "more" is a load-more stub
+			continue
+		}
+		c := child.Data.redditComment
+		if c.ID == "" || c.Body == "" {
+			continue
+		}
+		c.Depth = depth
+		c.LinkTitle = linkTitle
+		*comments = append(*comments, c)
+
+		if len(child.Data.Replies) > 0 && child.Data.Replies[0] == '{' {
+			r.flattenCommentTree(child.Data.Replies, depth+1, linkTitle, comments)
+		}
+	}
+}
+
+// getJSON requests path from the active Reddit host with the configured user agent and decodes the JSON body into v
+// A host that blocks the request is skipped for the fallback hosts; once it has blocked several requests in a row,
+// the first that answers is used for the rest of the run
+func (r *RedditSource) getJSON(ctx context.Context, path string, v interface{}) error {
+	return r.hosts.try(ctx, func(host string) error {
+		return r.getJSONFrom(ctx, host+path, v)
+	})
+}
+
+// getJSONFrom performs one GET request, reporting 403s and HTML block pages as *redditBlockedError
+func (r *RedditSource) getJSONFrom(ctx context.Context, u string, v interface{}) error {
+	req, err := http.NewRequestWithContext(ctx, "GET", u, nil)
+	if err != nil {
+		return err
+	}
+
+	req.Header.Set("User-Agent", r.config.UserAgent)
+
+	resp, err := r.client.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode == http.StatusForbidden {
+		return &redditBlockedError{status: resp.StatusCode}
+	}
+	body, err := readBody(resp, contentJSON)
+	if err != nil {
+		if detectContent(resp.Header.Get("Content-Type"), nil) == contentHTML {
+			return &redditBlockedError{status: resp.StatusCode}
+		}
+		return err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("reddit API returned %d", resp.StatusCode)
+	}
+
+	if err := json.Unmarshal(body, v); err != nil {
+		return fmt.Errorf("failed to decode response: %w", err)
+	}
+	return nil
+}
+
+// commentToArticle converts a Reddit comment to an Article
+func (r *RedditSource) commentToArticle(c redditComment) db.Article {
+	metadata, _ := json.Marshal(map[string]interface{}{
+		"score":        c.Score,
+		"num_comments": 0,
+		"subreddit":    c.Subreddit,
+		"kind":         "comment",
+		"post_id":      strings.TrimPrefix(c.LinkID, "t3_"),
+		"depth":        c.Depth,
+	})
+
+	title := "Comment"
+	if c.LinkTitle != "" {
+		title = "Re: " + c.LinkTitle
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   r.source.ID,
+		ExternalID: c.ID,
+		Title:      title,
+		Author:     c.Author,
+		Content:    c.Body,
+		URL:        "https://www.reddit.com" + c.Permalink,
+		WrittenAt:  time.Unix(int64(c.CreatedUTC), 0),
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
 // postToArticle converts a Reddit post to an Article
 func (r *RedditSource) postToArticle(post redditPost) db.Article {
 	metadata, _ := json.Marshal(map[string]interface{}{
@@ -0,0 +1,96 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestRedditFallsBackWhenBlocked(t *testing.T) {
+	var requests []string
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		requests = append(requests, r.URL.Path)
+		switch {
+		case strings.HasPrefix(r.URL.Path, "/www/"):
+			w.WriteHeader(http.StatusForbidden)
+		case strings.HasPrefix(r.URL.Path, "/old/"):
+			w.Header().Set("Content-Type", "text/html")
+			w.Write([]byte("<!doctype html><p>whoa there, pardner!</p>"))
+		case strings.HasSuffix(r.URL.Path, "/missing.json"):
+			w.WriteHeader(http.StatusNotFound)
+		default:
+			w.Write([]byte(`{"data":{"children":[{"data":{"id":"abc","title":"Hello"}}],"after":""}}`))
+		}
+	}))
+	defer server.Close()
+
+	r := &RedditSource{
+		source: &db.Source{ID: "src"},
+		config: &db.RedditConfig{Subreddit: "golang", Sort: "new", UserAgent: "test"},
+		client: server.Client(),
+		hosts:  newRedditHosts("src", server.URL+"/www", []string{server.URL + "/old", server.URL + "/api"}),
+	}
+
+	for i := 0; i < mirrorSwitchFailures; i++ {
+		requests = nil
+		posts, _, err := r.fetchPosts(context.Background(), "", 10)
+		if err != nil {
+			t.Fatalf("Expected the fallback host to answer, got %v", err)
+		}
+		if len(posts) != 1 || posts[0].ID != "abc" || len(requests) != 3 {
+			t.Errorf("Unexpected posts %+v after requests %v", posts, requests)
+		}
+	}
+
+	// After repeated blocks the host that answered serves the next request, and other errors don't move on
+	requests = nil
+	if err := r.getJSON(context.Background(), "/r/golang/missing.json", &redditListingResponse{}); err == nil {
+		t.Errorf("Expected a 404 to fail")
+	}
+	if len(requests) != 1 || requests[0] != "/api/r/golang/missing.json" {
+		t.Errorf("Expected one request to the active host, got %v", requests)
+	}
+}
+
+func TestRedditKeepsPostsWhenCommentsFail(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if strings.HasPrefix(r.URL.Path, "/comments/") {
+			w.WriteHeader(http.StatusInternalServerError)
+			return
+		}
+		w.Write([]byte(`{"data":{"children":[{"data":{"id":"abc","title":"Hello","num_comments":4,"created_utc":1700000000}}],"after":""}}`))
+	}))
+	defer server.Close()
+
+	r := &RedditSource{
+		source:          &db.Source{ID: "src"},
+		config:          &db.RedditConfig{Subreddit: "golang", Sort: "new", UserAgent: "test"},
+		client:          server.Client(),
+		maxCommentDepth: 3,
+		hosts:           newRedditHosts("src", server.URL, nil),
+	}
+
+	articles, comments, err := r.Fetch(context.Background(), time.Unix(0, 0))
+	if err != nil {
+		t.Fatalf("Expected a comment failure not to fail the fetch, got %v", err)
+	}
+	if len(articles) != 1 || len(comments) != 0 {
+		t.Fatalf("Expected the post without comments, got %d articles and %d comments", len(articles), len(comments))
+	}
+
+	var metadata struct {
+		EnrichmentErrors []EnrichmentError `json:"enrichment_errors"`
+	}
+	if err := json.Unmarshal(articles[0].Metadata, &metadata); err != nil {
+		t.Fatal(err)
+	}
+	if len(metadata.EnrichmentErrors) != 1 || metadata.EnrichmentErrors[0].Stage != "comments" || !strings.Contains(metadata.EnrichmentErrors[0].Error, "500") {
+		t.Errorf("Unexpected enrichment errors: %+v", metadata.EnrichmentErrors)
+	}
+}
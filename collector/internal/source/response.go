@@ -0,0 +1,84 @@
+package source
+
+import (
+	"bytes"
+	"fmt"
+	"io"
+	"mime"
+	"net/http"
+	"strings"
+)
+
+// maxResponseBytes bounds how much of a single response body a source reads into memory
+const maxResponseBytes = 32 << 20 // 32 MiB
+
+// contentKind is the body format a source expects from an endpoint
+type contentKind string
+
+const (
+	contentJSON contentKind = "JSON"
+	contentXML  contentKind = "XML"
+	contentHTML contentKind = "HTML"
+)
+
+// readBody reads a response body after checking its size and format against what the caller expects
+// Mismatches produce a targeted error (e.g. an HTML block page where JSON was expected)
+// instead of an opaque decode error further down
+func readBody(resp *http.Response, want contentKind) ([]byte, error) {
+	if resp.ContentLength > maxResponseBytes {
+		return nil, fmt.Errorf("response too large: %d bytes (limit %d, status %d)",
+			resp.ContentLength, maxResponseBytes, resp.StatusCode)
+	}
+
+	body, err := io.ReadAll(io.LimitReader(resp.Body, maxResponseBytes+1))
+	if err != nil {
+		return nil, err
+	}
+	if len(body) > maxResponseBytes {
+		return nil, fmt.Errorf("response too large: more than %d bytes (status %d)", maxResponseBytes, resp.StatusCode)
+	}
+
+	got := detectContent(resp.Header.Get("Content-Type"), body)
+	if got == "" || got == want {
+		return body, nil
+	}
+	if got == contentHTML {
+		return nil, fmt.Errorf("received an HTML page instead of %s (status %d): likely blocked, rate limited or a login page",
+			want, resp.StatusCode)
+	}
+	return nil, fmt.Errorf("received %s instead of %s (status %d)", got, want, resp.StatusCode)
+}
+
+// detectContent classifies a body from its Content-Type, sniffing the body when the header is generic
+// Returns "" when the format can't be determined, in which case no check is made
+func detectContent(contentType string, body []byte) contentKind {
+	mediaType, _, err := mime.ParseMediaType(contentType)
+	if err == nil {
+		switch {
+		case strings.HasSuffix(mediaType, "json"):
+			return contentJSON
+		case mediaType == "text/html" || mediaType == "application/xhtml+xml":
+			return contentHTML
+		case strings.HasSuffix(mediaType, "xml"):
+			return contentXML
+		}
+	}
+
+	// Missing, text/plain or octet-stream: look at the first bytes
+	trimmed := bytes.TrimSpace(body)
+	if len(trimmed) > 512 {
+		trimmed = trimmed[:512]
+	}
+	lower := bytes.ToLower(trimmed)
+	switch {
+	case len(trimmed) == 0:
+		return ""
+	case trimmed[0] == '{' || trimmed[0] == '[':
+		return contentJSON
+	case bytes.HasPrefix(lower, []byte("<!doctype html")) || bytes.HasPrefix(lower, []byte("<html")):
+		return contentHTML
+	case bytes.HasPrefix(lower, []byte("<?xml")):
+		return contentXML
+	}
+	return ""
+}
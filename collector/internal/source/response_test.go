@@ -0,0 +1,54 @@
+package source
+
+import (
+	"io"
+	"net/http"
+	"strings"
+	"testing"
+)
+
+func newTestResponse(status int, contentType, body string) *http.Response {
+	resp := &http.Response{
+		StatusCode:    status,
+		Header:        make(http.Header),
+		Body:          io.NopCloser(strings.NewReader(body)),
+		ContentLength: int64(len(body)),
+	}
+	if contentType != "" {
+		resp.Header.Set("Content-Type", contentType)
+	}
+	return resp
+}
+
+func TestReadBody_HTMLInsteadOfJSON(t *testing.T) {
+	resp := newTestResponse(403, "text/html; charset=utf-8", "<!DOCTYPE html><html><body>Please log in</body></html>")
+
+	_, err := readBody(resp, contentJSON)
+	if err == nil {
+		t.Fatal("Expected an error for an HTML page")
+	}
+	if !strings.Contains(err.Error(), "HTML page instead of JSON") || !strings.Contains(err.Error(), "403") {
+		t.Errorf("Expected a targeted error, got %q", err)
+	}
+}
+
+func TestReadBody_SniffsGenericContentType(t *testing.T) {
+	resp := newTestResponse(200, "text/plain", `  {"ok": true}`)
+	if _, err := readBody(resp, contentJSON); err != nil {
+		t.Errorf("Expected JSON body with text/plain header to pass, got %v", err)
+	}
+
+	resp = newTestResponse(200, "", "<?xml version=\"1.0\"?><rss></rss>")
+	if _, err := readBody(resp, contentJSON); err == nil || !strings.Contains(err.Error(), "received XML instead of JSON") {
+		t.Errorf("Expected XML/JSON mismatch error, got %v", err)
+	}
+}
+
+func TestReadBody_TooLarge(t *testing.T) {
+	resp := newTestResponse(200, "application/json", "{}")
+	resp.ContentLength = maxResponseBytes + 1
+
+	if _, err := readBody(resp, contentJSON); err == nil || !strings.Contains(err.Error(), "too large") {
+		t.Errorf("Expected size error, got %v", err)
+	}
+}
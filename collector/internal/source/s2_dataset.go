@@ -0,0 +1,131 @@
+package source
+
+import (
+	"bufio"
+	"compress/gzip"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"strconv"
+	"strings"
+)
+
+// s2DatasetPaper is one line of the S2 "papers" dataset (lowercase keys)
+// An "abstract" key is also read so pre-joined papers+abstracts files work
+type s2DatasetPaper struct {
+	CorpusID      int64      `json:"corpusid"`
+	Title         string     `json:"title"`
+	Abstract      string     `json:"abstract"`
+	Year          int        `json:"year"`
+	CitationCount int        `json:"citationcount"`
+	URL           string     `json:"url"`
+	Authors       []s2Author `json:"authors"`
+}
+
+// maxDatasetLine bounds a single JSONL record (some papers have thousands of authors)
+const maxDatasetLine = 16 << 20
+
+// fetchDataset streams papers from a dataset snapshot file (local path or URL, optionally gzipped)
+// Reading stops once MaxResults papers passing MinCitations have been collected
+func (s *SemanticScholarSource) fetchDataset(ctx context.Context) ([]s2Paper, error) {
+	reader, err := s.openDataset(ctx)
+	if err != nil {
+		return nil, err
+	}
+	defer reader.Close()
+
+	scanner := bufio.NewScanner(reader)
+	scanner.Buffer(make([]byte, 64<<10), maxDatasetLine)
+
+	var papers []s2Paper
+	line := 0
+	for len(papers) < s.config.MaxResults && scanner.Scan() {
+		line++
+
+		// Check context cancellation periodically, the file may be huge
+		if line%10000 == 0 {
+			if err := ctx.Err(); err != nil {
+				return nil, err
+			}
+		}
+
+		var record s2DatasetPaper
+		if err := json.Unmarshal(scanner.Bytes(), &record); err != nil {
+			return nil, fmt.Errorf("invalid dataset record on line %d: %w", line, err)
+		}
+		if record.Title == "" || record.CitationCount < s.config.MinCitations {
+			continue
+		}
+
+		papers = append(papers, s2Paper{
+			PaperID:       "CorpusId:" + strconv.FormatInt(record.CorpusID, 10),
+			Title:         record.Title,
+			Abstract:      record.Abstract,
+			Year:          record.Year,
+			CitationCount: record.CitationCount,
+			URL:           record.URL,
+			Authors:       record.Authors,
+		})
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, fmt.Errorf("failed to read dataset: %w", err)
+	}
+
+	return papers, nil
+}
+
+// openDataset opens the configured dataset path, transparently decompressing .gz files
+func (s *SemanticScholarSource) openDataset(ctx context.Context) (io.ReadCloser, error) {
+	path := *s.config.DatasetPath
+
+	var raw io.ReadCloser
+	if strings.HasPrefix(path, "http://") || strings.HasPrefix(path, "https://") {
+		// Dataset download links are pre-signed, so no API key header is sent
+		req, err := http.NewRequestWithContext(ctx, "GET", path, nil)
+		if err != nil {
+			return nil, err
+		}
+		resp, err := s.client.Do(req)
+		if err != nil {
+			return nil, err
+		}
+		if resp.StatusCode != http.StatusOK {
+			resp.Body.Close()
+			return nil, fmt.Errorf("dataset download returned %d", resp.StatusCode)
+		}
+		raw = resp.Body
+	} else {
+		f, err := os.Open(path)
+		if err != nil {
+			return nil, fmt.Errorf("failed to open dataset: %w", err)
+		}
+		raw = f
+	}
+
+	// Strip the query string of pre-signed URLs before checking the extension
+	if !strings.HasSuffix(strings.SplitN(path, "?", 2)[0], ".gz") {
+		return raw, nil
+	}
+
+	gz, err := gzip.NewReader(raw)
+	if err != nil {
+		raw.Close()
+		return nil, fmt.Errorf("failed to decompress dataset: %w", err)
+	}
+	return &gzipReadCloser{Reader: gz, underlying: raw}, nil
+}
+
+// gzipReadCloser closes both the gzip stream and the underlying file or response body
+type gzipReadCloser struct {
+	*gzip.Reader
+	underlying io.Closer
+}
+
+// Close implements io.Closer
+func (g *gzipReadCloser) Close() error {
+	g.Reader.Close()
+	return g.underlying.Close()
+}
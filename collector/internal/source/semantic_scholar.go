@@ -3,26 +3,49 @@ package source
 import (
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
-	"io"
+	"log/slog"
 	"net/http"
 	"net/url"
+	"slices"
 	"strconv"
+	"strings"
+	"sync"
 	"time"
 
 	"github.com/cheolwanpark/meows/collector/internal/config"
 	"github.com/cheolwanpark/meows/collector/internal/db"
 	"github.com/google/uuid"
-	"golang.org/x/time/rate"
 )
 
 // SemanticScholarSource implements the Source interface for Semantic Scholar
 type SemanticScholarSource struct {
-	source  *db.Source
-	config  *db.SemanticScholarConfig
-	client  *http.Client
-	limiter *rate.Limiter
-	apiKey  string // From global config (environment variable)
+	source *db.Source
+	config *db.SemanticScholarConfig
+	client *http.Client
+	apiKey string     // From global config (environment variable)
+	fields []string   // Optional fields still requested; shrinks when the API rejects some
+	mu     sync.Mutex // Guards fields across concurrent queries
+}
+
+// s2MaxQueries caps how many queries one search or bulk source runs
+const s2MaxQueries = 10
+
+// s2CoreFields are always requested; s2OptionalFields are dropped when the API rejects them
+var (
+	s2CoreFields     = []string{"paperId", "title", "url"}
+	s2OptionalFields = []string{"abstract", "year", "citationCount", "authors"}
+)
+
+// s2APIError is an unexpected response status from the Semantic Scholar API
+type s2APIError struct {
+	status int
+	body   string
+}
+
+func (e *s2APIError) Error() string {
+	return fmt.Sprintf("semantic scholar API returned %d: %s", e.status, e.body)
 }
 
 // Semantic Scholar API response structures
@@ -33,6 +56,12 @@ type s2SearchResponse struct {
 	Data   []s2Paper `json:"data"`
 }
 
+type s2BulkSearchResponse struct {
+	Total int       `json:"total"`
+	Token string    `json:"token"` // Continuation token, empty on the last page
+	Data  []s2Paper `json:"data"`
+}
+
 type s2RecommendationsResponse struct {
 	RecommendedPapers []s2Paper `json:"recommendedPapers"`
 }
@@ -45,6 +74,8 @@ type s2Paper struct {
 	CitationCount int        `json:"citationCount"`
 	URL           string     `json:"url"`
 	Authors       []s2Author `json:"authors"`
+
+	matchedQueries []string // Set when several queries are merged
 }
 
 type s2Author struct {
@@ -57,7 +88,7 @@ type s2Author struct {
 func NewSemanticScholarSource(
 	source *db.Source,
 	credentials *config.CredentialsConfig,
-	sharedLimiter *rate.Limiter,
+	httpClient *http.Client,
 ) (*SemanticScholarSource, error) {
 	var config db.SemanticScholarConfig
 	if err := json.Unmarshal(source.Config, &config); err != nil {
@@ -65,11 +96,10 @@ func NewSemanticScholarSource(
 	}
 
 	ss := &SemanticScholarSource{
-		source:  source,
-		config:  &config,
-		client:  &http.Client{Timeout: 30 * time.Second},
-		limiter: sharedLimiter,                           // Use shared rate limiter per source type
-		apiKey:  credentials.SemanticScholarAPIKey, // Use API key from config file
+		source: source,
+		config: &config,
+		client: httpClient,                        // Shared client with pooled connections
+		apiKey: credentials.SemanticScholarAPIKey, // Use API key from config file
 	}
 
 	return ss, nil
@@ -82,13 +112,42 @@ func (s *SemanticScholarSource) SourceType() string {
 
 // Validate checks if the configuration is valid
 func (s *SemanticScholarSource) Validate() error {
-	if err := validateEnum(s.config.Mode, []string{"search", "recommendations"}, "mode"); err != nil {
+	if err := validateEnum(s.config.Mode, []string{"search", "bulk", "recommendations", "author", "dataset"}, "mode"); err != nil {
 		return err
 	}
 
+	if s.config.Mode == "search" || s.config.Mode == "bulk" {
+		if len(s.config.Query) == 0 {
+			return fmt.Errorf("query is required for %s mode", s.config.Mode)
+		}
+		if len(s.config.Query) > s2MaxQueries {
+			return fmt.Errorf("query must have at most %d entries, got %d", s2MaxQueries, len(s.config.Query))
+		}
+		for _, query := range s.config.Query {
+			if strings.TrimSpace(query) == "" {
+				return fmt.Errorf("query must not contain empty entries")
+			}
+		}
+	}
+
 	if s.config.Mode == "search" {
-		if s.config.Query == nil || *s.config.Query == "" {
-			return fmt.Errorf("query is required for search mode")
+		if s.config.Sort == "" {
+			s.config.Sort = "relevance"
+		}
+		if err := validateEnum(s.config.Sort, []string{"relevance", "publicationDate", "citationCount"}, "sort"); err != nil {
+			return err
+		}
+	}
+
+	if s.config.Mode == "bulk" {
+		// Bulk search has no relevance ranking, an empty sort keeps the API's paper ID order
+		if s.config.Sort == "relevance" {
+			s.config.Sort = ""
+		}
+		if s.config.Sort != "" {
+			if err := validateEnum(s.config.Sort, []string{"publicationDate", "citationCount"}, "sort"); err != nil {
+				return err
+			}
 		}
 	}
 
@@ -98,6 +157,18 @@ func (s *SemanticScholarSource) Validate() error {
 		}
 	}
 
+	if s.config.Mode == "author" {
+		if s.config.AuthorID == nil || *s.config.AuthorID == "" {
+			return fmt.Errorf("author_id is required for author mode")
+		}
+	}
+
+	if s.config.Mode == "dataset" {
+		if s.config.DatasetPath == nil || *s.config.DatasetPath == "" {
+			return fmt.Errorf("dataset_path is required for dataset mode")
+		}
+	}
+
 	if s.config.MaxResults <= 0 {
 		s.config.MaxResults = 100
 	}
@@ -111,13 +182,22 @@ func (s *SemanticScholarSource) Fetch(ctx context.Context, since time.Time) ([]d
 		return nil, nil, err
 	}
 
+	s.fields = append([]string(nil), s2OptionalFields...)
+
 	var papers []s2Paper
 	var err error
 
-	if s.config.Mode == "search" {
-		papers, err = s.fetchSearch(ctx)
-	} else {
+	switch {
+	case s.config.Mode == "dataset":
+		papers, err = s.fetchDataset(ctx)
+	case s.config.Mode == "recommendations":
 		papers, err = s.fetchRecommendations(ctx)
+	case s.config.Mode == "author":
+		papers, err = s.fetchAuthorPapers(ctx)
+	case s.config.Mode == "bulk", s.config.Sort != "relevance":
+		papers, err = s.fetchQueries(ctx, s.fetchBulkSearch)
+	default:
+		papers, err = s.fetchQueries(ctx, s.fetchSearch)
 	}
 
 	if err != nil {
@@ -132,15 +212,86 @@ func (s *SemanticScholarSource) Fetch(ctx context.Context, since time.Time) ([]d
 			continue
 		}
 
-		articles = append(articles, s.paperToArticle(paper))
+		article := s.paperToArticle(paper)
+		if len(paper.matchedQueries) > 0 {
+			setMetadata(&article, "matched_queries", paper.matchedQueries)
+		}
+		articles = append(articles, article)
 	}
 
 	// Semantic Scholar doesn't have comments
 	return articles, []db.Comment{}, nil
 }
 
+// fetchQueries runs fetch for every configured query concurrently and merges the results
+// The shared limiter still paces the requests. Papers are deduped by paperId and taken from each query's results
+// in turn, so with several queries every one of them is represented within max_results. A query that fails is
+// logged and skipped unless all of them fail
+func (s *SemanticScholarSource) fetchQueries(ctx context.Context, fetch func(ctx context.Context, query string) ([]s2Paper, error)) ([]s2Paper, error) {
+	queries := s.config.Query
+	if len(queries) == 1 {
+		return fetch(ctx, queries[0])
+	}
+
+	results := make([][]s2Paper, len(queries))
+	errs := make([]error, len(queries))
+	var wg sync.WaitGroup
+	for i, query := range queries {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			results[i], errs[i] = fetch(ctx, query)
+		}()
+	}
+	wg.Wait()
+
+	failed := 0
+	for i, err := range errs {
+		if err != nil {
+			failed++
+			slog.Warn("Semantic Scholar query failed", "source_id", s.source.ID, "query", queries[i], "error", err)
+		}
+	}
+	if failed == len(queries) {
+		return nil, fmt.Errorf("all %d queries failed, first error: %w", failed, errs[0])
+	}
+
+	return mergeS2Results(queries, results, s.config.MaxResults), nil
+}
+
+// mergeS2Results interleaves per-query results by rank, dedupes by paperId and records which queries found each paper
+func mergeS2Results(queries []string, results [][]s2Paper, maxResults int) []s2Paper {
+	var merged []s2Paper
+	index := make(map[string]int) // paperId -> position in merged
+	for rank := 0; ; rank++ {
+		more := false
+		for i, papers := range results {
+			if rank >= len(papers) {
+				continue
+			}
+			more = true
+			paper := papers[rank]
+			if at, ok := index[paper.PaperID]; ok {
+				merged[at].matchedQueries = append(merged[at].matchedQueries, queries[i])
+				continue
+			}
+			index[paper.PaperID] = len(merged)
+			paper.matchedQueries = []string{queries[i]}
+			merged = append(merged, paper)
+		}
+		if !more {
+			break
+		}
+	}
+
+	if len(merged) > maxResults {
+		merged = merged[:maxResults]
+	}
+	return merged
+}
+
 // fetchSearch fetches papers using the search API
-func (s *SemanticScholarSource) fetchSearch(ctx context.Context) ([]s2Paper, error) {
+func (s *SemanticScholarSource) fetchSearch(ctx context.Context, query string) ([]s2Paper, error) {
 	var allPapers []s2Paper
 	offset := 0
 	limit := 100 // API limit per request
@@ -153,51 +304,92 @@ func (s *SemanticScholarSource) fetchSearch(ctx context.Context) ([]s2Paper, err
 		default:
 		}
 
-		// Rate limiting
-		if err := s.limiter.Wait(ctx); err != nil {
-			return nil, err
-		}
-
 		params := url.Values{}
-		params.Set("query", *s.config.Query)
+		params.Set("query", query)
 		params.Set("offset", strconv.Itoa(offset))
 		params.Set("limit", strconv.Itoa(limit))
-		params.Set("fields", "paperId,title,abstract,year,citationCount,url,authors")
-
-		if s.config.Year != nil && *s.config.Year != "" {
-			params.Set("year", *s.config.Year)
-		}
 
-		u := "https://api.semanticscholar.org/graph/v1/paper/search?" + params.Encode()
+		s.setFilters(params)
 
-		req, err := http.NewRequestWithContext(ctx, "GET", u, nil)
+		body, err := s.getWithFields(ctx, "https://api.semanticscholar.org/graph/v1/paper/search", params)
 		if err != nil {
 			return nil, err
 		}
 
-		if s.apiKey != "" {
-			req.Header.Set("x-api-key", s.apiKey)
+		var response s2SearchResponse
+		if err := json.Unmarshal(body, &response); err != nil {
+			return nil, fmt.Errorf("failed to decode response: %w", err)
 		}
 
-		resp, err := s.client.Do(req)
-		if err != nil {
-			return nil, err
+		if len(response.Data) == 0 {
+			break
 		}
 
-		body, err := io.ReadAll(resp.Body)
-		resp.Body.Close()
+		allPapers = append(allPapers, response.Data...)
+		offset += len(response.Data)
 
-		if err != nil {
-			return nil, err
+		// Check if we have more results
+		if response.Next == 0 || len(allPapers) >= s.config.MaxResults {
+			break
 		}
 
-		if resp.StatusCode == http.StatusTooManyRequests {
-			retryAfter := resp.Header.Get("Retry-After")
-			return nil, fmt.Errorf("rate limited, retry after: %s", retryAfter)
+		// S2 API has a 10,000 offset limit
+		if offset >= 10000 {
+			break
 		}
+	}
+
+	// Trim to max results
+	if len(allPapers) > s.config.MaxResults {
+		allPapers = allPapers[:s.config.MaxResults]
+	}
+
+	return allPapers, nil
+}
 
-		if resp.StatusCode != http.StatusOK {
-			return nil, fmt.Errorf("semantic scholar API returned %d: %s", resp.StatusCode, string(body))
+// fetchRecommendations fetches paper recommendations
+func (s *SemanticScholarSource) fetchRecommendations(ctx context.Context) ([]s2Paper, error) {
+	params := url.Values{}
+	params.Set("limit", strconv.Itoa(s.config.MaxResults))
+
+	u := fmt.Sprintf("https://api.semanticscholar.org/recommendations/v1/papers/forpaper/%s", *s.config.PaperID)
+
+	body, err := s.getWithFields(ctx, u, params)
+	if err != nil {
+		return nil, err
+	}
+
+	var response s2RecommendationsResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode response: %w", err)
+	}
+
+	return response.RecommendedPapers, nil
+}
+
+// fetchAuthorPapers pages through an author's papers
+func (s *SemanticScholarSource) fetchAuthorPapers(ctx context.Context) ([]s2Paper, error) {
+	var allPapers []s2Paper
+	offset := 0
+	limit := 100 // API limit per request
+
+	for len(allPapers) < s.config.MaxResults {
+		// Check context cancellation
+		select {
+		case <-ctx.Done():
+			return nil, ctx.Err()
+		default:
+		}
+
+		params := url.Values{}
+		params.Set("offset", strconv.Itoa(offset))
+		params.Set("limit", strconv.Itoa(limit))
+
+		u := fmt.Sprintf("https://api.semanticscholar.org/graph/v1/author/%s/papers", url.PathEscape(*s.config.AuthorID))
+
+		body, err := s.getWithFields(ctx, u, params)
+		if err != nil {
+			return nil, err
 		}
 
 		var response s2SearchResponse
@@ -212,15 +404,67 @@ func (s *SemanticScholarSource) fetchSearch(ctx context.Context) ([]s2Paper, err
 		allPapers = append(allPapers, response.Data...)
 		offset += len(response.Data)
 
-		// Check if we have more results
-		if response.Next == 0 || len(allPapers) >= s.config.MaxResults {
+		// Next is omitted on the last page
+		if response.Next == 0 {
 			break
 		}
+	}
 
-		// S2 API has a 10,000 offset limit
-		if offset >= 10000 {
+	// Trim to max results
+	if len(allPapers) > s.config.MaxResults {
+		allPapers = allPapers[:s.config.MaxResults]
+	}
+
+	return allPapers, nil
+}
+
+// fetchBulkSearch fetches papers using the bulk search API, which supports sorting
+// Bulk search has no relevance ranking, so it is used for bulk mode and publicationDate/citationCount sorts
+// Pages are chained by continuation token, so it is not capped by the 10,000 offset limit of fetchSearch
+func (s *SemanticScholarSource) fetchBulkSearch(ctx context.Context, query string) ([]s2Paper, error) {
+	var allPapers []s2Paper
+	token := ""
+
+	for len(allPapers) < s.config.MaxResults {
+		// Check context cancellation
+		select {
+		case <-ctx.Done():
+			return nil, ctx.Err()
+		default:
+		}
+
+		params := url.Values{}
+		params.Set("query", query)
+		if s.config.Sort != "" {
+			params.Set("sort", s.config.Sort+":desc")
+		}
+		s.setFilters(params)
+
+		if token != "" {
+			params.Set("token", token)
+		}
+
+		body, err := s.getWithFields(ctx, "https://api.semanticscholar.org/graph/v1/paper/search/bulk", params)
+		if err != nil {
+			return nil, err
+		}
+
+		var response s2BulkSearchResponse
+		if err := json.Unmarshal(body, &response); err != nil {
+			return nil, fmt.Errorf("failed to decode response: %w", err)
+		}
+
+		if len(response.Data) == 0 {
 			break
 		}
+
+		allPapers = append(allPapers, response.Data...)
+
+		// Continuation token is empty on the last page
+		if response.Token == "" {
+			break
+		}
+		token = response.Token
 	}
 
 	// Trim to max results
@@ -231,20 +475,18 @@ func (s *SemanticScholarSource) fetchSearch(ctx context.Context) ([]s2Paper, err
 	return allPapers, nil
 }
 
-// fetchRecommendations fetches paper recommendations
-func (s *SemanticScholarSource) fetchRecommendations(ctx context.Context) ([]s2Paper, error) {
-	// Rate limiting
-	if err := s.limiter.Wait(ctx); err != nil {
-		return nil, err
+// setFilters adds the year and fields-of-study filters shared by both search endpoints
+func (s *SemanticScholarSource) setFilters(params url.Values) {
+	if s.config.Year != nil && *s.config.Year != "" {
+		params.Set("year", *s.config.Year)
 	}
+	if len(s.config.FieldsOfStudy) > 0 {
+		params.Set("fieldsOfStudy", strings.Join(s.config.FieldsOfStudy, ","))
+	}
+}
 
-	params := url.Values{}
-	params.Set("fields", "paperId,title,abstract,year,citationCount,url,authors")
-	params.Set("limit", strconv.Itoa(s.config.MaxResults))
-
-	u := fmt.Sprintf("https://api.semanticscholar.org/recommendations/v1/papers/forpaper/%s?%s",
-		*s.config.PaperID, params.Encode())
-
+// get performs an authenticated GET request against the Semantic Scholar API
+func (s *SemanticScholarSource) get(ctx context.Context, u string) ([]byte, error) {
 	req, err := http.NewRequestWithContext(ctx, "GET", u, nil)
 	if err != nil {
 		return nil, err
@@ -259,7 +501,7 @@ func (s *SemanticScholarSource) fetchRecommendations(ctx context.Context) ([]s2P
 		return nil, err
 	}
 
-	body, err := io.ReadAll(resp.Body)
+	body, err := readBody(resp, contentJSON)
 	resp.Body.Close()
 
 	if err != nil {
@@ -272,15 +514,70 @@ func (s *SemanticScholarSource) fetchRecommendations(ctx context.Context) ([]s2P
 	}
 
 	if resp.StatusCode != http.StatusOK {
-		return nil, fmt.Errorf("semantic scholar API returned %d: %s", resp.StatusCode, string(body))
+		return nil, &s2APIError{status: resp.StatusCode, body: string(body)}
 	}
 
-	var response s2RecommendationsResponse
-	if err := json.Unmarshal(body, &response); err != nil {
-		return nil, fmt.Errorf("failed to decode response: %w", err)
+	return body, nil
+}
+
+// getWithFields requests endpoint with the paper fields still in s.fields
+// When the API rejects some of them, they are dropped for the rest of the fetch and the request is retried,
+// so a field that is invalid or temporarily unavailable costs its values instead of the whole source
+func (s *SemanticScholarSource) getWithFields(ctx context.Context, endpoint string, params url.Values) ([]byte, error) {
+	for {
+		s.mu.Lock()
+		requested := append([]string(nil), s.fields...)
+		s.mu.Unlock()
+
+		params.Set("fields", strings.Join(append(append([]string(nil), s2CoreFields...), requested...), ","))
+		body, err := s.get(ctx, endpoint+"?"+params.Encode())
+
+		var apiErr *s2APIError
+		if err == nil || !errors.As(err, &apiErr) || apiErr.status != http.StatusBadRequest {
+			return body, err
+		}
+		rejected := rejectedS2Fields(apiErr.body, requested)
+		if len(rejected) == 0 {
+			return nil, err
+		}
+
+		// Another query may have dropped some of them already
+		s.mu.Lock()
+		s.fields = slices.DeleteFunc(s.fields, func(field string) bool { return slices.Contains(rejected, field) })
+		remaining := append([]string(nil), s.fields...)
+		s.mu.Unlock()
+		slog.Warn("Semantic Scholar rejected fields, retrying without them",
+			"source_id", s.source.ID,
+			"dropped", rejected,
+			"remaining", remaining,
+			"error", apiErr.body)
 	}
+}
 
-	return response.RecommendedPapers, nil
+// rejectedS2Fields returns which of the requested optional fields a 400 response body blames
+// The API lists offending fields in brackets ("Unrecognized or unsupported fields: [abstract]");
+// a field error that names none of the requested ones blames them all
+func rejectedS2Fields(body string, requested []string) []string {
+	if !strings.Contains(strings.ToLower(body), "field") {
+		return nil // Some other bad request, e.g. a malformed query
+	}
+
+	var rejected []string
+	if start := strings.Index(body, "["); start >= 0 {
+		if end := strings.Index(body[start:], "]"); end > 0 {
+			for _, name := range strings.FieldsFunc(body[start+1:start+end], func(r rune) bool {
+				return r == ',' || r == ' ' || r == '\'' || r == '"'
+			}) {
+				if slices.Contains(requested, name) && !slices.Contains(rejected, name) {
+					rejected = append(rejected, name)
+				}
+			}
+		}
+	}
+	if len(rejected) == 0 {
+		return requested
+	}
+	return rejected
 }
 
 // paperToArticle converts a Semantic Scholar paper to an Article
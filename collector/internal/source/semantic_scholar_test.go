@@ -0,0 +1,88 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"strings"
+	"testing"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestRejectedS2Fields(t *testing.T) {
+	requested := []string{"abstract", "year", "citationCount", "authors"}
+
+	if got := rejectedS2Fields(`{"error":"Unrecognized or unsupported fields: [abstract, 'authors']"}`, requested); len(got) != 2 || got[0] != "abstract" || got[1] != "authors" {
+		t.Errorf("Expected abstract and authors, got %v", got)
+	}
+	if got := rejectedS2Fields(`{"error":"Requested fields are temporarily unavailable"}`, requested); len(got) != len(requested) {
+		t.Errorf("Expected every field to be blamed, got %v", got)
+	}
+	if got := rejectedS2Fields(`{"error":"Invalid query"}`, requested); got != nil {
+		t.Errorf("Expected a non-field error to blame nothing, got %v", got)
+	}
+}
+
+func TestS2GetWithFieldsDowngrades(t *testing.T) {
+	var requests []string
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		fields := r.URL.Query().Get("fields")
+		requests = append(requests, fields)
+		if strings.Contains(fields, "abstract") {
+			w.WriteHeader(http.StatusBadRequest)
+			w.Write([]byte(`{"error":"Unrecognized or unsupported fields: [abstract]"}`))
+			return
+		}
+		w.Write([]byte(`{"data":[]}`))
+	}))
+	defer server.Close()
+
+	s := &SemanticScholarSource{
+		source: &db.Source{ID: "src"},
+		config: &db.SemanticScholarConfig{},
+		client: server.Client(),
+		fields: append([]string(nil), s2OptionalFields...),
+	}
+
+	if _, err := s.getWithFields(context.Background(), server.URL, url.Values{}); err != nil {
+		t.Fatalf("Expected the retry to succeed, got %v", err)
+	}
+	if len(requests) != 2 || requests[1] != "paperId,title,url,year,citationCount,authors" {
+		t.Errorf("Unexpected requests: %v", requests)
+	}
+
+	// The reduced set sticks for the rest of the fetch
+	if _, err := s.getWithFields(context.Background(), server.URL, url.Values{}); err != nil || len(requests) != 3 {
+		t.Errorf("Expected one more request without a retry, got %v (%v)", requests, err)
+	}
+}
+
+func TestMergeS2Results(t *testing.T) {
+	var config db.SemanticScholarConfig
+	if err := json.Unmarshal([]byte(`{"mode": "search", "query": ["llm agents", "tool use"]}`), &config); err != nil {
+		t.Fatalf("Failed to decode a query list: %v", err)
+	}
+	if len(config.Query) != 2 || config.Query.String() != "llm agents | tool use" {
+		t.Errorf("Unexpected queries: %v", config.Query)
+	}
+
+	results := [][]s2Paper{
+		{{PaperID: "a"}, {PaperID: "b"}, {PaperID: "c"}},
+		{{PaperID: "b"}, {PaperID: "d"}},
+	}
+	merged := mergeS2Results(config.Query, results, 3)
+
+	var ids []string
+	for _, paper := range merged {
+		ids = append(ids, paper.PaperID)
+	}
+	if strings.Join(ids, ",") != "a,b,d" {
+		t.Errorf("Expected papers interleaved by rank and deduped, got %v", ids)
+	}
+	if got := merged[1].matchedQueries; len(got) != 2 || got[0] != "tool use" || got[1] != "llm agents" {
+		t.Errorf("Expected b to record both queries, got %v", got)
+	}
+}
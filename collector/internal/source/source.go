@@ -3,6 +3,7 @@ package source
 import (
 	"context"
 	"fmt"
+	"net/http"
 	"time"
 
 	"github.com/cheolwanpark/meows/collector/internal/config"
@@ -10,12 +11,25 @@ import (
 	"golang.org/x/time/rate"
 )
 
+// Types lists every supported source type
+var Types = []string{"reddit", "semantic_scholar", "hackernews", "github", "stackexchange", "bluesky", "openalex", "pubmed", "arxiv", "discourse", "nitter", "google_scholar", "web", "json_api", "news", "wikipedia", "kaggle", "podcast", "huggingface", "package_registry", "imap", "file", "feed", "zenodo"}
+
+// IsValidType reports whether the given string is a supported source type
+func IsValidType(sourceType string) bool {
+	for _, t := range Types {
+		if t == sourceType {
+			return true
+		}
+	}
+	return false
+}
+
 // Source is the interface that all content sources must implement
 type Source interface {
 	// Fetch retrieves articles since the given time
 	Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error)
 
-	// SourceType returns the type of this source ("reddit", "semantic_scholar", or "hackernews")
+	// SourceType returns the type of this source (one of Types)
 	SourceType() string
 
 	// Validate checks if the source configuration is valid
@@ -27,16 +41,85 @@ func Factory(
 	source *db.Source,
 	credentials *config.CredentialsConfig,
 	sharedLimiter *rate.Limiter,
+	httpClient *http.Client,
 	maxCommentDepth int,
 ) (Source, error) {
+	httpClient = sourceClient(httpClient, sharedLimiter)
+
 	switch source.Type {
 	case "reddit":
-		return NewRedditSource(source, credentials, sharedLimiter, maxCommentDepth)
+		return NewRedditSource(source, credentials, httpClient, maxCommentDepth)
 	case "semantic_scholar":
-		return NewSemanticScholarSource(source, credentials, sharedLimiter)
+		return NewSemanticScholarSource(source, credentials, httpClient)
 	case "hackernews":
-		return NewHackerNewsSource(source, credentials, sharedLimiter, maxCommentDepth)
+		return NewHackerNewsSource(source, credentials, httpClient, maxCommentDepth)
+	case "github":
+		return NewGitHubSource(source, credentials, httpClient)
+	case "stackexchange":
+		return NewStackExchangeSource(source, credentials, httpClient)
+	case "bluesky":
+		return NewBlueskySource(source, credentials, httpClient)
+	case "openalex":
+		return NewOpenAlexSource(source, credentials, httpClient)
+	case "pubmed":
+		return NewPubMedSource(source, credentials, httpClient)
+	case "arxiv":
+		return NewArxivSource(source, credentials, httpClient)
+	case "discourse":
+		return NewDiscourseSource(source, credentials, httpClient)
+	case "nitter":
+		return NewNitterSource(source, credentials, httpClient)
+	case "google_scholar":
+		return NewGoogleScholarSource(source, credentials, httpClient)
+	case "web":
+		return NewWebSource(source, credentials, httpClient)
+	case "json_api":
+		return NewJSONAPISource(source, credentials, httpClient)
+	case "news":
+		return NewNewsSource(source, credentials, httpClient)
+	case "wikipedia":
+		return NewWikipediaSource(source, credentials, httpClient)
+	case "kaggle":
+		return NewKaggleSource(source, credentials, httpClient)
+	case "podcast":
+		return NewPodcastSource(source, credentials, httpClient)
+	case "huggingface":
+		return NewHuggingFaceSource(source, credentials, httpClient)
+	case "package_registry":
+		return NewPackageRegistrySource(source, credentials, httpClient)
+	case "imap":
+		return NewImapSource(source, credentials, sharedLimiter)
+	case "file":
+		return NewFileSource(source, credentials)
+	case "feed":
+		return NewFeedSource(source, credentials, httpClient)
+	case "zenodo":
+		return NewZenodoSource(source, credentials, httpClient)
 	default:
 		return nil, fmt.Errorf("unknown source type: %s", source.Type)
 	}
 }
+
+// sourceClient composes the per-source middleware, currently the source type's rate limiter, on top of the shared stack
+// Only the Transport is wrapped, so every source still shares one connection pool
+func sourceClient(httpClient *http.Client, limiter *rate.Limiter) *http.Client {
+	if httpClient == nil {
+		return nil
+	}
+
+	var middlewares []Middleware
+	if limiter != nil {
+		middlewares = append(middlewares, withRateLimit(limiter))
+	}
+	if len(middlewares) == 0 {
+		return httpClient
+	}
+
+	base := httpClient.Transport
+	if base == nil {
+		base = http.DefaultTransport
+	}
+	client := *httpClient
+	client.Transport = chainMiddleware(base, middlewares...)
+	return &client
+}
@@ -0,0 +1,224 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"html"
+	"log/slog"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+// StackExchangeSource implements the Source interface for Stack Exchange sites
+type StackExchangeSource struct {
+	source *db.Source
+	config *db.StackExchangeConfig
+	client *http.Client
+	apiKey string // From global config (environment variable)
+}
+
+// Stack Exchange API response structures
+type seQuestionsResponse struct {
+	Items          []seQuestion `json:"items"`
+	HasMore        bool         `json:"has_more"`
+	QuotaRemaining int          `json:"quota_remaining"`
+	Backoff        int          `json:"backoff"` // Seconds to wait before hitting the same method again
+	ErrorID        int          `json:"error_id"`
+	ErrorName      string       `json:"error_name"`
+	ErrorMessage   string       `json:"error_message"`
+}
+
+type seQuestion struct {
+	QuestionID   int64    `json:"question_id"`
+	Title        string   `json:"title"` // HTML-escaped by the API
+	Body         string   `json:"body"`  // Only present with the "withbody" filter
+	Link         string   `json:"link"`
+	Score        int      `json:"score"`
+	AnswerCount  int      `json:"answer_count"`
+	ViewCount    int      `json:"view_count"`
+	IsAnswered   bool     `json:"is_answered"`
+	Tags         []string `json:"tags"`
+	CreationDate int64    `json:"creation_date"`
+	Owner        struct {
+		DisplayName string `json:"display_name"`
+	} `json:"owner"`
+}
+
+// NewStackExchangeSource creates a new Stack Exchange source
+// Uses the optional API key from config file
+func NewStackExchangeSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*StackExchangeSource, error) {
+	var config db.StackExchangeConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid stackexchange config: %w", err)
+	}
+
+	ss := &StackExchangeSource{
+		source: source,
+		config: &config,
+		client: httpClient,                      // Shared client with pooled connections
+		apiKey: credentials.StackExchangeAPIKey, // Optional, raises daily quota
+	}
+
+	return ss, nil
+}
+
+// SourceType returns "stackexchange"
+func (s *StackExchangeSource) SourceType() string {
+	return "stackexchange"
+}
+
+// Validate checks if the configuration is valid
+func (s *StackExchangeSource) Validate() error {
+	if s.config.Site == "" {
+		s.config.Site = "stackoverflow"
+	}
+
+	if s.config.Sort == "" {
+		s.config.Sort = "activity"
+	}
+	if err := validateEnum(s.config.Sort, []string{"activity", "votes", "creation"}, "sort"); err != nil {
+		return err
+	}
+
+	if s.config.Limit <= 0 {
+		s.config.Limit = 30
+	}
+	if s.config.Limit > 100 {
+		return fmt.Errorf("limit must be <= 100, got %d", s.config.Limit)
+	}
+
+	if s.config.MaxAgeDays < 0 {
+		return fmt.Errorf("max_age_days must be non-negative, got %d", s.config.MaxAgeDays)
+	}
+
+	return nil
+}
+
+// Fetch retrieves questions from the configured Stack Exchange site
+func (s *StackExchangeSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := s.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	// Date window: never look further back than max_age_days
+	fromDate := since
+	if s.config.MaxAgeDays > 0 {
+		windowStart := time.Now().AddDate(0, 0, -s.config.MaxAgeDays)
+		if windowStart.After(fromDate) {
+			fromDate = windowStart
+		}
+	}
+
+	questions, err := s.fetchQuestions(ctx, fromDate)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	var articles []db.Article
+	for _, q := range questions {
+		if q.Score < s.config.MinScore {
+			continue
+		}
+		articles = append(articles, s.questionToArticle(q))
+	}
+
+	// Answers are not fetched, answer_count is kept as num_comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchQuestions calls the /questions endpoint
+func (s *StackExchangeSource) fetchQuestions(ctx context.Context, fromDate time.Time) ([]seQuestion, error) {
+	params := url.Values{}
+	params.Set("site", s.config.Site)
+	params.Set("sort", s.config.Sort)
+	params.Set("order", "desc")
+	params.Set("pagesize", strconv.Itoa(s.config.Limit))
+	params.Set("filter", "withbody")
+	if len(s.config.Tags) > 0 {
+		params.Set("tagged", strings.Join(s.config.Tags, ";"))
+	}
+	if fromDate.Unix() > 0 {
+		params.Set("fromdate", strconv.FormatInt(fromDate.Unix(), 10))
+	}
+	if s.apiKey != "" {
+		params.Set("key", s.apiKey)
+	}
+
+	req, err := http.NewRequestWithContext(ctx, "GET", "https://api.stackexchange.com/2.3/questions?"+params.Encode(), nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+
+	// Responses are always gzip-compressed; net/http decompresses them transparently
+	resp, err := s.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	body, err := readBody(resp, contentJSON)
+	if err != nil {
+		return nil, err
+	}
+
+	var response seQuestionsResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode response (status %d): %w", resp.StatusCode, err)
+	}
+
+	// Errors are reported in the body with error_id/error_name
+	if response.ErrorID != 0 {
+		return nil, fmt.Errorf("Stack Exchange API error %d (%s): %s",
+			response.ErrorID, response.ErrorName, response.ErrorMessage)
+	}
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("Stack Exchange API returned %d", resp.StatusCode)
+	}
+
+	if response.Backoff > 0 {
+		slog.Warn("Stack Exchange API requested backoff", "site", s.config.Site, "seconds", response.Backoff)
+	}
+	if response.QuotaRemaining < 100 {
+		slog.Warn("Stack Exchange API quota running low", "quota_remaining", response.QuotaRemaining)
+	}
+
+	return response.Items, nil
+}
+
+// questionToArticle converts a Stack Exchange question to an Article
+func (s *StackExchangeSource) questionToArticle(q seQuestion) db.Article {
+	metadata, _ := json.Marshal(map[string]interface{}{
+		"score":        q.Score,
+		"num_comments": q.AnswerCount,
+		"view_count":   q.ViewCount,
+		"is_answered":  q.IsAnswered,
+		"se_tags":      q.Tags,
+		"site":         s.config.Site,
+	})
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   s.source.ID,
+		ExternalID: s.config.Site + ":" + strconv.FormatInt(q.QuestionID, 10),
+		Title:      html.UnescapeString(q.Title),
+		Author:     html.UnescapeString(q.Owner.DisplayName),
+		Content:    q.Body,
+		URL:        q.Link,
+		WrittenAt:  time.Unix(q.CreationDate, 0),
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
@@ -0,0 +1,220 @@
+package source
+
+import (
+	"context"
+	"io"
+	"log/slog"
+	"math/rand/v2"
+	"net/http"
+	"strconv"
+	"sync/atomic"
+	"time"
+
+	"golang.org/x/time/rate"
+)
+
+// maxRetryAfter caps how long the retry middleware honors a server's Retry-After header
+// NewHTTPClient lowers the cap further to fit within the client timeout (see retryAfterCap)
+// Longer waits are returned to the source so it can record a rate-limit error instead
+const maxRetryAfter = 60 * time.Second
+
+// retryAfterCap returns the longest Retry-After worth waiting for under the given client timeout
+// Half the timeout leaves the retried attempt time to complete; zero means the client has no timeout
+func retryAfterCap(timeout time.Duration) time.Duration {
+	if timeout <= 0 {
+		return maxRetryAfter
+	}
+	return min(maxRetryAfter, timeout/2)
+}
+
+// Middleware wraps a RoundTripper with cross-cutting behavior (rate limiting, retries, logging, recording)
+type Middleware func(http.RoundTripper) http.RoundTripper
+
+// chainMiddleware applies middlewares so the first one listed is the outermost
+func chainMiddleware(base http.RoundTripper, middlewares ...Middleware) http.RoundTripper {
+	rt := base
+	for i := len(middlewares) - 1; i >= 0; i-- {
+		rt = middlewares[i](rt)
+	}
+	return rt
+}
+
+// withRetry retries network errors, 429 and 502/503/504 responses with exponential backoff
+// A Retry-After longer than maxWait is not waited for
+func withRetry(maxRetries int, baseDelay, maxWait time.Duration) Middleware {
+	return func(next http.RoundTripper) http.RoundTripper {
+		return &retryTransport{next: next, maxRetries: maxRetries, baseDelay: baseDelay, maxWait: maxWait}
+	}
+}
+
+// withRateLimit waits on limiter before every request
+// Factory puts it on top of the shared stack, so retries are paced by their backoff rather than the limiter
+func withRateLimit(limiter *rate.Limiter) Middleware {
+	return func(next http.RoundTripper) http.RoundTripper {
+		return roundTripperFunc(func(req *http.Request) (*http.Response, error) {
+			if err := limiter.Wait(req.Context()); err != nil {
+				return nil, err
+			}
+			return next.RoundTrip(req)
+		})
+	}
+}
+
+// withLogging logs every attempt at debug level
+func withLogging() Middleware {
+	return func(next http.RoundTripper) http.RoundTripper {
+		return &loggingTransport{next: next}
+	}
+}
+
+type requestCounterKey struct{}
+
+// WithRequestCounter returns a context whose outbound requests CountRequests adds to counter
+// The scheduler gives each job its own counter, so one job's traffic never uses up another's request budget
+func WithRequestCounter(ctx context.Context, counter *atomic.Int64) context.Context {
+	return context.WithValue(ctx, requestCounterKey{}, counter)
+}
+
+// CountRequests increments the counter of the request's context (see WithRequestCounter) once per request
+// Exported so the scheduler can report request totals in status dumps
+func CountRequests() Middleware {
+	return func(next http.RoundTripper) http.RoundTripper {
+		return roundTripperFunc(func(req *http.Request) (*http.Response, error) {
+			if counter, ok := req.Context().Value(requestCounterKey{}).(*atomic.Int64); ok {
+				counter.Add(1)
+			}
+			return next.RoundTrip(req)
+		})
+	}
+}
+
+// roundTripperFunc adapts a function to http.RoundTripper
+type roundTripperFunc func(*http.Request) (*http.Response, error)
+
+// RoundTrip calls f(req)
+func (f roundTripperFunc) RoundTrip(req *http.Request) (*http.Response, error) {
+	return f(req)
+}
+
+// withArchive saves raw response bodies under dir (see archiveTransport)
+func withArchive(dir string) Middleware {
+	return func(next http.RoundTripper) http.RoundTripper {
+		return &archiveTransport{next: next, dir: dir}
+	}
+}
+
+// retryTransport re-sends failed requests
+// Retries count against the client's overall timeout (COLLECTOR_HTTP_TIMEOUT_SECONDS)
+type retryTransport struct {
+	next       http.RoundTripper
+	maxRetries int
+	baseDelay  time.Duration
+	maxWait    time.Duration // Longest Retry-After honored
+}
+
+// RoundTrip performs the request, retrying transient failures
+func (t *retryTransport) RoundTrip(req *http.Request) (*http.Response, error) {
+	// Requests whose body cannot be replayed are sent exactly once
+	replayable := req.Body == nil || req.Body == http.NoBody || req.GetBody != nil
+
+	for attempt := 0; ; attempt++ {
+		attemptReq := req
+		if attempt > 0 && req.GetBody != nil {
+			body, err := req.GetBody()
+			if err != nil {
+				return nil, err
+			}
+			attemptReq = req.Clone(req.Context())
+			attemptReq.Body = body
+		}
+
+		resp, err := t.next.RoundTrip(attemptReq)
+		noteAttempt(req.Context(), resp, attempt)
+		if !replayable || attempt >= t.maxRetries || req.Context().Err() != nil {
+			return resp, err
+		}
+
+		delay, retry := t.retryDelay(resp, err, attempt)
+		if !retry {
+			return resp, err
+		}
+
+		// Drain so the connection can be reused
+		if resp != nil {
+			io.Copy(io.Discard, io.LimitReader(resp.Body, 64<<10))
+			resp.Body.Close()
+		}
+
+		slog.Debug("Retrying HTTP request",
+			"host", req.URL.Host,
+			"attempt", attempt+1,
+			"delay_ms", delay.Milliseconds(),
+			"error", err)
+
+		timer := time.NewTimer(delay)
+		select {
+		case <-req.Context().Done():
+			timer.Stop()
+			return nil, req.Context().Err()
+		case <-timer.C:
+		}
+	}
+}
+
+// retryDelay decides whether an attempt should be retried and how long to wait first
+func (t *retryTransport) retryDelay(resp *http.Response, err error, attempt int) (time.Duration, bool) {
+	backoff := t.baseDelay << attempt
+	jitter := time.Duration(rand.Int64N(int64(t.baseDelay)/2 + 1))
+
+	if err != nil {
+		return backoff + jitter, true
+	}
+
+	switch resp.StatusCode {
+	case http.StatusTooManyRequests, http.StatusBadGateway, http.StatusServiceUnavailable, http.StatusGatewayTimeout:
+	default:
+		return 0, false
+	}
+
+	if retryAfter := resp.Header.Get("Retry-After"); retryAfter != "" {
+		seconds, parseErr := strconv.Atoi(retryAfter)
+		if parseErr == nil {
+			wait := time.Duration(seconds) * time.Second
+			if wait > t.maxWait {
+				return 0, false
+			}
+			return wait, true
+		}
+	}
+
+	return backoff + jitter, true
+}
+
+// loggingTransport logs method, host, path, status and duration of each request
+type loggingTransport struct {
+	next http.RoundTripper
+}
+
+// RoundTrip performs the request and logs its outcome
+func (t *loggingTransport) RoundTrip(req *http.Request) (*http.Response, error) {
+	start := time.Now()
+	resp, err := t.next.RoundTrip(req)
+
+	if err != nil {
+		slog.Debug("HTTP request failed",
+			"method", req.Method,
+			"host", req.URL.Host,
+			"path", req.URL.Path,
+			"duration_ms", time.Since(start).Milliseconds(),
+			"error", err)
+		return resp, err
+	}
+
+	slog.Debug("HTTP request",
+		"method", req.Method,
+		"host", req.URL.Host,
+		"path", req.URL.Path,
+		"status", resp.StatusCode,
+		"duration_ms", time.Since(start).Milliseconds())
+	return resp, nil
+}
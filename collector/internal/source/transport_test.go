@@ -0,0 +1,132 @@
+package source
+
+import (
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"golang.org/x/time/rate"
+)
+
+func TestRetryTransport_RetriesServiceUnavailable(t *testing.T) {
+	var calls int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if atomic.AddInt32(&calls, 1) < 3 {
+			w.WriteHeader(http.StatusServiceUnavailable)
+			return
+		}
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer server.Close()
+
+	client := &http.Client{Transport: chainMiddleware(http.DefaultTransport, withRetry(2, time.Millisecond, maxRetryAfter))}
+
+	resp, err := client.Get(server.URL)
+	if err != nil {
+		t.Fatalf("Expected request to succeed after retries, got %v", err)
+	}
+	resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		t.Errorf("Expected status 200, got %d", resp.StatusCode)
+	}
+	if calls != 3 {
+		t.Errorf("Expected 3 attempts, got %d", calls)
+	}
+}
+
+func TestRetryTransport_SkipsClientErrors(t *testing.T) {
+	var calls int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		atomic.AddInt32(&calls, 1)
+		w.WriteHeader(http.StatusNotFound)
+	}))
+	defer server.Close()
+
+	client := &http.Client{Transport: chainMiddleware(http.DefaultTransport, withRetry(2, time.Millisecond, maxRetryAfter))}
+
+	resp, err := client.Get(server.URL)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	resp.Body.Close()
+
+	if calls != 1 {
+		t.Errorf("Expected 404 to be returned without retrying, got %d attempts", calls)
+	}
+}
+
+func TestRetryTransport_SkipsRetryAfterBeyondCap(t *testing.T) {
+	var calls int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		atomic.AddInt32(&calls, 1)
+		w.Header().Set("Retry-After", "20")
+		w.WriteHeader(http.StatusTooManyRequests)
+	}))
+	defer server.Close()
+
+	// A 30s client timeout caps Retry-After at 15s, so waiting 20s would only end in a timeout
+	client := &http.Client{Transport: chainMiddleware(http.DefaultTransport, withRetry(2, time.Millisecond, retryAfterCap(30*time.Second)))}
+
+	resp, err := client.Get(server.URL)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	resp.Body.Close()
+
+	if resp.StatusCode != http.StatusTooManyRequests {
+		t.Errorf("Expected the 429 to be returned, got %d", resp.StatusCode)
+	}
+	if calls != 1 {
+		t.Errorf("Expected no retry for a Retry-After beyond the cap, got %d attempts", calls)
+	}
+}
+
+func TestRetryAfterCap(t *testing.T) {
+	tests := []struct {
+		timeout time.Duration
+		want    time.Duration
+	}{
+		{30 * time.Second, 15 * time.Second},
+		{300 * time.Second, maxRetryAfter},
+		{0, maxRetryAfter},
+	}
+	for _, tt := range tests {
+		if got := retryAfterCap(tt.timeout); got != tt.want {
+			t.Errorf("retryAfterCap(%v) = %v, want %v", tt.timeout, got, tt.want)
+		}
+	}
+}
+
+func TestSourceClient_RateLimitsRequests(t *testing.T) {
+	var calls int32
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		atomic.AddInt32(&calls, 1)
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer server.Close()
+
+	// One token, refilled hourly: the second request cannot be sent before its deadline
+	client := sourceClient(server.Client(), rate.NewLimiter(rate.Every(time.Hour), 1))
+
+	resp, err := client.Get(server.URL)
+	if err != nil {
+		t.Fatalf("Expected the first request to pass the limiter, got %v", err)
+	}
+	resp.Body.Close()
+
+	ctx, cancel := context.WithTimeout(context.Background(), time.Second)
+	defer cancel()
+	req, _ := http.NewRequestWithContext(ctx, "GET", server.URL, nil)
+	if resp, err := client.Do(req); err == nil {
+		resp.Body.Close()
+		t.Fatal("Expected the second request to be held back by the limiter")
+	}
+
+	if calls != 1 {
+		t.Errorf("Expected only 1 request to reach the server, got %d", calls)
+	}
+}
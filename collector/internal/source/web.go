@@ -0,0 +1,501 @@
+package source
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"encoding/xml"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"net/url"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/PuerkitoBio/goquery"
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	webMaxChildSitemaps = 5 // Child sitemaps followed from a sitemap index, newest first
+	webDescriptionLen   = 300
+)
+
+// webNoiseSelector matches elements that never hold readable article text
+const webNoiseSelector = "script, style, noscript, template, iframe, svg, form, nav, header, footer, aside"
+
+// webBlockSelector matches the text blocks collected from the content root
+const webBlockSelector = "h1, h2, h3, h4, p, li, pre, blockquote"
+
+// webLastModLayouts are the W3C datetime variants allowed in sitemap <lastmod>
+var webLastModLayouts = []string{time.RFC3339, "2006-01-02T15:04Z07:00", "2006-01-02"}
+
+// WebSource implements the Source interface for plain web pages, listed directly or through a sitemap
+type WebSource struct {
+	source     *db.Source
+	config     *db.WebConfig
+	userAgents []string       // Optional pool for HTML scraping
+	htmlClient *http.Client   // Client for HTML scraping (carries the cookie jar if configured)
+	cookieJar  *fileCookieJar // Optional persistent session for HTML scraping
+}
+
+// Sitemap structures (urlset and sitemapindex share the same entry shape)
+type sitemapDoc struct {
+	XMLName  xml.Name
+	URLs     []sitemapEntry `xml:"url"`
+	Sitemaps []sitemapEntry `xml:"sitemap"`
+}
+
+type sitemapEntry struct {
+	Loc     string `xml:"loc"`
+	LastMod string `xml:"lastmod"`
+}
+
+// webPage is the readable part of an HTML page
+type webPage struct {
+	title       string
+	author      string
+	description string
+	text        string
+	published   time.Time // Zero when the page doesn't declare it
+}
+
+// NewWebSource creates a new web page source
+func NewWebSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*WebSource, error) {
+	var config db.WebConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid web config: %w", err)
+	}
+
+	ws := &WebSource{
+		source:     source,
+		config:     &config,
+		htmlClient: httpClient, // Shared client with pooled connections
+	}
+
+	// Public pages, no credentials needed
+	_ = credentials
+
+	// Every request is HTML scraping, so user agent rotation and cookie sessions apply (opt-in)
+	if opts, err := ParseOptions(source.Config); err == nil {
+		ws.userAgents = opts.UserAgents
+		if opts.CookieFile != "" {
+			jar, err := loadCookieJar(opts.CookieFile)
+			if err != nil {
+				return nil, err
+			}
+			ws.cookieJar = jar
+			ws.htmlClient = withCookieJar(httpClient, jar)
+		}
+	}
+
+	return ws, nil
+}
+
+// SourceType returns "web"
+func (w *WebSource) SourceType() string {
+	return "web"
+}
+
+// Validate checks if the configuration is valid
+func (w *WebSource) Validate() error {
+	if len(w.config.URLs) == 0 && w.config.SitemapURL == "" {
+		return fmt.Errorf("urls or sitemap_url is required")
+	}
+	for _, u := range w.config.URLs {
+		if !isHTTPURL(u) {
+			return fmt.Errorf("urls must be absolute http(s) URLs, got %q", u)
+		}
+	}
+	if w.config.SitemapURL != "" && !isHTTPURL(w.config.SitemapURL) {
+		return fmt.Errorf("sitemap_url must be an absolute http(s) URL, got %q", w.config.SitemapURL)
+	}
+
+	// Set defaults
+	if w.config.Limit <= 0 {
+		w.config.Limit = 30
+	}
+	if w.config.Limit > 200 {
+		return fmt.Errorf("limit must be <= 200, got %d", w.config.Limit)
+	}
+	if w.config.MinWords < 0 {
+		return fmt.Errorf("min_words must be non-negative, got %d", w.config.MinWords)
+	}
+
+	return nil
+}
+
+// Fetch downloads the listed pages (or the newest sitemap entries) and extracts their readable text
+func (w *WebSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := w.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	// Persist session cookies for the next run, even after a partial fetch
+	if w.cookieJar != nil {
+		defer func() {
+			if err := w.cookieJar.Save(); err != nil {
+				slog.Warn("Failed to save cookie file", "path", w.cookieJar.path, "error", err)
+			}
+		}()
+	}
+
+	entries := make([]sitemapEntry, 0, len(w.config.URLs))
+	for _, u := range w.config.URLs {
+		entries = append(entries, sitemapEntry{Loc: u})
+	}
+	if w.config.SitemapURL != "" {
+		sitemapEntries, err := w.fetchSitemap(ctx, since)
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to fetch sitemap: %w", err)
+		}
+		entries = append(entries, sitemapEntries...)
+	}
+
+	var articles []db.Article
+	var lastErr error
+	seen := make(map[string]bool)
+	for _, entry := range entries {
+		if len(articles) >= w.config.Limit {
+			break
+		}
+		if seen[entry.Loc] {
+			continue
+		}
+		seen[entry.Loc] = true
+
+		page, err := w.fetchPage(ctx, entry.Loc)
+		if err != nil {
+			if ctx.Err() != nil {
+				return nil, nil, ctx.Err()
+			}
+			// One broken page shouldn't fail the whole run
+			slog.Warn("Failed to fetch web page", "source_id", w.source.ID, "url", entry.Loc, "error", err)
+			lastErr = err
+			continue
+		}
+
+		if page.published.IsZero() {
+			page.published = parseLastMod(entry.LastMod)
+		}
+		if !page.published.IsZero() && page.published.Before(since) {
+			continue
+		}
+		if wordCount(page.text) < w.config.MinWords {
+			continue
+		}
+
+		articles = append(articles, w.pageToArticle(entry.Loc, page))
+		stampProvenance(ctx, articles[len(articles)-1:], 0)
+	}
+
+	if len(articles) == 0 && lastErr != nil {
+		return nil, nil, fmt.Errorf("failed to fetch any page: %w", lastErr)
+	}
+
+	// Web pages have no comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchSitemap returns page entries from the sitemap, newest first
+// A sitemap index is followed one level deep, reading its most recently modified children
+func (w *WebSource) fetchSitemap(ctx context.Context, since time.Time) ([]sitemapEntry, error) {
+	doc, err := w.getSitemap(ctx, w.config.SitemapURL)
+	if err != nil {
+		return nil, err
+	}
+
+	entries := doc.URLs
+	if len(doc.Sitemaps) > 0 {
+		children := sortByLastMod(doc.Sitemaps)
+		if len(children) > webMaxChildSitemaps {
+			children = children[:webMaxChildSitemaps]
+		}
+		for _, child := range children {
+			childDoc, err := w.getSitemap(ctx, child.Loc)
+			if err != nil {
+				return nil, err
+			}
+			entries = append(entries, childDoc.URLs...)
+		}
+	}
+
+	// Entries older than the last run are skipped before any page is downloaded
+	var fresh []sitemapEntry
+	for _, entry := range sortByLastMod(entries) {
+		if lastMod := parseLastMod(entry.LastMod); !lastMod.IsZero() && lastMod.Before(since) {
+			continue
+		}
+		fresh = append(fresh, entry)
+	}
+
+	return fresh, nil
+}
+
+// getSitemap downloads and decodes one sitemap or sitemap index
+func (w *WebSource) getSitemap(ctx context.Context, sitemapURL string) (*sitemapDoc, error) {
+	body, err := w.get(ctx, sitemapURL, contentXML)
+	if err != nil {
+		return nil, err
+	}
+
+	var doc sitemapDoc
+	if err := xml.Unmarshal(body, &doc); err != nil {
+		return nil, fmt.Errorf("failed to decode sitemap %s: %w", sitemapURL, err)
+	}
+
+	// <loc> values are often wrapped in whitespace
+	for _, entries := range [][]sitemapEntry{doc.URLs, doc.Sitemaps} {
+		for i := range entries {
+			entries[i].Loc = strings.TrimSpace(entries[i].Loc)
+			if !isHTTPURL(entries[i].Loc) {
+				return nil, fmt.Errorf("invalid <loc> in sitemap %s: %q", sitemapURL, entries[i].Loc)
+			}
+		}
+	}
+
+	return &doc, nil
+}
+
+// fetchPage downloads one HTML page and extracts its readable content
+func (w *WebSource) fetchPage(ctx context.Context, pageURL string) (*webPage, error) {
+	body, err := w.get(ctx, pageURL, contentHTML)
+	if err != nil {
+		return nil, err
+	}
+
+	doc, err := goquery.NewDocumentFromReader(bytes.NewReader(body))
+	if err != nil {
+		return nil, fmt.Errorf("failed to parse HTML: %w", err)
+	}
+
+	return extractWebPage(doc), nil
+}
+
+// get performs a rate-limited GET and returns the body after checking its format
+func (w *WebSource) get(ctx context.Context, u string, want contentKind) ([]byte, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", u, nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", pickUserAgent(w.userAgents, "meows-collector/1.0"))
+
+	resp, err := w.htmlClient.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, want)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("HTTP %d from %s", resp.StatusCode, u)
+	}
+
+	return body, nil
+}
+
+// extractWebPage pulls title, byline and readable text out of a page
+// The content root is the <article>/<main> element if present, otherwise the element
+// holding the most paragraph text, which is close enough to readability for blogs and docs
+func extractWebPage(doc *goquery.Document) *webPage {
+	page := &webPage{
+		title:       metaContent(doc, `meta[property="og:title"]`),
+		author:      metaContent(doc, `meta[name="author"]`, `meta[property="article:author"]`),
+		description: metaContent(doc, `meta[property="og:description"]`, `meta[name="description"]`),
+	}
+	if page.title == "" {
+		page.title = collapseSpace(doc.Find("title").First().Text())
+	}
+	if page.title == "" {
+		page.title = collapseSpace(doc.Find("h1").First().Text())
+	}
+
+	published := metaContent(doc, `meta[property="article:published_time"]`, `meta[itemprop="datePublished"]`)
+	if published == "" {
+		published, _ = doc.Find("time[datetime]").First().Attr("datetime")
+	}
+	page.published = parseLastMod(published)
+
+	doc.Find(webNoiseSelector).Remove()
+	root := contentRoot(doc)
+
+	var blocks []string
+	root.Find(webBlockSelector).Each(func(i int, s *goquery.Selection) {
+		// Nested blocks (a <p> inside an <li>) are covered by their outer block
+		if s.ParentsFiltered(webBlockSelector).Length() > 0 {
+			return
+		}
+		if text := collapseSpace(s.Text()); text != "" {
+			blocks = append(blocks, text)
+		}
+	})
+	page.text = strings.Join(blocks, "\n\n")
+	if page.text == "" {
+		page.text = collapseSpace(root.Text())
+	}
+
+	if page.description == "" {
+		page.description = truncateRunes(page.text, webDescriptionLen)
+	}
+
+	return page
+}
+
+// contentRoot picks the element most likely to hold the main text of a page
+func contentRoot(doc *goquery.Document) *goquery.Selection {
+	for _, sel := range []string{"article", "main", `[role="main"]`} {
+		if s := doc.Find(sel).First(); s.Length() > 0 && strings.TrimSpace(s.Text()) != "" {
+			return s
+		}
+	}
+
+	// Score each paragraph container by the amount of text in its direct <p> children
+	var candidates []*goquery.Selection
+	var scores []int
+	doc.Find("p").Each(func(i int, p *goquery.Selection) {
+		parent := p.Parent()
+		idx := -1
+		for j, candidate := range candidates {
+			if candidate.IsSelection(parent) {
+				idx = j
+				break
+			}
+		}
+		if idx < 0 {
+			candidates = append(candidates, parent)
+			scores = append(scores, 0)
+			idx = len(candidates) - 1
+		}
+		scores[idx] += len(strings.TrimSpace(p.Text()))
+	})
+
+	best := -1
+	for i, score := range scores {
+		if score > 0 && (best < 0 || score > scores[best]) {
+			best = i
+		}
+	}
+	if best >= 0 {
+		return candidates[best]
+	}
+
+	return doc.Find("body")
+}
+
+// metaContent returns the content attribute of the first matching, non-empty meta tag
+func metaContent(doc *goquery.Document, selectors ...string) string {
+	for _, sel := range selectors {
+		if content, ok := doc.Find(sel).First().Attr("content"); ok && strings.TrimSpace(content) != "" {
+			return collapseSpace(content)
+		}
+	}
+	return ""
+}
+
+// pageToArticle converts an extracted page to an Article
+func (w *WebSource) pageToArticle(pageURL string, page *webPage) db.Article {
+	host := ""
+	if u, err := url.Parse(pageURL); err == nil {
+		host = u.Host
+	}
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"site":        host,
+		"description": page.description,
+		"word_count":  wordCount(page.text),
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	title := page.title
+	if title == "" {
+		title = pageURL
+	}
+
+	// Undated pages are stamped with the crawl time
+	writtenAt := page.published
+	if writtenAt.IsZero() {
+		writtenAt = time.Now()
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   w.source.ID,
+		ExternalID: pageURL,
+		Title:      title,
+		Author:     page.author,
+		Content:    page.text,
+		URL:        pageURL,
+		WrittenAt:  writtenAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// sortByLastMod returns entries newest first, undated entries last in their original order
+func sortByLastMod(entries []sitemapEntry) []sitemapEntry {
+	sorted := make([]sitemapEntry, len(entries))
+	copy(sorted, entries)
+	sort.SliceStable(sorted, func(i, j int) bool {
+		return parseLastMod(sorted[i].LastMod).After(parseLastMod(sorted[j].LastMod))
+	})
+	return sorted
+}
+
+// parseLastMod parses a W3C datetime, returning the zero time when empty or malformed
+func parseLastMod(value string) time.Time {
+	value = strings.TrimSpace(value)
+	for _, layout := range webLastModLayouts {
+		if t, err := time.Parse(layout, value); err == nil {
+			return t
+		}
+	}
+	return time.Time{}
+}
+
+// isHTTPURL reports whether s is an absolute http(s) URL
+func isHTTPURL(s string) bool {
+	u, err := url.Parse(s)
+	return err == nil && (u.Scheme == "http" || u.Scheme == "https") && u.Host != ""
+}
+
+// collapseSpace trims s and collapses internal whitespace runs to single spaces
+func collapseSpace(s string) string {
+	return strings.Join(strings.Fields(s), " ")
+}
+
+// htmlText returns the text of an HTML fragment with whitespace collapsed
+func htmlText(fragment string) string {
+	doc, err := goquery.NewDocumentFromReader(strings.NewReader(fragment))
+	if err != nil {
+		return collapseSpace(fragment)
+	}
+	return collapseSpace(doc.Text())
+}
+
+// wordCount counts whitespace-separated words
+func wordCount(s string) int {
+	return len(strings.Fields(s))
+}
+
+// truncateRunes shortens s to at most n runes, adding an ellipsis when cut
+func truncateRunes(s string, n int) string {
+	runes := []rune(s)
+	if len(runes) <= n {
+		return s
+	}
+	return strings.TrimSpace(string(runes[:n])) + "…"
+}
@@ -0,0 +1,82 @@
+package source
+
+import (
+	"encoding/xml"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/PuerkitoBio/goquery"
+)
+
+func TestExtractWebPage(t *testing.T) {
+	html := `<html><head>
+  <title>Fallback title</title>
+  <meta property="og:title" content="Release notes  for 2.0">
+  <meta name="author" content="Ada">
+  <meta property="article:published_time" content="2024-05-01T09:30:00Z">
+  <script>var tracking = true;</script>
+</head><body>
+  <nav><ul><li>Home</li><li>Blog</li></ul></nav>
+  <div class="sidebar"><p>Short aside.</p></div>
+  <div class="post">
+    <h2>What changed</h2>
+    <p>The scheduler   now runs sources in parallel.</p>
+    <ul><li><p>Faster crawls</p></li><li>Fewer timeouts</li></ul>
+    <p>Upgrade by replacing the binary.</p>
+  </div>
+  <footer><p>Copyright</p></footer>
+</body></html>`
+
+	doc, err := goquery.NewDocumentFromReader(strings.NewReader(html))
+	if err != nil {
+		t.Fatalf("Failed to parse HTML: %v", err)
+	}
+
+	page := extractWebPage(doc)
+	if page.title != "Release notes for 2.0" {
+		t.Errorf("Unexpected title: %q", page.title)
+	}
+	if page.author != "Ada" {
+		t.Errorf("Unexpected author: %q", page.author)
+	}
+	if want := time.Date(2024, 5, 1, 9, 30, 0, 0, time.UTC); !page.published.Equal(want) {
+		t.Errorf("Expected published %v, got %v", want, page.published)
+	}
+
+	want := "What changed\n\nThe scheduler now runs sources in parallel.\n\nFaster crawls\n\nFewer timeouts\n\nUpgrade by replacing the binary."
+	if page.text != want {
+		t.Errorf("Unexpected text:\n%q\nwant:\n%q", page.text, want)
+	}
+}
+
+func TestSitemapOrdering(t *testing.T) {
+	raw := `<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/old</loc><lastmod>2023-01-01</lastmod></url>
+  <url><loc>https://example.com/undated</loc></url>
+  <url><loc>https://example.com/new</loc><lastmod>2024-03-02T10:00:00+00:00</lastmod></url>
+</urlset>`
+
+	var doc sitemapDoc
+	if err := xml.Unmarshal([]byte(raw), &doc); err != nil {
+		t.Fatalf("Failed to decode sitemap: %v", err)
+	}
+
+	sorted := sortByLastMod(doc.URLs)
+	got := make([]string, len(sorted))
+	for i, entry := range sorted {
+		got[i] = entry.Loc
+	}
+	want := []string{"https://example.com/new", "https://example.com/old", "https://example.com/undated"}
+	if strings.Join(got, " ") != strings.Join(want, " ") {
+		t.Errorf("Expected order %v, got %v", want, got)
+	}
+}
+
+func TestHTMLText(t *testing.T) {
+	snippet := `The <span class="searchmatch">Rust</span> programming  language &amp; its compiler`
+	if got := htmlText(snippet); got != "The Rust programming language & its compiler" {
+		t.Errorf("Unexpected snippet text: %q", got)
+	}
+}
@@ -0,0 +1,416 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"net/url"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	wikipediaDefaultLimit = 20
+	wikipediaMaxLimit     = 50 // One pageviews request covers up to 50 pages
+	wikipediaMaxPages     = 50
+	wikipediaViewDays     = 7 // Page views are summed over the last week
+	// Wikimedia asks API clients to identify themselves with a contact URL
+	wikipediaUserAgent = "meows-collector/1.0 (https://github.com/cheolwanpark/meows)"
+)
+
+// wikipediaLanguagePattern matches wiki subdomains like "en", "de" or "zh-yue"
+var wikipediaLanguagePattern = regexp.MustCompile(`^[a-z]{2,3}(-[a-z]+)*$`)
+
+// WikipediaSource implements the Source interface for Wikipedia search results and page edits
+type WikipediaSource struct {
+	source *db.Source
+	config *db.WikipediaConfig
+	client *http.Client
+}
+
+// MediaWiki action API response structures (formatversion=2)
+type wikipediaSearchResponse struct {
+	Query struct {
+		Search []wikipediaSearchResult `json:"search"`
+	} `json:"query"`
+}
+
+type wikipediaSearchResult struct {
+	PageID    int       `json:"pageid"`
+	Title     string    `json:"title"`
+	Snippet   string    `json:"snippet"` // HTML with <span class="searchmatch"> highlights
+	WordCount int       `json:"wordcount"`
+	Timestamp time.Time `json:"timestamp"` // Last edit
+}
+
+type wikipediaPageViewsResponse struct {
+	Query struct {
+		Pages []struct {
+			PageID    int             `json:"pageid"`
+			PageViews map[string]*int `json:"pageviews"` // Date -> views, null when not yet available
+		} `json:"pages"`
+	} `json:"query"`
+}
+
+type wikipediaRecentChangesResponse struct {
+	Query struct {
+		RecentChanges []wikipediaChange `json:"recentchanges"`
+	} `json:"query"`
+}
+
+type wikipediaChange struct {
+	Type      string    `json:"type"` // "edit" or "new"
+	Title     string    `json:"title"`
+	PageID    int       `json:"pageid"`
+	RevID     int       `json:"revid"`
+	OldRevID  int       `json:"old_revid"` // 0 for page creations
+	User      string    `json:"user"`
+	Timestamp time.Time `json:"timestamp"`
+	Comment   string    `json:"comment"`
+	OldLen    int       `json:"oldlen"`
+	NewLen    int       `json:"newlen"`
+}
+
+// NewWikipediaSource creates a new Wikipedia source
+func NewWikipediaSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*WikipediaSource, error) {
+	var config db.WikipediaConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid wikipedia config: %w", err)
+	}
+
+	ws := &WikipediaSource{
+		source: source,
+		config: &config,
+		client: httpClient, // Shared client with pooled connections
+	}
+
+	// The MediaWiki API needs no credentials
+	_ = credentials
+
+	return ws, nil
+}
+
+// SourceType returns "wikipedia"
+func (w *WikipediaSource) SourceType() string {
+	return "wikipedia"
+}
+
+// Validate checks if the configuration is valid
+func (w *WikipediaSource) Validate() error {
+	if w.config.Mode == "" {
+		w.config.Mode = "search"
+	}
+	if err := validateEnum(w.config.Mode, []string{"search", "recent_changes"}, "mode"); err != nil {
+		return err
+	}
+	if w.config.Language == "" {
+		w.config.Language = "en"
+	}
+	if !wikipediaLanguagePattern.MatchString(w.config.Language) {
+		return fmt.Errorf("language must be a Wikipedia language code like \"en\", got %q", w.config.Language)
+	}
+
+	switch w.config.Mode {
+	case "search":
+		if w.config.Query == "" {
+			return fmt.Errorf("query is required for search mode")
+		}
+	case "recent_changes":
+		if len(w.config.Pages) == 0 {
+			return fmt.Errorf("pages is required for recent_changes mode")
+		}
+		if len(w.config.Pages) > wikipediaMaxPages {
+			return fmt.Errorf("pages must have at most %d entries, got %d", wikipediaMaxPages, len(w.config.Pages))
+		}
+	}
+
+	// Set defaults
+	if w.config.Limit <= 0 {
+		w.config.Limit = wikipediaDefaultLimit
+	}
+	if w.config.Limit > wikipediaMaxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", wikipediaMaxLimit, w.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch retrieves search results or recently edited watched pages
+func (w *WikipediaSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := w.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	var articles []db.Article
+	var err error
+	if w.config.Mode == "recent_changes" {
+		articles, err = w.fetchRecentChanges(ctx, since)
+	} else {
+		articles, err = w.fetchSearch(ctx, since)
+	}
+	if err != nil {
+		return nil, nil, err
+	}
+
+	// Talk pages are not fetched as comments
+	return articles, []db.Comment{}, nil
+}
+
+// fetchSearch runs a full-text search sorted by last edit and scores results by recent page views
+func (w *WikipediaSource) fetchSearch(ctx context.Context, since time.Time) ([]db.Article, error) {
+	params := url.Values{}
+	params.Set("list", "search")
+	params.Set("srsearch", w.config.Query)
+	params.Set("srnamespace", "0")
+	params.Set("srsort", "last_edit_desc")
+	params.Set("srprop", "snippet|wordcount|timestamp")
+	params.Set("srlimit", strconv.Itoa(w.config.Limit))
+
+	var response wikipediaSearchResponse
+	if err := w.query(ctx, params, &response); err != nil {
+		return nil, fmt.Errorf("failed to search: %w", err)
+	}
+
+	var results []wikipediaSearchResult
+	for _, result := range response.Query.Search {
+		// Sorted by last edit, so everything after this is older too
+		if result.Timestamp.Before(since) {
+			break
+		}
+		results = append(results, result)
+	}
+	if len(results) == 0 {
+		return nil, nil
+	}
+
+	// Page views are a nice-to-have score, search results are still useful without them
+	views, err := w.pageViews(ctx, results)
+	if err != nil {
+		if ctx.Err() != nil {
+			return nil, ctx.Err()
+		}
+		slog.Warn("Failed to fetch Wikipedia page views", "source_id", w.source.ID, "error", err)
+	}
+
+	articles := make([]db.Article, 0, len(results))
+	for _, result := range results {
+		article := w.searchResultToArticle(result, views[result.PageID])
+		if err != nil {
+			addEnrichmentError(&article, "page_views", err)
+		}
+		articles = append(articles, article)
+	}
+	return articles, nil
+}
+
+// pageViews sums the daily views of the last wikipediaViewDays days per page ID
+func (w *WikipediaSource) pageViews(ctx context.Context, results []wikipediaSearchResult) (map[int]int, error) {
+	ids := make([]string, len(results))
+	for i, result := range results {
+		ids[i] = strconv.Itoa(result.PageID)
+	}
+
+	params := url.Values{}
+	params.Set("prop", "pageviews")
+	params.Set("pvipdays", strconv.Itoa(wikipediaViewDays))
+	params.Set("pageids", strings.Join(ids, "|"))
+
+	var response wikipediaPageViewsResponse
+	if err := w.query(ctx, params, &response); err != nil {
+		return nil, err
+	}
+
+	views := make(map[int]int, len(response.Query.Pages))
+	for _, page := range response.Query.Pages {
+		total := 0
+		for _, count := range page.PageViews {
+			if count != nil {
+				total += *count
+			}
+		}
+		views[page.PageID] = total
+	}
+	return views, nil
+}
+
+// fetchRecentChanges collects non-bot edits to each watched page since the last run
+// Each page with edits becomes one article, scored by its edit count
+func (w *WikipediaSource) fetchRecentChanges(ctx context.Context, since time.Time) ([]db.Article, error) {
+	var pages [][]wikipediaChange
+	for _, title := range w.config.Pages {
+		params := url.Values{}
+		params.Set("list", "recentchanges")
+		params.Set("rctitle", title)
+		params.Set("rcprop", "title|ids|user|timestamp|comment|sizes")
+		params.Set("rctype", "edit|new")
+		params.Set("rcshow", "!bot")
+		params.Set("rclimit", "500")
+		if since.Unix() > 0 {
+			params.Set("rcend", since.UTC().Format(time.RFC3339)) // Listed newest first, so rcend is the oldest
+		}
+
+		var response wikipediaRecentChangesResponse
+		if err := w.query(ctx, params, &response); err != nil {
+			return nil, fmt.Errorf("failed to fetch changes of %q: %w", title, err)
+		}
+
+		if changes := response.Query.RecentChanges; len(changes) > 0 {
+			pages = append(pages, changes)
+		}
+	}
+
+	// Most edited pages first, limit applies to pages
+	sort.SliceStable(pages, func(i, j int) bool {
+		return len(pages[i]) > len(pages[j])
+	})
+	if len(pages) > w.config.Limit {
+		pages = pages[:w.config.Limit]
+	}
+
+	articles := make([]db.Article, 0, len(pages))
+	for _, changes := range pages {
+		articles = append(articles, w.changesToArticle(changes))
+	}
+	return articles, nil
+}
+
+// query performs a rate-limited MediaWiki action API query and decodes the response into v
+func (w *WikipediaSource) query(ctx context.Context, params url.Values, v interface{}) error {
+	params.Set("action", "query")
+	params.Set("format", "json")
+	params.Set("formatversion", "2")
+	u := fmt.Sprintf("https://%s.wikipedia.org/w/api.php?%s", w.config.Language, params.Encode())
+
+	req, err := http.NewRequestWithContext(ctx, "GET", u, nil)
+	if err != nil {
+		return err
+	}
+	req.Header.Set("User-Agent", wikipediaUserAgent)
+
+	resp, err := w.client.Do(req)
+	if err != nil {
+		return err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("Wikipedia API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	// API errors come back as 200 with an "error" object
+	var apiError struct {
+		Error *struct {
+			Code string `json:"code"`
+			Info string `json:"info"`
+		} `json:"error"`
+	}
+	if err := json.Unmarshal(body, &apiError); err == nil && apiError.Error != nil {
+		return fmt.Errorf("Wikipedia API error %s: %s", apiError.Error.Code, apiError.Error.Info)
+	}
+
+	if err := json.Unmarshal(body, v); err != nil {
+		return fmt.Errorf("failed to decode response: %w", err)
+	}
+	return nil
+}
+
+// searchResultToArticle converts a search result to an Article
+func (w *WikipediaSource) searchResultToArticle(result wikipediaSearchResult, views int) db.Article {
+	metadata, err := json.Marshal(map[string]interface{}{
+		"mode":       "search",
+		"language":   w.config.Language,
+		"page_id":    result.PageID,
+		"word_count": result.WordCount,
+		"page_views": views,
+		"score":      views,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   w.source.ID,
+		ExternalID: strconv.Itoa(result.PageID),
+		Title:      result.Title,
+		Author:     "",
+		Content:    htmlText(result.Snippet), // Drops the search highlight markup
+		URL:        w.pageURL(result.Title),
+		WrittenAt:  result.Timestamp,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// changesToArticle summarizes the edits of one page (newest first) as an Article
+// The page ID is the external ID, so later runs update the same article with the latest edits
+func (w *WikipediaSource) changesToArticle(changes []wikipediaChange) db.Article {
+	latest := changes[0]
+
+	editors := make(map[string]bool)
+	summaries := make([]string, 0, len(changes))
+	for _, change := range changes {
+		editors[change.User] = true
+		if comment := strings.TrimSpace(change.Comment); comment != "" {
+			summaries = append(summaries, fmt.Sprintf("%s (%s): %s", change.Timestamp.UTC().Format("2006-01-02 15:04"), change.User, comment))
+		}
+	}
+	oldest := changes[len(changes)-1]
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"mode":       "recent_changes",
+		"language":   w.config.Language,
+		"page_id":    latest.PageID,
+		"edits":      len(changes),
+		"editors":    len(editors),
+		"size_delta": latest.NewLen - oldest.OldLen,
+		"score":      len(changes),
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	// Link to the combined diff of all listed edits, or the page itself when it was created in the window
+	pageURL := w.pageURL(latest.Title)
+	if oldest.OldRevID > 0 {
+		pageURL = fmt.Sprintf("https://%s.wikipedia.org/w/index.php?diff=%d&oldid=%d", w.config.Language, latest.RevID, oldest.OldRevID)
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   w.source.ID,
+		ExternalID: strconv.Itoa(latest.PageID),
+		Title:      latest.Title,
+		Author:     latest.User,
+		Content:    strings.Join(summaries, "\n"),
+		URL:        pageURL,
+		WrittenAt:  latest.Timestamp,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
+
+// pageURL returns the canonical article URL for a page title
+func (w *WikipediaSource) pageURL(title string) string {
+	return fmt.Sprintf("https://%s.wikipedia.org/wiki/%s", w.config.Language, url.PathEscape(strings.ReplaceAll(title, " ", "_")))
+}
+
@@ -0,0 +1,48 @@
+package source
+
+import (
+	"encoding/json"
+	"testing"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestWikipediaChangesToArticle(t *testing.T) {
+	src := &WikipediaSource{
+		source: &db.Source{ID: "src"},
+		config: &db.WikipediaConfig{Language: "en"},
+	}
+
+	// Newest first, as returned by list=recentchanges
+	changes := []wikipediaChange{
+		{Type: "edit", Title: "Rust (programming language)", PageID: 7, RevID: 30, OldRevID: 20, User: "bob", Comment: "copyedit", OldLen: 1100, NewLen: 1150,
+			Timestamp: time.Date(2024, 11, 15, 12, 0, 0, 0, time.UTC)},
+		{Type: "edit", Title: "Rust (programming language)", PageID: 7, RevID: 20, OldRevID: 10, User: "ada", OldLen: 1000, NewLen: 1100,
+			Timestamp: time.Date(2024, 11, 15, 9, 0, 0, 0, time.UTC)},
+	}
+
+	article := src.changesToArticle(changes)
+	if article.ExternalID != "7" || article.Author != "bob" || !article.WrittenAt.Equal(changes[0].Timestamp) {
+		t.Errorf("Unexpected article: %+v", article)
+	}
+	if article.URL != "https://en.wikipedia.org/w/index.php?diff=30&oldid=10" {
+		t.Errorf("Unexpected diff URL: %s", article.URL)
+	}
+	if article.Content != "2024-11-15 12:00 (bob): copyedit" {
+		t.Errorf("Unexpected content: %q", article.Content)
+	}
+
+	var counts struct {
+		Edits     int `json:"edits"`
+		Editors   int `json:"editors"`
+		SizeDelta int `json:"size_delta"`
+		Score     int `json:"score"`
+	}
+	if err := json.Unmarshal(article.Metadata, &counts); err != nil {
+		t.Fatalf("Invalid metadata: %v", err)
+	}
+	if counts.Edits != 2 || counts.Editors != 2 || counts.SizeDelta != 150 || counts.Score != 2 {
+		t.Errorf("Unexpected metadata: %s", article.Metadata)
+	}
+}
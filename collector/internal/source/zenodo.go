@@ -0,0 +1,328 @@
+package source
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/cheolwanpark/meows/collector/internal/config"
+	"github.com/cheolwanpark/meows/collector/internal/db"
+	"github.com/google/uuid"
+)
+
+const (
+	zenodoBaseURL         = "https://zenodo.org"
+	zenodoDefaultLimit    = 50
+	zenodoMaxLimit        = 500
+	zenodoGuestPageSize   = 25  // Zenodo caps anonymous searches at 25 records per page
+	zenodoTokenPageSize   = 100 // And authenticated ones at 100
+	zenodoMaxCommunities  = 10
+	zenodoDescriptionSize = 5000
+)
+
+// zenodoResourceTypes are the top-level Zenodo resource types
+var zenodoResourceTypes = []string{"publication", "dataset", "software", "poster", "presentation", "image", "video", "lesson", "physicalobject", "workflow", "model", "other"}
+
+// ZenodoSource implements the Source interface for Zenodo records (datasets, preprints, software, ...)
+type ZenodoSource struct {
+	source *db.Source
+	config *db.ZenodoConfig
+	client *http.Client
+	token  string // Optional, from global config (environment variable)
+}
+
+// Zenodo API response structures
+type zenodoSearchResponse struct {
+	Hits struct {
+		Hits  []zenodoRecord `json:"hits"`
+		Total int            `json:"total"`
+	} `json:"hits"`
+}
+
+type zenodoRecord struct {
+	ID      int64     `json:"id"`
+	DOI     string    `json:"doi"`
+	Created time.Time `json:"created"`
+	Links   struct {
+		HTML     string `json:"html"`
+		SelfHTML string `json:"self_html"`
+	} `json:"links"`
+	Metadata struct {
+		Title           string `json:"title"`
+		Description     string `json:"description"` // HTML
+		PublicationDate string `json:"publication_date"`
+		Creators        []struct {
+			Name        string `json:"name"`
+			Affiliation string `json:"affiliation"`
+		} `json:"creators"`
+		Keywords     []string `json:"keywords"`
+		ResourceType struct {
+			Type    string `json:"type"`
+			Subtype string `json:"subtype"`
+			Title   string `json:"title"`
+		} `json:"resource_type"`
+		Communities []struct {
+			ID string `json:"id"`
+		} `json:"communities"`
+		AccessRight string `json:"access_right"`
+		Version     string `json:"version"`
+		License     struct {
+			ID string `json:"id"`
+		} `json:"license"`
+	} `json:"metadata"`
+	Stats struct {
+		Downloads       int `json:"downloads"`
+		UniqueDownloads int `json:"unique_downloads"`
+		Views           int `json:"views"`
+	} `json:"stats"`
+}
+
+// NewZenodoSource creates a new Zenodo source
+// Searches work anonymously; a token raises the rate limit and page size
+func NewZenodoSource(
+	source *db.Source,
+	credentials *config.CredentialsConfig,
+	httpClient *http.Client,
+) (*ZenodoSource, error) {
+	var config db.ZenodoConfig
+	if err := json.Unmarshal(source.Config, &config); err != nil {
+		return nil, fmt.Errorf("invalid zenodo config: %w", err)
+	}
+
+	zs := &ZenodoSource{
+		source: source,
+		config: &config,
+		client: httpClient, // Shared client with pooled connections
+		token:  credentials.ZenodoToken,
+	}
+
+	return zs, nil
+}
+
+// SourceType returns "zenodo"
+func (z *ZenodoSource) SourceType() string {
+	return "zenodo"
+}
+
+// Validate checks if the configuration is valid
+func (z *ZenodoSource) Validate() error {
+	if strings.TrimSpace(z.config.Query) == "" && len(z.config.Communities) == 0 {
+		return fmt.Errorf("query or communities is required")
+	}
+	if len(z.config.Communities) > zenodoMaxCommunities {
+		return fmt.Errorf("communities must have at most %d entries, got %d", zenodoMaxCommunities, len(z.config.Communities))
+	}
+	if z.config.ResourceType != "" {
+		if err := validateEnum(z.config.ResourceType, zenodoResourceTypes, "resource_type"); err != nil {
+			return err
+		}
+	}
+	for _, date := range []struct{ name, value string }{{"from", z.config.From}, {"to", z.config.To}} {
+		if date.value == "" {
+			continue
+		}
+		if _, err := time.Parse("2006-01-02", date.value); err != nil {
+			return fmt.Errorf("%s must be YYYY-MM-DD, got %q", date.name, date.value)
+		}
+	}
+	if z.config.From != "" && z.config.To != "" && z.config.From > z.config.To {
+		return fmt.Errorf("from must not be after to")
+	}
+
+	// Set defaults
+	if z.config.Limit <= 0 {
+		z.config.Limit = zenodoDefaultLimit
+	}
+	if z.config.Limit > zenodoMaxLimit {
+		return fmt.Errorf("limit must be <= %d, got %d", zenodoMaxLimit, z.config.Limit)
+	}
+
+	return nil
+}
+
+// Fetch retrieves records created since the last run, newest first
+func (z *ZenodoSource) Fetch(ctx context.Context, since time.Time) ([]db.Article, []db.Comment, error) {
+	if err := z.Validate(); err != nil {
+		return nil, nil, err
+	}
+
+	pageSize := zenodoGuestPageSize
+	if z.token != "" {
+		pageSize = zenodoTokenPageSize
+	}
+
+	articles := make([]db.Article, 0)
+	tally := newMatchTally(ctx, z.config.Limit)
+	for page := 1; !tally.done(); page++ {
+		response, err := z.search(ctx, page, pageSize)
+		if err != nil {
+			return nil, nil, err
+		}
+
+		pageStart := len(articles)
+		reachedSince := false
+		for _, record := range response.Hits.Hits {
+			// Sorted by creation time, so the rest is older too
+			if record.Created.Before(since) {
+				reachedSince = true
+				break
+			}
+			article := z.recordToArticle(record)
+			articles = append(articles, article)
+			tally.add(article)
+			if tally.done() {
+				break
+			}
+		}
+		stampProvenance(ctx, articles[pageStart:], page)
+
+		if reachedSince || len(response.Hits.Hits) < pageSize || page*pageSize >= response.Hits.Total {
+			break
+		}
+	}
+
+	// Zenodo has no comments
+	return articles, []db.Comment{}, nil
+}
+
+// search requests one page of records, newest first
+func (z *ZenodoSource) search(ctx context.Context, page, pageSize int) (*zenodoSearchResponse, error) {
+	req, err := http.NewRequestWithContext(ctx, "GET", zenodoBaseURL+"/api/records?"+z.buildParams(page, pageSize).Encode(), nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", "meows-collector/1.0")
+	req.Header.Set("Accept", "application/json")
+	if z.token != "" {
+		req.Header.Set("Authorization", "Bearer "+z.token)
+	}
+
+	resp, err := z.client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+
+	body, err := readBody(resp, contentJSON)
+	resp.Body.Close()
+
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("Zenodo API returned %d: %s", resp.StatusCode, string(body))
+	}
+
+	var response zenodoSearchResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return nil, fmt.Errorf("failed to decode response: %w", err)
+	}
+
+	return &response, nil
+}
+
+// buildParams builds the search query, with the publication date range folded into it
+func (z *ZenodoSource) buildParams(page, pageSize int) url.Values {
+	var clauses []string
+	if q := strings.TrimSpace(z.config.Query); q != "" {
+		clauses = append(clauses, "("+q+")")
+	}
+	if z.config.From != "" || z.config.To != "" {
+		from, to := z.config.From, z.config.To
+		if from == "" {
+			from = "*"
+		}
+		if to == "" {
+			to = "*"
+		}
+		clauses = append(clauses, fmt.Sprintf("publication_date:[%s TO %s]", from, to))
+	}
+
+	params := url.Values{}
+	if len(clauses) > 0 {
+		params.Set("q", strings.Join(clauses, " AND "))
+	}
+	for _, community := range z.config.Communities {
+		params.Add("communities", community)
+	}
+	if z.config.ResourceType != "" {
+		params.Set("type", z.config.ResourceType)
+	}
+	params.Set("sort", "newest")
+	params.Set("size", strconv.Itoa(pageSize))
+	params.Set("page", strconv.Itoa(page))
+	return params
+}
+
+// recordToArticle converts a Zenodo record to an Article
+// The description becomes the content, downloads the score
+func (z *ZenodoSource) recordToArticle(record zenodoRecord) db.Article {
+	authors := make([]string, 0, len(record.Metadata.Creators))
+	for _, creator := range record.Metadata.Creators {
+		authors = append(authors, creator.Name)
+	}
+	var primaryAuthor string
+	if len(authors) > 0 {
+		primaryAuthor = authors[0]
+	}
+
+	communities := make([]string, 0, len(record.Metadata.Communities))
+	for _, c := range record.Metadata.Communities {
+		communities = append(communities, c.ID)
+	}
+
+	resourceType := record.Metadata.ResourceType.Type
+	if record.Metadata.ResourceType.Subtype != "" {
+		resourceType += "/" + record.Metadata.ResourceType.Subtype
+	}
+
+	metadata, err := json.Marshal(map[string]interface{}{
+		"doi":              record.DOI,
+		"resource_type":    resourceType,
+		"publication_date": record.Metadata.PublicationDate,
+		"authors":          authors,
+		"keywords":         record.Metadata.Keywords,
+		"communities":      communities,
+		"access_right":     record.Metadata.AccessRight,
+		"license":          record.Metadata.License.ID,
+		"version":          record.Metadata.Version,
+		"downloads":        record.Stats.Downloads,
+		"views":            record.Stats.Views,
+		"score":            record.Stats.Downloads,
+	})
+	if err != nil {
+		metadata = []byte("{}")
+	}
+
+	link := record.Links.SelfHTML
+	if link == "" {
+		link = record.Links.HTML
+	}
+	if link == "" {
+		link = fmt.Sprintf("%s/records/%d", zenodoBaseURL, record.ID)
+	}
+
+	// Prefer the publication date; fall back to when the record was created
+	writtenAt, err := time.Parse("2006-01-02", record.Metadata.PublicationDate)
+	if err != nil {
+		writtenAt = record.Created
+	}
+
+	return db.Article{
+		ID:         uuid.New().String(),
+		SourceID:   z.source.ID,
+		ExternalID: strconv.FormatInt(record.ID, 10),
+		Title:      collapseSpace(record.Metadata.Title),
+		Author:     primaryAuthor,
+		Content:    truncateRunes(htmlText(record.Metadata.Description), zenodoDescriptionSize),
+		URL:        link,
+		WrittenAt:  writtenAt,
+		Metadata:   metadata,
+		CreatedAt:  time.Now(),
+	}
+}
@@ -0,0 +1,70 @@
+package source
+
+import (
+	"encoding/json"
+	"testing"
+
+	"github.com/cheolwanpark/meows/collector/internal/db"
+)
+
+func TestZenodoBuildParams(t *testing.T) {
+	z := &ZenodoSource{config: &db.ZenodoConfig{
+		Query:        "protein structure",
+		Communities:  []string{"biosyslit", "zenodo"},
+		ResourceType: "dataset",
+		From:         "2024-01-01",
+	}}
+
+	params := z.buildParams(2, zenodoGuestPageSize)
+	if got := params.Get("q"); got != "(protein structure) AND publication_date:[2024-01-01 TO *]" {
+		t.Errorf("Unexpected q: %q", got)
+	}
+	if got := params["communities"]; len(got) != 2 || got[0] != "biosyslit" || got[1] != "zenodo" {
+		t.Errorf("Unexpected communities: %v", got)
+	}
+	if params.Get("type") != "dataset" || params.Get("sort") != "newest" || params.Get("size") != "25" || params.Get("page") != "2" {
+		t.Errorf("Unexpected params: %v", params)
+	}
+}
+
+func TestZenodoRecordToArticle(t *testing.T) {
+	raw := `{
+  "id": 1234567,
+  "doi": "10.5281/zenodo.1234567",
+  "created": "2024-05-02T08:00:00+00:00",
+  "links": {"self_html": "https://zenodo.org/records/1234567"},
+  "metadata": {
+    "title": "Benchmark  dataset",
+    "description": "<p>Structures of <b>proteins</b></p>",
+    "publication_date": "2024-05-01",
+    "creators": [{"name": "Doe, Jane"}, {"name": "Roe, Rich"}],
+    "resource_type": {"type": "dataset"},
+    "communities": [{"id": "biosyslit"}]
+  },
+  "stats": {"downloads": 42, "views": 100}
+}`
+	var record zenodoRecord
+	if err := json.Unmarshal([]byte(raw), &record); err != nil {
+		t.Fatalf("Failed to decode record: %v", err)
+	}
+
+	z := &ZenodoSource{source: &db.Source{ID: "src"}, config: &db.ZenodoConfig{}}
+	article := z.recordToArticle(record)
+	if article.ExternalID != "1234567" || article.Title != "Benchmark dataset" || article.Author != "Doe, Jane" {
+		t.Errorf("Unexpected article: %+v", article)
+	}
+	if article.Content != "Structures of proteins" || article.URL != "https://zenodo.org/records/1234567" {
+		t.Errorf("Unexpected content or URL: %q %q", article.Content, article.URL)
+	}
+	if article.WrittenAt.Format("2006-01-02") != "2024-05-01" {
+		t.Errorf("Expected the publication date, got %v", article.WrittenAt)
+	}
+
+	var metadata map[string]interface{}
+	if err := json.Unmarshal(article.Metadata, &metadata); err != nil {
+		t.Fatal(err)
+	}
+	if metadata["score"] != float64(42) || metadata["doi"] != "10.5281/zenodo.1234567" {
+		t.Errorf("Unexpected metadata: %v", metadata)
+	}
+}
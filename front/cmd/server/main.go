@@ -65,6 +65,7 @@ func main() {
 	r.Get("/articles-partial", h.ArticleListPartial) // HTMX partial for article list
 	r.Get("/articles/{id}", h.ArticleDetail)
 	r.Get("/sources", h.SourcesPage)
+	r.Get("/dashboard", h.Dashboard)
 
 	// Profile routes
 	r.Get("/profiles/setup", h.ProfileSetup)
@@ -115,6 +115,33 @@ type ArticleListResponse struct {
 	Offset   int       `json:"offset"`
 }
 
+// RequestStats counts the HTTP requests a source made during a run
+type RequestStats struct {
+	Requests    int `json:"requests"`
+	Retries     int `json:"retries"`
+	RateLimited int `json:"rate_limited"`
+}
+
+// SourceOutcome represents one source's result in a crawl run
+type SourceOutcome struct {
+	SourceID   string       `json:"source_id"`
+	SourceType string       `json:"source_type"`
+	Status     string       `json:"status"` // "succeeded", "failed" or "skipped"
+	Articles   int          `json:"articles"`
+	Comments   int          `json:"comments"`
+	StartedAt  time.Time    `json:"started_at"`
+	DurationMs int64        `json:"duration_ms"`
+	HTTP       RequestStats `json:"http"`
+	Error      string       `json:"error,omitempty"`
+}
+
+// RunReport represents the current or most recent global crawl run
+type RunReport struct {
+	StartedAt  time.Time       `json:"started_at"`
+	FinishedAt *time.Time      `json:"finished_at,omitempty"` // Nil while the run is in progress
+	Outcomes   []SourceOutcome `json:"outcomes"`
+}
+
 // ErrorResponse represents an error response from the collector
 type ErrorResponse struct {
 	Error string `json:"error"`
@@ -576,6 +603,33 @@ func (c *Client) UnlikeArticle(ctx context.Context, likeID string) error {
 	return nil
 }
 
+// GetLastRun fetches the report of the current or most recent crawl run
+func (c *Client) GetLastRun(ctx context.Context) (*RunReport, error) {
+	url := fmt.Sprintf("%s/schedule/last-run", c.baseURL)
+
+	req, err := http.NewRequestWithContext(ctx, "GET", url, nil)
+	if err != nil {
+		return nil, fmt.Errorf("creating request: %w", err)
+	}
+
+	resp, err := c.httpClient.Do(req)
+	if err != nil {
+		return nil, fmt.Errorf("making request: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, c.parseError(resp)
+	}
+
+	var report RunReport
+	if err := json.NewDecoder(resp.Body).Decode(&report); err != nil {
+		return nil, fmt.Errorf("decoding response: %w", err)
+	}
+
+	return &report, nil
+}
+
 // parseError parses an error response from the collector
 func (c *Client) parseError(resp *http.Response) error {
 	var errResp ErrorResponse
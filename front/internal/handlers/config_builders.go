@@ -6,6 +6,7 @@ import (
 	"net/http"
 	"regexp"
 	"strconv"
+	"strings"
 )
 
 // buildRedditConfig builds and validates a Reddit source configuration from form data
@@ -94,6 +95,16 @@ func buildSemanticScholarConfig(r *http.Request) (map[string]interface{}, error)
 			return nil, errors.New("query is required when mode is 'search'")
 		}
 		config["query"] = query
+
+		// Optional: sort (relevance uses the ranked search, others the bulk search)
+		sort := r.FormValue("sort")
+		if sort != "" {
+			validSorts := map[string]bool{"relevance": true, "publicationDate": true, "citationCount": true}
+			if !validSorts[sort] {
+				return nil, errors.New("sort must be one of: relevance, publicationDate, citationCount")
+			}
+			config["sort"] = sort
+		}
 	} else if mode == "recommendations" {
 		paperID := r.FormValue("paper_id")
 		if paperID == "" {
@@ -141,9 +152,10 @@ func buildHackerNewsConfig(r *http.Request) (map[string]interface{}, error) {
 	validTypes := map[string]bool{
 		"top": true, "new": true, "best": true,
 		"ask": true, "show": true, "job": true,
+		"hiring": true,
 	}
 	if !validTypes[itemType] {
-		return nil, errors.New("item_type must be one of: top, new, best, ask, show, job")
+		return nil, errors.New("item_type must be one of: top, new, best, ask, show, job, hiring")
 	}
 	config["item_type"] = itemType
 
@@ -191,6 +203,21 @@ func buildHackerNewsConfig(r *http.Request) (map[string]interface{}, error) {
 	return config, nil
 }
 
+// parseTags splits a comma-separated tag list, dropping empty and duplicate entries
+func parseTags(s string) []string {
+	var tags []string
+	seen := make(map[string]bool)
+	for _, tag := range strings.Split(s, ",") {
+		tag = strings.TrimSpace(tag)
+		if tag == "" || seen[tag] {
+			continue
+		}
+		seen[tag] = true
+		tags = append(tags, tag)
+	}
+	return tags
+}
+
 // parseIntWithBounds parses a string to int with validation
 func parseIntWithBounds(s string, defaultVal, min, max int, fieldName string) (int, error) {
 	if s == "" {
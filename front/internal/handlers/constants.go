@@ -15,6 +15,9 @@ const (
 
 	// HomePageSize is the number of articles per page on the home page
 	HomePageSize = 10
+
+	// DashboardArticleLimit is the number of recent articles shown on the dashboard
+	DashboardArticleLimit = 10
 )
 
 // Reddit source configuration defaults and constraints
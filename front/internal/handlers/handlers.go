@@ -272,6 +272,57 @@ func (h *Handler) SourcesPage(w http.ResponseWriter, r *http.Request) {
 	component.Render(r.Context(), w)
 }
 
+// Dashboard renders the monitoring page: latest crawl run, per-source health and recent articles
+func (h *Handler) Dashboard(w http.ResponseWriter, r *http.Request) {
+	ctx, cancel := context.WithTimeout(r.Context(), DefaultRequestTimeout)
+	defer cancel()
+
+	// Get CSRF token
+	csrfToken := h.csrf.GetToken(r)
+	h.csrf.SetToken(w, r, csrfToken)
+
+	// Get profile ID from middleware context (if available)
+	profileID, _ := middleware.GetProfileID(r)
+
+	// Sources are required; the page is meaningless without them
+	collectorSources, err := h.collector.GetSources(ctx, profileID)
+	if err != nil {
+		slog.Error("Failed to fetch sources for dashboard", "error", err)
+		w.WriteHeader(http.StatusServiceUnavailable)
+		component := layouts.Base("Error", csrfToken, components.ErrorPage(
+			"Service Unavailable",
+			"Unable to fetch sources. The collector service may be down. Please try again later.",
+		))
+		component.Render(r.Context(), w)
+		return
+	}
+
+	sources := make([]models.Source, 0, len(collectorSources))
+	for _, s := range collectorSources {
+		sources = append(sources, models.FromCollectorSource(s))
+	}
+
+	// The run report and recent articles are optional; their sections render empty on failure
+	report, err := h.collector.GetLastRun(ctx)
+	if err != nil {
+		slog.Warn("Failed to fetch last run report", "error", err)
+	}
+
+	articles := make([]models.Article, 0, DashboardArticleLimit)
+	response, err := h.collector.GetArticles(ctx, DashboardArticleLimit, 0, profileID, false)
+	if err != nil {
+		slog.Warn("Failed to fetch recent articles for dashboard", "error", err)
+	} else {
+		for _, a := range response.Articles {
+			articles = append(articles, models.FromCollectorArticle(a))
+		}
+	}
+
+	// Render page
+	component := pages.DashboardPage(models.NewDashboard(report, sources, articles), csrfToken, profileID)
+	component.Render(r.Context(), w)
+}
+
 // CreateSource handles source creation (htmx endpoint)
 func (h *Handler) CreateSource(w http.ResponseWriter, r *http.Request) {
 	ctx, cancel := context.WithTimeout(r.Context(), DefaultRequestTimeout)
@@ -316,6 +367,19 @@ func (h *Handler) CreateSource(w http.ResponseWriter, r *http.Request) {
 		}
 	}
 
+	// Optional: tags and keyword filters shared by all source types
+	if config != nil {
+		if tags := parseTags(r.FormValue("tags")); len(tags) > 0 {
+			config["tags"] = tags
+		}
+		if keywords := parseTags(r.FormValue("include_keywords")); len(keywords) > 0 {
+			config["include_keywords"] = keywords
+		}
+		if keywords := parseTags(r.FormValue("exclude_keywords")); len(keywords) > 0 {
+			config["exclude_keywords"] = keywords
+		}
+	}
+
 	// If there are errors, return form with errors
 	if errors.HasErrors() {
 		w.WriteHeader(http.StatusUnprocessableEntity)
@@ -6,6 +6,7 @@ import (
 	"net/url"
 	"strings"
 	"time"
+	"unicode"
 )
 
 // RelativeTime converts a timestamp to a human-readable relative time string
@@ -35,6 +36,14 @@ func RelativeTime(t time.Time) string {
 	}
 }
 
+// FormatDuration converts a duration to a short human-readable string (e.g. "850ms", "1m12s")
+func FormatDuration(d time.Duration) string {
+	if d < time.Second {
+		return fmt.Sprintf("%dms", d.Milliseconds())
+	}
+	return d.Round(time.Second).String()
+}
+
 // ExtractDomain extracts the domain from a URL
 func ExtractDomain(rawURL string) string {
 	if rawURL == "" {
@@ -121,6 +130,89 @@ func ParseS2Metadata(metadata json.RawMessage) (citations int, year string) {
 	return data.Citations, data.Year
 }
 
+// ParseKeywordHits extracts the matched include keywords and the snippet around them from article metadata
+func ParseKeywordHits(metadata json.RawMessage) (hits []string, snippet string) {
+	if len(metadata) == 0 {
+		return nil, ""
+	}
+
+	var data struct {
+		KeywordHits    []string `json:"keyword_hits"`
+		KeywordSnippet string   `json:"keyword_snippet"`
+	}
+
+	if err := json.Unmarshal(metadata, &data); err != nil {
+		return nil, ""
+	}
+
+	return data.KeywordHits, data.KeywordSnippet
+}
+
+// TextSegment is a run of text that is or isn't a keyword hit
+type TextSegment struct {
+	Text string
+	Hit  bool
+}
+
+// HighlightKeywords splits text into segments so templates can wrap keyword hits in <mark>
+// Matching ignores case and prefers the longest keyword at each position
+func HighlightKeywords(text string, keywords []string) []TextSegment {
+	if len(keywords) == 0 {
+		return []TextSegment{{Text: text}}
+	}
+
+	// Lowercase rune by rune so positions line up with the original text
+	runes := []rune(text)
+	lower := make([]rune, len(runes))
+	for i, r := range runes {
+		lower[i] = unicode.ToLower(r)
+	}
+	needles := make([][]rune, 0, len(keywords))
+	for _, keyword := range keywords {
+		if needle := []rune(strings.ToLower(keyword)); len(needle) > 0 {
+			needles = append(needles, needle)
+		}
+	}
+
+	var segments []TextSegment
+	plainStart := 0
+	for i := 0; i < len(runes); {
+		length := 0
+		for _, needle := range needles {
+			if len(needle) > length && hasRunePrefix(lower[i:], needle) {
+				length = len(needle)
+			}
+		}
+		if length == 0 {
+			i++
+			continue
+		}
+		if plainStart < i {
+			segments = append(segments, TextSegment{Text: string(runes[plainStart:i])})
+		}
+		segments = append(segments, TextSegment{Text: string(runes[i : i+length]), Hit: true})
+		i += length
+		plainStart = i
+	}
+	if plainStart < len(runes) {
+		segments = append(segments, TextSegment{Text: string(runes[plainStart:])})
+	}
+	return segments
+}
+
+// hasRunePrefix reports whether s starts with prefix
+func hasRunePrefix(s, prefix []rune) bool {
+	if len(s) < len(prefix) {
+		return false
+	}
+	for i, r := range prefix {
+		if s[i] != r {
+			return false
+		}
+	}
+	return true
+}
+
 // ExtractConfigField extracts a specific field from a JSON config
 func ExtractConfigField(config json.RawMessage, field string) string {
 	if len(config) == 0 {
@@ -181,9 +273,9 @@ func DetermineCategory(sourceType string, externalID string) string {
 
 	// For HackerNews, determine category based on story type
 	if sourceType == "hackernews" {
-		// Jobs are categorized as business
+		// Jobs and hiring threads are categorized as business
 		// All other story types (top, new, best, ask, show) are tech-focused
-		if externalID == "job" {
+		if externalID == "job" || externalID == "hiring" {
 			return "business"
 		}
 		return "tech"
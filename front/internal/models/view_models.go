@@ -10,20 +10,22 @@ import (
 
 // Article represents a news article for display in templates
 type Article struct {
-	ID        string
-	SourceID  string
-	Title     string
-	Author    string
-	Content   string // Article body/selftext
-	URL       string
-	Domain    string
-	WrittenAt time.Time
-	TimeAgo   string
-	Score     int
-	Comments  int
-	Source    string // "reddit" or "semantic_scholar"
-	Liked     bool   // Whether current profile has liked this article
-	LikeID    string // ID of the like (for unlike button)
+	ID             string
+	SourceID       string
+	Title          string
+	Author         string
+	Content        string // Article body/selftext
+	URL            string
+	Domain         string
+	WrittenAt      time.Time
+	TimeAgo        string
+	Score          int
+	Comments       int
+	Source         string   // "reddit" or "semantic_scholar"
+	Liked          bool     // Whether current profile has liked this article
+	LikeID         string   // ID of the like (for unlike button)
+	KeywordHits    []string // Include keywords of the source the article matched
+	KeywordSnippet string   // Content around the earliest keyword hit
 }
 
 // FromCollectorArticle converts a collector.Article to a view model Article
@@ -47,6 +49,7 @@ func FromCollectorArticle(a collector.Article) Article {
 		Liked:     a.Liked,
 		LikeID:    a.LikeID,
 	}
+	article.KeywordHits, article.KeywordSnippet = ParseKeywordHits(a.Metadata)
 
 	// Parse metadata based on source type
 	switch sourceType {
@@ -68,17 +71,18 @@ func FromCollectorArticle(a collector.Article) Article {
 
 // Source represents a crawling source for display in templates
 type Source struct {
-	ID            string
-	Type          string
-	Name          string
-	URL           string
-	Category      string
-	CategoryEmoji string
-	Status        string
-	LastRunAt     *time.Time
-	LastRunAgo    string
-	LastError     string
-	IsActive      bool
+	ID             string
+	Type           string
+	Name           string
+	URL            string
+	Category       string
+	CategoryEmoji  string
+	Status         string
+	LastRunAt      *time.Time
+	LastRunAgo     string
+	LastSuccessAgo string
+	LastError      string
+	IsActive       bool
 }
 
 // FromCollectorSource converts a collector.Source to a view model Source
@@ -124,23 +128,25 @@ func FromCollectorSource(s collector.Source) Source {
 		}
 	} else if s.Type == "hackernews" {
 		if s.ExternalID != "" {
-			// ExternalID is the story type (top, new, best, ask, show, job)
+			// ExternalID is the story type (top, new, best, ask, show, job, hiring)
 			storyTypeNames := map[string]string{
-				"top":  "Top Stories",
-				"new":  "New Stories",
-				"best": "Best Stories",
-				"ask":  "Ask HN",
-				"show": "Show HN",
-				"job":  "Jobs",
+				"top":    "Top Stories",
+				"new":    "New Stories",
+				"best":   "Best Stories",
+				"ask":    "Ask HN",
+				"show":   "Show HN",
+				"job":    "Jobs",
+				"hiring": "Who is Hiring",
 			}
 			// Map item_type to actual HN URLs (top→news, new→newest, job→jobs)
 			storyTypeURLs := map[string]string{
-				"top":  "news",
-				"new":  "newest",
-				"best": "best",
-				"ask":  "ask",
-				"show": "show",
-				"job":  "jobs",
+				"top":    "news",
+				"new":    "newest",
+				"best":   "best",
+				"ask":    "ask",
+				"show":   "show",
+				"job":    "jobs",
+				"hiring": "submitted?id=whoishiring",
 			}
 			storyName := storyTypeNames[s.ExternalID]
 			if storyName == "" {
@@ -164,10 +170,13 @@ func FromCollectorSource(s collector.Source) Source {
 		}
 	}
 
-	// Calculate last run time ago
+	// Calculate last run and last success time ago
 	if source.LastRunAt != nil {
 		source.LastRunAgo = RelativeTime(*source.LastRunAt)
 	}
+	if s.LastSuccessAt != nil {
+		source.LastSuccessAgo = RelativeTime(*s.LastSuccessAt)
+	}
 
 	// Determine if active (idle status and no errors)
 	source.IsActive = s.Status == "idle" && s.LastError == ""
@@ -175,6 +184,89 @@ func FromCollectorSource(s collector.Source) Source {
 	return source
 }
 
+// RunOutcome represents one source's result in the latest crawl run for display
+type RunOutcome struct {
+	SourceID    string
+	SourceName  string
+	Status      string // "succeeded", "failed" or "skipped"
+	Articles    int
+	Comments    int
+	Duration    string
+	Requests    int
+	Retries     int
+	RateLimited int
+	Error       string
+}
+
+// Dashboard holds the latest run, source health and recent articles for the dashboard page
+type Dashboard struct {
+	HasRun         bool // False until the collector has started a global run
+	RunInProgress  bool
+	RunStartedAgo  string
+	RunDuration    string
+	Succeeded      int
+	Failed         int
+	Skipped        int
+	Outcomes       []RunOutcome
+	Sources        []Source
+	RecentArticles []Article
+}
+
+// NewDashboard builds the dashboard view model
+// report may be nil when the run report could not be fetched
+func NewDashboard(report *collector.RunReport, sources []Source, articles []Article) Dashboard {
+	dashboard := Dashboard{
+		Sources:        sources,
+		RecentArticles: articles,
+	}
+	if report == nil || report.StartedAt.IsZero() {
+		return dashboard
+	}
+
+	names := make(map[string]string, len(sources))
+	for _, s := range sources {
+		names[s.ID] = s.Name
+	}
+
+	dashboard.HasRun = true
+	dashboard.RunStartedAgo = RelativeTime(report.StartedAt)
+	if report.FinishedAt != nil {
+		dashboard.RunDuration = FormatDuration(report.FinishedAt.Sub(report.StartedAt))
+	} else {
+		dashboard.RunInProgress = true
+	}
+
+	for _, o := range report.Outcomes {
+		switch o.Status {
+		case "succeeded":
+			dashboard.Succeeded++
+		case "failed":
+			dashboard.Failed++
+		case "skipped":
+			dashboard.Skipped++
+		}
+
+		name := names[o.SourceID]
+		if name == "" {
+			name = o.SourceType // Sources of other profiles
+		}
+		dashboard.Outcomes = append(dashboard.Outcomes, RunOutcome{
+			SourceID:    o.SourceID,
+			SourceName:  name,
+			Status:      o.Status,
+			Articles:    o.Articles,
+			Comments:    o.Comments,
+			Duration:    FormatDuration(time.Duration(o.DurationMs) * time.Millisecond),
+			Requests:    o.HTTP.Requests,
+			Retries:     o.HTTP.Retries,
+			RateLimited: o.HTTP.RateLimited,
+			Error:       o.Error,
+		})
+	}
+
+	return dashboard
+}
+
 // Pagination holds pagination state for templates
 type Pagination struct {
 	Page      int